@@ -8,13 +8,16 @@ pub enum Clef {
     G,
     F,
     Percussion,
+
+    /// Tablature, where notes are written as fret numbers instead of by position.
+    Tab,
 }
 
 impl Clef {
     /// Y-position of C0, in steps.
     pub fn offset(self) -> i32 {
         match self {
-            Clef::G | Clef::Percussion => 34,
+            Clef::G | Clef::Percussion | Clef::Tab => 34,
             Clef::F => 22,
         }
     }
@@ -129,6 +132,55 @@ impl NoteModifier {
     }
 }
 
+/// The distance between two pitches, such as a major second, which is 1 step and 2 semitones.
+///
+/// Intervals are upwards if positive and downwards if negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Interval {
+    /// The number of note names between the pitches, e.g., 0 for a unison and 4 for a fifth.
+    pub steps: i8,
+    pub semitones: i8,
+}
+
+impl Interval {
+    pub fn new(steps: i8, semitones: i8) -> Interval {
+        Interval { steps, semitones }
+    }
+
+    pub fn unison() -> Interval {
+        Interval::new(0, 0)
+    }
+
+    /// The number of sharps this interval adds to a key signature, or flats if negative.
+    ///
+    /// A perfect fifth adds one sharp, and a major second adds two.
+    pub fn fifths(self) -> i8 {
+        7 * self.semitones - 12 * self.steps
+    }
+}
+
+/// The key signature that music in `key` is written in after being transposed by `interval`, and
+/// the interval to transpose its notes by.
+///
+/// Keys with more than 7 sharps or flats are written in the enharmonic key instead, e.g., G# major
+/// is written as Ab major, so the interval may be respelled by a diminished second.
+pub fn transpose_key(key: i8, interval: Interval) -> (i8, Interval) {
+    let key = key + interval.fifths();
+    if key > 7 {
+        (
+            key - 12,
+            Interval::new(interval.steps + 1, interval.semitones),
+        )
+    } else if key < -7 {
+        (
+            key + 12,
+            Interval::new(interval.steps - 1, interval.semitones),
+        )
+    } else {
+        (key, interval)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pitch {
     name: NoteName,
@@ -173,6 +225,18 @@ impl Pitch {
         Some(Pitch::new(name, modifier, octave))
     }
 
+    /// Spells a midi note as a natural if it is a white key, and otherwise as a sharp, or as a
+    /// flat in keys with flats.
+    pub fn from_midi(midi: u8, key: i8) -> Pitch {
+        match Pitch::from_base_midi(midi, None) {
+            Some(pitch) => pitch,
+            None if key < 0 => {
+                Pitch::from_base_midi(midi + 1, Some(NoteModifier::SemiDown)).unwrap()
+            }
+            None => Pitch::from_base_midi(midi - 1, Some(NoteModifier::SemiUp)).unwrap(),
+        }
+    }
+
     pub fn a440() -> Pitch {
         Self::new(NoteName::A, None, 4)
     }
@@ -210,6 +274,26 @@ impl Pitch {
     pub fn y(self, clef: Clef) -> f64 {
         (clef.offset() - self.name().index() - 7 * (self.octave() as i32)) as f64 * 125f64
     }
+
+    /// This pitch moved by `interval`.
+    ///
+    /// Pitches which would need a double sharp or flat are respelled, e.g., E# up a major second is
+    /// G, not F##.
+    pub fn transpose(self, interval: Interval) -> Pitch {
+        let steps = i32::from(self.octave) * 7 + self.name.index() + i32::from(interval.steps);
+        let name = NoteName::from_index(steps.rem_euclid(7) as u8).unwrap();
+        let octave = steps.div_euclid(7) as i8;
+        let midi = i32::from(self.midi()) + i32::from(interval.semitones);
+        let base_midi = (i32::from(octave) + 1) * 12 + name as i32;
+
+        match midi - base_midi {
+            0 => Pitch::new(name, None, octave),
+            modifier if modifier.abs() == 1 => {
+                Pitch::new(name, NoteModifier::from_raw(modifier as i8), octave)
+            }
+            modifier => Pitch::from_midi(midi.clamp(0, 127) as u8, modifier.signum() as i8),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -225,4 +309,66 @@ mod tests {
             60
         );
     }
+
+    #[test]
+    fn from_midi() {
+        assert_eq!(Pitch::from_midi(60, 0), Pitch::middle_c());
+        assert_eq!(
+            Pitch::from_midi(61, 0),
+            Pitch::new(NoteName::C, Some(NoteModifier::SemiUp), 4)
+        );
+        assert_eq!(
+            Pitch::from_midi(61, -2),
+            Pitch::new(NoteName::D, Some(NoteModifier::SemiDown), 4)
+        );
+        assert_eq!(Pitch::from_midi(61, -2).midi(), 61);
+    }
+
+    #[test]
+    fn transpose() {
+        let major_second = Interval::new(1, 2);
+        let flat = Some(NoteModifier::SemiDown);
+        let sharp = Some(NoteModifier::SemiUp);
+
+        assert_eq!(
+            Pitch::new(NoteName::B, flat, 3).transpose(major_second),
+            Pitch::middle_c()
+        );
+        assert_eq!(
+            Pitch::new(NoteName::F, sharp, 4).transpose(major_second),
+            Pitch::new(NoteName::G, sharp, 4)
+        );
+        assert_eq!(
+            Pitch::middle_c().transpose(Interval::new(-5, -9)),
+            Pitch::new(NoteName::E, flat, 3)
+        );
+
+        // Double sharps are respelled.
+        assert_eq!(
+            Pitch::new(NoteName::E, sharp, 4).transpose(major_second),
+            Pitch::new(NoteName::G, None, 4)
+        );
+    }
+
+    #[test]
+    fn transpose_keys() {
+        assert_eq!(Interval::new(1, 2).fifths(), 2);
+        assert_eq!(Interval::new(5, 9).fifths(), 3);
+        assert_eq!(Interval::new(-4, -7).fifths(), -1);
+        assert_eq!(Interval::new(7, 12).fifths(), 0);
+
+        // Eb major for a Bb instrument is written in F major.
+        assert_eq!(
+            transpose_key(-3, Interval::new(1, 2)),
+            (-1, Interval::new(1, 2))
+        );
+
+        // B major for an Eb instrument would be G# major, so it is written in Ab major.
+        let (key, interval) = transpose_key(5, Interval::new(5, 9));
+        assert_eq!((key, interval), (-4, Interval::new(6, 9)));
+        assert_eq!(
+            Pitch::new(NoteName::B, None, 3).transpose(interval),
+            Pitch::new(NoteName::A, Some(NoteModifier::SemiDown), 4)
+        );
+    }
 }
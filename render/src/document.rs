@@ -0,0 +1,269 @@
+//! A text format for saving songs.
+//!
+//! A document is a header, `six-eight <version>`, followed by a tree of nodes, one per line. Each
+//! node is a kind followed by `key=value` attributes, and is indented two spaces more than its
+//! parent. Values are words, like `3/4` or `-1`, or quoted strings.
+//!
+//! ```text
+//! six-eight 1
+//! song title="Six Eight" width=215.9 height=279.4
+//!   staff
+//!     signature clef=g time=4/4 key=0
+//!     bar time=4/4
+//!       chord note=-2 dots=0 start=1/4 pitch=60
+//!     signature barline=final
+//! ```
+//!
+//! Kinds and attributes that a reader does not know are ignored, so new ones can be added
+//! without a new version. Changes that older documents must be rewritten for need a migration.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Upgrades for documents saved by older versions. `MIGRATIONS[i]` rewrites a version `i + 1`
+/// document as a version `i + 2` document.
+const MIGRATIONS: &[fn(&mut Node)] = &[];
+
+/// The version of documents that are written.
+pub(crate) const VERSION: usize = MIGRATIONS.len() + 1;
+
+const HEADER: &str = "six-eight";
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct Node {
+    pub kind: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(kind: &str) -> Node {
+        Node {
+            kind: kind.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with(mut self, key: &str, value: impl ToString) -> Node {
+        self.attributes.push((key.to_owned(), value.to_string()));
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The value of an attribute, or None if it is missing or cannot be read as a `T`.
+    pub fn parse<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key)?.parse().ok()
+    }
+
+    fn write(&self, depth: usize, out: &mut String) {
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+        out.push_str(&self.kind);
+        for (key, value) in &self.attributes {
+            write!(out, " {}=", key).unwrap();
+            write_value(value, out);
+        }
+        out.push('\n');
+        for child in &self.children {
+            child.write(depth + 1, out);
+        }
+    }
+}
+
+fn is_word(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./+#".contains(c))
+}
+
+fn write_value(value: &str, out: &mut String) {
+    if is_word(value) {
+        out.push_str(value);
+        return;
+    }
+
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Read a line into a node, without children.
+fn read_line(line: &str) -> Option<Node> {
+    let mut chars = line.chars().peekable();
+    let word = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        let mut word = String::new();
+        while let Some(c) = chars.next_if(|&c| c != ' ' && c != '=') {
+            word.push(c);
+        }
+        word
+    };
+
+    let mut node = Node::new(&word(&mut chars));
+    if node.kind.is_empty() {
+        return None;
+    }
+    while chars.next_if_eq(&' ').is_some() {
+        let key = word(&mut chars);
+        if key.is_empty() || chars.next() != Some('=') {
+            return None;
+        }
+        let value = if chars.next_if_eq(&'"').is_some() {
+            let mut value = String::new();
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => value.push(match chars.next()? {
+                        'n' => '\n',
+                        c => c,
+                    }),
+                    c => value.push(c),
+                }
+            }
+            value
+        } else {
+            word(&mut chars)
+        };
+        node.attributes.push((key, value));
+    }
+
+    if chars.next().is_some() {
+        return None;
+    }
+
+    Some(node)
+}
+
+/// Write a document with a single root node.
+pub(crate) fn write(root: &Node) -> String {
+    let mut out = format!("{} {}\n", HEADER, VERSION);
+    root.write(0, &mut out);
+    out
+}
+
+/// Read a document, upgrading it to the current version. Returns None if it is not a document,
+/// or is from a newer version.
+pub(crate) fn read(text: &str) -> Option<Node> {
+    read_with_migrations(text, MIGRATIONS)
+}
+
+fn read_with_migrations(text: &str, migrations: &[fn(&mut Node)]) -> Option<Node> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+    let mut header = lines.next()?.split(' ');
+    if header.next()? != HEADER {
+        return None;
+    }
+    let version: usize = header.next()?.parse().ok()?;
+    if version == 0 || version > migrations.len() + 1 || header.next().is_some() {
+        return None;
+    }
+
+    // The nodes that are open, from the root to the last node read.
+    let mut open: Vec<Node> = Vec::new();
+    let mut root = None;
+    for line in lines {
+        let content = line.trim_start_matches(' ');
+        let indent = line.len() - content.len();
+        if indent % 2 != 0 || indent / 2 > open.len() {
+            return None;
+        }
+        while open.len() > indent / 2 {
+            let node = open.pop().unwrap();
+            match open.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => root = Some(node),
+            }
+        }
+        if root.is_some() {
+            // There is more than one root.
+            return None;
+        }
+        open.push(read_line(content)?);
+    }
+    while let Some(node) = open.pop() {
+        match open.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => root = Some(node),
+        }
+    }
+
+    let mut root = root?;
+    for migration in &migrations[version - 1..] {
+        migration(&mut root);
+    }
+
+    Some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let root = Node {
+            children: vec![
+                Node::new("staff").with("name", "Tr. \"1\"\\2\nin Bb"),
+                Node {
+                    children: vec![Node::new("chord").with("start", "1/4")],
+                    ..Node::new("bar").with("time", "3/4").with("empty", "")
+                },
+            ],
+            ..Node::new("song").with("title", "Six Eight")
+        };
+
+        let text = write(&root);
+        assert_eq!(
+            text,
+            "six-eight 1\n\
+             song title=\"Six Eight\"\n  \
+               staff name=\"Tr. \\\"1\\\"\\\\2\\nin Bb\"\n  \
+               bar time=3/4 empty=\"\"\n    \
+                 chord start=1/4\n"
+        );
+        assert_eq!(read(&text), Some(root));
+    }
+
+    #[test]
+    fn versions() {
+        fn rename_staves(node: &mut Node) {
+            if node.kind == "stave" {
+                node.kind = "staff".to_owned();
+            }
+            node.children.iter_mut().for_each(rename_staves);
+        }
+        let migrations: &[fn(&mut Node)] = &[rename_staves];
+
+        let old = "six-eight 1\nsong\n  stave\n";
+        let upgraded = read_with_migrations(old, migrations).unwrap();
+        assert_eq!(upgraded.children[0].kind, "staff");
+
+        let current = "six-eight 2\nsong\n  stave\n";
+        let read_current = read_with_migrations(current, migrations).unwrap();
+        assert_eq!(read_current.children[0].kind, "stave");
+
+        assert_eq!(
+            read_with_migrations("six-eight 3\nsong\n", migrations),
+            None
+        );
+        assert_eq!(read("six-eight 2\nsong\n"), None);
+        assert_eq!(read("{\"v\": 1}"), None);
+        assert_eq!(read("six-eight 1\nsong\n   staff\n"), None);
+        assert_eq!(read("six-eight 1\nsong\nsong\n"), None);
+    }
+}
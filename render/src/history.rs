@@ -0,0 +1,290 @@
+use rhythm::components::Bar;
+use specs::{Component, Entity, World, WorldExt};
+use staff::components::{
+    Children, Chord, Context, DynamicMark, Hairpin, Harmony, RehearsalMark, Signature, Simile,
+    Slur, Song, Staff, TabStaff,
+};
+use stencil::components::Parent;
+
+/// Older undo steps are forgotten once there are this many.
+const MAX_UNDO_STEPS: usize = 200;
+
+/// The components of an entity that are set through `NativeSixDom`, as opposed to the ones that
+/// systems derive from them.
+#[derive(Debug, Default, Clone)]
+struct EntityState {
+    parent: Option<Parent>,
+    children: Option<Children>,
+    song: Option<Song>,
+    staff: Option<Staff>,
+    tab_staff: Option<TabStaff>,
+    bar: Option<Bar>,
+    chord: Option<Chord>,
+    context: Option<Context>,
+    signature: Option<Signature>,
+    simile: Option<Simile>,
+    dynamic_mark: Option<DynamicMark>,
+    hairpin: Option<Hairpin>,
+    harmony: Option<Harmony>,
+    rehearsal_mark: Option<RehearsalMark>,
+    slur: Option<Slur>,
+}
+
+fn get<T: Component + Clone>(world: &World, entity: Entity) -> Option<T> {
+    world.read_component::<T>().get(entity).cloned()
+}
+
+/// Set or remove a component. `keep` copies derived fields from the current value, if any.
+fn put<T: Component>(
+    world: &World,
+    entity: Entity,
+    saved: Option<T>,
+    keep: impl FnOnce(&T, &mut T),
+) {
+    let mut storage = world.write_component::<T>();
+    match saved {
+        Some(mut saved) => {
+            if let Some(current) = storage.get(entity) {
+                keep(current, &mut saved);
+            }
+            storage.insert(entity, saved).unwrap();
+        }
+        None => {
+            storage.remove(entity);
+        }
+    }
+}
+
+impl EntityState {
+    fn capture(world: &World, entity: Entity) -> EntityState {
+        EntityState {
+            parent: get(world, entity),
+            children: get(world, entity),
+            song: get(world, entity),
+            staff: get(world, entity),
+            tab_staff: get(world, entity),
+            bar: get(world, entity),
+            chord: get(world, entity),
+            context: get(world, entity),
+            signature: get(world, entity),
+            simile: get(world, entity),
+            dynamic_mark: get(world, entity),
+            hairpin: get(world, entity),
+            harmony: get(world, entity),
+            rehearsal_mark: get(world, entity),
+            slur: get(world, entity),
+        }
+    }
+
+    fn restore(self, world: &World, entity: Entity) {
+        // Managed rests are only attached to the bar that manages them.
+        if let Some(bar) = &self.bar {
+            let mut parents = world.write_component::<Parent>();
+            if let Some(current) = world.read_component::<Bar>().get(entity) {
+                for rest in current.managed() {
+                    if !bar.managed().contains(rest) {
+                        parents.remove(*rest);
+                    }
+                }
+            }
+            for rest in bar.managed() {
+                if world.is_alive(*rest) {
+                    parents.insert(*rest, Parent(entity)).unwrap();
+                }
+            }
+        }
+
+        put(world, entity, self.parent, |_, _| {});
+        put(world, entity, self.children, |_, _| {});
+        put(world, entity, self.song, |current, saved| {
+            saved.prev_freeze_spacing = current.prev_freeze_spacing;
+            saved.prev_rastal_size = current.prev_rastal_size;
+            saved.chord_legend_stencil = current.chord_legend_stencil;
+            saved.title_stencil = current.title_stencil;
+            saved.author_stencil = current.author_stencil;
+            saved.part_name_stencil = current.part_name_stencil;
+        });
+        put(world, entity, self.staff, |current, saved| {
+            saved.lines = current.lines.clone();
+            saved.multirests = current.multirests.clone();
+            saved.bar_numbers = current.bar_numbers.clone();
+            saved.mirrors = current.mirrors.clone();
+        });
+        put(world, entity, self.tab_staff, |_, _| {});
+        put(world, entity, self.bar, |_, _| {});
+        put(world, entity, self.chord, |_, _| {});
+        put(world, entity, self.context, |_, _| {});
+        put(world, entity, self.signature, |_, _| {});
+        put(world, entity, self.simile, |_, _| {});
+        put(world, entity, self.dynamic_mark, |_, _| {});
+        put(world, entity, self.hairpin, |current, saved| {
+            saved.segments = current.segments.clone();
+        });
+        put(world, entity, self.harmony, |_, _| {});
+        put(world, entity, self.rehearsal_mark, |current, saved| {
+            saved.text = current.text.clone();
+        });
+        put(world, entity, self.slur, |current, saved| {
+            saved.segments = current.segments.clone();
+        });
+    }
+
+    /// Entities that must stay alive for this state to be restored.
+    fn references(&self) -> Vec<Entity> {
+        let mut references = Vec::new();
+        references.extend(self.parent.as_ref().map(|parent| parent.0));
+        if let Some(children) = &self.children {
+            references.extend(children.0.iter().copied());
+        }
+        if let Some(song) = &self.song {
+            references.extend(song.score);
+            references.extend(song.parts.iter().copied());
+        }
+        if let Some(staff) = &self.staff {
+            references.extend(staff.link);
+        }
+        if let Some(bar) = &self.bar {
+            references.extend(bar.rhythm().iter().filter_map(|(_, l)| l.to_option()));
+            references.extend(bar.managed().iter().copied());
+        }
+        if let Some(hairpin) = &self.hairpin {
+            references.push(hairpin.end_bar);
+        }
+        if let Some(slur) = &self.slur {
+            references.push(slur.start);
+            references.push(slur.end);
+        }
+        references
+    }
+}
+
+/// One undo step: what each entity it changed was like before.
+#[derive(Debug, Default)]
+struct Command(Vec<(Entity, EntityState)>);
+
+impl Command {
+    /// Restore the saved states, and return a command that restores the states they replace.
+    fn apply(self, world: &World) -> Command {
+        let states: Vec<_> = self
+            .0
+            .into_iter()
+            .filter(|(entity, _)| world.is_alive(*entity))
+            .collect();
+        let inverse = states
+            .iter()
+            .map(|(entity, _)| (*entity, EntityState::capture(world, *entity)))
+            .collect();
+        for (entity, state) in states {
+            state.restore(world, entity);
+        }
+
+        Command(inverse)
+    }
+
+    fn retained(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0
+            .iter()
+            .flat_map(|(entity, state)| std::iter::once(*entity).chain(state.references()))
+    }
+}
+
+/// Undo and redo stacks for edits made through `NativeSixDom`.
+///
+/// Edits are recorded before they are made. Edits made while a group is open, like every edit
+/// in a single drag, are undone together.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Command>,
+    redo: Vec<Command>,
+
+    /// The step that edits are added to while a group is open.
+    group: Option<Command>,
+    depth: usize,
+}
+
+impl History {
+    /// Save the state of `entities`, which are about to be changed.
+    pub fn record(&mut self, world: &World, entities: &[Entity]) {
+        let group = self.group.get_or_insert_with(Command::default);
+        for &entity in entities {
+            if world.is_alive(entity) && group.0.iter().all(|(e, _)| *e != entity) {
+                group.0.push((entity, EntityState::capture(world, entity)));
+            }
+        }
+        if !group.0.is_empty() {
+            self.redo.clear();
+        }
+
+        if self.depth == 0 {
+            self.close_group();
+        }
+    }
+
+    pub fn begin_group(&mut self) {
+        self.depth += 1;
+    }
+
+    pub fn end_group(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            self.close_group();
+        }
+    }
+
+    fn close_group(&mut self) {
+        self.depth = 0;
+        if let Some(group) = self.group.take() {
+            if !group.0.is_empty() {
+                self.undo.push(group);
+                if self.undo.len() > MAX_UNDO_STEPS {
+                    self.undo.remove(0);
+                }
+            }
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || self.group.as_ref().is_some_and(|group| !group.0.is_empty())
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Revert the last step, closing any open group. Returns false if there was nothing to undo.
+    pub fn undo(&mut self, world: &World) -> bool {
+        self.close_group();
+        match self.undo.pop() {
+            Some(command) => {
+                self.redo.push(command.apply(world));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapply the last undone step. Returns false if there was nothing to redo.
+    pub fn redo(&mut self, world: &World) -> bool {
+        self.close_group();
+        match self.redo.pop() {
+            Some(command) => {
+                self.undo.push(command.apply(world));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = History::default();
+    }
+
+    /// Entities that can be brought back by undo or redo, so must not be deleted.
+    pub fn retained(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.undo
+            .iter()
+            .chain(self.redo.iter())
+            .chain(self.group.iter())
+            .flat_map(Command::retained)
+    }
+}
@@ -52,8 +52,8 @@ mod tests {
     fn it_works() {
         assert_eq!(JsFrac::reduce(4, 2), vec![2, 1]);
         assert_eq!(JsFrac::add(1, 2, 1, 4), vec![3, 4]);
-        assert_eq!(JsFrac::gt(1, 2, 1, 3), true);
-        assert_eq!(JsFrac::lt(1, 2, 1, 3), false);
-        assert_eq!(JsFrac::eq(1, 2, 1, 3), false);
+        assert!(JsFrac::gt(1, 2, 1, 3));
+        assert!(!JsFrac::lt(1, 2, 1, 3));
+        assert!(!JsFrac::eq(1, 2, 1, 3));
     }
 }
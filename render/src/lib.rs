@@ -1,7 +1,11 @@
-#![allow(clippy::blacklisted_name)]
+#![allow(clippy::disallowed_names)]
 
 mod components;
+mod document;
+mod history;
 mod jsfrac;
+mod midi;
 mod native_six_dom;
 mod state;
+mod svg;
 mod systems;
@@ -0,0 +1,349 @@
+//! A minimal Standard MIDI File writer.
+
+use num_rational::Rational;
+use staff::components::{Dynamic, Grace, HairpinKind};
+
+/// Ticks per quarter note.
+const DIVISION: u16 = 480;
+
+/// Microseconds per quarter note, for 120 BPM.
+const TEMPO: u32 = 500_000;
+
+/// The velocity of notes before the first dynamic mark.
+const DEFAULT_VELOCITY: u8 = 80;
+
+/// How much a hairpin changes the velocity, if no dynamic mark follows it.
+const HAIRPIN_CHANGE: u8 = 24;
+
+/// The longest a grace note is played for, in whole notes.
+const GRACE_DURATION: (isize, isize) = (1, 32);
+
+/// A note, with times in whole notes from the start of the song.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct MidiNote {
+    pub start: Rational,
+    pub duration: Rational,
+    pub key: u8,
+    pub velocity: u8,
+}
+
+/// The pitched or percussion notes of a staff.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct MidiTrack {
+    pub notes: Vec<MidiNote>,
+
+    /// If true, the notes are General MIDI percussion keys, and are played on channel 10.
+    pub percussion: bool,
+}
+
+/// The dynamics of a staff, with times in whole notes from the start of the song.
+#[derive(Debug, Default)]
+pub(crate) struct DynamicTimeline {
+    marks: Vec<(Rational, Dynamic)>,
+    hairpins: Vec<(Rational, Rational, HairpinKind)>,
+}
+
+impl DynamicTimeline {
+    pub fn add_mark(&mut self, t: Rational, dynamic: Dynamic) {
+        self.marks.push((t, dynamic));
+        self.marks.sort_by_key(|(t, _)| *t);
+    }
+
+    pub fn add_hairpin(&mut self, start: Rational, end: Rational, kind: HairpinKind) {
+        self.hairpins.push((start, end, kind));
+    }
+
+    /// The velocity of notes after all marks at or before `t`.
+    fn sustained_velocity(&self, t: Rational) -> u8 {
+        self.marks
+            .iter()
+            .take_while(|(mark_t, _)| *mark_t <= t)
+            .fold(DEFAULT_VELOCITY, |prev, (_, dynamic)| {
+                dynamic.sustained_velocity(prev)
+            })
+    }
+
+    /// The velocity of a note starting at `t`.
+    ///
+    /// Notes on a mark take the mark's velocity. Notes during a hairpin are interpolated between
+    /// the velocity at its start and the mark at its end.
+    pub fn velocity(&self, t: Rational) -> u8 {
+        if let Some((_, dynamic)) = self.marks.iter().find(|(mark_t, _)| *mark_t == t) {
+            return dynamic.velocity();
+        }
+
+        for &(start, end, kind) in &self.hairpins {
+            if t > start && t < end {
+                let from = self.sustained_velocity(start);
+                let to = self
+                    .marks
+                    .iter()
+                    .find(|(mark_t, _)| *mark_t == end)
+                    .map(|(_, dynamic)| dynamic.velocity())
+                    .unwrap_or_else(|| match kind {
+                        HairpinKind::Crescendo => from.saturating_add(HAIRPIN_CHANGE).min(127),
+                        HairpinKind::Decrescendo => from.saturating_sub(HAIRPIN_CHANGE).max(1),
+                    });
+                let progress = (t - start) / (end - start);
+                let progress = *progress.numer() as f64 / *progress.denom() as f64;
+
+                return (from as f64 + (to as f64 - from as f64) * progress).round() as u8;
+            }
+        }
+
+        self.sustained_velocity(t)
+    }
+}
+
+/// When the first of `count` grace notes before a note at `start` is played, and how long each
+/// grace note is.
+///
+/// Appoggiaturas are played on the beat, and steal time from the main note. Acciaccaturas are
+/// played just before the beat, and steal time from the previous note, unless there is none.
+/// Grace notes never take more than half of the main note.
+pub(crate) fn grace_timing(
+    grace: Grace,
+    count: usize,
+    start: Rational,
+    duration: Rational,
+) -> (Rational, Rational) {
+    let count = count.max(1) as isize;
+    let length = Rational::new(GRACE_DURATION.0, GRACE_DURATION.1).min(duration / (2 * count));
+    let total = length * count;
+
+    match grace {
+        Grace::Acciaccatura if start >= total => (start - total, length),
+        Grace::Acciaccatura | Grace::Appoggiatura => (start, length),
+    }
+}
+
+fn ticks(t: Rational) -> u32 {
+    let t = t * (DIVISION as isize * 4);
+    (*t.numer() as f64 / *t.denom() as f64).round().max(0.0) as u32
+}
+
+fn write_var_len(out: &mut Vec<u8>, mut value: u32) {
+    let mut bytes = vec![(value & 0x7f) as u8];
+    value >>= 7;
+    while value > 0 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.reverse();
+    out.extend(bytes);
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend(kind);
+    out.extend(&(data.len() as u32).to_be_bytes());
+    out.extend(data);
+}
+
+/// The channel for percussion tracks, which is channel 10 counting from 1.
+const PERCUSSION_CHANNEL: u8 = 9;
+
+/// The channel for the nth track, skipping channel 10, which is for percussion.
+fn channel(track: usize) -> u8 {
+    let channel = (track % 15) as u8;
+    if channel >= 9 {
+        channel + 1
+    } else {
+        channel
+    }
+}
+
+/// Write a format 1 MIDI file, with a tempo track followed by one track per staff.
+///
+/// `markers` are rehearsal marks and section labels, which are written to the tempo track.
+pub(crate) fn write_midi(tracks: &[MidiTrack], markers: &[(Rational, String)]) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    let mut header = Vec::new();
+    header.extend(&1u16.to_be_bytes());
+    header.extend(&(tracks.len() as u16 + 1).to_be_bytes());
+    header.extend(&DIVISION.to_be_bytes());
+    write_chunk(&mut out, b"MThd", &header);
+
+    let mut tempo_track = vec![0x00, 0xff, 0x51, 0x03];
+    tempo_track.extend(&TEMPO.to_be_bytes()[1..]);
+    let mut markers = markers.to_vec();
+    markers.sort_by_key(|(t, _)| *t);
+    let mut prev_tick = 0;
+    for (t, text) in markers {
+        let tick = ticks(t);
+        write_var_len(&mut tempo_track, tick - prev_tick);
+        prev_tick = tick;
+        tempo_track.extend(&[0xff, 0x06]);
+        write_var_len(&mut tempo_track, text.len() as u32);
+        tempo_track.extend(text.as_bytes());
+    }
+    tempo_track.extend(&[0x00, 0xff, 0x2f, 0x00]);
+    write_chunk(&mut out, b"MTrk", &tempo_track);
+
+    for (i, track) in tracks.iter().enumerate() {
+        let channel = if track.percussion {
+            PERCUSSION_CHANNEL
+        } else {
+            channel(i)
+        };
+
+        // (tick, is_on, key, velocity). Note offs sort before note ons at the same tick.
+        let mut events: Vec<(u32, bool, u8, u8)> = track
+            .notes
+            .iter()
+            .flat_map(|note| {
+                vec![
+                    (ticks(note.start), true, note.key, note.velocity),
+                    (ticks(note.start + note.duration), false, note.key, 0),
+                ]
+            })
+            .collect();
+        events.sort_by_key(|&(tick, is_on, key, _)| (tick, is_on, key));
+
+        let mut track = Vec::new();
+        let mut prev_tick = 0;
+        for (tick, is_on, key, velocity) in events {
+            write_var_len(&mut track, tick - prev_tick);
+            prev_tick = tick;
+            if is_on {
+                track.extend(&[0x90 | channel, key & 0x7f, velocity.max(1) & 0x7f]);
+            } else {
+                track.extend(&[0x80 | channel, key & 0x7f, 0]);
+            }
+        }
+        track.extend(&[0x00, 0xff, 0x2f, 0x00]);
+        write_chunk(&mut out, b"MTrk", &track);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn var_len() {
+        let encode = |value| {
+            let mut out = vec![];
+            write_var_len(&mut out, value);
+            out
+        };
+        assert_eq!(encode(0), vec![0x00]);
+        assert_eq!(encode(0x7f), vec![0x7f]);
+        assert_eq!(encode(0x80), vec![0x81, 0x00]);
+        assert_eq!(encode(0x0fff_ffff), vec![0xff, 0xff, 0xff, 0x7f]);
+    }
+
+    #[test]
+    fn velocities() {
+        let mut timeline = DynamicTimeline::default();
+        assert_eq!(timeline.velocity(Rational::new(0, 1)), DEFAULT_VELOCITY);
+
+        timeline.add_mark(Rational::new(0, 1), Dynamic::Piano);
+        timeline.add_mark(Rational::new(1, 1), Dynamic::Forte);
+        timeline.add_hairpin(
+            Rational::new(0, 1),
+            Rational::new(1, 1),
+            HairpinKind::Crescendo,
+        );
+        timeline.add_mark(Rational::new(2, 1), Dynamic::Sforzando);
+
+        assert_eq!(timeline.velocity(Rational::new(0, 1)), 49);
+        assert_eq!(timeline.velocity(Rational::new(1, 2)), 73);
+        assert_eq!(timeline.velocity(Rational::new(1, 1)), 96);
+        assert_eq!(timeline.velocity(Rational::new(2, 1)), 127);
+        // Sforzandos only last for one note.
+        assert_eq!(timeline.velocity(Rational::new(9, 4)), 96);
+
+        // Without a mark at the end, hairpins change the velocity by a fixed amount.
+        timeline.add_hairpin(
+            Rational::new(3, 1),
+            Rational::new(4, 1),
+            HairpinKind::Decrescendo,
+        );
+        assert_eq!(timeline.velocity(Rational::new(7, 2)), 84);
+    }
+
+    #[test]
+    fn grace_notes() {
+        let quarter = Rational::new(1, 4);
+        assert_eq!(
+            grace_timing(Grace::Appoggiatura, 2, quarter, quarter),
+            (quarter, Rational::new(1, 32))
+        );
+        assert_eq!(
+            grace_timing(Grace::Acciaccatura, 2, quarter, quarter),
+            (Rational::new(3, 16), Rational::new(1, 32))
+        );
+
+        // At the start of the song, there is nothing to steal from.
+        assert_eq!(
+            grace_timing(Grace::Acciaccatura, 1, Rational::new(0, 1), quarter),
+            (Rational::new(0, 1), Rational::new(1, 32))
+        );
+
+        // Short notes are not swallowed by their grace notes.
+        assert_eq!(
+            grace_timing(Grace::Appoggiatura, 2, quarter, Rational::new(1, 16)),
+            (quarter, Rational::new(1, 64))
+        );
+    }
+
+    #[test]
+    fn file() {
+        let note = MidiNote {
+            start: Rational::new(0, 1),
+            duration: Rational::new(1, 4),
+            key: 60,
+            velocity: 80,
+        };
+        let mut track = MidiTrack {
+            notes: vec![note],
+            percussion: false,
+        };
+        let midi = write_midi(&[track.clone()], &[]);
+
+        assert_eq!(&midi[0..4], b"MThd");
+        assert_eq!(&midi[8..14], &[0, 1, 0, 2, 0x01, 0xe0]);
+        assert_eq!(
+            &midi[midi.len() - 21..],
+            &[
+                b'M', b'T', b'r', b'k', 0, 0, 0, 13, // Header
+                0x00, 0x90, 60, 80, // Note on
+                0x83, 0x60, 0x80, 60, 0, // Note off, a quarter later
+                0x00, 0xff, 0x2f, 0x00, // End of track
+            ]
+        );
+
+        // Percussion is always on channel 10.
+        track.percussion = true;
+        let midi = write_midi(&[track], &[]);
+        assert_eq!(
+            &midi[midi.len() - 13..midi.len() - 9],
+            &[0x00, 0x99, 60, 80]
+        );
+    }
+
+    #[test]
+    fn markers() {
+        let midi = write_midi(
+            &[],
+            &[
+                (Rational::new(1, 1), "B".to_owned()),
+                (Rational::new(0, 1), "A".to_owned()),
+            ],
+        );
+
+        assert_eq!(
+            &midi[14..],
+            &[
+                b'M', b'T', b'r', b'k', 0, 0, 0, 22, // Header
+                0x00, 0xff, 0x51, 0x03, 0x07, 0xa1, 0x20, // Tempo
+                0x00, 0xff, 0x06, 1, b'A', // Marker
+                0x8f, 0x00, 0xff, 0x06, 1, b'B', // Marker, a whole note later
+                0x00, 0xff, 0x2f, 0x00, // End of track
+            ]
+        );
+    }
+}
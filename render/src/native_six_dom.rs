@@ -1,23 +1,34 @@
-#![allow(clippy::blacklisted_name)]
+#![allow(clippy::disallowed_names)]
 
 use crate::components::Css;
+use crate::document::{self, Node};
+use crate::history::History;
+use crate::midi::{grace_timing, write_midi, DynamicTimeline, MidiNote, MidiTrack};
 use crate::state::State;
+use crate::svg::SvgWriter;
 use kurbo::{Affine, Size, Vec2};
 use num_rational::Rational;
-use pitch::{Clef, NoteModifier, Pitch};
+use pitch::{Clef, Interval, NoteModifier, Pitch};
 use rhythm::{
     components::{Bar, Spacing},
-    BarChild, Duration, Lifetime, Metre, NoteValue,
+    BarChild, Duration, Lifetime, Metre, NoteValue, MAX_DOTS,
 };
 use specs::{world::Builder, Entity, Join, WorldExt};
 use staff::{
-    components::{Children, Chord, Context, Cursor, FlagAttachment, Signature, Song, Staff},
+    components::{
+        Accidental, Articulation, BarNumbering, BeamOverride, BeamingStyle, Children, Chord,
+        ChordDiagrams, Context, Cursor, Dynamic, DynamicMark, FlagAttachment, Grace, Hairpin,
+        HairpinKind, Harmony, LegerLine, Linked, RehearsalMark, RehearsalMarkStyle, Signature,
+        Simile, Slur, Song, Staff, StemDirection, TabStaff,
+    },
     resources::Root,
-    Barline, PitchKind,
+    Barline, ChordSymbol, DrumKit, Instrument, PercussionInstrument, PitchKind, Voicing,
 };
+use std::collections::HashMap;
+use std::ops::Range;
 use stencil::{
     components::{Parent, Stencil, StencilMap, WorldBbox},
-    Pdf,
+    embedded_files, Pdf, Raster,
 };
 use wasm_bindgen::prelude::*;
 
@@ -79,14 +90,20 @@ impl NativeSixDom {
     pub fn child_append(&mut self, parent: u32, child: u32) {
         let parent = self.state.world.entities().entity(parent);
         let child = self.state.world.entities().entity(child);
+        if self.state.world.read_component::<Parent>().contains(child)
+            || !self
+                .state
+                .world
+                .read_component::<Children>()
+                .contains(parent)
+        {
+            return;
+        }
+        self.record(&[parent, child]);
 
         let mut parents = self.state.world.write_component::<Parent>();
         let mut children = self.state.world.write_component::<Children>();
 
-        if parents.contains(child) {
-            return;
-        }
-
         if let Some(ordered_children) = children.get_mut(parent) {
             ordered_children.0.push(child);
             parents.insert(child, Parent(parent)).unwrap();
@@ -101,14 +118,20 @@ impl NativeSixDom {
         let before = self.state.world.entities().entity(before);
         let child = self.state.world.entities().entity(child);
         let parent = self.state.world.entities().entity(parent);
+        let has_before = self
+            .state
+            .world
+            .read_component::<Children>()
+            .get(parent)
+            .is_some_and(|children| children.0.contains(&before));
+        if self.state.world.read_component::<Parent>().contains(child) || !has_before {
+            return;
+        }
+        self.record(&[parent, child]);
 
         let mut parents = self.state.world.write_component::<Parent>();
         let mut children = self.state.world.write_component::<Children>();
 
-        if parents.contains(child) {
-            return;
-        }
-
         if let Some(ordered_children) = children.get_mut(parent) {
             if let Some(idx) = ordered_children.0.iter().position(|&x| x == before) {
                 ordered_children.0.insert(idx, child);
@@ -123,6 +146,16 @@ impl NativeSixDom {
     pub fn child_remove(&mut self, parent: u32, exchild: u32) {
         let parent = self.state.world.entities().entity(parent);
         let exchild = self.state.world.entities().entity(exchild);
+        let has_child = self
+            .state
+            .world
+            .read_component::<Children>()
+            .get(parent)
+            .is_some_and(|children| children.0.contains(&exchild));
+        if !has_child {
+            return;
+        }
+        self.record(&[parent, exchild]);
 
         let mut parents = self.state.world.write_component::<Parent>();
         let mut children = self.state.world.write_component::<Children>();
@@ -159,6 +192,7 @@ impl NativeSixDom {
 
     pub fn song_set_size(&mut self, song: u32, width: f64, height: f64) {
         let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
         let mut songs = self.state.world.write_component::<Song>();
 
         if let Some(song) = songs.get_mut(song) {
@@ -167,8 +201,180 @@ impl NativeSixDom {
         }
     }
 
+    /// Set the rastal size, from 0 (large) to 8 (small). See `Song::rastal_size`.
+    ///
+    /// Out-of-range sizes are ignored.
+    pub fn song_set_rastal_size(&mut self, song: u32, rastal_size: u8) {
+        if rastal_size > 8 {
+            return;
+        }
+
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.rastal_size = rastal_size;
+        }
+    }
+
+    pub fn song_set_beaming_style(&mut self, song: u32, beaming_style: BeamingStyle) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.beaming_style = beaming_style;
+        }
+    }
+
+    /// Draw consecutive empty bars as multi-measure rests.
+    pub fn song_set_multirests(&mut self, song: u32, multirests: bool) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.multirests = multirests;
+        }
+    }
+
+    pub fn song_set_cautionary_accidentals(&mut self, song: u32, cautionary_accidentals: bool) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.cautionary_accidentals = cautionary_accidentals;
+        }
+    }
+
+    /// Write transposing instruments at the pitch they sound, instead of the pitch the player
+    /// reads.
+    pub fn song_set_concert_pitch(&mut self, song: u32, concert_pitch: bool) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.concert_pitch = concert_pitch;
+        }
+    }
+
+    pub fn song_set_drum_kit(&mut self, song: u32, drum_kit: DrumKit) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.drum_kit = drum_kit;
+        }
+    }
+
+    /// Show fretboard diagrams for chord symbols, for an instrument with the given tuning.
+    ///
+    /// `tuning` is the pitch of each open string as a midi note, from the highest string to the
+    /// lowest.
+    pub fn song_set_chord_diagrams(
+        &mut self,
+        song: u32,
+        chord_diagrams: ChordDiagrams,
+        tuning: Vec<u8>,
+    ) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.chord_diagrams = chord_diagrams;
+            song.chord_diagram_tuning = tuning;
+        }
+    }
+
+    /// Set the voicing shown in the diagram of a chord symbol, written from the lowest string, as
+    /// in "x32010". Pass None to generate one.
+    ///
+    /// Returns false if the symbol or voicing cannot be read.
+    pub fn song_set_chord_voicing(
+        &mut self,
+        song: u32,
+        symbol: &str,
+        voicing: Option<String>,
+    ) -> bool {
+        let song = self.state.world.entities().entity(song);
+        let symbol = match ChordSymbol::parse(symbol) {
+            Some(symbol) if self.state.world.read_component::<Song>().contains(song) => {
+                symbol.to_string()
+            }
+            _ => return false,
+        };
+        let voicing = match voicing.map(|voicing| Voicing::parse(&voicing)) {
+            Some(Some(voicing)) => Some(voicing),
+            Some(None) => return false,
+            None => None,
+        };
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            match voicing {
+                Some(voicing) => {
+                    song.chord_voicings.insert(symbol, voicing);
+                }
+                None => {
+                    song.chord_voicings.remove(&symbol);
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn song_set_rehearsal_mark_style(&mut self, song: u32, style: RehearsalMarkStyle) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.rehearsal_mark_style = style;
+        }
+    }
+
+    /// Set which bars have their number printed above them.
+    ///
+    /// `interval` is only used for `BarNumbering::Interval`.
+    pub fn song_set_bar_numbering(
+        &mut self,
+        song: u32,
+        bar_numbering: BarNumbering,
+        interval: usize,
+        boxed: bool,
+    ) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.bar_numbering = bar_numbering;
+            song.bar_number_interval = interval;
+            song.bar_numbers_boxed = boxed;
+        }
+    }
+
+    /// Set the number of the first full bar.
+    pub fn song_set_first_bar_number(&mut self, song: u32, first_bar_number: isize) {
+        let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        if let Some(song) = songs.get_mut(song) {
+            song.first_bar_number = first_bar_number;
+        }
+    }
+
     pub fn song_set_title(&mut self, song: u32, title: &str, width: f64) {
         let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
         let mut songs = self.state.world.write_component::<Song>();
 
         if let Some(song) = songs.get_mut(song) {
@@ -179,6 +385,7 @@ impl NativeSixDom {
 
     pub fn song_set_author(&mut self, song: u32, author: &str, width: f64) {
         let song = self.state.world.entities().entity(song);
+        self.record(&[song]);
         let mut songs = self.state.world.write_component::<Song>();
 
         if let Some(song) = songs.get_mut(song) {
@@ -187,6 +394,78 @@ impl NativeSixDom {
         }
     }
 
+    /// Extract a part for `staff`, a staff of the song `score`, as a new song which shows only that
+    /// staff. Set it as the root to show it.
+    ///
+    /// The part shows the bars of the staff, so edits to either apply to both, but it has its own
+    /// page and layout settings. It is kept alive with the score until it is removed.
+    pub fn part_create(&mut self, score: u32, staff: u32) -> Option<u32> {
+        let score = self.state.world.entities().entity(score);
+        let staff = self.state.world.entities().entity(staff);
+        if self.state.world.read_component::<Parent>().get(staff) != Some(&Parent(score)) {
+            return None;
+        }
+        let part_song = self
+            .state
+            .world
+            .read_component::<Song>()
+            .get(score)?
+            .part(score);
+        self.record(&[score]);
+
+        let part = self
+            .state
+            .world
+            .create_entity()
+            .with(part_song)
+            .with(StencilMap::default())
+            .with(Css::default())
+            .build();
+        let part_staff = self
+            .state
+            .world
+            .create_entity()
+            .with(Staff {
+                link: Some(staff),
+                ..Default::default()
+            })
+            .with(StencilMap::default())
+            .with(Children::default())
+            .with(Parent(part))
+            .build();
+
+        self.state
+            .world
+            .write_component::<Children>()
+            .insert(part, Children(vec![part_staff]))
+            .unwrap();
+        if let Some(score) = self.state.world.write_component::<Song>().get_mut(score) {
+            score.parts.push(part);
+        }
+
+        Some(part.id())
+    }
+
+    /// Stop keeping a part alive with its score. Unless it is the root, it is deleted.
+    pub fn part_remove(&mut self, part: u32) {
+        let part = self.state.world.entities().entity(part);
+        let score = self
+            .state
+            .world
+            .read_component::<Song>()
+            .get(part)
+            .and_then(|part| part.score);
+        let mut touched = vec![part];
+        touched.extend(score);
+        self.record(&touched);
+        let mut songs = self.state.world.write_component::<Song>();
+
+        let score = songs.get_mut(part).and_then(|part| part.score.take());
+        if let Some(score) = score.and_then(|score| songs.get_mut(score)) {
+            score.parts.retain(|&p| p != part);
+        }
+    }
+
     /// Create a staff, without attaching it to a song.
     pub fn staff_create(&mut self) -> u32 {
         self.state
@@ -199,6 +478,156 @@ impl NativeSixDom {
             .id()
     }
 
+    /// Force all stems on a staff up or down, or pass None to pick directions automatically.
+    pub fn staff_set_stem_direction(&mut self, staff: u32, stem_direction: Option<StemDirection>) {
+        let staff = self.state.world.entities().entity(staff);
+        self.record(&[staff]);
+        let mut staffs = self.state.world.write_component::<Staff>();
+
+        if let Some(staff) = staffs.get_mut(staff) {
+            staff.stem_direction = stem_direction;
+        }
+    }
+
+    /// Set the instrument a staff is for.
+    ///
+    /// Its music is written `steps` note names and `semitones` semitones above the pitch that
+    /// sounds, e.g., 1 and 2 for an instrument in Bb. `low` and `high` are the lowest and highest
+    /// notes it can play, as concert midi notes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn staff_set_instrument(
+        &mut self,
+        staff: u32,
+        name: &str,
+        steps: i8,
+        semitones: i8,
+        clef: Clef,
+        low: u8,
+        high: u8,
+    ) {
+        let staff = self.state.world.entities().entity(staff);
+        self.record(&[staff]);
+        let mut staffs = self.state.world.write_component::<Staff>();
+
+        if let Some(staff) = staffs.get_mut(staff) {
+            staff.instrument = Instrument {
+                name: name.to_owned(),
+                transposition: Interval::new(steps, semitones),
+                clef,
+                range: (low, high),
+            };
+        }
+    }
+
+    /// Create a tablature staff, without attaching it to a song.
+    ///
+    /// `tuning` is the pitch of each open string as a midi note, from the highest string to the
+    /// lowest.
+    pub fn tab_staff_create(&mut self, tuning: Vec<u8>) -> u32 {
+        self.state
+            .world
+            .create_entity()
+            .with(Staff {
+                stem_direction: Some(StemDirection::Down),
+                ..Default::default()
+            })
+            .with(TabStaff::new(tuning))
+            .with(StencilMap::default())
+            .with(Children::default())
+            .build()
+            .id()
+    }
+
+    /// Show the bars of `staff` on a tab staff, or pass None to show its own bars.
+    ///
+    /// Bars and chords on a linked tab staff can be edited like the ones they show, and edits
+    /// apply to both staffs.
+    pub fn tab_staff_set_link(&mut self, tab_staff: u32, staff: Option<u32>) {
+        let tab_staff = self.state.world.entities().entity(tab_staff);
+        let staff = staff.map(|staff| self.state.world.entities().entity(staff));
+        if !self
+            .state
+            .world
+            .read_component::<TabStaff>()
+            .contains(tab_staff)
+        {
+            return;
+        }
+        self.record(&[tab_staff]);
+        let tab_staffs = self.state.world.read_component::<TabStaff>();
+        let mut staffs = self.state.world.write_component::<Staff>();
+
+        if let (true, Some(tab_staff)) = (tab_staffs.contains(tab_staff), staffs.get_mut(tab_staff))
+        {
+            tab_staff.link = staff;
+        }
+    }
+
+    /// Save the state of `entities`, which are about to be changed, as an undo step, if any of
+    /// them is in a song.
+    ///
+    /// Changes to entities that are not attached yet are not recorded, since undoing the change
+    /// that attaches them removes them.
+    fn record(&self, entities: &[Entity]) {
+        let world = &self.state.world;
+        let in_song = |mut entity: Entity| {
+            let parents = world.read_component::<Parent>();
+            let songs = world.read_component::<Song>();
+            loop {
+                if songs.contains(entity) {
+                    return true;
+                }
+                match parents.get(entity) {
+                    Some(parent) => entity = parent.0,
+                    None => return false,
+                }
+            }
+        };
+
+        if entities.iter().any(|&entity| in_song(entity)) {
+            world.write_resource::<History>().record(world, entities);
+        }
+    }
+
+    /// A bar and everything in it, which a change to its rhythm can affect.
+    fn bar_contents(&self, bar: Entity) -> Vec<Entity> {
+        let world = &self.state.world;
+        let mut contents = vec![bar];
+        if let Some(bar) = world.read_component::<Bar>().get(bar) {
+            contents.extend(bar.managed().iter().copied());
+        }
+        for (entity, parent) in (&world.entities(), &world.read_component::<Parent>()).join() {
+            if parent.0 == bar && !contents.contains(&entity) {
+                contents.push(entity);
+            }
+        }
+
+        contents
+    }
+
+    /// True if `child` is a preview in `bar`, which is not recorded for undo.
+    fn is_temporary(&self, bar: Entity, child: Entity) -> bool {
+        self.state
+            .world
+            .read_component::<Bar>()
+            .get(bar)
+            .is_some_and(|bar| {
+                bar.rhythm()
+                    .iter()
+                    .any(|(_, lifetime)| *lifetime == Lifetime::Temporary(child))
+            })
+    }
+
+    /// The entity that `id` shows if it is on a linked staff, and otherwise `id`'s entity.
+    fn source_entity(&self, id: u32) -> Entity {
+        let entity = self.state.world.entities().entity(id);
+        self.state
+            .world
+            .read_component::<Linked>()
+            .get(entity)
+            .map_or(entity, |linked| linked.0)
+    }
+
     fn bar_by_index(&self, staff_children: &[Entity], idx: usize) -> Option<Entity> {
         let bars = self.state.world.read_component::<Bar>();
 
@@ -270,7 +699,7 @@ impl NativeSixDom {
             Some((bar_idx, t))
         }?;
 
-        let bar = bars.get(self.bar_by_index(&staff_bars.0, t.0 as usize)?)?;
+        let bar = bars.get(self.bar_by_index(&staff_bars.0, t.0)?)?;
 
         // Make sure we are not in the middle of a note.
         for BarChild {
@@ -278,9 +707,11 @@ impl NativeSixDom {
             start,
             lifetime,
             stencil,
+            grace,
         } in bar.children()
         {
-            if !lifetime.is_temporary()
+            if !grace
+                && !lifetime.is_temporary()
                 && !chords.get(stencil).unwrap().pitch.is_rest()
                 && t.1 > start
                 && t.1 < start + duration.duration()
@@ -315,22 +746,53 @@ impl NativeSixDom {
     ///
     /// Note that children of bars are not ordered, instead children have a `start` property.
     pub fn bar_insert(&mut self, bar: u32, child: u32, is_temporary: bool) {
-        let child = self.state.world.entities().entity(child);
-        let bar = self.state.world.entities().entity(bar);
+        let child = self.source_entity(child);
+        let bar = self.source_entity(bar);
+        if self.state.world.read_component::<Parent>().contains(child)
+            || !self.state.world.read_component::<Bar>().contains(bar)
+        {
+            return;
+        }
+        if !is_temporary {
+            let mut contents = self.bar_contents(bar);
+            contents.push(child);
+            self.record(&contents);
+        }
 
         let mut parents = self.state.world.write_component::<Parent>();
         let mut bars = self.state.world.write_component::<Bar>();
         let chords = self.state.world.read_component::<Chord>();
         let contexts = self.state.world.read_component::<Context>();
 
-        if parents.contains(child) {
+        let parent = Parent(bar);
+
+        // Dynamics, chord symbols and rehearsal marks are not part of the bar's rhythm.
+        if self
+            .state
+            .world
+            .read_component::<DynamicMark>()
+            .contains(child)
+            || self.state.world.read_component::<Hairpin>().contains(child)
+            || self.state.world.read_component::<Harmony>().contains(child)
+            || self
+                .state
+                .world
+                .read_component::<RehearsalMark>()
+                .contains(child)
+        {
+            if bars.contains(bar) {
+                parents.insert(child, parent).unwrap();
+            }
             return;
         }
 
-        let parent = Parent(bar);
-
         if let Some(bar) = bars.get_mut(bar) {
             if let (Some(chord), Some(start)) = (chords.get(child), contexts.get(child)) {
+                if chord.grace.is_some() {
+                    bar.insert_grace(start.beat, chord.duration(), child);
+                    parents.insert(child, parent).unwrap();
+                    return;
+                }
                 bar.splice(
                     start.beat,
                     vec![(
@@ -347,12 +809,18 @@ impl NativeSixDom {
         }
     }
 
-    /// Remove a Chord from a bar.
+    /// Remove a Chord, dynamic mark, hairpin, chord symbol, or rehearsal mark from a bar.
     ///
     /// Note that children of bars are not ordered, instead children have a `start` property.
     pub fn bar_remove(&mut self, bar: u32, child: u32) {
-        let bar = self.state.world.entities().entity(bar);
-        let child = self.state.world.entities().entity(child);
+        let bar = self.source_entity(bar);
+        let child = self.source_entity(child);
+        if !self.state.world.read_component::<Bar>().contains(bar) {
+            return;
+        }
+        if !self.is_temporary(bar, child) {
+            self.record(&self.bar_contents(bar));
+        }
 
         {
             let mut parents = self.state.world.write_component::<Parent>();
@@ -370,7 +838,8 @@ impl NativeSixDom {
     }
 
     pub fn bar_set_skip(&mut self, bar: u32, num: isize, den: isize) {
-        let bar = self.state.world.entities().entity(bar);
+        let bar = self.source_entity(bar);
+        self.record(&self.bar_contents(bar));
         let mut bars = self.state.world.write_component::<Bar>();
 
         if let Some(bar) = bars.get_mut(bar) {
@@ -379,7 +848,8 @@ impl NativeSixDom {
     }
 
     pub fn bar_clear_skip(&mut self, bar: u32) {
-        let bar = self.state.world.entities().entity(bar);
+        let bar = self.source_entity(bar);
+        self.record(&self.bar_contents(bar));
         let mut bars = self.state.world.write_component::<Bar>();
 
         if let Some(bar) = bars.get_mut(bar) {
@@ -387,6 +857,35 @@ impl NativeSixDom {
         }
     }
 
+    /// Fill the bar with one slash per beat, instead of rests, where there are no notes.
+    pub fn bar_set_slash_fill(&mut self, bar: u32, slash_fill: bool) {
+        let bar = self.source_entity(bar);
+        self.record(&self.bar_contents(bar));
+        let mut bars = self.state.world.write_component::<Bar>();
+
+        if let Some(bar) = bars.get_mut(bar) {
+            bar.set_slash_fill(slash_fill);
+        }
+    }
+
+    /// Replace the bar with a repeat sign, or pass None to show its contents.
+    ///
+    /// A two-bar repeat replaces this bar and the next.
+    pub fn bar_set_simile(&mut self, bar: u32, simile: Option<Simile>) {
+        let bar = self.source_entity(bar);
+        self.record(&[bar]);
+        let mut similes = self.state.world.write_component::<Simile>();
+
+        match simile {
+            Some(simile) => {
+                similes.insert(bar, simile).unwrap();
+            }
+            None => {
+                similes.remove(bar);
+            }
+        }
+    }
+
     /// Create a Chord, without attaching it to a bar.
     pub fn chord_create(
         &mut self,
@@ -420,7 +919,8 @@ impl NativeSixDom {
     }
 
     pub fn chord_set_rest(&mut self, chord: u32) {
-        let chord = self.state.world.entities().entity(chord);
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
         let mut chords = self.state.world.write_component::<Chord>();
 
         if let Some(chord) = chords.get_mut(chord) {
@@ -429,7 +929,8 @@ impl NativeSixDom {
     }
 
     pub fn chord_set_unpitched(&mut self, chord: u32) {
-        let chord = self.state.world.entities().entity(chord);
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
         let mut chords = self.state.world.write_component::<Chord>();
 
         if let Some(chord) = chords.get_mut(chord) {
@@ -437,18 +938,137 @@ impl NativeSixDom {
         }
     }
 
-    pub fn chord_set_pitch(&mut self, chord: u32, midi: u8, modifier: i8) {
-        let chord = self.state.world.entities().entity(chord);
+    pub fn chord_set_percussion(&mut self, chord: u32, instrument: PercussionInstrument) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
         let mut chords = self.state.world.write_component::<Chord>();
 
         if let Some(chord) = chords.get_mut(chord) {
-            chord.pitch = PitchKind::Pitch(
-                Pitch::from_base_midi(midi, NoteModifier::from_raw(modifier)).unwrap_or_default(),
-            );
+            chord.pitch = PitchKind::Percussion(instrument);
         }
     }
 
-    pub fn chord_update_time(
+    /// Write the chord as a rhythm slash. Its pitch is kept, but not written or played.
+    pub fn chord_set_slash(&mut self, chord: u32, slash: bool) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.slash = slash;
+        }
+    }
+
+    pub fn chord_set_pitch(&mut self, chord: u32, midi: u8, modifier: i8) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.pitch = PitchKind::Pitch(
+                Pitch::from_base_midi(midi, NoteModifier::from_raw(modifier)).unwrap_or_default(),
+            );
+        }
+    }
+
+    /// Play a note on the given string of a tab staff, or pass None to pick a string
+    /// automatically.
+    pub fn chord_set_string(&mut self, chord: u32, string: Option<u8>) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.string = string;
+        }
+    }
+
+    /// Set the pitch of a note on a tab staff to the given fret of the given string.
+    pub fn chord_set_fret(&mut self, chord: u32, string: u8, fret: u8) {
+        let mirror = self.state.world.entities().entity(chord);
+        let chord = self.source_entity(chord);
+        let (key, signature_key) = {
+            let parents = self.state.world.read_component::<Parent>();
+            let tab_staffs = self.state.world.read_component::<TabStaff>();
+            let contexts = self.state.world.read_component::<Context>();
+
+            let bar = parents.get(mirror).map(|parent| parent.0);
+            let tab_staff = bar
+                .and_then(|bar| parents.get(bar))
+                .and_then(|staff| tab_staffs.get(staff.0));
+            let key = tab_staff.and_then(|tab_staff| tab_staff.key(string, fret));
+            match key {
+                Some(key) => (key, contexts.get(mirror).map_or(0, |context| context.key)),
+                None => return,
+            }
+        };
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.pitch = PitchKind::Pitch(Pitch::from_midi(key, signature_key));
+            chord.string = Some(string);
+        }
+    }
+
+    /// True if the instrument of the chord's staff cannot play it.
+    pub fn chord_is_out_of_range(&self, chord: u32) -> bool {
+        let chord = self.state.world.entities().entity(chord);
+        self.state
+            .world
+            .read_component::<Chord>()
+            .get(chord)
+            .is_some_and(|chord| chord.out_of_range)
+    }
+
+    /// Override how a note is beamed, or pass None to beam it by the metre.
+    ///
+    /// The override is kept when the chord's time changes.
+    pub fn chord_set_beam_override(&mut self, chord: u32, beam_override: Option<BeamOverride>) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.beam_override = beam_override;
+        }
+    }
+
+    /// Add or remove an articulation, fermata, or ornament on a note or rest.
+    pub fn chord_set_articulation(
+        &mut self,
+        chord: u32,
+        articulation: Articulation,
+        enabled: bool,
+    ) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            if enabled {
+                chord.articulations.insert(articulation);
+            } else {
+                chord.articulations.remove(&articulation);
+            }
+        }
+    }
+
+    /// Make a note a grace note, or pass None to make it a normal note.
+    ///
+    /// This must be set before the chord is inserted into a bar. Grace notes are written before
+    /// the note at their start time, and take no time.
+    pub fn chord_set_grace(&mut self, chord: u32, grace: Option<Grace>) {
+        let chord = self.source_entity(chord);
+        self.record(&[chord]);
+        let mut chords = self.state.world.write_component::<Chord>();
+
+        if let Some(chord) = chords.get_mut(chord) {
+            chord.grace = grace;
+        }
+    }
+
+    pub fn chord_update_time(
         &mut self,
         chord_ent: u32,
         note_value: isize,
@@ -458,7 +1078,20 @@ impl NativeSixDom {
         is_temporary: bool,
     ) {
         let note_value = NoteValue::new(note_value).unwrap();
-        let chord_ent = self.state.world.entities().entity(chord_ent);
+        let chord_ent = self.source_entity(chord_ent);
+        if !is_temporary {
+            let bar = self
+                .state
+                .world
+                .read_component::<Parent>()
+                .get(chord_ent)
+                .map(|parent| parent.0);
+            if let Some(bar) = bar {
+                let mut contents = self.bar_contents(bar);
+                contents.push(chord_ent);
+                self.record(&contents);
+            }
+        }
         let mut chords = self.state.world.write_component::<Chord>();
         let mut bars = self.state.world.write_component::<Bar>();
         let mut contexts = self.state.world.write_component::<Context>();
@@ -475,17 +1108,21 @@ impl NativeSixDom {
                 start.natural_beat = start.beat;
                 chord.duration = Duration::new(note_value, dots, None);
                 chord.natural_duration = chord.duration;
-                bar.splice(
-                    start.beat,
-                    vec![(
-                        chord.duration(),
-                        if is_temporary {
-                            Lifetime::Temporary(chord_ent)
-                        } else {
-                            Lifetime::Explicit(chord_ent)
-                        },
-                    )],
-                );
+                if chord.grace.is_some() {
+                    bar.insert_grace(start.beat, chord.duration(), chord_ent);
+                } else {
+                    bar.splice(
+                        start.beat,
+                        vec![(
+                            chord.duration(),
+                            if is_temporary {
+                                Lifetime::Temporary(chord_ent)
+                            } else {
+                                Lifetime::Explicit(chord_ent)
+                            },
+                        )],
+                    );
+                }
             }
 
             drop(chords);
@@ -496,6 +1133,140 @@ impl NativeSixDom {
         }
     }
 
+    /// Create a dynamic mark, without attaching it to a bar.
+    pub fn dynamic_create(
+        &mut self,
+        dynamic: Dynamic,
+        time_numer: isize,
+        time_denom: isize,
+    ) -> u32 {
+        self.state
+            .world
+            .create_entity()
+            .with(DynamicMark {
+                dynamic,
+                time: Rational::new(time_numer, time_denom),
+            })
+            .with(Stencil::default())
+            .with(Css::default())
+            .build()
+            .id()
+    }
+
+    /// Create a chord symbol such as "Am7" or "G/B", without attaching it to a bar.
+    ///
+    /// Returns None if `symbol` is not a chord symbol.
+    pub fn harmony_create(
+        &mut self,
+        symbol: &str,
+        beat_numer: isize,
+        beat_denom: isize,
+    ) -> Option<u32> {
+        let symbol = ChordSymbol::parse(symbol)?;
+
+        Some(
+            self.state
+                .world
+                .create_entity()
+                .with(Harmony::new(symbol, Rational::new(beat_numer, beat_denom)))
+                .with(Stencil::default())
+                .with(Css::default())
+                .build()
+                .id(),
+        )
+    }
+
+    /// Change the chord of a chord symbol. Returns false if `symbol` is not a chord symbol.
+    pub fn harmony_set_symbol(&mut self, harmony: u32, symbol: &str) -> bool {
+        let harmony = self.state.world.entities().entity(harmony);
+        let symbol = match ChordSymbol::parse(symbol) {
+            Some(symbol)
+                if self
+                    .state
+                    .world
+                    .read_component::<Harmony>()
+                    .contains(harmony) =>
+            {
+                symbol
+            }
+            _ => return false,
+        };
+        self.record(&[harmony]);
+        let mut harmonies = self.state.world.write_component::<Harmony>();
+
+        if let Some(harmony) = harmonies.get_mut(harmony) {
+            harmony.symbol = symbol;
+        }
+
+        true
+    }
+
+    /// Create a rehearsal mark or section label, without attaching it to a bar.
+    ///
+    /// Rehearsal marks are boxed. If `label` is None, the mark is labelled automatically, according
+    /// to the song's rehearsal mark style.
+    pub fn rehearsal_mark_create(&mut self, label: Option<String>, boxed: bool) -> u32 {
+        self.state
+            .world
+            .create_entity()
+            .with(RehearsalMark::new(label, boxed))
+            .with(Stencil::default())
+            .with(Css::default())
+            .build()
+            .id()
+    }
+
+    /// Create a hairpin, without attaching it to a bar.
+    ///
+    /// The hairpin starts in the bar it is inserted into, and ends in `end_bar`.
+    pub fn hairpin_create(
+        &mut self,
+        kind: HairpinKind,
+        start_numer: isize,
+        start_denom: isize,
+        end_bar: u32,
+        end_numer: isize,
+        end_denom: isize,
+    ) -> u32 {
+        let end_bar = self.state.world.entities().entity(end_bar);
+
+        self.state
+            .world
+            .create_entity()
+            .with(Hairpin::new(
+                kind,
+                Rational::new(start_numer, start_denom),
+                end_bar,
+                Rational::new(end_numer, end_denom),
+            ))
+            .build()
+            .id()
+    }
+
+    /// Create a slur from `start` to `end`, which are chords in the same staff.
+    ///
+    /// The slur is owned by `start`, so it is removed with it.
+    pub fn slur_create(&mut self, start: u32, end: u32) -> u32 {
+        let start = self.state.world.entities().entity(start);
+        let end = self.state.world.entities().entity(end);
+
+        // The slur is attached as soon as it is created, so its creation is recorded.
+        let slur = self.state.world.create_entity().build();
+        self.record(&[slur, start]);
+        self.state
+            .world
+            .write_component::<Slur>()
+            .insert(slur, Slur::new(start, end))
+            .unwrap();
+        self.state
+            .world
+            .write_component::<Parent>()
+            .insert(slur, Parent(start))
+            .unwrap();
+
+        slur.id()
+    }
+
     pub fn cursor_create(&mut self) -> u32 {
         self.state
             .world
@@ -507,6 +1278,511 @@ impl NativeSixDom {
             .id()
     }
 
+    /// The document node of `song`, a score, with the part that is `root` marked.
+    fn song_node(&self, song: Entity, root: Entity) -> Option<Node> {
+        let world = &self.state.world;
+        let entities = world.entities();
+        let songs = world.read_component::<Song>();
+        let staffs = world.read_component::<Staff>();
+        let tab_staffs = world.read_component::<TabStaff>();
+        let children = world.read_component::<Children>();
+        let parents = world.read_component::<Parent>();
+        let signatures = world.read_component::<Signature>();
+        let bars = world.read_component::<Bar>();
+        let similes = world.read_component::<Simile>();
+        let chords = world.read_component::<Chord>();
+        let contexts = world.read_component::<Context>();
+        let dynamics = world.read_component::<DynamicMark>();
+        let hairpins = world.read_component::<Hairpin>();
+        let harmonies = world.read_component::<Harmony>();
+        let rehearsal_marks = world.read_component::<RehearsalMark>();
+        let slurs = world.read_component::<Slur>();
+
+        let song_data = songs.get(song)?;
+        let mut song_node = song_settings(Node::new("song"), song_data);
+        let mut voicings: Vec<_> = song_data.chord_voicings.iter().collect();
+        voicings.sort_by_key(|&(symbol, _)| symbol);
+        for (symbol, voicing) in voicings {
+            song_node.children.push(
+                Node::new("voicing")
+                    .with("symbol", symbol)
+                    .with("frets", voicing),
+            );
+        }
+
+        // Links, parts, hairpins and slurs refer to staffs, bars and chords by their index.
+        let song_staffs = &children.get(song)?.0;
+        let staff_index = |staff: Entity| song_staffs.iter().position(|&s| s == staff);
+
+        for &staff in song_staffs {
+            let staff_data = match staffs.get(staff) {
+                Some(staff_data) => staff_data,
+                None => continue,
+            };
+            let mut staff_node = match tab_staffs.get(staff) {
+                Some(tab_staff) => {
+                    Node::new("tab-staff").with("tuning", tuning_text(&tab_staff.tuning))
+                }
+                None => Node::new("staff"),
+            };
+            if let Some(stem_direction) = staff_data.stem_direction {
+                staff_node = staff_node.with("stem-direction", stem_direction_name(stem_direction));
+            }
+            if staff_data.instrument != Instrument::default() {
+                staff_node
+                    .children
+                    .push(instrument_node(&staff_data.instrument));
+            }
+
+            // A linked staff shows the bars of the staff it is linked to, instead of its own.
+            if let Some(link) = staff_data.link {
+                if let Some(link) = staff_index(link) {
+                    staff_node = staff_node.with("link", link);
+                }
+                song_node.children.push(staff_node);
+                continue;
+            }
+
+            let staff_children = children.get(staff).map_or(&[][..], |c| &c.0);
+            let staff_bars: Vec<Entity> = staff_children
+                .iter()
+                .copied()
+                .filter(|&child| bars.contains(child))
+                .collect();
+            let bar_chords: Vec<Vec<Entity>> = staff_bars
+                .iter()
+                .filter_map(|&bar| bars.get(bar))
+                .map(|bar| {
+                    bar.rhythm()
+                        .iter()
+                        .filter_map(|(_, lifetime)| match *lifetime {
+                            Lifetime::Explicit(chord) => Some(chord),
+                            _ => None,
+                        })
+                        .chain(bar.grace_notes())
+                        .collect()
+                })
+                .collect();
+            let chord_index = |chord: Entity| {
+                bar_chords.iter().enumerate().find_map(|(bar, bar_chords)| {
+                    Some((bar, bar_chords.iter().position(|&c| c == chord)?))
+                })
+            };
+
+            for &child in staff_children {
+                if let Some(signature) = signatures.get(child) {
+                    let mut node = Node::new("signature");
+                    if let Some(barline) = signature.barline {
+                        node = node.with("barline", barline_name(barline));
+                    }
+                    if let Some(clef) = signature.clef {
+                        node = node.with("clef", clef_name(clef));
+                    }
+                    if let Some((num, den)) = signature.time {
+                        node = node.with("time", format!("{}/{}", num, den));
+                    }
+                    if let Some(key) = signature.key {
+                        node = node.with("key", key);
+                    }
+                    staff_node.children.push(node);
+                } else if let Some(bar) = bars.get(child) {
+                    let metre = bar.metre();
+                    let mut bar_node =
+                        Node::new("bar").with("time", format!("{}/{}", metre.num()?, metre.den()?));
+                    if *bar.skip().numer() != 0 {
+                        bar_node = bar_node.with("skip", bar.skip());
+                    }
+                    if bar.slash_fill() {
+                        bar_node = bar_node.with("slash-fill", true);
+                    }
+                    if let Some(&simile) = similes.get(child) {
+                        bar_node = bar_node.with("simile", simile_name(simile));
+                    }
+
+                    let bar_index = staff_bars.iter().position(|&bar| bar == child)?;
+                    for &chord in &bar_chords[bar_index] {
+                        if let (Some(chord_data), Some(context)) =
+                            (chords.get(chord), contexts.get(chord))
+                        {
+                            let mut chord_node = chord_node(chord_data, context)?;
+                            for slur in slurs.join().filter(|slur| slur.start == chord) {
+                                if let Some((end_bar, end_chord)) = chord_index(slur.end) {
+                                    chord_node.children.push(
+                                        Node::new("slur")
+                                            .with("end-bar", end_bar)
+                                            .with("end-chord", end_chord),
+                                    );
+                                }
+                            }
+                            bar_node.children.push(chord_node);
+                        }
+                    }
+
+                    // Marks are not ordered in the bar, so they are saved by kind, and then by time.
+                    let in_bar = |mark: Entity| parents.get(mark) == Some(&Parent(child));
+                    for (mark, rehearsal_mark) in (&entities, &rehearsal_marks).join() {
+                        if in_bar(mark) {
+                            let mut node =
+                                Node::new("rehearsal-mark").with("boxed", rehearsal_mark.boxed);
+                            if let Some(label) = &rehearsal_mark.label {
+                                node = node.with("label", label);
+                            }
+                            bar_node.children.push(node);
+                        }
+                    }
+                    let mut marks: Vec<(Rational, Node)> = (&entities, &dynamics)
+                        .join()
+                        .filter(|&(mark, _)| in_bar(mark))
+                        .map(|(_, dynamic)| {
+                            let node = Node::new("dynamic")
+                                .with("dynamic", dynamic_name(dynamic.dynamic))
+                                .with("time", dynamic.time);
+                            (dynamic.time, node)
+                        })
+                        .collect();
+                    marks.sort_by_key(|&(time, _)| time);
+                    bar_node
+                        .children
+                        .extend(marks.into_iter().map(|(_, node)| node));
+                    let mut marks: Vec<(Rational, Node)> = (&entities, &hairpins)
+                        .join()
+                        .filter(|&(mark, _)| in_bar(mark))
+                        .filter_map(|(_, hairpin)| {
+                            let end_bar =
+                                staff_bars.iter().position(|&bar| bar == hairpin.end_bar)?;
+                            let node = Node::new("hairpin")
+                                .with("kind", hairpin_kind_name(hairpin.kind))
+                                .with("start", hairpin.start)
+                                .with("end-bar", end_bar)
+                                .with("end", hairpin.end);
+                            Some((hairpin.start, node))
+                        })
+                        .collect();
+                    marks.sort_by_key(|&(time, _)| time);
+                    bar_node
+                        .children
+                        .extend(marks.into_iter().map(|(_, node)| node));
+                    let mut marks: Vec<(Rational, Node)> = (&entities, &harmonies)
+                        .join()
+                        .filter(|&(mark, _)| in_bar(mark))
+                        .map(|(_, harmony)| {
+                            let node = Node::new("harmony")
+                                .with("symbol", &harmony.symbol)
+                                .with("beat", harmony.beat);
+                            (harmony.beat, node)
+                        })
+                        .collect();
+                    marks.sort_by_key(|&(time, _)| time);
+                    bar_node
+                        .children
+                        .extend(marks.into_iter().map(|(_, node)| node));
+
+                    staff_node.children.push(bar_node);
+                }
+            }
+            song_node.children.push(staff_node);
+        }
+
+        for &part in &song_data.parts {
+            let staff = children
+                .get(part)
+                .and_then(|children| children.0.first())
+                .and_then(|&staff| staffs.get(staff))
+                .and_then(|staff| staff.link)
+                .and_then(staff_index);
+            if let (Some(part_data), Some(staff)) = (songs.get(part), staff) {
+                let mut part_node = Node::new("part").with("staff", staff);
+                if part == root {
+                    part_node = part_node.with("root", true);
+                }
+                song_node.children.push(song_settings(part_node, part_data));
+            }
+        }
+
+        Some(song_node)
+    }
+
+    /// Set the page and notation settings of a song, as written by `song_settings`.
+    ///
+    /// Settings that are missing are left as they are.
+    fn load_song_settings(&mut self, song: u32, node: &Node) -> Option<()> {
+        let defaults = Song::default();
+        if let (Some(width), Some(height)) = (node.parse("width"), node.parse("height")) {
+            self.song_set_size(song, width, height);
+        }
+        if let Some(title) = node.get("title") {
+            self.song_set_title(song, title, node.parse("title-width").unwrap_or(0.0));
+        }
+        if let Some(author) = node.get("author") {
+            self.song_set_author(song, author, node.parse("author-width").unwrap_or(0.0));
+        }
+        if let Some(rastal_size) = node.parse("rastal-size") {
+            self.song_set_rastal_size(song, rastal_size);
+        }
+        if let Some(multirests) = node.parse("multirests") {
+            self.song_set_multirests(song, multirests);
+        }
+        if let Some(beaming_style) = node.get("beaming") {
+            self.song_set_beaming_style(song, parse_beaming_style(beaming_style)?);
+        }
+        if let Some(bar_numbering) = node.get("bar-numbering") {
+            self.song_set_bar_numbering(
+                song,
+                parse_bar_numbering(bar_numbering)?,
+                node.parse("bar-number-interval")
+                    .unwrap_or(defaults.bar_number_interval),
+                node.parse("bar-numbers-boxed")
+                    .unwrap_or(defaults.bar_numbers_boxed),
+            );
+        }
+        if let Some(first_bar_number) = node.parse("first-bar-number") {
+            self.song_set_first_bar_number(song, first_bar_number);
+        }
+        if let Some(cautionary_accidentals) = node.parse("cautionary-accidentals") {
+            self.song_set_cautionary_accidentals(song, cautionary_accidentals);
+        }
+        if let Some(concert_pitch) = node.parse("concert-pitch") {
+            self.song_set_concert_pitch(song, concert_pitch);
+        }
+        if let Some(drum_kit) = node.get("drum-kit") {
+            self.song_set_drum_kit(song, parse_drum_kit(drum_kit)?);
+        }
+        if let Some(chord_diagrams) = node.get("chord-diagrams") {
+            let tuning = match node.get("chord-diagram-tuning") {
+                Some(tuning) => parse_tuning(tuning)?,
+                None => defaults.chord_diagram_tuning,
+            };
+            self.song_set_chord_diagrams(song, parse_chord_diagrams(chord_diagrams)?, tuning);
+        }
+        if let Some(style) = node.get("rehearsal-marks") {
+            self.song_set_rehearsal_mark_style(song, parse_rehearsal_mark_style(style)?);
+        }
+
+        Some(())
+    }
+
+    /// Load a score and its parts. Returns the score, or the part that was the root when it was
+    /// saved.
+    fn load_song(&mut self, node: &Node) -> Option<u32> {
+        let song = self.song_create();
+        self.load_song_settings(song, node)?;
+        for node in node.children.iter().filter(|node| node.kind == "voicing") {
+            let frets = node.get("frets")?.to_owned();
+            if !self.song_set_chord_voicing(song, node.get("symbol")?, Some(frets)) {
+                return None;
+            }
+        }
+
+        let mut staffs = vec![];
+        let mut links = vec![];
+        for staff_node in node
+            .children
+            .iter()
+            .filter(|node| node.kind == "staff" || node.kind == "tab-staff")
+        {
+            let staff = if staff_node.kind == "tab-staff" {
+                self.tab_staff_create(parse_tuning(staff_node.get("tuning")?)?)
+            } else {
+                self.staff_create()
+            };
+            let stem_direction = match staff_node.get("stem-direction") {
+                Some(stem_direction) => Some(parse_stem_direction(stem_direction)?),
+                None => None,
+            };
+            self.staff_set_stem_direction(staff, stem_direction);
+            if let Some(link) = staff_node.parse::<usize>("link") {
+                links.push((staff, link));
+            }
+
+            // Each bar, with its chords in the order they were saved.
+            let mut bars: Vec<(u32, Vec<u32>)> = vec![];
+            let mut hairpins = vec![];
+            let mut slurs = vec![];
+            for node in &staff_node.children {
+                match node.kind.as_str() {
+                    "instrument" => {
+                        self.staff_set_instrument(
+                            staff,
+                            node.get("name").unwrap_or(""),
+                            node.parse("steps").unwrap_or(0),
+                            node.parse("semitones").unwrap_or(0),
+                            parse_clef(node.get("clef")?)?,
+                            node.parse("low")?,
+                            node.parse("high")?,
+                        );
+                    }
+                    "signature" => {
+                        let barline = match node.get("barline") {
+                            Some(barline) => Some(parse_barline(barline)?),
+                            None => None,
+                        };
+                        let clef = match node.get("clef") {
+                            Some(clef) => Some(parse_clef(clef)?),
+                            None => None,
+                        };
+                        let time = match node.get("time") {
+                            Some(time) => Some(parse_time(time)?),
+                            None => None,
+                        };
+                        let signature = self.signature_create(
+                            barline,
+                            clef,
+                            time.map(|time| time.0),
+                            time.map(|time| time.1),
+                            node.parse("key"),
+                        );
+                        self.child_append(staff, signature);
+                    }
+                    "bar" => {
+                        let (num, den) = parse_time(node.get("time")?)?;
+                        let bar = self.bar_create(num, den);
+                        self.child_append(staff, bar);
+                        if let Some(skip) = node.parse::<Rational>("skip") {
+                            self.bar_set_skip(bar, *skip.numer(), *skip.denom());
+                        }
+                        if let Some(slash_fill) = node.parse("slash-fill") {
+                            self.bar_set_slash_fill(bar, slash_fill);
+                        }
+                        if let Some(simile) = node.get("simile") {
+                            self.bar_set_simile(bar, Some(parse_simile(simile)?));
+                        }
+
+                        let mut chords = vec![];
+                        for node in &node.children {
+                            let child = match node.kind.as_str() {
+                                "chord" => {
+                                    let chord = self.load_chord(node)?;
+                                    chords.push(chord);
+                                    slurs.extend(
+                                        node.children
+                                            .iter()
+                                            .filter(|node| node.kind == "slur")
+                                            .map(|node| (chord, node)),
+                                    );
+                                    chord
+                                }
+                                "dynamic" => {
+                                    let time: Rational = node.parse("time")?;
+                                    self.dynamic_create(
+                                        parse_dynamic(node.get("dynamic")?)?,
+                                        *time.numer(),
+                                        *time.denom(),
+                                    )
+                                }
+                                "harmony" => {
+                                    let beat: Rational = node.parse("beat")?;
+                                    self.harmony_create(
+                                        node.get("symbol")?,
+                                        *beat.numer(),
+                                        *beat.denom(),
+                                    )?
+                                }
+                                "rehearsal-mark" => self.rehearsal_mark_create(
+                                    node.get("label").map(str::to_owned),
+                                    node.parse("boxed").unwrap_or(false),
+                                ),
+                                "hairpin" => {
+                                    hairpins.push((bar, node));
+                                    continue;
+                                }
+                                _ => continue,
+                            };
+                            self.bar_insert(bar, child, false);
+                        }
+                        bars.push((bar, chords));
+                    }
+                    _ => {}
+                }
+            }
+
+            // Hairpins and slurs can end in a later bar, so they are added once every bar is.
+            for (bar, node) in hairpins {
+                let (end_bar, _) = bars.get(node.parse::<usize>("end-bar")?)?;
+                let start: Rational = node.parse("start")?;
+                let end: Rational = node.parse("end")?;
+                let hairpin = self.hairpin_create(
+                    parse_hairpin_kind(node.get("kind")?)?,
+                    *start.numer(),
+                    *start.denom(),
+                    *end_bar,
+                    *end.numer(),
+                    *end.denom(),
+                );
+                self.bar_insert(bar, hairpin, false);
+            }
+            for (chord, node) in slurs {
+                let (_, chords) = bars.get(node.parse::<usize>("end-bar")?)?;
+                let end = *chords.get(node.parse::<usize>("end-chord")?)?;
+                self.slur_create(chord, end);
+            }
+
+            self.child_append(song, staff);
+            staffs.push(staff);
+        }
+
+        for (staff, link) in links {
+            self.tab_staff_set_link(staff, Some(*staffs.get(link)?));
+        }
+
+        let mut root = song;
+        for node in node.children.iter().filter(|node| node.kind == "part") {
+            let part = self.part_create(song, *staffs.get(node.parse::<usize>("staff")?)?)?;
+            self.load_song_settings(part, node)?;
+            if node.parse("root") == Some(true) {
+                root = part;
+            }
+        }
+
+        Some(root)
+    }
+
+    fn load_chord(&mut self, node: &Node) -> Option<u32> {
+        let note_value = NoteValue::new(node.parse("note")?)?;
+        let dots = node.parse("dots").unwrap_or(0);
+        let start: Rational = node.parse("start")?;
+        if dots > MAX_DOTS {
+            return None;
+        }
+
+        let chord = self.chord_create(
+            note_value.log2() as isize,
+            dots,
+            *start.numer(),
+            *start.denom(),
+        );
+        match node.get("pitch")? {
+            "rest" => self.chord_set_rest(chord),
+            "unpitched" => self.chord_set_unpitched(chord),
+            "percussion" => {
+                let instrument = parse_percussion_instrument(node.get("instrument")?)?;
+                self.chord_set_percussion(chord, instrument);
+            }
+            midi => {
+                let midi: u8 = midi.parse().ok()?;
+                self.chord_set_pitch(chord, midi, node.parse("modifier").unwrap_or(0));
+            }
+        }
+        if let Some(slash) = node.parse("slash") {
+            self.chord_set_slash(chord, slash);
+        }
+        if let Some(grace) = node.get("grace") {
+            self.chord_set_grace(chord, Some(parse_grace(grace)?));
+        }
+        if let Some(beam_override) = node.get("beam") {
+            self.chord_set_beam_override(chord, Some(parse_beam_override(beam_override)?));
+        }
+        if let Some(articulations) = node.get("articulations") {
+            for articulation in articulations.split_whitespace() {
+                self.chord_set_articulation(chord, parse_articulation(articulation)?, true);
+            }
+        }
+        if let Some(string) = node.parse("string") {
+            self.chord_set_string(chord, Some(string));
+        }
+
+        Some(chord)
+    }
+
     fn fixup_bar(&mut self, parent_id: Entity) {
         let parents = self.state.world.read_component::<Parent>();
         let mut bars = self.state.world.write_component::<Bar>();
@@ -616,6 +1892,7 @@ impl NativeSixDom {
         key: Option<i8>,
     ) {
         let signature = self.state.world.entities().entity(signature);
+        self.record(&[signature]);
         let mut signatures = self.state.world.write_storage::<Signature>();
 
         let time = if let (Some(time_numer), Some(time_denom)) = (time_numer, time_denom) {
@@ -644,24 +1921,109 @@ impl NativeSixDom {
         self.state.exec();
     }
 
-    pub fn stencils(&self) -> String {
-        let mut lines: Vec<String> = Vec::new();
-        for (entity, css, stencil) in (
-            &self.state.world.entities(),
-            self.state.world.read_component::<Css>().maybe(),
-            &self.state.world.read_component::<Stencil>(),
-        )
-            .join()
-        {
-            lines.push(entity.id().to_string());
-            lines.push(css.and_then(|c| c.class.clone()).unwrap_or_default());
-            lines.push(stencil.to_svg());
-        }
-        lines.join("\n")
-    }
-
-    pub fn parents(&self) -> String {
-        let mut lines: Vec<String> = Vec::new();
+    /// Save the root song as a document, which `load` opens.
+    ///
+    /// If the root is a part, its score is saved, with every part, and the part is opened as the
+    /// root when the document is loaded.
+    pub fn save(&self) -> Option<String> {
+        let root = self.state.world.read_resource::<Root>().0?;
+        let score = self
+            .state
+            .world
+            .read_component::<Song>()
+            .get(root)?
+            .score
+            .unwrap_or(root);
+        Some(document::write(&self.song_node(score, root)?))
+    }
+
+    /// Open a document created by `save`, as the root song of a new `NativeSixDom`.
+    ///
+    /// Returns None if `text` is not a document, or is from a newer version of Six Eight.
+    pub fn load(text: &str) -> Option<NativeSixDom> {
+        let node = document::read(text)?;
+        if node.kind != "song" {
+            return None;
+        }
+
+        let mut render = NativeSixDom::default();
+        let root = render.load_song(&node)?;
+        render.root_set(root);
+        render.history_clear();
+
+        Some(render)
+    }
+
+    /// Open the document embedded in a PDF by `to_pdf_with_document`, given as base64.
+    ///
+    /// Returns None if the PDF has no such document.
+    pub fn load_pdf(pdf: &str) -> Option<NativeSixDom> {
+        let pdf = base64::decode(pdf).ok()?;
+        let (_, document) = embedded_files(&pdf)?
+            .into_iter()
+            .find(|(name, _)| name == "sixeight.txt")?;
+
+        NativeSixDom::load(std::str::from_utf8(&document).ok()?)
+    }
+
+    /// Revert the last edit, or group of edits, to any song. Returns false if there is nothing to
+    /// undo.
+    ///
+    /// Previews, i.e., temporary notes, are not recorded.
+    pub fn undo(&mut self) -> bool {
+        let world = &self.state.world;
+        world.write_resource::<History>().undo(world)
+    }
+
+    /// Reapply the last undone edit. Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let world = &self.state.world;
+        world.write_resource::<History>().redo(world)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.state.world.read_resource::<History>().can_undo()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.state.world.read_resource::<History>().can_redo()
+    }
+
+    /// Start a group of edits which are undone together, such as the edits made during a drag.
+    ///
+    /// Groups can be nested. The group ends when every `history_begin_group` is matched by a
+    /// `history_end_group`, or on undo or redo.
+    pub fn history_begin_group(&mut self) {
+        self.state.world.write_resource::<History>().begin_group();
+    }
+
+    pub fn history_end_group(&mut self) {
+        self.state.world.write_resource::<History>().end_group();
+    }
+
+    /// Forget every edit, e.g., after loading a new song.
+    pub fn history_clear(&mut self) {
+        self.state.world.write_resource::<History>().clear();
+    }
+
+    pub fn stencils(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        for (entity, css, stencil) in (
+            &self.state.world.entities(),
+            self.state.world.read_component::<Css>().maybe(),
+            &self.state.world.read_component::<Stencil>(),
+        )
+            .join()
+        {
+            lines.push(entity.id().to_string());
+            lines.push(css.and_then(|c| c.class.clone()).unwrap_or_default());
+            lines.push(stencil.to_svg());
+        }
+        lines.join("\n")
+    }
+
+    pub fn parents(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
         for (child, parent) in (
             &self.state.world.entities(),
             &self.state.world.read_component::<Parent>(),
@@ -727,18 +2089,7 @@ impl NativeSixDom {
             let contexts = self.state.world.read_component::<Context>();
             let start = contexts.get(entity);
             lines.push(entity.id().to_string());
-            let kind = if self.state.world.read_component::<Chord>().contains(entity) {
-                0
-            } else if self
-                .state
-                .world
-                .read_component::<Signature>()
-                .contains(entity)
-            {
-                1
-            } else {
-                -1
-            };
+            let kind = self.stencil_kind(entity);
             lines.push(format!(
                 "[{},{},{},{},{},{},{},{}]",
                 bbox.0.x0,
@@ -754,6 +2105,53 @@ impl NativeSixDom {
         lines.join("\n")
     }
 
+    /// What an entity is, for hit testing: 0 for chords, 1 for signatures, 2 for accidentals, 3
+    /// for leger lines, and -1 for anything else.
+    fn stencil_kind(&self, entity: Entity) -> isize {
+        let world = &self.state.world;
+        if world.read_component::<Chord>().contains(entity) {
+            0
+        } else if world.read_component::<Signature>().contains(entity) {
+            1
+        } else if world.read_component::<Accidental>().contains(entity) {
+            2
+        } else if world.read_component::<LegerLine>().contains(entity) {
+            3
+        } else {
+            -1
+        }
+    }
+
+    /// Returns [entity, kind, parent] for the smallest glyph under the given point, where kind is
+    /// as in `get_stencil_bboxes`, and parent is the chord of an accidental or leger line, or -1.
+    pub fn get_glyph_at(&self, x: f64, y: f64) -> Option<Vec<isize>> {
+        let (entity, _) = (
+            &self.state.world.entities(),
+            &self.state.world.read_component::<WorldBbox>(),
+            &self.state.world.read_component::<Stencil>(),
+        )
+            .join()
+            .filter(|(_, WorldBbox(bbox), _)| {
+                bbox.area() > 0.0 && x >= bbox.x0 && x <= bbox.x1 && y >= bbox.y0 && y <= bbox.y1
+            })
+            .map(|(entity, WorldBbox(bbox), _)| (entity, bbox.area()))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())?;
+
+        let kind = self.stencil_kind(entity);
+        let parent = match kind {
+            2 | 3 => self
+                .state
+                .world
+                .read_component::<Parent>()
+                .get(entity)
+                .map(|parent| parent.0.id() as isize)
+                .unwrap_or(-1),
+            _ => -1,
+        };
+
+        Some(vec![entity.id() as isize, kind, parent])
+    }
+
     /// Returns [bar, num, den, pitch_base, pitch_modifier]
     pub fn get_hover_info(&self, x: f64, y: f64) -> Option<Vec<isize>> {
         let quant = Rational::new(1, 8);
@@ -768,6 +2166,7 @@ impl NativeSixDom {
                 let child_contexts: Vec<_> = bar
                     .children()
                     .into_iter()
+                    .filter(|c| !c.grace)
                     .map(|c| {
                         (
                             self.state
@@ -800,8 +2199,8 @@ impl NativeSixDom {
 
                         return Some(vec![
                             context.bar as isize,
-                            *beat.numer() as isize,
-                            *beat.denom() as isize,
+                            *beat.numer(),
+                            *beat.denom(),
                             pitch.base_midi() as isize,
                             pitch.modifier().map(|m| m as isize).unwrap_or(0),
                         ]);
@@ -810,8 +2209,8 @@ impl NativeSixDom {
 
                 return Some(vec![
                     context.bar as isize,
-                    *context.beat.numer() as isize,
-                    *context.beat.denom() as isize,
+                    *context.beat.numer(),
+                    *context.beat.denom(),
                     pitch.base_midi() as isize,
                     pitch.modifier().map(|m| m as isize).unwrap_or(0),
                 ]);
@@ -840,7 +2239,7 @@ impl NativeSixDom {
             // Format it.
             solution
                 .into_iter()
-                .map(|part| {
+                .flat_map(|part| {
                     let my_start = start;
                     start += part.duration();
                     vec![
@@ -853,7 +2252,6 @@ impl NativeSixDom {
                     ]
                     .into_iter()
                 })
-                .flatten()
                 .collect()
         } else {
             vec![]
@@ -879,6 +2277,19 @@ impl NativeSixDom {
     }
 
     pub fn to_pdf(&self, embed_file: Option<String>) -> Option<String> {
+        let embed_file =
+            embed_file.map(|file| ("sixeight.json".to_owned(), "application/json", file));
+        self.pdf(embed_file)
+    }
+
+    /// Render the root song as a PDF, with the document from `save` embedded, so that it can be
+    /// opened again.
+    pub fn to_pdf_with_document(&self) -> Option<String> {
+        let document = self.save()?;
+        self.pdf(Some(("sixeight.txt".to_owned(), "text/plain", document)))
+    }
+
+    fn pdf(&self, embed_file: Option<(String, &str, String)>) -> Option<String> {
         let songs = self.state.world.read_component::<Song>();
         let stencils = self.state.world.read_component::<Stencil>();
         let stencil_maps = self.state.world.read_component::<StencilMap>();
@@ -888,8 +2299,8 @@ impl NativeSixDom {
         let mut pdf = Pdf::new();
         let scale = song.scale();
         pdf.add_page(Size::new(215.9, 279.4));
-        if let Some(embed_file) = embed_file {
-            pdf.add_file(&embed_file);
+        if let Some((name, mime_type, file)) = embed_file {
+            pdf.add_file(&name, mime_type, &file);
         }
 
         pdf.write_stencil_map(
@@ -900,6 +2311,650 @@ impl NativeSixDom {
         );
         Some(base64::encode(pdf.into_binary()))
     }
+
+    /// Render the root song as a standalone SVG document.
+    ///
+    /// Elements keep their CSS classes. If `outline_text` is set, text is drawn as paths, so
+    /// that the document does not depend on the fonts of whatever displays it.
+    pub fn to_svg(&self, outline_text: bool) -> Option<String> {
+        let songs = self.state.world.read_component::<Song>();
+        let root = self.state.world.read_resource::<Root>().0?;
+        let song = songs.get(root)?;
+
+        let writer = SvgWriter {
+            stencil_maps: self.state.world.read_component(),
+            stencils: self.state.world.read_component(),
+            csses: self.state.world.read_component(),
+            outline_text,
+        };
+        Some(writer.write(
+            root,
+            song.width,
+            song.height,
+            song.scale(),
+            Some(song.title.as_str()).filter(|title| !title.is_empty()),
+        ))
+    }
+
+    /// Render the root song as a base64-encoded PNG, with `dpi` pixels per inch.
+    pub fn to_png(&self, dpi: f64) -> Option<String> {
+        let songs = self.state.world.read_component::<Song>();
+        let stencils = self.state.world.read_component::<Stencil>();
+        let stencil_maps = self.state.world.read_component::<StencilMap>();
+        let root = self.state.world.read_resource::<Root>().0?;
+        let song = songs.get(root)?;
+
+        let mut raster = Raster::new(Size::new(song.width, song.height), dpi);
+        let transform = raster.mm() * Affine::scale(song.scale());
+        raster.write_stencil_map(stencil_maps.get(root)?, transform, &stencils, &stencil_maps);
+        Some(base64::encode(raster.to_png()))
+    }
+
+    /// Export the song as a base64-encoded Standard MIDI File.
+    pub fn to_midi(&self) -> Option<String> {
+        Some(base64::encode(write_midi(
+            &self.midi_tracks()?,
+            &self.midi_markers()?,
+        )))
+    }
+
+    /// The rehearsal marks and section labels in the song, with times in whole notes.
+    ///
+    /// Marks that are on every staff are only included once.
+    fn midi_markers(&self) -> Option<Vec<(Rational, String)>> {
+        let root = self.state.world.read_resource::<Root>().0?;
+        let children = self.state.world.read_component::<Children>();
+        let staffs = self.state.world.read_component::<Staff>();
+        let bars = self.state.world.read_component::<Bar>();
+        let parents = self.state.world.read_component::<Parent>();
+        let marks = self.state.world.read_component::<RehearsalMark>();
+
+        let mut markers = vec![];
+        for &staff in &children.get(root)?.0 {
+            if !staffs.contains(staff) {
+                continue;
+            }
+
+            let mut bar_starts = HashMap::new();
+            let mut t = Rational::new(0, 1);
+            for &child in &children.get(staff)?.0 {
+                if let Some(bar) = bars.get(child) {
+                    bar_starts.insert(child, t);
+                    t += bar.metre().duration() - bar.skip();
+                }
+            }
+
+            for (mark, parent) in (&marks, &parents).join() {
+                if let Some(&t) = bar_starts.get(&parent.0) {
+                    if !mark.text.is_empty() && !markers.contains(&(t, mark.text.clone())) {
+                        markers.push((t, mark.text.clone()));
+                    }
+                }
+            }
+        }
+
+        Some(markers)
+    }
+
+    /// The notes of each staff in the song, with velocities from its dynamics.
+    ///
+    /// Percussion notes are played with the song's drum kit, in their own track, so a staff with
+    /// both pitched and percussion notes has two tracks. Bars replaced by a repeat sign play the
+    /// bars they repeat.
+    fn midi_tracks(&self) -> Option<Vec<MidiTrack>> {
+        let root = self.state.world.read_resource::<Root>().0?;
+        let kit = self
+            .state
+            .world
+            .read_component::<Song>()
+            .get(root)?
+            .drum_kit;
+        let children = self.state.world.read_component::<Children>();
+        let staffs = self.state.world.read_component::<Staff>();
+        let bars = self.state.world.read_component::<Bar>();
+        let chords = self.state.world.read_component::<Chord>();
+        let parents = self.state.world.read_component::<Parent>();
+        let marks = self.state.world.read_component::<DynamicMark>();
+        let hairpins = self.state.world.read_component::<Hairpin>();
+        let similes = self.state.world.read_component::<Simile>();
+        let tab_staffs = self.state.world.read_component::<TabStaff>();
+
+        let mut tracks = vec![];
+        for &staff in &children.get(root)?.0 {
+            if !staffs.contains(staff) {
+                continue;
+            }
+
+            // Linked tab staffs show notes which are already played by the staff they are linked
+            // to. Parts show a staff which is not in the song, so they are played.
+            if tab_staffs.contains(staff) && staffs.get(staff).unwrap().link.is_some() {
+                continue;
+            }
+
+            // When each bar starts, in whole notes from the start of the song.
+            let mut bar_starts = HashMap::new();
+            let mut t = Rational::new(0, 1);
+            for &child in &children.get(staff)?.0 {
+                if let Some(bar) = bars.get(child) {
+                    // Pickup bars start part-way through.
+                    bar_starts.insert(child, t - bar.skip());
+                    t += bar.metre().duration() - bar.skip();
+                }
+            }
+
+            let mut timeline = DynamicTimeline::default();
+            for (mark, parent) in (&marks, &parents).join() {
+                if let Some(bar_start) = bar_starts.get(&parent.0) {
+                    timeline.add_mark(bar_start + mark.time, mark.dynamic);
+                }
+            }
+            for (hairpin, parent) in (&hairpins, &parents).join() {
+                if let (Some(start), Some(end)) =
+                    (bar_starts.get(&parent.0), bar_starts.get(&hairpin.end_bar))
+                {
+                    timeline.add_hairpin(start + hairpin.start, end + hairpin.end, hairpin.kind);
+                }
+            }
+
+            let replaced = Simile::replaced_bars(&children.get(staff)?.0, &bars, &similes);
+            // The start of each bar so far, and the notes played in it.
+            let mut played: Vec<(Rational, Range<usize>)> = vec![];
+
+            // Each note, and whether it is percussion. Percussion is played on its own channel,
+            // so that pitched notes on the same staff do not play as drums.
+            let mut notes: Vec<(MidiNote, bool)> = vec![];
+            for &child in &children.get(staff)?.0 {
+                if let (Some(bar), Some(&bar_start)) = (bars.get(child), bar_starts.get(&child)) {
+                    let first_note = notes.len();
+                    if let Some(simile) = replaced.get(&child) {
+                        let repeated = played.len().checked_sub(simile.distance());
+                        if let Some((repeated_start, range)) = repeated.map(|i| played[i].clone()) {
+                            for i in range {
+                                let (note, percussion) = notes[i];
+                                let start = note.start - repeated_start + bar_start;
+                                notes.push((
+                                    MidiNote {
+                                        start,
+                                        velocity: timeline.velocity(start),
+                                        ..note
+                                    },
+                                    percussion,
+                                ));
+                            }
+                        }
+                        played.push((bar_start, first_note..notes.len()));
+                        continue;
+                    }
+
+                    // Grace notes waiting for their main note, with their key.
+                    let mut graces: Vec<(Grace, Option<(u8, bool)>)> = vec![];
+                    for BarChild {
+                        duration,
+                        start,
+                        lifetime,
+                        stencil,
+                        grace,
+                    } in bar.children()
+                    {
+                        if lifetime.is_temporary() {
+                            continue;
+                        }
+                        let chord = chords.get(stencil);
+                        let key = match chord.filter(|c| !c.slash).map(|c| c.pitch) {
+                            Some(PitchKind::Pitch(pitch)) => Some((pitch.midi(), false)),
+                            Some(PitchKind::Percussion(instrument)) => {
+                                Some((kit.midi(instrument), true))
+                            }
+                            _ => None,
+                        };
+                        if grace {
+                            if let Some(grace) = chord.and_then(|c| c.grace) {
+                                graces.push((grace, key));
+                            }
+                            continue;
+                        }
+
+                        let mut start = bar_start + start;
+                        let mut duration = duration.duration();
+                        if let Some(&(kind, _)) = graces.first() {
+                            let (first, length) = grace_timing(kind, graces.len(), start, duration);
+                            if first < start {
+                                // Steal from the previous note.
+                                for (note, _) in &mut notes {
+                                    if note.start < first && note.start + note.duration > first {
+                                        note.duration = first - note.start;
+                                    }
+                                }
+                            } else {
+                                // Steal from the main note.
+                                let stolen = length * (graces.len() as isize);
+                                start += stolen;
+                                duration -= stolen;
+                            }
+                            for (i, (_, key)) in graces.drain(..).enumerate() {
+                                if let Some((key, percussion)) = key {
+                                    let grace_start = first + length * (i as isize);
+                                    let note = MidiNote {
+                                        start: grace_start,
+                                        duration: length,
+                                        key,
+                                        velocity: timeline.velocity(grace_start),
+                                    };
+                                    notes.push((note, percussion));
+                                }
+                            }
+                        }
+
+                        if let Some((key, percussion)) = key {
+                            let note = MidiNote {
+                                start,
+                                duration,
+                                key,
+                                velocity: timeline.velocity(start),
+                            };
+                            notes.push((note, percussion));
+                        }
+                    }
+                    played.push((bar_start, first_note..notes.len()));
+                }
+            }
+            let (percussion, pitched): (Vec<_>, Vec<_>) =
+                notes.into_iter().partition(|&(_, percussion)| percussion);
+            if !pitched.is_empty() || percussion.is_empty() {
+                tracks.push(MidiTrack {
+                    notes: pitched.into_iter().map(|(note, _)| note).collect(),
+                    percussion: false,
+                });
+            }
+            if !percussion.is_empty() {
+                tracks.push(MidiTrack {
+                    notes: percussion.into_iter().map(|(note, _)| note).collect(),
+                    percussion: true,
+                });
+            }
+        }
+
+        Some(tracks)
+    }
+}
+
+/// The page and notation settings of a song, as attributes of `node`.
+fn song_settings(node: Node, song: &Song) -> Node {
+    node.with("title", &song.title)
+        .with("title-width", song.title_width)
+        .with("author", &song.author)
+        .with("author-width", song.author_width)
+        .with("width", song.width)
+        .with("height", song.height)
+        .with("rastal-size", song.rastal_size)
+        .with("multirests", song.multirests)
+        .with("beaming", beaming_style_name(song.beaming_style))
+        .with("bar-numbering", bar_numbering_name(song.bar_numbering))
+        .with("bar-number-interval", song.bar_number_interval)
+        .with("bar-numbers-boxed", song.bar_numbers_boxed)
+        .with("first-bar-number", song.first_bar_number)
+        .with("cautionary-accidentals", song.cautionary_accidentals)
+        .with("concert-pitch", song.concert_pitch)
+        .with("drum-kit", drum_kit_name(song.drum_kit))
+        .with("chord-diagrams", chord_diagrams_name(song.chord_diagrams))
+        .with(
+            "chord-diagram-tuning",
+            tuning_text(&song.chord_diagram_tuning),
+        )
+        .with(
+            "rehearsal-marks",
+            rehearsal_mark_style_name(song.rehearsal_mark_style),
+        )
+}
+
+fn instrument_node(instrument: &Instrument) -> Node {
+    Node::new("instrument")
+        .with("name", &instrument.name)
+        .with("steps", instrument.transposition.steps)
+        .with("semitones", instrument.transposition.semitones)
+        .with("clef", clef_name(instrument.clef))
+        .with("low", instrument.range.0)
+        .with("high", instrument.range.1)
+}
+
+fn chord_node(chord: &Chord, context: &Context) -> Option<Node> {
+    let duration = chord.natural_duration;
+    let node = Node::new("chord")
+        .with("note", duration.duration_display_base()?.log2())
+        .with("dots", duration.display_dots()?)
+        .with("start", context.natural_beat);
+    let mut node = match chord.pitch {
+        PitchKind::Rest => node.with("pitch", "rest"),
+        PitchKind::Unpitched => node.with("pitch", "unpitched"),
+        PitchKind::Percussion(instrument) => node
+            .with("pitch", "percussion")
+            .with("instrument", percussion_instrument_name(instrument)),
+        PitchKind::Pitch(pitch) => match pitch.modifier() {
+            Some(modifier) => node
+                .with("pitch", pitch.base_midi())
+                .with("modifier", modifier as i8),
+            None => node.with("pitch", pitch.base_midi()),
+        },
+    };
+
+    if chord.slash {
+        node = node.with("slash", true);
+    }
+    if let Some(grace) = chord.grace {
+        node = node.with("grace", grace_name(grace));
+    }
+    if let Some(beam_override) = chord.beam_override {
+        node = node.with("beam", beam_override_name(beam_override));
+    }
+    if !chord.articulations.is_empty() {
+        let articulations: Vec<&str> = chord
+            .articulations
+            .iter()
+            .map(|&articulation| articulation_name(articulation))
+            .collect();
+        node = node.with("articulations", articulations.join(" "));
+    }
+    if let Some(string) = chord.string {
+        node = node.with("string", string);
+    }
+
+    Some(node)
+}
+
+/// The value in `values` whose name is `name`.
+fn parse_name<T: Copy>(values: &[T], value_name: fn(T) -> &'static str, name: &str) -> Option<T> {
+    values
+        .iter()
+        .copied()
+        .find(|&value| value_name(value) == name)
+}
+
+fn clef_name(clef: Clef) -> &'static str {
+    match clef {
+        Clef::G => "g",
+        Clef::F => "f",
+        Clef::Percussion => "percussion",
+        Clef::Tab => "tab",
+    }
+}
+
+fn parse_clef(name: &str) -> Option<Clef> {
+    parse_name(
+        &[Clef::G, Clef::F, Clef::Percussion, Clef::Tab],
+        clef_name,
+        name,
+    )
+}
+
+fn barline_name(barline: Barline) -> &'static str {
+    match barline {
+        Barline::Normal => "normal",
+        Barline::Final => "final",
+    }
+}
+
+fn parse_barline(name: &str) -> Option<Barline> {
+    parse_name(&[Barline::Normal, Barline::Final], barline_name, name)
+}
+
+fn stem_direction_name(stem_direction: StemDirection) -> &'static str {
+    match stem_direction {
+        StemDirection::Up => "up",
+        StemDirection::Down => "down",
+    }
+}
+
+fn parse_stem_direction(name: &str) -> Option<StemDirection> {
+    parse_name(
+        &[StemDirection::Up, StemDirection::Down],
+        stem_direction_name,
+        name,
+    )
+}
+
+fn beaming_style_name(beaming_style: BeamingStyle) -> &'static str {
+    match beaming_style {
+        BeamingStyle::Division => "division",
+        BeamingStyle::Beat => "beat",
+    }
+}
+
+fn parse_beaming_style(name: &str) -> Option<BeamingStyle> {
+    parse_name(
+        &[BeamingStyle::Division, BeamingStyle::Beat],
+        beaming_style_name,
+        name,
+    )
+}
+
+fn bar_numbering_name(bar_numbering: BarNumbering) -> &'static str {
+    match bar_numbering {
+        BarNumbering::Never => "never",
+        BarNumbering::System => "system",
+        BarNumbering::Interval => "interval",
+        BarNumbering::EveryBar => "every-bar",
+    }
+}
+
+fn parse_bar_numbering(name: &str) -> Option<BarNumbering> {
+    parse_name(
+        &[
+            BarNumbering::Never,
+            BarNumbering::System,
+            BarNumbering::Interval,
+            BarNumbering::EveryBar,
+        ],
+        bar_numbering_name,
+        name,
+    )
+}
+
+fn chord_diagrams_name(chord_diagrams: ChordDiagrams) -> &'static str {
+    match chord_diagrams {
+        ChordDiagrams::Never => "never",
+        ChordDiagrams::AboveStaff => "above-staff",
+        ChordDiagrams::Legend => "legend",
+    }
+}
+
+fn parse_chord_diagrams(name: &str) -> Option<ChordDiagrams> {
+    parse_name(
+        &[
+            ChordDiagrams::Never,
+            ChordDiagrams::AboveStaff,
+            ChordDiagrams::Legend,
+        ],
+        chord_diagrams_name,
+        name,
+    )
+}
+
+fn rehearsal_mark_style_name(style: RehearsalMarkStyle) -> &'static str {
+    match style {
+        RehearsalMarkStyle::Letters => "letters",
+        RehearsalMarkStyle::BarNumbers => "bar-numbers",
+    }
+}
+
+fn parse_rehearsal_mark_style(name: &str) -> Option<RehearsalMarkStyle> {
+    parse_name(
+        &[RehearsalMarkStyle::Letters, RehearsalMarkStyle::BarNumbers],
+        rehearsal_mark_style_name,
+        name,
+    )
+}
+
+fn drum_kit_name(drum_kit: DrumKit) -> &'static str {
+    match drum_kit {
+        DrumKit::Acoustic => "acoustic",
+        DrumKit::Electric => "electric",
+    }
+}
+
+fn parse_drum_kit(name: &str) -> Option<DrumKit> {
+    parse_name(&[DrumKit::Acoustic, DrumKit::Electric], drum_kit_name, name)
+}
+
+fn percussion_instrument_name(instrument: PercussionInstrument) -> &'static str {
+    match instrument {
+        PercussionInstrument::Kick => "kick",
+        PercussionInstrument::Snare => "snare",
+        PercussionInstrument::HiHatClosed => "hi-hat-closed",
+        PercussionInstrument::HiHatOpen => "hi-hat-open",
+        PercussionInstrument::Ride => "ride",
+        PercussionInstrument::Crash => "crash",
+        PercussionInstrument::HighTom => "high-tom",
+        PercussionInstrument::MidTom => "mid-tom",
+        PercussionInstrument::FloorTom => "floor-tom",
+    }
+}
+
+fn parse_percussion_instrument(name: &str) -> Option<PercussionInstrument> {
+    parse_name(&PercussionInstrument::ALL, percussion_instrument_name, name)
+}
+
+fn articulation_name(articulation: Articulation) -> &'static str {
+    match articulation {
+        Articulation::Staccato => "staccato",
+        Articulation::Tenuto => "tenuto",
+        Articulation::Accent => "accent",
+        Articulation::Marcato => "marcato",
+        Articulation::Turn => "turn",
+        Articulation::Trill => "trill",
+        Articulation::Fermata => "fermata",
+    }
+}
+
+fn parse_articulation(name: &str) -> Option<Articulation> {
+    parse_name(
+        &[
+            Articulation::Staccato,
+            Articulation::Tenuto,
+            Articulation::Accent,
+            Articulation::Marcato,
+            Articulation::Turn,
+            Articulation::Trill,
+            Articulation::Fermata,
+        ],
+        articulation_name,
+        name,
+    )
+}
+
+fn grace_name(grace: Grace) -> &'static str {
+    match grace {
+        Grace::Appoggiatura => "appoggiatura",
+        Grace::Acciaccatura => "acciaccatura",
+    }
+}
+
+fn parse_grace(name: &str) -> Option<Grace> {
+    parse_name(
+        &[Grace::Appoggiatura, Grace::Acciaccatura],
+        grace_name,
+        name,
+    )
+}
+
+fn beam_override_name(beam_override: BeamOverride) -> &'static str {
+    match beam_override {
+        BeamOverride::BreakBefore => "break-before",
+        BeamOverride::JoinNext => "join-next",
+        BeamOverride::JoinOverRests => "join-over-rests",
+        BeamOverride::FullBar => "full-bar",
+    }
+}
+
+fn parse_beam_override(name: &str) -> Option<BeamOverride> {
+    parse_name(
+        &[
+            BeamOverride::BreakBefore,
+            BeamOverride::JoinNext,
+            BeamOverride::JoinOverRests,
+            BeamOverride::FullBar,
+        ],
+        beam_override_name,
+        name,
+    )
+}
+
+fn simile_name(simile: Simile) -> &'static str {
+    match simile {
+        Simile::OneBar => "one-bar",
+        Simile::TwoBars => "two-bars",
+    }
+}
+
+fn parse_simile(name: &str) -> Option<Simile> {
+    parse_name(&[Simile::OneBar, Simile::TwoBars], simile_name, name)
+}
+
+fn dynamic_name(dynamic: Dynamic) -> &'static str {
+    match dynamic {
+        Dynamic::Pianissimo => "pp",
+        Dynamic::Piano => "p",
+        Dynamic::MezzoPiano => "mp",
+        Dynamic::MezzoForte => "mf",
+        Dynamic::Forte => "f",
+        Dynamic::Fortissimo => "ff",
+        Dynamic::Sforzando => "sfz",
+        Dynamic::FortePiano => "fp",
+    }
+}
+
+fn parse_dynamic(name: &str) -> Option<Dynamic> {
+    parse_name(
+        &[
+            Dynamic::Pianissimo,
+            Dynamic::Piano,
+            Dynamic::MezzoPiano,
+            Dynamic::MezzoForte,
+            Dynamic::Forte,
+            Dynamic::Fortissimo,
+            Dynamic::Sforzando,
+            Dynamic::FortePiano,
+        ],
+        dynamic_name,
+        name,
+    )
+}
+
+fn hairpin_kind_name(kind: HairpinKind) -> &'static str {
+    match kind {
+        HairpinKind::Crescendo => "crescendo",
+        HairpinKind::Decrescendo => "decrescendo",
+    }
+}
+
+fn parse_hairpin_kind(name: &str) -> Option<HairpinKind> {
+    parse_name(
+        &[HairpinKind::Crescendo, HairpinKind::Decrescendo],
+        hairpin_kind_name,
+        name,
+    )
+}
+
+/// The pitches of open strings, as midi notes separated by spaces, like "64 59 55 50 45 40".
+fn tuning_text(tuning: &[u8]) -> String {
+    let tuning: Vec<String> = tuning.iter().map(u8::to_string).collect();
+    tuning.join(" ")
+}
+
+fn parse_tuning(text: &str) -> Option<Vec<u8>> {
+    text.split_whitespace()
+        .map(|key| key.parse().ok())
+        .collect()
+}
+
+/// Read a time signature like "3/4".
+fn parse_time(time: &str) -> Option<(u8, u8)> {
+    let mut parts = time.split('/');
+    let time: (u8, u8) = (parts.next()?.parse().ok()?, parts.next()?.parse().ok()?);
+    if parts.next().is_some() || time.0 == 0 || !time.1.is_power_of_two() {
+        return None;
+    }
+
+    Some(time)
 }
 
 #[cfg(test)]
@@ -960,56 +3015,23 @@ mod tests {
         render.root_clear(song);
         render.exec();
 
-        assert_eq!(
-            render.state.world.read_component::<Parent>().is_empty(),
-            true
-        );
-        assert_eq!(render.state.world.read_component::<Song>().is_empty(), true);
-        assert_eq!(
-            render.state.world.read_component::<Staff>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render
-                .state
-                .world
-                .read_component::<LineOfStaff>()
-                .is_empty(),
-            true
-        );
-        assert_eq!(render.state.world.read_component::<Bar>().is_empty(), true);
-        assert_eq!(
-            render.state.world.read_component::<Signature>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<Chord>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<Stencil>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<StencilMap>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<WorldBbox>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<Context>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<Spacing>().is_empty(),
-            true
-        );
-        assert_eq!(
-            render.state.world.read_component::<Children>().is_empty(),
-            true
-        );
+        assert!(render.state.world.read_component::<Parent>().is_empty());
+        assert!(render.state.world.read_component::<Song>().is_empty());
+        assert!(render.state.world.read_component::<Staff>().is_empty());
+        assert!(render
+            .state
+            .world
+            .read_component::<LineOfStaff>()
+            .is_empty());
+        assert!(render.state.world.read_component::<Bar>().is_empty());
+        assert!(render.state.world.read_component::<Signature>().is_empty());
+        assert!(render.state.world.read_component::<Chord>().is_empty());
+        assert!(render.state.world.read_component::<Stencil>().is_empty());
+        assert!(render.state.world.read_component::<StencilMap>().is_empty());
+        assert!(render.state.world.read_component::<WorldBbox>().is_empty());
+        assert!(render.state.world.read_component::<Context>().is_empty());
+        assert!(render.state.world.read_component::<Spacing>().is_empty());
+        assert!(render.state.world.read_component::<Children>().is_empty());
     }
 
     #[test]
@@ -1052,4 +3074,2258 @@ mod tests {
             &render.print_for_demo().unwrap_or_default(),
         );
     }
+
+    #[test]
+    fn stem_direction_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Six Eight", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let bar1 = render.bar_create(4, 4);
+        render.child_append(staff, bar1);
+
+        // A rising beamed group above the middle line, a note on the middle line, and a note
+        // below it.
+        for (i, &midi) in [72, 74, 76, 79].iter().enumerate() {
+            let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, i as isize, 8);
+            render.chord_set_pitch(chord, midi, 0);
+            render.bar_insert(bar1, chord, false);
+        }
+        let middle = render.chord_create(NoteValue::Quarter.log2() as isize, 0, 2, 4);
+        render.chord_set_pitch(middle, 71, 0);
+        render.bar_insert(bar1, middle, false);
+        let below = render.chord_create(NoteValue::Quarter.log2() as isize, 0, 3, 4);
+        render.chord_set_pitch(below, 64, 0);
+        render.bar_insert(bar1, below, false);
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let directions = render.state.world.read_component::<StemDirection>();
+            let entities = render.state.world.entities();
+            assert_eq!(
+                directions.get(entities.entity(middle)),
+                Some(&StemDirection::Down)
+            );
+            assert_eq!(
+                directions.get(entities.entity(below)),
+                Some(&StemDirection::Up)
+            );
+        }
+
+        snapshot(
+            "./snapshots/stem_direction_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        // The voice can force a direction.
+        render.staff_set_stem_direction(staff, Some(StemDirection::Up));
+        render.exec();
+        let directions = render.state.world.read_component::<StemDirection>();
+        let entities = render.state.world.entities();
+        assert_eq!(
+            directions.get(entities.entity(middle)),
+            Some(&StemDirection::Up)
+        );
+    }
+
+    #[test]
+    fn beam_overrides() {
+        use rhythm::NoteValue;
+        use staff::components::Beam;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let bar1 = render.bar_create(4, 4);
+        render.child_append(staff, bar1);
+
+        let chords: Vec<u32> = (0..8)
+            .map(|i| {
+                let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, i, 8);
+                render.chord_set_pitch(chord, 72, 0);
+                render.bar_insert(bar1, chord, false);
+                chord
+            })
+            .collect();
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        let beam_sizes = |render: &mut NativeSixDom| {
+            render.exec();
+            let mut sizes: Vec<usize> = render
+                .state
+                .world
+                .read_component::<Beam>()
+                .join()
+                .map(|beam| beam.0.len())
+                .collect();
+            sizes.sort_unstable();
+            sizes
+        };
+
+        // Eighths in 4/4 are beamed in fours, or in pairs.
+        assert_eq!(beam_sizes(&mut render), vec![4, 4]);
+        render.song_set_beaming_style(song, BeamingStyle::Beat);
+        assert_eq!(beam_sizes(&mut render), vec![2, 2, 2, 2]);
+        render.song_set_beaming_style(song, BeamingStyle::Division);
+
+        render.chord_set_beam_override(chords[2], Some(BeamOverride::BreakBefore));
+        assert_eq!(beam_sizes(&mut render), vec![2, 2, 4]);
+
+        // Overrides survive the bar being re-spliced.
+        render.chord_update_time(chords[2], NoteValue::Eighth.log2() as isize, 0, 2, 8, false);
+        assert_eq!(beam_sizes(&mut render), vec![2, 2, 4]);
+
+        render.chord_set_beam_override(chords[3], Some(BeamOverride::JoinNext));
+        assert_eq!(beam_sizes(&mut render), vec![2, 6]);
+
+        render.chord_set_beam_override(chords[3], None);
+        render.chord_set_rest(chords[5]);
+        assert_eq!(beam_sizes(&mut render), vec![2, 2, 2]);
+
+        render.chord_set_beam_override(chords[4], Some(BeamOverride::JoinOverRests));
+        assert_eq!(beam_sizes(&mut render), vec![2, 2, 3]);
+
+        render.chord_set_beam_override(chords[0], Some(BeamOverride::FullBar));
+        assert_eq!(beam_sizes(&mut render), vec![7]);
+    }
+
+    #[test]
+    fn articulations_1() {
+        use rhythm::NoteValue;
+        use staff::components::LineOfStaff;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Articulations", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let bar1 = render.bar_create(4, 4);
+        render.child_append(staff, bar1);
+
+        // Stems up, with marks below; stems down, with marks above; and a note above the staff.
+        let mut chords = vec![];
+        for (i, &midi) in [64, 67, 74, 88].iter().enumerate() {
+            let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, i as isize, 4);
+            render.chord_set_pitch(chord, midi, 0);
+            render.bar_insert(bar1, chord, false);
+            chords.push(chord);
+        }
+
+        let barline = render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+        render.child_append(staff, barline);
+
+        let bar2 = render.bar_create(4, 4);
+        render.child_append(staff, bar2);
+        let half = render.chord_create(NoteValue::Half.log2() as isize, 0, 0, 4);
+        render.chord_set_pitch(half, 71, 0);
+        render.bar_insert(bar2, half, false);
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+        let plain_top = render
+            .state
+            .world
+            .read_component::<LineOfStaff>()
+            .join()
+            .map(|line| line.skyline.highest())
+            .fold(0.0, f64::min);
+
+        render.chord_set_articulation(chords[0], Articulation::Staccato, true);
+        render.chord_set_articulation(chords[0], Articulation::Accent, true);
+        render.chord_set_articulation(chords[1], Articulation::Tenuto, true);
+        render.chord_set_articulation(chords[1], Articulation::Fermata, true);
+        render.chord_set_articulation(chords[2], Articulation::Marcato, true);
+        render.chord_set_articulation(chords[2], Articulation::Staccato, true);
+        render.chord_set_articulation(chords[3], Articulation::Trill, true);
+        render.chord_set_articulation(chords[3], Articulation::Accent, true);
+        render.chord_set_articulation(half, Articulation::Turn, true);
+        render.chord_set_articulation(half, Articulation::Fermata, true);
+        render.exec();
+
+        // Space is kept for marks above the staff.
+        let top = render
+            .state
+            .world
+            .read_component::<LineOfStaff>()
+            .join()
+            .map(|line| line.skyline.highest())
+            .fold(0.0, f64::min);
+        assert!(top < plain_top);
+
+        snapshot(
+            "./snapshots/articulations_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        render.chord_set_articulation(chords[3], Articulation::Trill, false);
+        render.exec();
+        let entities = render.state.world.entities();
+        let chord_components = render.state.world.read_component::<Chord>();
+        assert_eq!(
+            chord_components
+                .get(entities.entity(chords[3]))
+                .map(|chord| chord.articulations.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn dynamics_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Dynamics", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let mut bars = vec![];
+        for i in 0..12 {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            for beat in 0..4 {
+                let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, beat, 4);
+                render.chord_set_pitch(chord, 72, 0);
+                render.bar_insert(bar, chord, false);
+            }
+            bars.push(bar);
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        // p < f, with the crescendo broken across lines, then sfz and a decrescendo.
+        let piano = render.dynamic_create(Dynamic::Piano, 0, 1);
+        render.bar_insert(bars[0], piano, false);
+        let crescendo = render.hairpin_create(HairpinKind::Crescendo, 0, 1, bars[8], 0, 1);
+        render.bar_insert(bars[0], crescendo, false);
+        let forte = render.dynamic_create(Dynamic::Forte, 0, 1);
+        render.bar_insert(bars[8], forte, false);
+        let sforzando = render.dynamic_create(Dynamic::Sforzando, 2, 4);
+        render.bar_insert(bars[9], sforzando, false);
+        let decrescendo = render.hairpin_create(HairpinKind::Decrescendo, 0, 1, bars[11], 3, 4);
+        render.bar_insert(bars[10], decrescendo, false);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let hairpins = render.state.world.read_component::<Hairpin>();
+            let entities = render.state.world.entities();
+            let segments = |hairpin: u32| {
+                hairpins
+                    .get(entities.entity(hairpin))
+                    .map(|h| h.segments.len())
+                    .unwrap_or(0)
+            };
+            assert!(segments(crescendo) > 1);
+            assert_eq!(segments(decrescendo), 1);
+        }
+
+        snapshot(
+            "./snapshots/dynamics_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        // Velocities follow the dynamics.
+        let velocities: Vec<u8> = render.midi_tracks().unwrap()[0]
+            .notes
+            .iter()
+            .map(|note| note.velocity)
+            .collect();
+        assert_eq!(velocities[0], Dynamic::Piano.velocity());
+        assert!(velocities[1] > velocities[0]);
+        assert!(velocities[31] > velocities[16]);
+        assert_eq!(velocities[32], Dynamic::Forte.velocity());
+        assert_eq!(velocities[38], Dynamic::Sforzando.velocity());
+        assert_eq!(velocities[39], Dynamic::Forte.velocity());
+        assert!(velocities[46] < velocities[40]);
+
+        let midi = base64::decode(render.to_midi().unwrap()).unwrap();
+        assert_eq!(&midi[0..4], b"MThd");
+
+        // Removing a hairpin removes it, and its segments, once the orphans are deleted and it
+        // can no longer be brought back by undo.
+        render.bar_remove(bars[0], crescendo);
+        render.history_clear();
+        render.exec();
+        render.exec();
+        assert_eq!(
+            render
+                .state
+                .world
+                .read_component::<Hairpin>()
+                .join()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn slurs_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Slurs", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // Stems up, stems down, a beamed run over a high note, and a run of bars to break.
+        let melody: [u8; 8] = [64, 65, 67, 69, 74, 76, 72, 71];
+        let mut chords = vec![];
+        for i in 0..10 {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            for beat in 0..8 {
+                let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, beat, 8);
+                let midi = if i == 2 && beat == 2 {
+                    84
+                } else {
+                    melody[beat as usize] - if i % 2 == 0 { 0 } else { 5 }
+                };
+                render.chord_set_pitch(chord, midi, 0);
+                render.bar_insert(bar, chord, false);
+                chords.push(chord);
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        let short = render.slur_create(chords[0], chords[3]);
+        let backwards = render.slur_create(chords[12], chords[10]);
+        let over_high_note = render.slur_create(chords[16], chords[20]);
+        let across_lines = render.slur_create(chords[30], chords[60]);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let slurs = render.state.world.read_component::<Slur>();
+            let entities = render.state.world.entities();
+            let segments = |slur: u32| {
+                slurs
+                    .get(entities.entity(slur))
+                    .map(|s| s.segments.len())
+                    .unwrap_or(0)
+            };
+            assert_eq!(segments(short), 1);
+            // Slurs must go forwards.
+            assert_eq!(segments(backwards), 0);
+            assert_eq!(segments(over_high_note), 1);
+            assert_eq!(segments(across_lines), 2);
+        }
+
+        snapshot(
+            "./snapshots/slurs_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+    }
+
+    #[test]
+    fn grace_notes_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Grace Notes", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        for (i, &midi) in [72, 71, 74, 72, 69, 67].iter().enumerate() {
+            let (note_value, start) = if i < 2 {
+                (NoteValue::Quarter, Rational::new(i as isize, 4))
+            } else {
+                (NoteValue::Eighth, Rational::new(i as isize + 2, 8))
+            };
+            let chord = render.chord_create(
+                note_value.log2() as isize,
+                0,
+                *start.numer(),
+                *start.denom(),
+            );
+            render.chord_set_pitch(chord, midi, 0);
+            render.bar_insert(bar, chord, false);
+        }
+
+        // A slashed grace note, a pair of beamed grace notes, and a grace note within a beam.
+        let mut add_grace = |note_value: NoteValue, grace: Grace, midi: u8, numer, denom| {
+            let chord = render.chord_create(note_value.log2() as isize, 0, numer, denom);
+            render.chord_set_pitch(chord, midi, 0);
+            render.chord_set_grace(chord, Some(grace));
+            render.bar_insert(bar, chord, false);
+        };
+        add_grace(NoteValue::Eighth, Grace::Acciaccatura, 74, 0, 1);
+        add_grace(NoteValue::Sixteenth, Grace::Appoggiatura, 69, 1, 4);
+        add_grace(NoteValue::Sixteenth, Grace::Appoggiatura, 74, 1, 4);
+        add_grace(NoteValue::Eighth, Grace::Acciaccatura, 76, 5, 8);
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let bars = render.state.world.read_component::<Bar>();
+            let bar = bars.get(render.state.world.entities().entity(bar)).unwrap();
+            // Grace notes take no time.
+            assert_eq!(bar.rhythm().len(), 6);
+            assert_eq!(bar.children().iter().filter(|c| c.grace).count(), 4);
+        }
+
+        snapshot(
+            "./snapshots/grace_notes_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        let notes = &render.midi_tracks().unwrap()[0].notes;
+        let timing: Vec<(Rational, Rational, u8)> = notes
+            .iter()
+            .map(|note| (note.start, note.duration, note.key))
+            .collect();
+        assert_eq!(
+            &timing[0..5],
+            &[
+                // The first acciaccatura has nothing before it, so it is on the beat.
+                (Rational::new(0, 1), Rational::new(1, 32), 74),
+                (Rational::new(1, 32), Rational::new(7, 32), 72),
+                // Appoggiaturas are on the beat.
+                (Rational::new(1, 4), Rational::new(1, 32), 69),
+                (Rational::new(9, 32), Rational::new(1, 32), 74),
+                (Rational::new(5, 16), Rational::new(3, 16), 71),
+            ]
+        );
+        // Acciaccaturas steal from the previous note.
+        assert_eq!(timing[5], (Rational::new(1, 2), Rational::new(3, 32), 74));
+        assert_eq!(timing[6], (Rational::new(19, 32), Rational::new(1, 32), 76));
+    }
+
+    #[test]
+    fn multirests_1() {
+        use rhythm::NoteValue;
+        use staff::components::Multirest;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Multirests", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_multirests(song, true);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // 5 empty bars, a time change, 3 empty bars, a bar with a note, and 1 empty bar.
+        let bar_counts = [(5, None), (3, Some((3, 4))), (1, None), (1, None)];
+        for (i, &(count, time)) in bar_counts.iter().enumerate() {
+            if i > 0 {
+                let (numer, denom) = match time {
+                    Some((numer, denom)) => (Some(numer), Some(denom)),
+                    None => (None, None),
+                };
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, numer, denom, None);
+                render.child_append(staff, barline);
+            }
+            let (numer, denom) = time.unwrap_or((4, 4));
+            for j in 0..count {
+                if j > 0 {
+                    let barline =
+                        render.signature_create(Some(Barline::Normal), None, None, None, None);
+                    render.child_append(staff, barline);
+                }
+                let bar = render.bar_create(numer, denom);
+                render.child_append(staff, bar);
+                if i == 2 {
+                    let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, 0, 1);
+                    render.chord_set_pitch(chord, 72, 0);
+                    render.bar_insert(bar, chord, false);
+                }
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        let counts = |render: &NativeSixDom| -> Vec<usize> {
+            let staffs = render.state.world.read_component::<Staff>();
+            let multirests = render.state.world.read_component::<Multirest>();
+            let staff = staffs
+                .get(render.state.world.entities().entity(staff))
+                .unwrap();
+            staff
+                .multirests
+                .iter()
+                .map(|&multirest| multirests.get(multirest).unwrap().bars.len())
+                .collect()
+        };
+
+        // Consolidation stops at the time change, and single empty bars are not consolidated.
+        assert_eq!(counts(&render), vec![5, 3]);
+
+        snapshot(
+            "./snapshots/multirests_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        render.song_set_multirests(song, false);
+        render.exec();
+        assert!(counts(&render).is_empty());
+    }
+
+    #[test]
+    fn rehearsal_marks_1() {
+        use rhythm::NoteValue;
+        use staff::components::{LineOfStaff, Multirest};
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Rehearsal Marks", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_multirests(song, true);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // Bars 4 to 9 are empty.
+        let mut bars = vec![];
+        for i in 0..16 {
+            if i > 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, None);
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            if !(4..10).contains(&i) {
+                for (j, &midi) in [60, 64, 67, 72].iter().enumerate() {
+                    let chord =
+                        render.chord_create(NoteValue::Quarter.log2() as isize, 0, j as isize, 4);
+                    render.chord_set_pitch(chord, midi, 0);
+                    render.bar_insert(bar, chord, false);
+                }
+            }
+            bars.push(bar);
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        let mut add_mark = |bar: usize, label: Option<&str>, boxed: bool| {
+            let mark = render.rehearsal_mark_create(label.map(|l| l.to_owned()), boxed);
+            render.bar_insert(bars[bar], mark, false);
+            mark
+        };
+        let a = add_mark(0, None, true);
+        let verse = add_mark(0, Some("Verse"), false);
+        let b = add_mark(6, None, true);
+        let chorus = add_mark(10, Some("Chorus"), false);
+        let c = add_mark(10, None, true);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let marks = render.state.world.read_component::<RehearsalMark>();
+            let text = |mark| {
+                marks
+                    .get(render.state.world.entities().entity(mark))
+                    .unwrap()
+                    .text
+                    .clone()
+            };
+            assert_eq!(text(a), "A");
+            assert_eq!(text(verse), "Verse");
+            assert_eq!(text(b), "B");
+            assert_eq!(text(chorus), "Chorus");
+            assert_eq!(text(c), "C");
+
+            // Multi-measure rests stop at rehearsal marks.
+            let staffs = render.state.world.read_component::<Staff>();
+            let multirests = render.state.world.read_component::<Multirest>();
+            let staff = staffs
+                .get(render.state.world.entities().entity(staff))
+                .unwrap();
+            let counts: Vec<usize> = staff
+                .multirests
+                .iter()
+                .map(|&multirest| multirests.get(multirest).unwrap().bars.len())
+                .collect();
+            assert_eq!(counts, vec![2, 4]);
+
+            let line_of_staffs = render.state.world.read_component::<LineOfStaff>();
+            let children = render.state.world.read_component::<Children>();
+            let starts: Vec<Entity> = staff
+                .lines
+                .iter()
+                .filter(|&&line| line_of_staffs.contains(line))
+                .filter_map(|&line| children.get(line)?.0.get(1).copied())
+                .collect();
+            // The line is broken early, so that the next starts at a rehearsal mark.
+            assert_eq!(starts[1], staff.multirests[1]);
+        }
+
+        snapshot(
+            "./snapshots/rehearsal_marks_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        assert_eq!(
+            render.midi_markers().unwrap(),
+            vec![
+                (Rational::new(0, 1), "A".to_owned()),
+                (Rational::new(0, 1), "Verse".to_owned()),
+                (Rational::new(6, 1), "B".to_owned()),
+                (Rational::new(10, 1), "Chorus".to_owned()),
+                (Rational::new(10, 1), "C".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn bar_numbers_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Bar Numbers", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_bar_numbering(song, BarNumbering::System, 0, false);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // A pickup, then 16 full bars.
+        for i in 0..17 {
+            if i > 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, None);
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            if i == 0 {
+                render.bar_set_skip(bar, 3, 4);
+            }
+            for j in if i == 0 { 3..4 } else { 0..4 } {
+                let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, j, 4);
+                render.chord_set_pitch(chord, 67 + j as u8, 0);
+                render.bar_insert(bar, chord, false);
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        let bar_numbers = |render: &mut NativeSixDom| {
+            render.exec();
+            let staffs = render.state.world.read_component::<Staff>();
+            let staff = staffs
+                .get(render.state.world.entities().entity(staff))
+                .unwrap();
+            (staff.lines.len(), staff.bar_numbers.len())
+        };
+
+        // Every line but the first is numbered.
+        let (lines, numbers) = bar_numbers(&mut render);
+        assert!(lines > 1);
+        assert_eq!(numbers, lines - 1);
+
+        snapshot(
+            "./snapshots/bar_numbers_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        // The pickup is bar 0, so bars 5, 10, and 15 are numbered.
+        render.song_set_bar_numbering(song, BarNumbering::Interval, 5, true);
+        assert_eq!(bar_numbers(&mut render).1, 3);
+        let svg = render.print_for_demo().unwrap_or_default();
+        assert!(svg.contains(">15<"));
+
+        render.song_set_first_bar_number(song, 101);
+        assert_eq!(bar_numbers(&mut render).1, 3);
+        let svg = render.print_for_demo().unwrap_or_default();
+        assert!(svg.contains(">115<"));
+
+        render.song_set_bar_numbering(song, BarNumbering::EveryBar, 0, false);
+        assert_eq!(bar_numbers(&mut render).1, 16);
+    }
+
+    #[test]
+    fn courtesy_1() {
+        use pitch::NoteName;
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Courtesy", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_cautionary_accidentals(song, true);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(2));
+        render.child_append(staff, clef);
+
+        // 5 bars in D major and 4/4, then 11 bars in F major and 3/4.
+        let mut plain_barline = None;
+        let mut key_change = None;
+        let mut chords = vec![];
+        for i in 0..16 {
+            if i == 5 {
+                let signature = render.signature_create(
+                    Some(Barline::Normal),
+                    None,
+                    Some(3),
+                    Some(4),
+                    Some(-1),
+                );
+                render.child_append(staff, signature);
+                key_change = Some(signature);
+            } else if i > 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, None);
+                render.child_append(staff, barline);
+                plain_barline = Some(barline);
+            }
+            let beats = if i < 5 { 4 } else { 3 };
+            let bar = render.bar_create(beats, 4);
+            render.child_append(staff, bar);
+            for j in 0..beats {
+                let chord =
+                    render.chord_create(NoteValue::Quarter.log2() as isize, 0, j as isize, 4);
+                // G#5 and G4 in the first bar, then G5 in the second.
+                let (midi, modifier) = match (i, j) {
+                    (0, 0) => (79, 1),
+                    (0, 1) => (67, 0),
+                    (1, 0) => (79, 0),
+                    _ => (74, 0),
+                };
+                render.chord_set_pitch(chord, midi, modifier);
+                render.bar_insert(bar, chord, false);
+                chords.push(chord);
+            }
+        }
+
+        let final_barline = render.signature_create(Some(Barline::Final), None, None, None, None);
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let cautionary = |chord: u32| {
+                let contexts = render.state.world.read_component::<Context>();
+                let context = contexts
+                    .get(render.state.world.entities().entity(chord))
+                    .unwrap();
+                context.cautionary.clone()
+            };
+            // The G in the other octave, and the G in the next bar.
+            assert!(cautionary(chords[1]).contains(&(NoteName::G, 4)));
+            assert!(cautionary(chords[4]).contains(&(NoteName::G, 5)));
+            assert!(cautionary(chords[8]).is_empty());
+
+            // The courtesy key and time signature make the end of the line wider.
+            let signatures = render.state.world.read_component::<Signature>();
+            let stencils = render.state.world.read_component::<Stencil>();
+            let end_advance = |signature: u32| {
+                let signature = signatures
+                    .get(render.state.world.entities().entity(signature))
+                    .unwrap();
+                stencils.get(signature.stencil_end).unwrap().advance()
+            };
+            assert!(
+                end_advance(key_change.unwrap()) > end_advance(plain_barline.unwrap()) + 1000.0
+            );
+        }
+
+        snapshot(
+            "./snapshots/courtesy_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        render.song_set_cautionary_accidentals(song, false);
+        render.exec();
+        let contexts = render.state.world.read_component::<Context>();
+        assert!(contexts
+            .get(render.state.world.entities().entity(chords[4]))
+            .unwrap()
+            .cautionary
+            .is_empty());
+    }
+
+    #[test]
+    fn clef_changes_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Clef Changes", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // A change to the bass clef in the middle of the first line, and back to the treble clef
+        // at the start of the second line.
+        let mut bar_chords = vec![];
+        let mut treble = None;
+        for i in 0..12 {
+            if i > 0 {
+                let clef = match i {
+                    2 => Some(Clef::F),
+                    5 => Some(Clef::G),
+                    _ => None,
+                };
+                let barline =
+                    render.signature_create(Some(Barline::Normal), clef, None, None, None);
+                render.child_append(staff, barline);
+                if i == 5 {
+                    treble = Some(barline);
+                }
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            let mut chords = vec![];
+            for j in 0..4 {
+                let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, j, 4);
+                let midi = if (2..5).contains(&i) { 48 } else { 72 };
+                render.chord_set_pitch(chord, midi + 2 * j as u8, 0);
+                render.bar_insert(bar, chord, false);
+                chords.push(chord);
+            }
+            bar_chords.push(chords);
+        }
+
+        let final_barline = render.signature_create(Some(Barline::Final), None, None, None, None);
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        render.exec();
+
+        {
+            let contexts = render.state.world.read_component::<Context>();
+            let clef_of = |chord: u32| {
+                contexts
+                    .get(render.state.world.entities().entity(chord))
+                    .unwrap()
+                    .clef
+            };
+            assert_eq!(clef_of(bar_chords[1][3]), Clef::G);
+            assert_eq!(clef_of(bar_chords[2][0]), Clef::F);
+            assert_eq!(clef_of(bar_chords[4][3]), Clef::F);
+            assert_eq!(clef_of(bar_chords[5][0]), Clef::G);
+
+            // The change back to the treble clef starts the second line, so the first line ends
+            // with a courtesy clef.
+            let signatures = render.state.world.read_component::<Signature>();
+            let children = render.state.world.read_component::<Children>();
+            let staffs = render.state.world.read_component::<Staff>();
+            let treble = signatures
+                .get(render.state.world.entities().entity(treble.unwrap()))
+                .unwrap();
+            let staff = staffs
+                .get(render.state.world.entities().entity(staff))
+                .unwrap();
+            let first_line = &children.get(staff.lines[0]).unwrap().0;
+            assert_eq!(first_line.last(), Some(&treble.stencil_end));
+        }
+
+        snapshot(
+            "./snapshots/clef_changes_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+    }
+
+    #[test]
+    fn glyph_hit_testing() {
+        use rhythm::NoteValue;
+        use staff::components::{AccidentalsForChord, LegerLinesForChord};
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // A sharp with two leger lines below the staff, and a plain note.
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        let low = render.chord_create(NoteValue::Half.log2() as isize, 0, 0, 1);
+        render.chord_set_pitch(low, 55, 1);
+        render.bar_insert(bar, low, false);
+        let plain = render.chord_create(NoteValue::Half.log2() as isize, 0, 1, 2);
+        render.chord_set_pitch(plain, 71, 0);
+        render.bar_insert(bar, plain, false);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        let world = &render.state.world;
+        let low_entity = world.entities().entity(low);
+        let plain_entity = world.entities().entity(plain);
+        let accidentals = world.read_component::<AccidentalsForChord>();
+        let leger_lines = world.read_component::<LegerLinesForChord>();
+        assert_eq!(accidentals.get(low_entity).unwrap().0.len(), 1);
+        assert_eq!(leger_lines.get(low_entity).unwrap().0.len(), 2);
+        assert!(accidentals.get(plain_entity).unwrap().0.is_empty());
+        assert!(leger_lines.get(plain_entity).unwrap().0.is_empty());
+
+        let center = |entity: Entity| {
+            let bbox = world.read_component::<WorldBbox>().get(entity).unwrap().0;
+            ((bbox.x0 + bbox.x1) / 2.0, (bbox.y0 + bbox.y1) / 2.0)
+        };
+
+        let accidental = accidentals.get(low_entity).unwrap().0[0];
+        let (x, y) = center(accidental);
+        assert_eq!(
+            render.get_glyph_at(x, y),
+            Some(vec![accidental.id() as isize, 2, low as isize])
+        );
+
+        // The end of a leger line, beyond the notehead.
+        let leger_line = leger_lines.get(low_entity).unwrap().0[0];
+        let bbox = world
+            .read_component::<WorldBbox>()
+            .get(leger_line)
+            .unwrap()
+            .0;
+        assert_eq!(
+            render.get_glyph_at(bbox.x1 - 1.0, (bbox.y0 + bbox.y1) / 2.0),
+            Some(vec![leger_line.id() as isize, 3, low as isize])
+        );
+
+        let (x, y) = center(plain_entity);
+        assert_eq!(render.get_glyph_at(x, y).map(|glyph| glyph[1]), Some(0));
+
+        // Without an accidental, its entity is orphaned, and then deleted.
+        drop(accidentals);
+        drop(leger_lines);
+        render.chord_set_pitch(low, 55, 0);
+        render.exec();
+        assert!(!render
+            .state
+            .world
+            .read_component::<Accidental>()
+            .contains(accidental));
+        render.exec();
+        assert!(!render.state.world.entities().is_alive(accidental));
+    }
+
+    #[test]
+    fn rastal_size_reflows() {
+        use staff::components::LineOfStaff;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_freeze_spacing(song, Some(1));
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        for _ in 0..24 {
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            let barline = render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+            render.child_append(staff, barline);
+        }
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        let count_lines =
+            |render: &NativeSixDom| render.state.world.read_component::<LineOfStaff>().count();
+
+        // The title stays at the same place on the page, in mm.
+        let title_y = |render: &NativeSixDom| {
+            let song = render.state.world.entities().entity(song);
+            let songs = render.state.world.read_component::<Song>();
+            let song = songs.get(song).unwrap();
+            match render
+                .state
+                .world
+                .read_component::<Stencil>()
+                .get(song.title_stencil.unwrap())
+            {
+                Some(Stencil::Translate(offset, _)) => offset.y * song.scale(),
+                _ => panic!("Expected a translated title"),
+            }
+        };
+        render.song_set_title(song, "Six Eight", 26.4f64);
+
+        render.song_set_rastal_size(song, 8);
+        render.exec();
+        let small_lines = count_lines(&render);
+        let small_title_y = title_y(&render);
+
+        // Spacing is frozen, but changing the rastal size must still re-flow.
+        render.song_set_rastal_size(song, 0);
+        render.exec();
+        let large_lines = count_lines(&render);
+
+        assert!(small_lines > 0);
+        assert!(large_lines > small_lines);
+        assert!((title_y(&render) - small_title_y).abs() < 0.001);
+
+        // Out-of-range sizes are ignored.
+        render.song_set_rastal_size(song, 9);
+        render.exec();
+        assert_eq!(count_lines(&render), large_lines);
+    }
+
+    #[test]
+    fn skyline_placement() {
+        use rhythm::NoteValue;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // A note far below the staff, then one inside it, each with a dynamic.
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        let low = render.chord_create(NoteValue::Half.log2() as isize, 0, 0, 1);
+        render.chord_set_pitch(low, 48, 0);
+        render.bar_insert(bar, low, false);
+        let high = render.chord_create(NoteValue::Half.log2() as isize, 0, 1, 2);
+        render.chord_set_pitch(high, 72, 0);
+        render.bar_insert(bar, high, false);
+        let forte = render.dynamic_create(Dynamic::Forte, 0, 1);
+        render.bar_insert(bar, forte, false);
+        let piano = render.dynamic_create(Dynamic::Piano, 1, 2);
+        render.bar_insert(bar, piano, false);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        let world = &render.state.world;
+        let bbox = |id: u32| {
+            world
+                .read_component::<WorldBbox>()
+                .get(world.entities().entity(id))
+                .unwrap()
+                .0
+        };
+
+        // The dynamic under the low note is pushed below it, but the other stays put.
+        assert!(bbox(forte).y0 > bbox(low).y1);
+        assert!(bbox(piano).y0 < bbox(forte).y0);
+    }
+
+    #[test]
+    fn percussion_1() {
+        use rhythm::NoteValue;
+        use staff::components::LegerLinesForChord;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Percussion", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_drum_kit(song, DrumKit::Electric);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::Percussion), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // Each instrument in turn, as quarters and then as eighths.
+        let instruments = [
+            PercussionInstrument::Kick,
+            PercussionInstrument::FloorTom,
+            PercussionInstrument::Snare,
+            PercussionInstrument::MidTom,
+            PercussionInstrument::HighTom,
+            PercussionInstrument::Ride,
+            PercussionInstrument::HiHatClosed,
+            PercussionInstrument::HiHatOpen,
+            PercussionInstrument::Crash,
+        ];
+        for (i, &instrument) in instruments.iter().enumerate() {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            for beat in 0..2 {
+                let chord = render.chord_create(NoteValue::Quarter.log2() as isize, 0, beat, 4);
+                render.chord_set_percussion(chord, instrument);
+                render.bar_insert(bar, chord, false);
+            }
+            for eighth in 4..8 {
+                let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, eighth, 8);
+                render.chord_set_percussion(chord, instrument);
+                render.bar_insert(bar, chord, false);
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        snapshot(
+            "./snapshots/percussion_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        // Only the crash needs a leger line.
+        {
+            let chords = render.state.world.read_component::<Chord>();
+            let leger_lines = render.state.world.read_component::<LegerLinesForChord>();
+            for (chord, leger_lines) in (&chords, &leger_lines).join() {
+                let expected = match chord.pitch {
+                    PitchKind::Percussion(PercussionInstrument::Crash) => 1,
+                    _ => 0,
+                };
+                assert_eq!(leger_lines.0.len(), expected);
+            }
+        }
+
+        let tracks = render.midi_tracks().unwrap();
+        assert!(tracks[0].percussion);
+        assert_eq!(tracks[0].notes.len(), instruments.len() * 6);
+        assert_eq!(tracks[0].notes[0].key, 36);
+        assert_eq!(tracks[0].notes[12].key, 40);
+
+        render.song_set_drum_kit(song, DrumKit::Acoustic);
+        let tracks = render.midi_tracks().unwrap();
+        assert_eq!(tracks[0].notes[0].key, 35);
+        assert_eq!(tracks[0].notes[12].key, 38);
+
+        // Pitched notes on the same staff are not played as drums.
+        let clef = render.signature_create(None, Some(Clef::G), None, None, None);
+        render.child_insert_before(staff, final_barline, clef);
+        let bar = render.bar_create(4, 4);
+        render.child_insert_before(staff, final_barline, bar);
+        let chord = render.chord_create(NoteValue::Whole.log2() as isize, 0, 0, 1);
+        render.chord_set_pitch(chord, 60, 0);
+        render.bar_insert(bar, chord, false);
+        render.exec();
+
+        let tracks = render.midi_tracks().unwrap();
+        assert_eq!(tracks.len(), 2);
+        assert!(!tracks[0].percussion);
+        assert_eq!(tracks[0].notes.len(), 1);
+        assert_eq!(tracks[0].notes[0].key, 60);
+        assert!(tracks[1].percussion);
+        assert_eq!(tracks[1].notes.len(), instruments.len() * 6);
+    }
+
+    #[test]
+    fn slashes_1() {
+        use rhythm::NoteValue;
+        use staff::components::LegerLinesForChord;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Slashes", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        // A bar of quarters, repeated by a one-bar repeat, and then both bars repeated by a two-bar
+        // repeat. Then a bar of beat slashes, and a bar of rhythm slashes.
+        let mut bars = vec![];
+        for i in 0..6 {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            bars.push(bar);
+        }
+
+        for (beat, &midi) in [60, 62, 64, 65].iter().enumerate() {
+            let chord =
+                render.chord_create(NoteValue::Quarter.log2() as isize, 0, beat as isize, 4);
+            render.chord_set_pitch(chord, midi, 0);
+            render.bar_insert(bars[0], chord, false);
+        }
+        render.bar_set_simile(bars[1], Some(Simile::OneBar));
+        render.bar_set_simile(bars[2], Some(Simile::TwoBars));
+        render.bar_set_slash_fill(bars[4], true);
+
+        let rhythm = [
+            (NoteValue::Eighth, 0, 8),
+            (NoteValue::Eighth, 1, 8),
+            (NoteValue::Quarter, 1, 4),
+            (NoteValue::Half, 1, 2),
+        ];
+        for &(note_value, num, den) in &rhythm {
+            let chord = render.chord_create(note_value.log2() as isize, 0, num, den);
+            render.chord_set_pitch(chord, 84, 0);
+            render.chord_set_slash(chord, true);
+            render.bar_insert(bars[5], chord, false);
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        snapshot(
+            "./snapshots/slashes_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        {
+            let entities = render.state.world.entities();
+            let bar_components = render.state.world.read_component::<Bar>();
+            let chords = render.state.world.read_component::<Chord>();
+            let leger_lines = render.state.world.read_component::<LegerLinesForChord>();
+
+            // The empty bar has a slash for each beat.
+            let slash_fill = bar_components
+                .get(entities.entity(bars[4]))
+                .unwrap()
+                .children();
+            assert_eq!(slash_fill.len(), 4);
+            for child in &slash_fill {
+                assert!(chords.get(child.stencil).unwrap().slash);
+            }
+
+            // Rhythm slashes are on the middle line, whatever their pitch.
+            for (chord, leger_lines) in (&chords, &leger_lines).join() {
+                if chord.slash {
+                    assert!(leger_lines.0.is_empty());
+                }
+            }
+        }
+
+        // Repeats play the bars they repeat. Slashes are not played.
+        let tracks = render.midi_tracks().unwrap();
+        let notes: Vec<(Rational, u8)> = tracks[0]
+            .notes
+            .iter()
+            .map(|note| (note.start, note.key))
+            .collect();
+        let bar: Vec<(Rational, u8)> = [60, 62, 64, 65]
+            .iter()
+            .enumerate()
+            .map(|(beat, &key)| (Rational::new(beat as isize, 4), key))
+            .collect();
+        let expected: Vec<(Rational, u8)> = (0..4)
+            .flat_map(|i| {
+                bar.iter()
+                    .map(move |&(start, key)| (start + Rational::from_integer(i), key))
+            })
+            .collect();
+        assert_eq!(notes, expected);
+    }
+
+    #[test]
+    fn tab_1() {
+        use rhythm::NoteValue;
+        use staff::components::TabNote;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Tablature", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let mut bars = vec![];
+        for i in 0..3 {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            bars.push(bar);
+        }
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        // (bar, note value, dots, start, base midi, modifier)
+        let notes = [
+            (0, NoteValue::Quarter, 0, (0, 4), 40, 0),
+            (0, NoteValue::Quarter, 0, (1, 4), 45, 0),
+            (0, NoteValue::Quarter, 0, (2, 4), 52, 0),
+            (0, NoteValue::Quarter, 0, (3, 4), 67, 0),
+            (1, NoteValue::Eighth, 0, (0, 8), 60, 0),
+            (1, NoteValue::Eighth, 0, (1, 8), 62, 0),
+            (1, NoteValue::Quarter, 1, (1, 4), 64, 0),
+            (1, NoteValue::Eighth, 0, (5, 8), 65, 1),
+            (2, NoteValue::Whole, 0, (0, 1), 57, 0),
+        ];
+        let mut chords = vec![];
+        for &(bar, note_value, dots, (num, den), midi, modifier) in &notes {
+            let chord = render.chord_create(note_value.log2() as isize, dots, num, den);
+            render.chord_set_pitch(chord, midi, modifier);
+            render.bar_insert(bars[bar], chord, false);
+            chords.push(chord);
+        }
+        render.chord_set_string(chords[8], Some(5));
+
+        let tab_staff = render.tab_staff_create(vec![64, 59, 55, 50, 45, 40]);
+        render.tab_staff_set_link(tab_staff, Some(staff));
+
+        render.child_append(song, staff);
+        render.child_append(song, tab_staff);
+        render.root_set(song);
+        render.exec();
+
+        snapshot(
+            "./snapshots/tab_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        let mirror = |render: &NativeSixDom, chord: u32| {
+            let entities = render.state.world.entities();
+            let staffs = render.state.world.read_component::<Staff>();
+            staffs.get(entities.entity(tab_staff)).unwrap().mirrors[&entities.entity(chord)]
+        };
+        let tab_note = |render: &NativeSixDom, chord: u32| {
+            let tab_notes = render.state.world.read_component::<TabNote>();
+            tab_notes.get(mirror(render, chord)).copied()
+        };
+
+        // Open strings and low frets are preferred, then frets near the last one, unless a string
+        // is chosen.
+        let expected = [
+            (6, 0),
+            (5, 0),
+            (4, 2),
+            (1, 3),
+            (2, 1),
+            (2, 3),
+            (1, 0),
+            (1, 2),
+            (5, 12),
+        ];
+        for (&chord, &(string, fret)) in chords.iter().zip(&expected) {
+            assert_eq!(tab_note(&render, chord), Some(TabNote { string, fret }));
+        }
+
+        // Edits on the tab staff are made to the staff it is linked to.
+        let mirror_id = mirror(&render, chords[8]).id();
+        render.chord_set_fret(mirror_id, 2, 1);
+        {
+            let entities = render.state.world.entities();
+            let chord_components = render.state.world.read_component::<Chord>();
+            let chord = chord_components.get(entities.entity(chords[8])).unwrap();
+            assert_eq!(chord.pitch, PitchKind::Pitch(Pitch::middle_c()));
+            assert_eq!(chord.string, Some(2));
+        }
+        render.exec();
+        assert_eq!(
+            tab_note(&render, chords[8]),
+            Some(TabNote { string: 2, fret: 1 })
+        );
+
+        // The tab staff is not played twice.
+        assert_eq!(render.midi_tracks().unwrap().len(), 1);
+    }
+
+    /// A song of slash bars with chord symbols, for the chord diagram tests.
+    fn chord_symbol_song(chord_diagrams: ChordDiagrams) -> (NativeSixDom, u32, Vec<u32>) {
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Chord Diagrams", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_chord_diagrams(song, chord_diagrams, vec![64, 59, 55, 50, 45, 40]);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+
+        let chords = [
+            [("C", 0), ("Am", 2)],
+            [("F", 0), ("G/B", 2)],
+            [("C", 0), ("Bb", 2)],
+            [("F#m7", 0), ("C", 2)],
+        ];
+        let mut harmonies = vec![];
+        for (i, bar_chords) in chords.iter().enumerate() {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(0));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.bar_set_slash_fill(bar, true);
+            render.child_append(staff, bar);
+            for &(symbol, beat) in bar_chords {
+                let harmony = render.harmony_create(symbol, beat, 4).unwrap();
+                render.bar_insert(bar, harmony, false);
+                harmonies.push(harmony);
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+
+        (render, song, harmonies)
+    }
+
+    #[test]
+    fn chord_diagrams_1() {
+        use stencil::snapshot;
+
+        let (mut render, song, harmonies) = chord_symbol_song(ChordDiagrams::AboveStaff);
+        assert!(render.song_set_chord_voicing(song, "F", Some("xx3211".to_owned())));
+        assert!(!render.song_set_chord_voicing(song, "F", Some("xx32?1".to_owned())));
+        assert!(!render.song_set_chord_voicing(song, "Fmaj13", None));
+        assert!(render.harmony_create("H7", 0, 1).is_none());
+        render.exec();
+
+        snapshot(
+            "./snapshots/chord_diagrams_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        // Only the first use of each chord has a diagram.
+        let entities = render.state.world.entities();
+        let stencils = render.state.world.read_component::<Stencil>();
+        let height = |harmony: u32| {
+            stencils
+                .get(entities.entity(harmony))
+                .unwrap()
+                .rect()
+                .height()
+        };
+        let with_diagram = harmonies
+            .iter()
+            .filter(|&&harmony| height(harmony) > 1500.0);
+        assert_eq!(with_diagram.count(), 6);
+        assert!(height(harmonies[0]) > height(harmonies[4]));
+    }
+
+    #[test]
+    fn chord_diagrams_2() {
+        use stencil::snapshot;
+
+        let (mut render, song, harmonies) = chord_symbol_song(ChordDiagrams::Legend);
+        render.exec();
+
+        snapshot(
+            "./snapshots/chord_diagrams_2.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        {
+            let entities = render.state.world.entities();
+            let songs = render.state.world.read_component::<Song>();
+            let stencils = render.state.world.read_component::<Stencil>();
+            let legend = songs
+                .get(entities.entity(song))
+                .unwrap()
+                .chord_legend_stencil;
+            assert!(stencils.contains(legend.unwrap()));
+            for &harmony in &harmonies {
+                let height = stencils
+                    .get(entities.entity(harmony))
+                    .unwrap()
+                    .rect()
+                    .height();
+                assert!(height < 1500.0);
+            }
+        }
+
+        // The legend is removed when diagrams are turned off.
+        render.song_set_chord_diagrams(song, ChordDiagrams::Never, vec![]);
+        render.exec();
+        let entities = render.state.world.entities();
+        let songs = render.state.world.read_component::<Song>();
+        assert_eq!(
+            songs
+                .get(entities.entity(song))
+                .unwrap()
+                .chord_legend_stencil,
+            None
+        );
+    }
+
+    #[test]
+    fn transposing_1() {
+        use pitch::NoteName;
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Transposing", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+
+        let staff = render.staff_create();
+        render.staff_set_instrument(staff, "Trumpet in Bb", 1, 2, Clef::G, 52, 84);
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(-3));
+        render.child_append(staff, clef);
+
+        // Eb major is written in F major, and F# major, which would be G# major, in Ab major.
+        let bars = [
+            [(59, -1), (64, -1), (67, 0), (50, 0)],
+            [(65, 1), (69, 1), (71, 0), (72, 1)],
+        ];
+        let mut chords = vec![];
+        for (i, notes) in bars.iter().enumerate() {
+            if i != 0 {
+                let barline =
+                    render.signature_create(Some(Barline::Normal), None, None, None, Some(6));
+                render.child_append(staff, barline);
+            }
+            let bar = render.bar_create(4, 4);
+            render.child_append(staff, bar);
+            for (beat, &(midi, modifier)) in notes.iter().enumerate() {
+                let chord =
+                    render.chord_create(NoteValue::Quarter.log2() as isize, 0, beat as isize, 4);
+                render.chord_set_pitch(chord, midi, modifier);
+                render.bar_insert(bar, chord, false);
+                chords.push(chord);
+            }
+        }
+
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        snapshot(
+            "./snapshots/transposing_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        let written = |render: &NativeSixDom, chord: u32| {
+            let chord = render.state.world.entities().entity(chord);
+            let chords = render.state.world.read_component::<Chord>();
+            let chord = chords.get(chord).unwrap();
+            (chord.pitch, chord.written_pitch())
+        };
+        let (pitch, written_pitch) = written(&render, chords[0]);
+        assert_eq!(
+            pitch,
+            PitchKind::Pitch(Pitch::new(NoteName::B, Some(NoteModifier::SemiDown), 3))
+        );
+        assert_eq!(written_pitch, PitchKind::Pitch(Pitch::middle_c()));
+        assert_eq!(
+            written(&render, chords[4]).1,
+            PitchKind::Pitch(Pitch::new(NoteName::A, Some(NoteModifier::SemiDown), 4))
+        );
+
+        // D3 is below the range of the trumpet.
+        let out_of_range: Vec<bool> = chords
+            .iter()
+            .map(|&chord| render.chord_is_out_of_range(chord))
+            .collect();
+        assert_eq!(
+            out_of_range,
+            vec![false, false, false, true, false, false, false, false]
+        );
+
+        // At concert pitch, notes are written as stored.
+        render.song_set_concert_pitch(song, true);
+        render.exec();
+        for &chord in &chords {
+            let (pitch, written_pitch) = written(&render, chord);
+            assert_eq!(pitch, written_pitch);
+        }
+        assert!(render.chord_is_out_of_range(chords[3]));
+    }
+
+    #[test]
+    fn part_1() {
+        use rhythm::NoteValue;
+        use staff::components::Multirest;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Parts", 26.4f64);
+        render.song_set_author(song, "Six Eight", 26.4f64 * 5f64 / 7f64);
+        render.song_set_concert_pitch(song, true);
+
+        let mut staffs = vec![];
+        let mut chords = vec![];
+        for &(name, steps, semitones) in &[("Flute", 0, 0), ("Trumpet in Bb", 1, 2)] {
+            let staff = render.staff_create();
+            render.staff_set_instrument(staff, name, steps, semitones, Clef::G, 52, 96);
+            let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(-3));
+            render.child_append(staff, clef);
+
+            // A bar of notes, 3 empty bars, and a rehearsal mark on a bar of notes.
+            for i in 0..5 {
+                if i != 0 {
+                    let barline =
+                        render.signature_create(Some(Barline::Normal), None, None, None, None);
+                    render.child_append(staff, barline);
+                }
+                let bar = render.bar_create(4, 4);
+                render.child_append(staff, bar);
+                if i == 0 {
+                    let forte = render.dynamic_create(Dynamic::MezzoForte, 0, 1);
+                    render.bar_insert(bar, forte, false);
+                }
+                if i == 4 {
+                    let mark = render.rehearsal_mark_create(None, true);
+                    render.bar_insert(bar, mark, false);
+                }
+                if i == 0 || i == 4 {
+                    for (beat, &(midi, modifier)) in
+                        [(59, -1), (64, -1), (65, 0), (67, 0)].iter().enumerate()
+                    {
+                        let chord = render.chord_create(
+                            NoteValue::Quarter.log2() as isize,
+                            0,
+                            beat as isize,
+                            4,
+                        );
+                        render.chord_set_pitch(chord, midi, modifier);
+                        render.bar_insert(bar, chord, false);
+                        chords.push(chord);
+                    }
+                }
+            }
+
+            let final_barline =
+                render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+            render.child_append(staff, final_barline);
+            render.child_append(song, staff);
+            staffs.push(staff);
+        }
+
+        render.root_set(song);
+        render.exec();
+
+        let part = render.part_create(song, staffs[1]).unwrap();
+        assert_eq!(render.part_create(part, staffs[1]), None);
+
+        // The score is kept alive while the part is shown.
+        render.root_clear(song);
+        render.root_set(part);
+        render.exec();
+        assert!(render
+            .state
+            .world
+            .is_alive(render.state.world.entities().entity(song)));
+
+        snapshot(
+            "./snapshots/part_1.svg",
+            &render.print_for_demo().unwrap_or_default(),
+        );
+
+        let entity = |render: &NativeSixDom, id: u32| render.state.world.entities().entity(id);
+        let part_staff = render
+            .state
+            .world
+            .read_component::<Children>()
+            .get(entity(&render, part))
+            .unwrap()
+            .0[0];
+        let mirror = |render: &NativeSixDom, chord: u32| {
+            let staffs = render.state.world.read_component::<Staff>();
+            staffs.get(part_staff).unwrap().mirrors[&entity(render, chord)]
+        };
+
+        // The empty bars of the part are a multi-measure rest, but not those of the score.
+        let multirest_bars = |render: &NativeSixDom, staff: Entity| -> Vec<usize> {
+            let staffs = render.state.world.read_component::<Staff>();
+            let multirests = render.state.world.read_component::<Multirest>();
+            staffs
+                .get(staff)
+                .unwrap()
+                .multirests
+                .iter()
+                .map(|&multirest| multirests.get(multirest).unwrap().bars.len())
+                .collect()
+        };
+        assert_eq!(multirest_bars(&render, part_staff), vec![3]);
+
+        // Edits to the part apply to the score, and the part stays transposed.
+        let first = mirror(&render, chords[8]);
+        render.chord_set_pitch(first.id(), 60, 0);
+        render.exec();
+        let pitches = |render: &NativeSixDom, chord: Entity| {
+            let chords = render.state.world.read_component::<Chord>();
+            let chord = chords.get(chord).unwrap();
+            (chord.pitch, chord.written_pitch())
+        };
+        assert_eq!(
+            pitches(&render, entity(&render, chords[8])).0,
+            PitchKind::Pitch(Pitch::middle_c())
+        );
+        assert_eq!(
+            pitches(&render, mirror(&render, chords[8])).1,
+            PitchKind::Pitch(Pitch::from_midi(62, 0))
+        );
+
+        render.root_clear(part);
+        render.root_set(song);
+        render.exec();
+        assert_eq!(multirest_bars(&render, entity(&render, staffs[1])), vec![]);
+
+        // A removed part is deleted with the next update, unless undo can bring it back.
+        let part_entity = entity(&render, part);
+        render.part_remove(part);
+        render.exec();
+        assert!(render.state.world.is_alive(part_entity));
+        render.history_clear();
+        render.exec();
+        assert!(!render.state.world.is_alive(part_entity));
+    }
+
+    #[test]
+    fn undo_1() {
+        use rhythm::{BarChild, NoteValue};
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        // Only attaching the staff to the song is recorded.
+        assert!(render.can_undo());
+        render.history_clear();
+        assert!(!render.can_undo());
+
+        let bar = render.state.world.entities().entity(bar);
+        let bar_state = |render: &NativeSixDom| -> (Vec<(Duration, Lifetime)>, Vec<BarChild>) {
+            let bars = render.state.world.read_component::<Bar>();
+            let bar = bars.get(bar).unwrap();
+            (bar.rhythm().clone(), bar.children())
+        };
+        let empty = bar_state(&render);
+
+        let quarter = NoteValue::Quarter.log2() as isize;
+        let chord = render.chord_create(quarter, 0, 1, 4);
+        render.chord_set_pitch(chord, 60, 0);
+        render.bar_insert(bar.id(), chord, false);
+        render.exec();
+        let inserted = bar_state(&render);
+
+        // A drag is one undo step, and previews are not recorded.
+        render.history_begin_group();
+        render.chord_update_time(chord, quarter, 0, 2, 4, false);
+        render.exec();
+        render.chord_update_time(chord, quarter, 0, 3, 4, false);
+        render.exec();
+        render.history_end_group();
+        let preview = render.chord_create(quarter, 0, 0, 4);
+        render.bar_insert(bar.id(), preview, true);
+        render.exec();
+        render.bar_remove(bar.id(), preview);
+        render.exec();
+        let dragged = bar_state(&render);
+        assert_ne!(dragged, inserted);
+
+        // Undo restores the bar exactly, including its automatic rests.
+        assert!(render.undo());
+        render.exec();
+        assert_eq!(bar_state(&render), inserted);
+        assert!(render.undo());
+        render.exec();
+        assert_eq!(bar_state(&render), empty);
+        assert!(!render.undo());
+
+        let chord_entity = render.state.world.entities().entity(chord);
+        assert!(render.state.world.is_alive(chord_entity));
+        assert!(render.redo());
+        render.exec();
+        assert_eq!(bar_state(&render), inserted);
+        assert!(render.redo());
+        render.exec();
+        assert_eq!(bar_state(&render), dragged);
+        assert!(!render.redo());
+
+        // A new edit forgets what was undone.
+        render.undo();
+        assert!(render.can_redo());
+        render.chord_set_pitch(chord, 62, 0);
+        assert!(!render.can_redo());
+
+        // Forgotten entities are deleted.
+        render.undo();
+        render.undo();
+        render.history_clear();
+        render.exec();
+        assert!(!render.state.world.is_alive(chord_entity));
+        assert_eq!(bar_state(&render), empty);
+    }
+
+    #[test]
+    fn undo_2() {
+        use rhythm::NoteValue;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        let staff = render.staff_create();
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+        render.history_clear();
+
+        let quarter = NoteValue::Quarter.log2() as isize;
+        let chord = render.chord_create(quarter, 0, 0, 4);
+        render.chord_set_pitch(chord, 60, 0);
+        render.bar_insert(bar, chord, false);
+        render.exec();
+        assert!(render.undo());
+        render.exec();
+        assert!(!render.can_undo());
+        assert!(render.can_redo());
+
+        // Edits that are rejected do not add undo steps or forget what was undone.
+        render.child_append(song, staff);
+        render.child_insert_before(staff, chord, bar);
+        render.child_remove(staff, chord);
+        render.bar_insert(bar, staff, false);
+        assert!(!render.song_set_chord_voicing(song, "not a chord", None));
+        assert!(!render.song_set_chord_voicing(song, "C", Some("not a voicing".into())));
+        render.chord_set_fret(chord, 1, 3);
+        assert!(!render.can_undo());
+        assert!(render.can_redo());
+
+        // Page size changes are undone.
+        render.song_set_size(song, 148.0, 210.0);
+        assert!(render.undo());
+        assert_eq!(render.get_song_width(song), Some(0.0));
+        assert_eq!(render.get_song_height(song), Some(0.0));
+    }
+
+    #[test]
+    fn save_1() {
+        use rhythm::NoteValue;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 215.9, 279.4);
+        render.song_set_title(song, "Save \"1\"", 26.4f64);
+
+        let quarter = NoteValue::Quarter.log2() as isize;
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::F), Some(3), Some(4), Some(-2));
+        render.child_append(staff, clef);
+        let pickup = render.bar_create(3, 4);
+        render.bar_set_skip(pickup, 1, 2);
+        render.child_append(staff, pickup);
+        let slur_start = render.chord_create(quarter, 0, 1, 2);
+        render.chord_set_pitch(slur_start, 59, -1);
+        render.chord_set_articulation(slur_start, Articulation::Fermata, true);
+        render.chord_set_articulation(slur_start, Articulation::Staccato, true);
+        render.chord_set_beam_override(slur_start, Some(BeamOverride::JoinNext));
+        render.bar_insert(pickup, slur_start, false);
+        let barline = render.signature_create(Some(Barline::Normal), None, None, None, None);
+        render.child_append(staff, barline);
+        let bar = render.bar_create(3, 4);
+        render.bar_set_slash_fill(bar, true);
+        render.child_append(staff, bar);
+        let chord = render.chord_create(quarter, 1, 0, 1);
+        render.chord_set_rest(chord);
+        render.bar_insert(bar, chord, false);
+        let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, 3, 8);
+        render.chord_set_pitch(chord, 50, 0);
+        render.chord_set_slash(chord, true);
+        render.bar_insert(bar, chord, false);
+        let grace = render.chord_create(NoteValue::Eighth.log2() as isize, 0, 3, 8);
+        render.chord_set_pitch(grace, 52, 0);
+        render.chord_set_grace(grace, Some(Grace::Acciaccatura));
+        render.bar_insert(bar, grace, false);
+        render.slur_create(slur_start, grace);
+        let hairpin = render.hairpin_create(HairpinKind::Crescendo, 1, 2, bar, 3, 8);
+        render.bar_insert(pickup, hairpin, false);
+        let dynamic = render.dynamic_create(Dynamic::MezzoForte, 3, 8);
+        render.bar_insert(bar, dynamic, false);
+        let dynamic = render.dynamic_create(Dynamic::Piano, 0, 1);
+        render.bar_insert(bar, dynamic, false);
+        let harmony = render.harmony_create("G/B", 0, 1).unwrap();
+        render.bar_insert(bar, harmony, false);
+        let mark = render.rehearsal_mark_create(Some("Chorus".to_owned()), false);
+        render.bar_insert(bar, mark, false);
+        let repeat = render.bar_create(3, 4);
+        render.bar_set_simile(repeat, Some(Simile::OneBar));
+        render.child_append(staff, repeat);
+        let final_barline =
+            render.signature_create(Some(Barline::Final), None, None, None, Some(0));
+        render.child_append(staff, final_barline);
+        render.staff_set_instrument(staff, "Horn in F", 4, 7, Clef::F, 35, 77);
+        render.staff_set_stem_direction(staff, Some(StemDirection::Up));
+        render.child_append(song, staff);
+
+        let drums = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::Percussion), Some(3), Some(4), None);
+        render.child_append(drums, clef);
+        let bar = render.bar_create(3, 4);
+        render.child_append(drums, bar);
+        let chord = render.chord_create(quarter, 0, 1, 4);
+        render.chord_set_percussion(chord, PercussionInstrument::Snare);
+        render.bar_insert(bar, chord, false);
+        let chord = render.chord_create(quarter, 0, 1, 2);
+        render.chord_set_percussion(chord, PercussionInstrument::HiHatOpen);
+        render.bar_insert(bar, chord, false);
+        render.child_append(song, drums);
+
+        let tab = render.tab_staff_create(vec![43, 38, 33, 28]);
+        render.tab_staff_set_link(tab, Some(staff));
+        render.child_append(song, tab);
+        let tab = render.tab_staff_create(vec![64, 59, 55, 50, 45, 40]);
+        let clef = render.signature_create(None, Some(Clef::Tab), Some(3), Some(4), None);
+        render.child_append(tab, clef);
+        let bar = render.bar_create(3, 4);
+        render.child_append(tab, bar);
+        let chord = render.chord_create(quarter, 0, 0, 1);
+        render.chord_set_pitch(chord, 64, 0);
+        render.chord_set_string(chord, Some(2));
+        render.bar_insert(bar, chord, false);
+        render.child_append(song, tab);
+
+        render.song_set_beaming_style(song, BeamingStyle::Beat);
+        render.song_set_bar_numbering(song, BarNumbering::Interval, 4, true);
+        render.song_set_first_bar_number(song, 0);
+        render.song_set_cautionary_accidentals(song, true);
+        render.song_set_concert_pitch(song, true);
+        render.song_set_drum_kit(song, DrumKit::Electric);
+        render.song_set_chord_diagrams(song, ChordDiagrams::Legend, vec![43, 38, 33, 28]);
+        render.song_set_rehearsal_mark_style(song, RehearsalMarkStyle::BarNumbers);
+        assert!(render.song_set_chord_voicing(song, "G", Some("320003".to_owned())));
+        assert!(render.song_set_chord_voicing(song, "D", Some("x 5 7 7 7 5".to_owned())));
+        assert!(render.song_set_chord_voicing(song, "A", Some("x 12 14 14 14 12".to_owned())));
+        let part = render.part_create(song, drums).unwrap();
+        render.song_set_rastal_size(part, 5);
+
+        render.root_set(song);
+        render.exec();
+
+        let saved = render.save().unwrap();
+        assert_eq!(
+            saved,
+            "six-eight 1\n\
+             song title=\"Save \\\"1\\\"\" title-width=26.4 author=\"\" author-width=0 \
+             width=215.9 height=279.4 rastal-size=3 multirests=false beaming=beat \
+             bar-numbering=interval bar-number-interval=4 bar-numbers-boxed=true \
+             first-bar-number=0 cautionary-accidentals=true concert-pitch=true drum-kit=electric \
+             chord-diagrams=legend chord-diagram-tuning=\"43 38 33 28\" \
+             rehearsal-marks=bar-numbers\n  \
+               voicing symbol=A frets=\"x 12 14 14 14 12\"\n  \
+               voicing symbol=D frets=x57775\n  \
+               voicing symbol=G frets=320003\n  \
+               staff stem-direction=up\n    \
+                 instrument name=\"Horn in F\" steps=4 semitones=7 clef=f low=35 high=77\n    \
+                 signature clef=f time=3/4 key=-2\n    \
+                 bar time=3/4 skip=1/2\n      \
+                   chord note=-2 dots=0 start=1/2 pitch=59 modifier=-1 beam=join-next \
+                   articulations=\"staccato fermata\"\n        \
+                     slur end-bar=1 end-chord=2\n      \
+                   hairpin kind=crescendo start=1/2 end-bar=1 end=3/8\n    \
+                 signature barline=normal\n    \
+                 bar time=3/4 slash-fill=true\n      \
+                   chord note=-2 dots=1 start=0 pitch=rest\n      \
+                   chord note=-3 dots=0 start=3/8 pitch=50 slash=true\n      \
+                   chord note=-3 dots=0 start=3/8 pitch=52 grace=acciaccatura\n      \
+                   rehearsal-mark boxed=false label=Chorus\n      \
+                   dynamic dynamic=p time=0\n      \
+                   dynamic dynamic=mf time=3/8\n      \
+                   harmony symbol=G/B beat=0\n    \
+                 bar time=3/4 simile=one-bar\n    \
+                 signature barline=final key=0\n  \
+               staff\n    \
+                 signature clef=percussion time=3/4\n    \
+                 bar time=3/4\n      \
+                   chord note=-2 dots=0 start=1/4 pitch=percussion instrument=snare\n      \
+                   chord note=-2 dots=0 start=1/2 pitch=percussion instrument=hi-hat-open\n  \
+               tab-staff tuning=\"43 38 33 28\" stem-direction=down link=0\n  \
+               tab-staff tuning=\"64 59 55 50 45 40\" stem-direction=down\n    \
+                 signature clef=tab time=3/4\n    \
+                 bar time=3/4\n      \
+                   chord note=-2 dots=0 start=0 pitch=64 string=2\n  \
+               part staff=1 title=\"Save \\\"1\\\"\" title-width=26.4 author=\"\" author-width=0 \
+               width=215.9 height=279.4 rastal-size=5 multirests=true beaming=beat \
+               bar-numbering=interval bar-number-interval=4 bar-numbers-boxed=true \
+               first-bar-number=0 cautionary-accidentals=true concert-pitch=false \
+               drum-kit=electric chord-diagrams=legend chord-diagram-tuning=\"43 38 33 28\" \
+               rehearsal-marks=bar-numbers\n"
+        );
+
+        // Loading the document rebuilds the same bars.
+        let mut loaded = NativeSixDom::load(&saved).unwrap();
+        loaded.exec();
+        assert_eq!(loaded.save().unwrap(), saved);
+        assert!(!loaded.can_undo());
+
+        let rhythms = |render: &NativeSixDom| {
+            let bars = render.state.world.read_component::<Bar>();
+            let mut rhythms: Vec<_> = bars
+                .join()
+                .map(|bar| {
+                    bar.children()
+                        .iter()
+                        .map(|child| (child.start, child.duration, child.lifetime.is_explicit()))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            rhythms.sort();
+            rhythms
+        };
+        assert_eq!(rhythms(&loaded), rhythms(&render));
+
+        // The document can be embedded in a PDF.
+        let pdf = render.to_pdf_with_document().unwrap();
+        let decoded = base64::decode(&pdf).unwrap();
+        assert!(String::from_utf8_lossy(&decoded).contains("/Subtype /text#2Fplain"));
+        let mut from_pdf = NativeSixDom::load_pdf(&pdf).unwrap();
+        from_pdf.exec();
+        assert_eq!(from_pdf.save().unwrap(), saved);
+        assert!(NativeSixDom::load_pdf(&render.to_pdf(Some("{}".to_owned())).unwrap()).is_none());
+
+        // A part that is the root is opened as the root.
+        render.root_clear(song);
+        render.root_set(part);
+        let saved = render.save().unwrap();
+        assert!(saved.contains("part staff=1 root=true"));
+        let loaded = NativeSixDom::load(&saved).unwrap();
+        let root = loaded.get_root_id().unwrap();
+        let root = loaded.state.world.entities().entity(root);
+        assert!(loaded
+            .state
+            .world
+            .read_component::<Song>()
+            .get(root)
+            .unwrap()
+            .score
+            .is_some());
+
+        assert!(NativeSixDom::load("six-eight 2\nsong\n").is_none());
+        assert!(NativeSixDom::load("six-eight 1\nsong\n  staff\n    bar\n").is_none());
+    }
+
+    #[test]
+    fn svg_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 148.0, 210.0);
+        render.song_set_title(song, "Six & Eight", 26.4f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(0));
+        render.child_append(staff, clef);
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        let chord = render.chord_create(NoteValue::Half.log2() as isize, 0, 1, 2);
+        render.chord_set_pitch(chord, 60, 0);
+        render.bar_insert(bar, chord, false);
+        render.css_set_class(chord, "selected");
+        let barline = render.signature_create(Some(Barline::Final), None, None, None, None);
+        render.child_append(staff, barline);
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        let scale = {
+            let song = render.state.world.entities().entity(song);
+            render
+                .state
+                .world
+                .read_component::<Song>()
+                .get(song)
+                .unwrap()
+                .scale()
+        };
+        let svg = render.to_svg(false).unwrap();
+        assert!(svg.starts_with(&format!(
+            "<svg viewBox=\"0 0 {} {}\" width=\"148mm\" height=\"210mm\"",
+            (148.0 / scale).round(),
+            (210.0 / scale).round()
+        )));
+        assert!(svg.contains("<title>Six &amp; Eight</title>"));
+        assert!(svg.contains("<g class=\"selected\""));
+        assert!(svg.contains(">Six &amp; Eight</text>"));
+
+        let outlined = render.to_svg(true).unwrap();
+        assert!(!outlined.contains("<text"));
+        assert!(outlined.contains("<g class=\"selected\""));
+        snapshot("./snapshots/svg_1.svg", &outlined);
+    }
+
+    #[test]
+    fn png_1() {
+        use rhythm::NoteValue;
+        use stencil::snapshot_bytes;
+
+        let mut render = NativeSixDom::default();
+        let song = render.song_create();
+        render.song_set_size(song, 148.0, 105.0);
+        render.song_set_title(song, "Six Eight", 26.4f64);
+
+        let staff = render.staff_create();
+        let clef = render.signature_create(None, Some(Clef::G), Some(4), Some(4), Some(-3));
+        render.child_append(staff, clef);
+        let bar = render.bar_create(4, 4);
+        render.child_append(staff, bar);
+        for (i, &(pitch, modifier)) in [(64, -1), (67, 0), (71, -1), (74, 0)].iter().enumerate() {
+            let chord = render.chord_create(NoteValue::Eighth.log2() as isize, 0, i as isize, 8);
+            render.chord_set_pitch(chord, pitch, modifier);
+            render.bar_insert(bar, chord, false);
+        }
+        let barline = render.signature_create(Some(Barline::Final), None, None, None, None);
+        render.child_append(staff, barline);
+        render.child_append(song, staff);
+        render.root_set(song);
+        render.exec();
+
+        let png = base64::decode(render.to_png(96.0).unwrap()).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        // 148 x 105 mm at 96 dpi.
+        assert_eq!(&png[16..24], &[0, 0, 2, 47, 0, 0, 1, 141]);
+        snapshot_bytes("./snapshots/png_1.png", &png);
+    }
 }
@@ -1,19 +1,24 @@
 use crate::{
     components::Css,
+    history::History,
     systems::{DeleteOrphans, PrintMeta, PrintSong, UpdateKeepSpacing, UpdateWorldBbox},
 };
 use rhythm::components::{Bar, Spacing};
 use specs::{RunNow, World, WorldExt};
 use staff::{
     components::{
-        Beam, BeamForChord, Children, Chord, Context, Cursor, FlagAttachment, LineOfStaff,
-        Signature, Song, SpaceTimeWarp, Staff,
+        Accidental, AccidentalsForChord, Beam, BeamForChord, Children, Chord, Context, Cursor,
+        DynamicMark, FlagAttachment, Hairpin, Harmony, LegerLine, LegerLinesForChord, LineOfStaff,
+        LinePlacement, Linked, Multirest, RehearsalMark, Signature, Simile, Slur, Song,
+        SpaceTimeWarp, Staff, StemDirection, TabNote, TabStaff,
     },
     resources::{KeepSpacing, Root},
     systems::{
-        ApplySpaceTimeWarp, BreakIntoLines, DraftBeam, MaintainAutorests, PrintBeam, PrintChord,
-        PrintCursor, PrintSignature, PrintStaff, PrintStaffLines, RecordSpaceTimeWarp, SpaceBeam,
-        UpdateContext,
+        ApplySpaceTimeWarp, BreakIntoLines, DraftBeam, MaintainAutorests, PrintAccidentals,
+        PrintBarNumbers, PrintBeam, PrintChord, PrintChordSymbols, PrintCursor, PrintDynamics,
+        PrintLegerLines, PrintRehearsalMarks, PrintSignature, PrintSimile, PrintSlur, PrintStaff,
+        PrintStaffLines, PrintTablature, RecordSpaceTimeWarp, SpaceBeam, UpdateContext,
+        UpdateLinkedStaffs, UpdateMultirests, UpdateSkyline, UpdateStemDirection, UpdateTablature,
     },
 };
 use stencil::components::{Parent, Stencil, StencilMap, WorldBbox};
@@ -24,20 +29,34 @@ struct Systems {
     draft_beam: DraftBeam,
     print_beam: PrintBeam,
     print_chord: PrintChord,
+    print_chord_symbols: PrintChordSymbols,
+    print_accidentals: PrintAccidentals,
+    print_leger_lines: PrintLegerLines,
     record_space_time_warp: RecordSpaceTimeWarp,
     space_beam: SpaceBeam,
     maintain_autorests: MaintainAutorests,
     break_into_lines: BreakIntoLines,
     print_signature: PrintSignature,
+    print_simile: PrintSimile,
     print_staff: PrintStaff,
     print_staff_lines: PrintStaffLines,
+    print_tablature: PrintTablature,
     print_cursor: PrintCursor,
+    print_dynamics: PrintDynamics,
+    print_slur: PrintSlur,
+    print_rehearsal_marks: PrintRehearsalMarks,
+    print_bar_numbers: PrintBarNumbers,
     delete_orphans: DeleteOrphans,
     print_meta: PrintMeta,
     print_song: PrintSong,
     update_keep_spacing: UpdateKeepSpacing,
+    update_linked_staffs: UpdateLinkedStaffs,
     update_world_bbox: UpdateWorldBbox,
     update_context: UpdateContext,
+    update_multirests: UpdateMultirests,
+    update_skyline: UpdateSkyline,
+    update_stem_direction: UpdateStemDirection,
+    update_tablature: UpdateTablature,
 }
 
 pub struct State {
@@ -50,7 +69,10 @@ impl Default for State {
         let mut world = World::new();
         world.insert(KeepSpacing(false));
         world.insert(Root(None));
+        world.insert(History::default());
 
+        world.register::<Accidental>();
+        world.register::<AccidentalsForChord>();
         world.register::<Bar>();
         world.register::<Beam>();
         world.register::<BeamForChord>();
@@ -59,16 +81,30 @@ impl Default for State {
         world.register::<Context>();
         world.register::<Css>();
         world.register::<Cursor>();
+        world.register::<DynamicMark>();
         world.register::<FlagAttachment>();
+        world.register::<Hairpin>();
+        world.register::<Harmony>();
+        world.register::<LegerLine>();
+        world.register::<LegerLinesForChord>();
         world.register::<LineOfStaff>();
+        world.register::<Linked>();
+        world.register::<LinePlacement>();
+        world.register::<Multirest>();
         world.register::<Parent>();
+        world.register::<RehearsalMark>();
         world.register::<Signature>();
+        world.register::<Simile>();
+        world.register::<Slur>();
         world.register::<Song>();
         world.register::<SpaceTimeWarp>();
         world.register::<Spacing>();
         world.register::<Staff>();
+        world.register::<StemDirection>();
         world.register::<Stencil>();
         world.register::<StencilMap>();
+        world.register::<TabNote>();
+        world.register::<TabStaff>();
         world.register::<WorldBbox>();
 
         Self {
@@ -84,12 +120,21 @@ impl State {
         self.systems.update_keep_spacing.run_now(&self.world);
         self.systems.maintain_autorests.run_now(&self.world);
         self.world.maintain();
+        self.systems.update_linked_staffs.run_now(&self.world);
+        self.world.maintain();
 
         self.systems.draft_beam.run_now(&self.world);
         self.systems.update_context.run_now(&self.world);
+        self.systems.update_stem_direction.run_now(&self.world);
+        self.systems.update_tablature.run_now(&self.world);
 
         self.systems.print_chord.run_now(&self.world);
+        self.systems.print_accidentals.run_now(&self.world);
+        self.systems.print_leger_lines.run_now(&self.world);
         self.systems.print_signature.run_now(&self.world);
+        self.systems.print_tablature.run_now(&self.world);
+        self.systems.print_simile.run_now(&self.world);
+        self.systems.update_multirests.run_now(&self.world);
 
         self.systems.apply_space_time_warp.run_now(&self.world);
         self.systems.break_into_lines.run_now(&self.world);
@@ -100,6 +145,12 @@ impl State {
         self.systems.print_cursor.run_now(&self.world);
 
         self.systems.print_staff.run_now(&self.world);
+        self.systems.update_skyline.run_now(&self.world);
+        self.systems.print_slur.run_now(&self.world);
+        self.systems.print_dynamics.run_now(&self.world);
+        self.systems.print_chord_symbols.run_now(&self.world);
+        self.systems.print_rehearsal_marks.run_now(&self.world);
+        self.systems.print_bar_numbers.run_now(&self.world);
         self.systems.print_staff_lines.run_now(&self.world);
         self.systems.print_meta.run_now(&self.world);
         self.systems.print_song.run_now(&self.world);
@@ -110,6 +161,7 @@ impl State {
         if let Some(root) = self.world.read_resource::<Root>().0 {
             if let Some(root) = self.world.write_component::<Song>().get_mut(root) {
                 root.prev_freeze_spacing = root.freeze_spacing;
+                root.prev_rastal_size = root.rastal_size;
             }
         }
     }
@@ -0,0 +1,90 @@
+use crate::components::Css;
+use kurbo::Vec2;
+use specs::{Entity, ReadStorage};
+use std::fmt::Write;
+use stencil::components::{Stencil, StencilMap};
+use stencil::escape;
+
+/// Writes a page as a standalone SVG document.
+pub(crate) struct SvgWriter<'a> {
+    pub stencil_maps: ReadStorage<'a, StencilMap>,
+    pub stencils: ReadStorage<'a, Stencil>,
+    pub csses: ReadStorage<'a, Css>,
+
+    /// Draw text as paths, so that the document looks the same without the font.
+    pub outline_text: bool,
+}
+
+impl<'a> SvgWriter<'a> {
+    /// Write the page drawn by `root`.
+    ///
+    /// The page is `width` by `height` mm, and one unit of the drawing is `scale` mm.
+    pub fn write(
+        &self,
+        root: Entity,
+        width: f64,
+        height: f64,
+        scale: f64,
+        title: Option<&str>,
+    ) -> String {
+        let mut out = String::new();
+        write!(
+            out,
+            "<svg viewBox=\"0 0 {} {}\" width=\"{}mm\" height=\"{}mm\" \
+             xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\">",
+            (width / scale).round(),
+            (height / scale).round(),
+            width,
+            height,
+        )
+        .unwrap();
+        if let Some(title) = title {
+            write!(out, "<title>{}</title>", escape(title)).unwrap();
+        }
+        self.write_entity(root, None, &mut out);
+        out.push_str("</svg>\n");
+
+        out
+    }
+
+    fn write_entity(&self, entity: Entity, translate: Option<Vec2>, out: &mut String) {
+        let class = self
+            .csses
+            .get(entity)
+            .and_then(|css| css.class.as_ref())
+            .filter(|class| !class.is_empty());
+        let translate = match (translate, self.stencil_maps.get(entity)) {
+            (Some(a), Some(map)) => Some(a + map.translate().unwrap_or_default()),
+            (None, Some(map)) => *map.translate(),
+            (translate, None) => translate,
+        };
+
+        out.push_str("<g");
+        if let Some(class) = class {
+            write!(out, " class=\"{}\"", escape(class)).unwrap();
+        }
+        if let Some(translate) = translate {
+            write!(
+                out,
+                " transform=\"translate({},{})\"",
+                translate.x, translate.y
+            )
+            .unwrap();
+        }
+        out.push('>');
+
+        if let Some(map) = self.stencil_maps.get(entity) {
+            for (child, translate) in map.get_sorted_children() {
+                self.write_entity(child, translate, out);
+            }
+        } else if let Some(stencil) = self.stencils.get(entity) {
+            if self.outline_text {
+                out.push_str(&stencil.clone().with_text_outlines().to_svg());
+            } else {
+                out.push_str(&stencil.to_svg());
+            }
+        }
+
+        out.push_str("</g>");
+    }
+}
@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use crate::history::History;
 use specs::{Entities, Entity, Join, Read, ReadStorage, System};
-use staff::resources::Root;
+use staff::{components::Song, resources::Root};
 use stencil::components::Parent;
 
 #[derive(Debug, Default)]
 pub struct DeleteOrphans;
 
 impl<'a> System<'a> for DeleteOrphans {
-    type SystemData = (Entities<'a>, Read<'a, Root>, ReadStorage<'a, Parent>);
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Root>,
+        Read<'a, History>,
+        ReadStorage<'a, Parent>,
+        ReadStorage<'a, Song>,
+    );
 
-    fn run(&mut self, (entities, root, parents): Self::SystemData) {
+    fn run(&mut self, (entities, root, history, parents, songs): Self::SystemData) {
         // Find which entities are attached to the root.
         let live = if let Some(root) = root.0 {
             // Invert 'parents'.
@@ -20,12 +27,23 @@ impl<'a> System<'a> for DeleteOrphans {
                 tree.entry(parent.0).or_default().push(child);
             }
 
+            // A score and its parts keep each other alive.
+            for (song_entity, song) in (&entities, &songs).join() {
+                let linked = tree.entry(song_entity).or_default();
+                linked.extend(song.score);
+                linked.extend(song.parts.iter().copied());
+            }
+
+            // Entities that undo or redo can restore are kept, with what they contain.
             let mut q = vec![root];
+            q.extend(history.retained());
             let mut live = HashSet::new();
 
             // Walk the tree.
             while let Some(el) = q.pop() {
-                live.insert(el);
+                if !live.insert(el) {
+                    continue;
+                }
                 if let Some(children) = tree.get(&el) {
                     for child in children {
                         q.push(*child);
@@ -1,39 +1,52 @@
 use kurbo::Vec2;
-use specs::{Entities, Join, System, WriteStorage};
-use staff::components::Song;
+use specs::{Entities, Join, ReadStorage, System, WriteStorage};
+use staff::{
+    components::{Children, Song, Staff},
+    print_text,
+};
 use stencil::components::{Parent, Stencil};
 
+/// The baseline of the title, in mm from the top of the page.
+const TITLE_Y: f64 = 17.5;
+
+/// The baseline of the author and part name, in mm from the top of the page.
+const AUTHOR_Y: f64 = 24.5;
+
 #[derive(Debug, Default)]
 pub struct PrintMeta;
 
 impl<'a> System<'a> for PrintMeta {
     type SystemData = (
         Entities<'a>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
         WriteStorage<'a, Parent>,
         WriteStorage<'a, Song>,
         WriteStorage<'a, Stencil>,
     );
 
-    fn run(&mut self, (entities, mut parents, mut songs, mut stencils): Self::SystemData) {
-        for (song_id, song) in (&entities, &mut songs).join() {
+    fn run(
+        &mut self,
+        (entities, staffs, children, mut parents, mut songs, mut stencils): Self::SystemData,
+    ) {
+        for (song_id, song, song_children) in (&entities, &mut songs, &children).join() {
             if song.title_stencil.is_none() {
                 let id = entities.create();
                 song.title_stencil = Some(id);
                 parents.insert(id, Parent(song_id)).unwrap();
             }
 
-            // TODO: rastral size.
-            let title_x = (song.width / 2f64 - song.title_width / 2f64) * 1000f64 / 7f64;
+            let title_x = (song.width / 2f64 - song.title_width / 2f64) / song.scale();
             stencils
                 .insert(
                     song.title_stencil.unwrap(),
                     // TODO: sync with reconciler.ts.
                     Stencil::text(
                         &song.title,
-                        7f64 * 1000f64 / 7f64,
-                        song.title_width * 1000f64 / 7f64,
+                        7f64 / song.scale(),
+                        song.title_width / song.scale(),
                     )
-                    .with_translation(Vec2::new(title_x, 2500f64)),
+                    .with_translation(Vec2::new(title_x, TITLE_Y / song.scale())),
                 )
                 .unwrap();
 
@@ -43,21 +56,43 @@ impl<'a> System<'a> for PrintMeta {
                 parents.insert(id, Parent(song_id)).unwrap();
             }
 
-            // TODO: rastral size.
             // TODO: margin size
-            let author_x = (song.width - song.author_width) * 1000f64 / 7f64 - 2500f64;
+            let author_x = (song.width - song.author_width) / song.scale() - 2500f64;
             stencils
                 .insert(
                     song.author_stencil.unwrap(),
                     // TODO: sync with reconciler.ts.
                     Stencil::text(
                         &song.author,
-                        5f64 * 1000f64 / 7f64,
-                        song.author_width * 1000f64 / 7f64,
+                        5f64 / song.scale(),
+                        song.author_width / song.scale(),
                     )
-                    .with_translation(Vec2::new(author_x, 3500f64)),
+                    .with_translation(Vec2::new(author_x, AUTHOR_Y / song.scale())),
                 )
                 .unwrap();
+
+            // Parts are labelled with the instrument of their staff.
+            if song.score.is_some() {
+                if song.part_name_stencil.is_none() {
+                    let id = entities.create();
+                    song.part_name_stencil = Some(id);
+                    parents.insert(id, Parent(song_id)).unwrap();
+                }
+
+                let name = song_children
+                    .0
+                    .iter()
+                    .find_map(|&child| staffs.get(child))
+                    .map(|staff| staff.instrument.name.as_str())
+                    .unwrap_or_default();
+                stencils
+                    .insert(
+                        song.part_name_stencil.unwrap(),
+                        print_text(name, 5f64 / song.scale(), false)
+                            .with_translation(Vec2::new(2500f64, AUTHOR_Y / song.scale())),
+                    )
+                    .unwrap();
+            }
         }
     }
 }
@@ -1,7 +1,13 @@
 use kurbo::Vec2;
 use specs::{Join, ReadStorage, System, WriteStorage};
-use staff::components::{Children, Song, Staff};
-use stencil::components::StencilMap;
+use staff::{
+    components::{Children, LineOfStaff, Song, Staff},
+    Skyline,
+};
+use stencil::components::{Stencil, StencilMap};
+
+/// The minimum space between the lowest point of a line and the highest point of the next.
+const LINE_PADDING: f64 = 500.0;
 
 #[derive(Debug, Default)]
 pub struct PrintSong;
@@ -11,16 +17,46 @@ impl<'a> System<'a> for PrintSong {
         ReadStorage<'a, Song>,
         ReadStorage<'a, Staff>,
         ReadStorage<'a, Children>,
+        ReadStorage<'a, LineOfStaff>,
+        ReadStorage<'a, Stencil>,
         WriteStorage<'a, StencilMap>,
     );
 
-    fn run(&mut self, (songs, staffs, children, mut stencil_maps): Self::SystemData) {
+    fn run(
+        &mut self,
+        (songs, staffs, children, line_of_staffs, stencils, mut stencil_maps): Self::SystemData,
+    ) {
         for (song, children, render) in (&songs, &children, &mut stencil_maps).join() {
             let mut map = StencilMap::new();
-            let mut h = 5500.0;
+
+            // The legend of chord diagrams is between the title and the first line.
+            let mut top = 4500.0;
+            if let Some(legend) = song.chord_legend_stencil {
+                if let Some(stencil) = stencils.get(legend) {
+                    map = map.and(legend, Some(Vec2::new(0.0, top)));
+                    top += stencil.rect().height() + LINE_PADDING * 2.0;
+                }
+            }
+
+            let mut h = top + 1000.0;
+            let mut prev_skyline: Option<&Skyline> = None;
+            let default_skyline = Skyline::default();
             for &child in &children.0 {
                 if let Some(staff) = staffs.get(child) {
                     for line in &staff.lines {
+                        let skyline = line_of_staffs
+                            .get(*line)
+                            .map(|line_of_staff| &line_of_staff.skyline)
+                            .unwrap_or(&default_skyline);
+
+                        // Lines are 3000 apart, unless something on them would collide.
+                        h = match prev_skyline {
+                            Some(prev_skyline) => {
+                                h + (prev_skyline.distance_to(skyline) + LINE_PADDING).max(3000.0)
+                            }
+                            None => h.max(top - skyline.highest()),
+                        };
+                        prev_skyline = Some(skyline);
                         map = map.and(
                             *line,
                             if h > 0.0 {
@@ -29,7 +65,6 @@ impl<'a> System<'a> for PrintSong {
                                 None
                             },
                         );
-                        h += 3000.0;
                     }
                 }
             }
@@ -39,6 +74,9 @@ impl<'a> System<'a> for PrintSong {
             if let Some(author_stencil) = song.author_stencil {
                 map = map.and(author_stencil, None);
             }
+            if let Some(part_name_stencil) = song.part_name_stencil {
+                map = map.and(part_name_stencil, None);
+            }
             *render = map;
         }
     }
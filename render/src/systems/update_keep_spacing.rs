@@ -20,6 +20,7 @@ impl<'a> System<'a> for UpdateKeepSpacing {
             .and_then(|root| songs.get(root))
             .map(|root| {
                 root.freeze_spacing.is_some()
+                    && root.rastal_size == root.prev_rastal_size
                     && (root.freeze_spacing == root.prev_freeze_spacing
                         || root.prev_freeze_spacing.is_none())
             })
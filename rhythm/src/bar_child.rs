@@ -9,5 +9,7 @@ pub struct BarChild {
     pub start: Rational,
     pub lifetime: Lifetime,
     pub stencil: Entity,
+    /// Grace notes take no time. They are written before the note at `start`, and `duration` is
+    /// only how they are written.
+    pub grace: bool,
 }
-
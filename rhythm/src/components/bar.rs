@@ -1,4 +1,4 @@
-#![allow(clippy::blacklisted_name)]
+#![allow(clippy::disallowed_names)]
 
 use crate::bar_child::BarChild;
 use crate::duration::Duration;
@@ -28,6 +28,15 @@ pub struct Bar {
 
     // For pickup bars, the number of beats that are skipped.
     skip: Rational,
+
+    /// Grace notes, in order, with the time of the note they are written before.
+    ///
+    /// These take no time, so they are not part of `rhythm`.
+    grace: Vec<(Rational, Duration, Entity)>,
+
+    /// If true, automatic rests are split at each beat, so that they can be drawn as beat
+    /// slashes.
+    slash_fill: bool,
 }
 
 impl Component for Bar {
@@ -42,9 +51,42 @@ impl Bar {
             rhythm: vec![],
             managed: vec![],
             skip: Rational::zero(),
+            grace: vec![],
+            slash_fill: false,
         }
     }
 
+    /// A copy of this bar, with the entity of each note, rest, and grace note replaced by
+    /// `map(entity)`.
+    ///
+    /// This is used to show the same bar on more than one staff.
+    pub fn map_entities(&self, mut map: impl FnMut(Entity) -> Entity) -> Bar {
+        let mut bar = self.clone();
+        for (_, lifetime) in &mut bar.rhythm {
+            *lifetime = match *lifetime {
+                Lifetime::Temporary(entity) => Lifetime::Temporary(map(entity)),
+                Lifetime::Explicit(entity) => Lifetime::Explicit(map(entity)),
+                Lifetime::AutomaticRest | Lifetime::HiddenRest => *lifetime,
+            };
+        }
+        for entity in &mut bar.managed {
+            *entity = map(*entity);
+        }
+        for (_, _, entity) in &mut bar.grace {
+            *entity = map(*entity);
+        }
+
+        bar
+    }
+
+    pub fn slash_fill(&self) -> bool {
+        self.slash_fill
+    }
+
+    pub fn set_slash_fill(&mut self, slash_fill: bool) {
+        self.slash_fill = slash_fill;
+    }
+
     pub fn whole_rest(&self) -> bool {
         self.rhythm.is_empty()
     }
@@ -208,7 +250,7 @@ impl Bar {
     ) -> Vec<Rational> {
         let mut powers: Vec<Rational> = (0..div_parts).map(|_| Rational::zero()).collect();
         for p in 1..=div_parts {
-            if div_parts % p != 0 {
+            if !div_parts.is_multiple_of(p) {
                 continue;
             }
             if Duration::exact(quant * (p as isize) * tuplet_kind, Some(tuplet_kind)).display_dots()
@@ -386,10 +428,9 @@ impl Bar {
                                         beats_exposed,
                                     );
                                     for q in (start_q + 1)..end_q {
-                                        if powers[q as usize] >= powers[start_q] {
-                                            score -= (Rational::one() + powers[q as usize]
-                                                - powers[start_q])
-                                                * 2;
+                                        if powers[q] >= powers[start_q] {
+                                            score -=
+                                                (Rational::one() + powers[q] - powers[start_q]) * 2;
                                         }
                                     }
                                 }
@@ -511,9 +552,24 @@ impl Bar {
         self.optimize();
     }
 
+    /// Add a grace note before the note at `t`, after any grace notes already there.
+    pub fn insert_grace(&mut self, t: Rational, duration: Duration, chord: Entity) {
+        let idx = self
+            .grace
+            .iter()
+            .position(|(grace_t, _, _)| *grace_t > t)
+            .unwrap_or(self.grace.len());
+        self.grace.insert(idx, (t, duration, chord));
+    }
+
+    /// Remove a note, rest, or chord, replacing it with automatic rests.
+    ///
+    /// Grace notes are removed without a trace, and do not return a lifetime.
     pub fn remove(&mut self, chord: Entity) -> Option<Lifetime> {
         let mut ret = None;
 
+        self.grace.retain(|(_, _, grace)| *grace != chord);
+
         for (_, entity) in &mut self.rhythm {
             if entity.to_option() == Some(chord) {
                 ret = Some(*entity);
@@ -659,6 +715,21 @@ impl Bar {
 
     /// Determine how to beam several notes
     pub fn beaming(&self, t0: Rational, durations: Vec<Duration>) -> Vec<Option<RhythmicBeaming>> {
+        let breaks = vec![None; durations.len()];
+        self.beaming_with_breaks(t0, durations, &breaks)
+    }
+
+    /// Determine how to beam several notes, overriding where beams are broken.
+    ///
+    /// `breaks[i]` is `Some(true)` to start a new beam at `durations[i]`, `Some(false)` to beam
+    /// `durations[i]` to the previous note even across a division, and `None` to follow the metre.
+    /// The first note always starts a beam.
+    pub fn beaming_with_breaks(
+        &self,
+        t0: Rational,
+        durations: Vec<Duration>,
+        breaks: &[Option<bool>],
+    ) -> Vec<Option<RhythmicBeaming>> {
         // TODO: For now, we're going to assume beaming follows the same rules as long-note
         // splitting. This isn't true, but it's a start.
         let mut beams = Vec::with_capacity(durations.len());
@@ -707,6 +778,12 @@ impl Bar {
             t = t_end;
         }
 
+        for (i, split_before) in split_befores.iter_mut().enumerate().skip(1) {
+            if let Some(Some(force)) = breaks.get(i) {
+                split_before.0 = *force;
+            }
+        }
+
         let mut beaming = Vec::with_capacity(durations.len());
 
         for (i, (&beam, &(split_before, t))) in beams.iter().zip(&split_befores).enumerate() {
@@ -759,44 +836,56 @@ impl Bar {
         beaming
     }
 
-    fn target_managed_count(&self) -> usize {
-        if self.whole_rest() {
-            return 1;
+    /// The automatic rest starting at `start`, split at each beat if this bar is slash-filled.
+    fn split_at_beats(&self, start: Rational, duration: Duration) -> Vec<(Duration, Rational)> {
+        if !self.slash_fill {
+            return vec![(duration, start)];
         }
 
-        let mut target_managed_count = 0;
-        for chord in &self.rhythm {
-            if chord.1.is_automatic() {
-                target_managed_count += 1;
+        let end = start + duration.duration();
+        let mut pieces = vec![];
+        let mut piece_start = start;
+        for beat in self.metre.beats() {
+            if beat > piece_start && beat < end {
+                pieces.push((Duration::exact(beat - piece_start, None), piece_start));
+                piece_start = beat;
             }
         }
+        pieces.push((Duration::exact(end - piece_start, None), piece_start));
 
-        target_managed_count
+        pieces
     }
 
-    /// If there are insufficient automatic rests, returns the next required duration & start time.
-    pub fn next_missing_child(&self) -> Option<(Duration, Rational)> {
-        let mut managed_idx = self.managed.len();
-
-        if self.whole_rest() && managed_idx == 0 {
-            return Some((
-                Duration::new_whole_rest(self.metre.duration()),
-                Rational::zero(),
-            ));
+    /// The duration and start time of each managed entity.
+    fn automatic_children(&self) -> Vec<(Duration, Rational)> {
+        if self.whole_rest() {
+            let duration = self.metre.duration();
+            return if self.slash_fill {
+                self.split_at_beats(Rational::zero(), Duration::exact(duration, None))
+            } else {
+                vec![(Duration::new_whole_rest(duration), Rational::zero())]
+            };
         }
 
+        let mut children = vec![];
         let mut start = Rational::zero();
         for note in &self.rhythm {
             if note.1.is_automatic() {
-                if managed_idx == 0 {
-                    return Some((note.0, start));
-                }
-                managed_idx -= 1;
+                children.extend(self.split_at_beats(start, note.0));
             }
             start += note.0.duration();
         }
 
-        None
+        children
+    }
+
+    fn target_managed_count(&self) -> usize {
+        self.automatic_children().len()
+    }
+
+    /// If there are insufficient automatic rests, returns the next required duration & start time.
+    pub fn next_missing_child(&self) -> Option<(Duration, Rational)> {
+        self.automatic_children().get(self.managed.len()).copied()
     }
 
     /// Append an automatic rest to this bar.
@@ -822,40 +911,65 @@ impl Bar {
         &self.managed
     }
 
-    /// Rest/note/chords (RNCs)
+    /// The grace notes in the bar, in the order they are written.
+    pub fn grace_notes(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.grace.iter().map(|&(_, _, chord)| chord)
+    }
+
+    /// Rest/note/chords (RNCs), with grace notes before the notes they are attached to.
     pub fn children(&self) -> Vec<BarChild> {
         let mut managed = self.managed().iter();
+        let mut grace = self.grace.iter().peekable();
         let mut start = Rational::zero();
+        let mut children = Vec::new();
+
+        let grace_child = |&(t, duration, stencil): &(Rational, Duration, Entity)| BarChild {
+            duration,
+            start: t,
+            lifetime: Lifetime::Explicit(stencil),
+            stencil,
+            grace: true,
+        };
+
+        let mut automatic_child = |(duration, start): (Duration, Rational)| BarChild {
+            duration,
+            start,
+            lifetime: Lifetime::AutomaticRest,
+            stencil: *managed.next().unwrap(),
+            grace: false,
+        };
 
         if self.whole_rest() {
-            return vec![BarChild {
-                duration: Duration::new_whole_rest(self.metre.duration()),
-                start,
-                lifetime: Lifetime::AutomaticRest,
-                stencil: *managed.next().unwrap(),
-            }];
+            children.extend(grace.map(grace_child));
+            children.extend(self.automatic_children().into_iter().map(automatic_child));
+            return children;
         }
 
-        self.rhythm
-            .iter()
-            .filter_map(|(rhy, lifetime)| {
-                let data = if lifetime.is_hidden() {
-                    None
-                } else {
-                    Some(BarChild {
+        for (rhy, lifetime) in &self.rhythm {
+            if !lifetime.is_hidden() {
+                while let Some(g) = grace.next_if(|(t, _, _)| *t <= start) {
+                    children.push(grace_child(g));
+                }
+                match lifetime.to_option() {
+                    Some(stencil) => children.push(BarChild {
                         duration: *rhy,
                         start,
                         lifetime: *lifetime,
-                        stencil: lifetime
-                            .to_option()
-                            .unwrap_or_else(|| *managed.next().unwrap()),
-                    })
-                };
-                start += rhy.duration();
+                        stencil,
+                        grace: false,
+                    }),
+                    None => children.extend(
+                        self.split_at_beats(start, *rhy)
+                            .into_iter()
+                            .map(&mut automatic_child),
+                    ),
+                }
+            }
+            start += rhy.duration();
+        }
+        children.extend(grace.map(grace_child));
 
-                data
-            })
-            .collect()
+        children
     }
 }
 
@@ -3463,4 +3577,173 @@ mod bar_tests {
             ]
         );
     }
+
+    #[test]
+    fn beaming_with_breaks() {
+        let four_eight = Bar::new(Metre::new(4, 8));
+        let eighths = vec![Duration::new(NoteValue::Eighth, 0, None); 4];
+        let beaming = |entering, leaving| Some(RhythmicBeaming { entering, leaving });
+
+        // Join across the division.
+        assert_eq!(
+            four_eight.beaming_with_breaks(
+                Rational::zero(),
+                eighths.clone(),
+                &[None, None, Some(false), None]
+            ),
+            vec![beaming(0, 1), beaming(1, 1), beaming(1, 1), beaming(1, 0)]
+        );
+
+        // Break within a division. A single note is not beamed.
+        assert_eq!(
+            four_eight.beaming_with_breaks(
+                Rational::zero(),
+                eighths.clone(),
+                &[None, Some(true), None, None]
+            ),
+            vec![None, None, beaming(0, 1), beaming(1, 0)]
+        );
+
+        // The first note always starts a beam.
+        assert_eq!(
+            four_eight.beaming_with_breaks(Rational::zero(), eighths.clone(), &[Some(false); 4]),
+            four_eight.beaming_with_breaks(
+                Rational::zero(),
+                eighths,
+                &[None, Some(false), Some(false), Some(false)]
+            ),
+        );
+    }
+
+    #[test]
+    fn grace_notes() {
+        let ents = EntitiesRes::default();
+        let note = ents.create();
+        let grace_a = ents.create();
+        let grace_b = ents.create();
+        let rest = ents.create();
+
+        let mut bar = Bar::new(Metre::new(2, 4));
+        bar.splice(
+            Rational::new(1, 4),
+            vec![(
+                Duration::new(NoteValue::Quarter, 0, None),
+                Lifetime::Explicit(note),
+            )],
+        );
+        bar.push_managed_entity(rest);
+        let rhythm = bar.rhythm().clone();
+
+        let sixteenth = Duration::new(NoteValue::Sixteenth, 0, None);
+        bar.insert_grace(Rational::new(1, 4), sixteenth, grace_a);
+        bar.insert_grace(Rational::new(1, 4), sixteenth, grace_b);
+
+        // The rhythm is unchanged, and grace notes come before their note, in order.
+        assert_eq!(bar.rhythm(), &rhythm);
+        let children: Vec<(Entity, Rational, bool)> = bar
+            .children()
+            .iter()
+            .map(|c| (c.stencil, c.start, c.grace))
+            .collect();
+        assert_eq!(
+            children,
+            vec![
+                (rest, Rational::zero(), false),
+                (grace_a, Rational::new(1, 4), true),
+                (grace_b, Rational::new(1, 4), true),
+                (note, Rational::new(1, 4), false),
+            ]
+        );
+
+        assert_eq!(bar.remove(grace_a), None);
+        assert_eq!(bar.rhythm(), &rhythm);
+        assert_eq!(bar.children().len(), 3);
+    }
+
+    #[test]
+    fn slash_fill() {
+        let ents = EntitiesRes::default();
+        let note = ents.create();
+
+        let mut bar = Bar::new(Metre::new(4, 4));
+        bar.set_slash_fill(true);
+
+        // A whole rest becomes one slash per beat.
+        let quarter = Duration::new(NoteValue::Quarter, 0, None);
+        for i in 0..4 {
+            assert_eq!(
+                bar.next_missing_child(),
+                Some((quarter, Rational::new(i, 4)))
+            );
+            bar.push_managed_entity(ents.create());
+        }
+        assert_eq!(bar.next_missing_child(), None);
+
+        // Rests which span beats are split at each beat.
+        bar.splice(
+            Rational::new(1, 8),
+            vec![(
+                Duration::new(NoteValue::Eighth, 0, None),
+                Lifetime::Explicit(note),
+            )],
+        );
+        while bar.next_missing_child().is_some() {
+            bar.push_managed_entity(ents.create());
+        }
+        assert_eq!(bar.pop_managed_entity(), None);
+        let children: Vec<(Rational, Rational)> = bar
+            .children()
+            .iter()
+            .map(|c| (c.start, c.duration.duration()))
+            .collect();
+        assert_eq!(
+            children,
+            vec![
+                (Rational::zero(), Rational::new(1, 8)),
+                (Rational::new(1, 8), Rational::new(1, 8)),
+                (Rational::new(1, 4), Rational::new(1, 4)),
+                (Rational::new(1, 2), Rational::new(1, 4)),
+                (Rational::new(3, 4), Rational::new(1, 4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn map_entities() {
+        let ents = EntitiesRes::default();
+        let note = ents.create();
+        let grace = ents.create();
+        let rest = ents.create();
+
+        let mut bar = Bar::new(Metre::new(2, 4));
+        bar.splice(
+            Rational::new(1, 4),
+            vec![(
+                Duration::new(NoteValue::Quarter, 0, None),
+                Lifetime::Explicit(note),
+            )],
+        );
+        bar.push_managed_entity(rest);
+        bar.insert_grace(
+            Rational::new(1, 4),
+            Duration::new(NoteValue::Sixteenth, 0, None),
+            grace,
+        );
+
+        let mirrors: Vec<Entity> = (0..3).map(|_| ents.create()).collect();
+        let mirror = |entity: Entity| {
+            mirrors[[note, grace, rest]
+                .iter()
+                .position(|&e| e == entity)
+                .unwrap()]
+        };
+        let mirrored = bar.map_entities(mirror);
+
+        assert_eq!(mirrored.rhythm().len(), bar.rhythm().len());
+        for (child, mirrored_child) in bar.children().iter().zip(mirrored.children()) {
+            assert_eq!(mirrored_child.stencil, mirror(child.stencil));
+            assert_eq!(mirrored_child.start, child.start);
+            assert_eq!(mirrored_child.grace, child.grace);
+        }
+    }
 }
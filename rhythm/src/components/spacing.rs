@@ -36,6 +36,18 @@ impl Spacing {
         }
     }
 
+    /// The spacing of a grace note before the note at `t`.
+    ///
+    /// Grace notes take no time, so they have a fixed width, and do not stretch.
+    pub fn grace(t: Rational, start_x: f64, end_x: f64) -> Spacing {
+        Spacing {
+            t,
+            relative: 0.0,
+            start_x,
+            end_x,
+        }
+    }
+
     pub fn relative(self) -> f64 {
         self.relative
     }
@@ -105,7 +105,7 @@ impl NoteValue {
 /// In every case, the dots are on a half note."
 ///
 /// https://web.archive.org/web/20141031082831/http://www.informatics.indiana.edu/donbyrd/CMNExtremesBody.htm
-const MAX_DOTS: u8 = 4;
+pub const MAX_DOTS: u8 = 4;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 /// The duration of a note, rest, or chord.
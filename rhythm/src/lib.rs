@@ -6,7 +6,7 @@ mod metre;
 mod rhythmic_beaming;
 
 pub use bar_child::BarChild;
-pub use duration::{Duration, NoteValue};
+pub use duration::{Duration, NoteValue, MAX_DOTS};
 pub use lifetime::Lifetime;
 pub use metre::{Metre, MetreSegment, Subdivision, Superdivision};
 pub use rhythmic_beaming::RhythmicBeaming;
@@ -1,11 +1,12 @@
 use specs::Entity;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
 /// Why this this element created?
 pub enum Lifetime {
     /// This is a rest that was automatically inserted by the program.
     ///
     /// This should be considered empty space, and can be replaced without explicit user intent.
+    #[default]
     AutomaticRest,
 
     /// This is a rest that is hidden.
@@ -26,31 +27,19 @@ pub enum Lifetime {
 
 impl Lifetime {
     pub fn is_explicit(&self) -> bool {
-        match self {
-            Lifetime::Explicit(_) => true,
-            _ => false,
-        }
+        matches!(self, Lifetime::Explicit(_))
     }
 
     pub fn is_temporary(&self) -> bool {
-        match self {
-            Lifetime::Temporary(_) => true,
-            _ => false,
-        }
+        matches!(self, Lifetime::Temporary(_))
     }
 
     pub fn is_automatic(&self) -> bool {
-        match self {
-            Lifetime::AutomaticRest => true,
-            _ => false,
-        }
+        matches!(self, Lifetime::AutomaticRest)
     }
 
     pub fn is_hidden(&self) -> bool {
-        match self {
-            Lifetime::HiddenRest => true,
-            _ => false,
-        }
+        matches!(self, Lifetime::HiddenRest)
     }
 
     pub fn to_option(self) -> Option<Entity> {
@@ -60,10 +49,3 @@ impl Lifetime {
         }
     }
 }
-
-impl Default for Lifetime {
-    fn default() -> Self {
-        Lifetime::AutomaticRest
-    }
-}
-
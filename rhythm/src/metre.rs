@@ -67,7 +67,7 @@ impl MetreSegment {
 
     /// The classification of how this segment is divided.
     pub fn subdivision(&self) -> Subdivision {
-        if self.subdivisions % 2 == 0 || self.subdivisions == 1 {
+        if self.subdivisions.is_multiple_of(2) || self.subdivisions == 1 {
             Subdivision::Simple
         } else {
             Subdivision::Compound
@@ -1,3 +1,4 @@
+use crate::components::{Grace, StemDirection};
 use kurbo::Point;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -6,4 +7,7 @@ pub struct BeamAttachment {
     pub extreme_y: f64,
     pub entering: u8,
     pub leaving: u8,
+    pub stem_direction: StemDirection,
+    /// Grace notes have small beams.
+    pub grace: Option<Grace>,
 }
@@ -0,0 +1,216 @@
+use crate::text::{print_text, CAP_HEIGHT};
+use kurbo::Vec2;
+use pitch::{NoteModifier, NoteName};
+use std::fmt;
+use stencil::components::Stencil;
+
+/// The height of the text of chord symbols.
+const FONT_SIZE: f64 = 500.0;
+
+/// The size of sharps and flats in chord symbols, relative to their size on a staff.
+const ACCIDENTAL_SCALE: f64 = 0.6;
+
+/// The suffixes that chord symbols can have, and the notes of each chord, in semitones above the
+/// root.
+const QUALITIES: &[(&[&str], &[u8])] = &[
+    (&[""], &[0, 4, 7]),
+    (&["m", "min", "-"], &[0, 3, 7]),
+    (&["dim", "°"], &[0, 3, 6]),
+    (&["aug", "+"], &[0, 4, 8]),
+    (&["5"], &[0, 7]),
+    (&["6"], &[0, 4, 7, 9]),
+    (&["m6", "min6", "-6"], &[0, 3, 7, 9]),
+    (&["7"], &[0, 4, 7, 10]),
+    (&["maj7", "M7"], &[0, 4, 7, 11]),
+    (&["m7", "min7", "-7"], &[0, 3, 7, 10]),
+    (&["m7b5", "ø", "ø7"], &[0, 3, 6, 10]),
+    (&["dim7", "°7"], &[0, 3, 6, 9]),
+    (&["sus2"], &[0, 2, 7]),
+    (&["sus4", "sus"], &[0, 5, 7]),
+    (&["7sus4", "7sus"], &[0, 5, 7, 10]),
+    (&["add9"], &[0, 2, 4, 7]),
+    (&["9"], &[0, 2, 4, 7, 10]),
+];
+
+/// A note in a chord symbol, which has no octave.
+pub type ChordNote = (NoteName, Option<NoteModifier>);
+
+/// A chord symbol, such as "Am7" or "G/B".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordSymbol {
+    pub root: ChordNote,
+
+    /// The quality of the chord, as written, such as "m7".
+    pub suffix: String,
+
+    /// The lowest note, if it is not the root.
+    pub bass: Option<ChordNote>,
+}
+
+fn parse_note(text: &str) -> Option<(ChordNote, &str)> {
+    let name = match text.chars().next()? {
+        'C' => NoteName::C,
+        'D' => NoteName::D,
+        'E' => NoteName::E,
+        'F' => NoteName::F,
+        'G' => NoteName::G,
+        'A' => NoteName::A,
+        'B' => NoteName::B,
+        _ => return None,
+    };
+    let rest = &text[1..];
+    if let Some(rest) = rest.strip_prefix('#') {
+        Some(((name, Some(NoteModifier::SemiUp)), rest))
+    } else if let Some(rest) = rest.strip_prefix('b') {
+        Some(((name, Some(NoteModifier::SemiDown)), rest))
+    } else {
+        Some(((name, None), rest))
+    }
+}
+
+/// The pitch class of a note, from 0 (C) to 11 (B).
+fn pitch_class((name, modifier): ChordNote) -> u8 {
+    (name as i8 + modifier.map(|m| m as i8).unwrap_or(0)).rem_euclid(12) as u8
+}
+
+/// Print a note name with a real sharp or flat, since text is not always in a font that has them.
+fn print_note((name, modifier): ChordNote) -> Stencil {
+    let letter = print_text(&format!("{:?}", name), FONT_SIZE, false);
+    let accidental = match modifier {
+        Some(NoteModifier::SemiUp) => Stencil::sharp(),
+        Some(NoteModifier::SemiDown) => Stencil::flat(),
+        None => return letter,
+    };
+    let accidental = accidental
+        .with_scale(ACCIDENTAL_SCALE)
+        .with_translation(Vec2::new(0.0, -FONT_SIZE * CAP_HEIGHT));
+
+    letter.and_right(accidental)
+}
+
+impl ChordSymbol {
+    /// Parses a chord symbol such as "C", "F#m7", or "Bb/D".
+    pub fn parse(text: &str) -> Option<ChordSymbol> {
+        let (root, rest) = parse_note(text.trim())?;
+        let (suffix, bass) = match rest.find('/') {
+            Some(slash) => {
+                let (bass, after) = parse_note(&rest[slash + 1..])?;
+                if !after.is_empty() {
+                    return None;
+                }
+                (&rest[..slash], Some(bass))
+            }
+            None => (rest, None),
+        };
+
+        let symbol = ChordSymbol {
+            root,
+            suffix: suffix.to_owned(),
+            bass,
+        };
+        symbol.intervals()?;
+
+        Some(symbol)
+    }
+
+    fn intervals(&self) -> Option<&'static [u8]> {
+        QUALITIES
+            .iter()
+            .find(|(suffixes, _)| suffixes.contains(&self.suffix.as_str()))
+            .map(|&(_, intervals)| intervals)
+    }
+
+    /// The pitch classes in the chord, from 0 (C) to 11 (B), starting with the root.
+    pub fn notes(&self) -> Vec<u8> {
+        let root = pitch_class(self.root);
+        let mut notes: Vec<u8> = self
+            .intervals()
+            .unwrap_or_default()
+            .iter()
+            .map(|interval| (root + interval) % 12)
+            .collect();
+        if let Some(bass) = self.bass.map(pitch_class) {
+            if !notes.contains(&bass) {
+                notes.push(bass);
+            }
+        }
+
+        notes
+    }
+
+    /// The pitch class of the root.
+    pub fn root_class(&self) -> u8 {
+        pitch_class(self.root)
+    }
+
+    /// The pitch class of the lowest note.
+    pub fn bass_class(&self) -> u8 {
+        pitch_class(self.bass.unwrap_or(self.root))
+    }
+
+    /// Print the symbol with its bottom at y = 0, starting at x = 0.
+    pub fn print(&self) -> Stencil {
+        let mut stencil = print_note(self.root);
+        if !self.suffix.is_empty() {
+            stencil = stencil.and_right(print_text(&self.suffix, FONT_SIZE, false));
+        }
+        if let Some(bass) = self.bass {
+            stencil = stencil
+                .and_right(print_text("/", FONT_SIZE, false))
+                .and_right(print_note(bass));
+        }
+
+        stencil
+    }
+}
+
+impl fmt::Display for ChordSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let write_note = |f: &mut fmt::Formatter, (name, modifier): ChordNote| {
+            let accidental = match modifier {
+                Some(NoteModifier::SemiUp) => "#",
+                Some(NoteModifier::SemiDown) => "b",
+                None => "",
+            };
+            write!(f, "{:?}{}", name, accidental)
+        };
+
+        write_note(f, self.root)?;
+        write!(f, "{}", self.suffix)?;
+        if let Some(bass) = self.bass {
+            write!(f, "/")?;
+            write_note(f, bass)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let symbol = ChordSymbol::parse("F#m7").unwrap();
+        assert_eq!(symbol.root, (NoteName::F, Some(NoteModifier::SemiUp)));
+        assert_eq!(symbol.suffix, "m7");
+        assert_eq!(symbol.bass, None);
+        assert_eq!(symbol.notes(), vec![6, 9, 1, 4]);
+
+        let symbol = ChordSymbol::parse("Bb/D").unwrap();
+        assert_eq!(symbol.notes(), vec![10, 2, 5]);
+        assert_eq!(symbol.bass_class(), 2);
+        assert_eq!(symbol.to_string(), "Bb/D");
+
+        // The bass note is added to the chord if it is not in it.
+        assert_eq!(
+            ChordSymbol::parse("C/Bb").unwrap().notes(),
+            vec![0, 4, 7, 10]
+        );
+
+        assert_eq!(ChordSymbol::parse("H7"), None);
+        assert_eq!(ChordSymbol::parse("Cmaj13"), None);
+        assert_eq!(ChordSymbol::parse("C/"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use kurbo::Vec2;
+use pitch::NoteModifier;
+use specs::{Component, Entity, VecStorage};
+use stencil::components::Stencil;
+
+/// An accidental in front of a notehead of the chord that is its parent.
+#[derive(Debug)]
+pub struct Accidental {
+    /// The accidental to show, or None for a natural.
+    pub modifier: Option<NoteModifier>,
+
+    /// The y of the notehead.
+    pub y: f64,
+
+    /// Accidentals which would collide are moved into columns further left. The column nearest
+    /// the notehead is 0.
+    pub column: usize,
+}
+
+impl Component for Accidental {
+    type Storage = VecStorage<Self>;
+}
+
+impl Accidental {
+    /// Print the accidental with its right edge at `x`, relative to the chord.
+    pub fn print(&self, x: f64) -> Stencil {
+        let glyph = Self::glyph(self.modifier);
+        let x1 = glyph.rect().x1;
+        glyph.with_translation(Vec2::new(x - x1, self.y))
+    }
+
+    pub(crate) fn glyph(modifier: Option<NoteModifier>) -> Stencil {
+        match modifier {
+            None => Stencil::natural(),
+            Some(NoteModifier::SemiUp) => Stencil::sharp(),
+            Some(NoteModifier::SemiDown) => Stencil::flat(),
+        }
+    }
+}
+
+/// The accidentals of a chord, which are managed by `PrintAccidentals`.
+#[derive(Debug, Default)]
+pub struct AccidentalsForChord(pub Vec<Entity>);
+
+impl Component for AccidentalsForChord {
+    type Storage = VecStorage<Self>;
+}
@@ -1,21 +1,141 @@
 use kurbo::{Point, Vec2};
+use pitch::{Clef, Interval};
 use rhythm::{Duration, NoteValue};
 use specs::{Component, VecStorage};
 use stencil::components::Stencil;
 
-use crate::{components::Context, PitchKind};
-use pitch::NoteModifier;
+use crate::{
+    components::{Context, StemDirection},
+    PercussionNotehead, PitchKind,
+};
+use std::collections::BTreeSet;
+use wasm_bindgen::prelude::*;
+
+/// A user override of how a note is beamed.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BeamOverride {
+    /// Start a new beam at this note.
+    BreakBefore,
+
+    /// Beam this note to the next note, even across a beat.
+    JoinNext,
+
+    /// Beam this note over any rests to the next note.
+    JoinOverRests,
+
+    /// Beam this note to every following note and rest in the bar.
+    FullBar,
+}
+
+/// A small note written before another note, which takes no time in the bar.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Grace {
+    /// An unslashed grace note, played on the beat.
+    Appoggiatura,
+
+    /// A slashed grace note, played just before the beat.
+    Acciaccatura,
+}
+
+/// How big grace notes are, relative to other notes.
+pub(crate) const GRACE_SCALE: f64 = 0.65;
+
+/// Where the stems of notes on a tab staff start, below the lowest string.
+const TAB_STEM_START: f64 = 625.0;
+
+/// The size of the circle above an open hi-hat.
+const OPEN_RADIUS: f64 = 80.0;
+const OPEN_THICKNESS: f64 = 30.0;
+
+/// A mark written above or below a note or rest.
+///
+/// Marks are stacked outwards from the note in the order they are declared here.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Articulation {
+    Staccato,
+    Tenuto,
+    Accent,
+    Marcato,
+    Turn,
+    Trill,
+    Fermata,
+}
+
+impl Articulation {
+    /// Fermatas and ornaments are always above the staff. Everything else is opposite the stem.
+    fn always_above(self) -> bool {
+        match self {
+            Articulation::Turn | Articulation::Trill | Articulation::Fermata => true,
+            Articulation::Staccato
+            | Articulation::Tenuto
+            | Articulation::Accent
+            | Articulation::Marcato => false,
+        }
+    }
+
+    /// Whether this mark is small enough to be written in a space inside the staff.
+    fn fits_in_space(self) -> bool {
+        match self {
+            Articulation::Staccato | Articulation::Tenuto => true,
+            Articulation::Accent
+            | Articulation::Marcato
+            | Articulation::Turn
+            | Articulation::Trill
+            | Articulation::Fermata => false,
+        }
+    }
+
+    fn stencil(self, above: bool) -> Stencil {
+        match (self, above) {
+            (Articulation::Staccato, true) => Stencil::staccato_above(),
+            (Articulation::Staccato, false) => Stencil::staccato_below(),
+            (Articulation::Tenuto, true) => Stencil::tenuto_above(),
+            (Articulation::Tenuto, false) => Stencil::tenuto_below(),
+            (Articulation::Accent, true) => Stencil::accent_above(),
+            (Articulation::Accent, false) => Stencil::accent_below(),
+            (Articulation::Marcato, true) => Stencil::marcato_above(),
+            (Articulation::Marcato, false) => Stencil::marcato_below(),
+            (Articulation::Fermata, true) => Stencil::fermata_above(),
+            (Articulation::Fermata, false) => Stencil::fermata_below(),
+            (Articulation::Trill, _) => Stencil::trill(),
+            (Articulation::Turn, _) => Stencil::turn(),
+        }
+    }
+}
 
 /// A Rest, Note, or Chord.
 ///
 /// There are two kinds of RNCs:
 ///  - explicit RNCs, created by the user.
 ///  - managed RNCs: rests created sys_update_chord_timing.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Chord {
     pub duration: Duration,
     pub natural_duration: Duration,
+
+    /// The pitch that sounds. See `written_pitch` for the pitch that is shown.
     pub pitch: PitchKind,
+    pub beam_override: Option<BeamOverride>,
+    pub articulations: BTreeSet<Articulation>,
+    pub grace: Option<Grace>,
+
+    /// If true, a note is written as a rhythm slash on the middle line, and a rest is written as
+    /// a beat slash without a stem. The pitch is kept, but not written or played.
+    pub slash: bool,
+
+    /// On a tab staff, the string to play this note on, if it can be. Strings are numbered from 1,
+    /// the highest.
+    pub string: Option<u8>,
+
+    /// How far above `pitch` this note is written, from the instrument of its staff and the
+    /// song's concert pitch setting. Set by `UpdateContext`.
+    pub transposition: Interval,
+
+    /// True if the instrument of its staff cannot play this note. Set by `UpdateContext`.
+    pub out_of_range: bool,
 }
 
 impl Component for Chord {
@@ -28,9 +148,21 @@ impl Chord {
             natural_duration: duration,
             duration,
             pitch,
+            beam_override: None,
+            articulations: BTreeSet::new(),
+            grace: None,
+            slash: false,
+            string: None,
+            transposition: Interval::unison(),
+            out_of_range: false,
         }
     }
 
+    /// The pitch that is shown, which is transposed for transposing instruments.
+    pub fn written_pitch(&self) -> PitchKind {
+        self.pitch.transpose(self.transposition)
+    }
+
     pub fn duration(&self) -> Duration {
         self.duration
     }
@@ -38,203 +170,331 @@ impl Chord {
     pub fn is_note(&self) -> bool {
         match self.pitch {
             PitchKind::Rest => false,
-            PitchKind::Unpitched | PitchKind::Pitch(_) => true,
+            PitchKind::Unpitched | PitchKind::Pitch(_) | PitchKind::Percussion(_) => true,
+        }
+    }
+
+    /// Where the notehead is written, relative to the middle line, or None for rests.
+    ///
+    /// Slashes are always written on the middle line.
+    pub fn y(&self, clef: Clef) -> Option<f64> {
+        if self.slash && self.is_note() {
+            Some(0.0)
+        } else {
+            self.written_pitch().y(clef)
         }
     }
 
-    pub fn print(&self, context: &Context, has_beam: bool) -> (Stencil, Option<Point>) {
+    /// The notehead of a note, and where its stem attaches, relative to its center.
+    ///
+    /// Unpitched notes and cymbals have cross noteheads.
+    pub(crate) fn notehead(&self, stem_direction: StemDirection) -> (Stencil, Option<Point>) {
+        let is_up = stem_direction == StemDirection::Up;
+        if self.slash {
+            return match (self.duration.duration_display_base(), is_up) {
+                (Some(NoteValue::Maxima), _)
+                | (Some(NoteValue::Longa), _)
+                | (Some(NoteValue::DoubleWhole), _)
+                | (Some(NoteValue::Whole), _) => Stencil::notehead_slash_whole(),
+                (Some(NoteValue::Half), true) => Stencil::notehead_slash_half_up(),
+                (Some(NoteValue::Half), false) => Stencil::notehead_slash_half_down(),
+                (_, true) => Stencil::notehead_slash_black_up(),
+                (_, false) => Stencil::notehead_slash_black_down(),
+            };
+        }
+
+        let is_x = match self.pitch {
+            PitchKind::Unpitched => true,
+            PitchKind::Percussion(instrument) => instrument.notehead() == PercussionNotehead::X,
+            PitchKind::Rest | PitchKind::Pitch(_) => false,
+        };
+        if is_x {
+            return match (self.duration.duration_display_base(), is_up) {
+                (Some(NoteValue::Maxima), _)
+                | (Some(NoteValue::Longa), _)
+                | (Some(NoteValue::DoubleWhole), _) => Stencil::notehead_x_double_whole(),
+                (Some(NoteValue::Whole), _) => Stencil::notehead_x_whole(),
+                (Some(NoteValue::Half), true) => Stencil::notehead_x_half_up(),
+                (Some(NoteValue::Half), false) => Stencil::notehead_x_half_down(),
+                (_, true) => Stencil::notehead_x_black_up(),
+                (_, false) => Stencil::notehead_x_black_stem_down_attachment(),
+            };
+        }
+
+        match (self.duration.duration_display_base(), is_up) {
+            (Some(NoteValue::Maxima), _)
+            | (Some(NoteValue::Longa), _)
+            | (Some(NoteValue::DoubleWhole), _) => Stencil::notehead_double_whole(),
+            (Some(NoteValue::Whole), _) => Stencil::notehead_whole(),
+            (Some(NoteValue::Half), true) => Stencil::notehead_half_up(),
+            (Some(NoteValue::Quarter), true)
+            | (Some(NoteValue::Eighth), true)
+            | (Some(NoteValue::Sixteenth), true)
+            | (Some(NoteValue::ThirtySecond), true)
+            | (Some(NoteValue::SixtyFourth), true)
+            | (Some(NoteValue::HundredTwentyEighth), true)
+            | (Some(NoteValue::TwoHundredFiftySixth), true)
+            | (None, true) => Stencil::notehead_black_up(),
+            (Some(NoteValue::Half), false) => Stencil::notehead_half_down(),
+            (Some(NoteValue::Quarter), false)
+            | (Some(NoteValue::Eighth), false)
+            | (Some(NoteValue::Sixteenth), false)
+            | (Some(NoteValue::ThirtySecond), false)
+            | (Some(NoteValue::SixtyFourth), false)
+            | (Some(NoteValue::HundredTwentyEighth), false)
+            | (Some(NoteValue::TwoHundredFiftySixth), false)
+            | (None, false) => Stencil::notehead_black_down(),
+        }
+    }
+
+    /// The stem and flag of a note whose notehead is at `y`, with the given stem attachment.
+    ///
+    /// Stems are 3.5 spaces long, but always reach the middle line.
+    fn print_stem(&self, attachment: Point, y: f64, stem_direction: StemDirection) -> Stencil {
+        let mut stencil = Stencil::default();
+        match stem_direction {
+            StemDirection::Up => {
+                let flag = match self.duration.duration_display_base() {
+                    Some(NoteValue::Eighth) => Some(Stencil::flag_up_8()),
+                    Some(NoteValue::Sixteenth) => Some(Stencil::flag_up_16()),
+                    Some(NoteValue::ThirtySecond) => Some(Stencil::flag_up_32()),
+                    Some(NoteValue::SixtyFourth) => Some(Stencil::flag_up_64()),
+                    Some(NoteValue::HundredTwentyEighth) => Some(Stencil::flag_up_128()),
+                    Some(NoteValue::TwoHundredFiftySixth) => Some(Stencil::flag_up_256()),
+                    _ => None,
+                };
+
+                let top = (attachment.y + y - 875.0).min(0.0);
+                let stem = Stencil::stem_line(
+                    attachment.x,
+                    attachment.y + y,
+                    top + flag.as_ref().map(|a| a.1.y).unwrap_or(0.0),
+                );
+                let stem_width = stem.rect().width();
+                stencil = stencil.and(stem);
+
+                if let Some((flag, _)) = flag {
+                    stencil = stencil.and(
+                        flag.with_translation(Vec2::new(attachment.x - stem_width / 2.0, top)),
+                    );
+                }
+            }
+            StemDirection::Down => {
+                let flag = match self.duration.duration_display_base() {
+                    Some(NoteValue::Eighth) => Some(Stencil::flag_down_8()),
+                    Some(NoteValue::Sixteenth) => Some(Stencil::flag_down_16()),
+                    Some(NoteValue::ThirtySecond) => Some(Stencil::flag_down_32()),
+                    Some(NoteValue::SixtyFourth) => Some(Stencil::flag_down_64()),
+                    Some(NoteValue::HundredTwentyEighth) => Some(Stencil::flag_down_128()),
+                    Some(NoteValue::TwoHundredFiftySixth) => Some(Stencil::flag_down_256()),
+                    _ => None,
+                };
+
+                let bottom = (attachment.y + y + 875.0).max(0.0);
+                let stem = Stencil::stem_line(
+                    attachment.x,
+                    attachment.y + y,
+                    bottom + flag.as_ref().map(|a| a.1.y).unwrap_or(0.0),
+                );
+                let stem_width = stem.rect().width();
+                stencil = stencil.and(stem);
+
+                if let Some((flag, _)) = flag {
+                    stencil = stencil.and(
+                        flag.with_translation(Vec2::new(attachment.x - stem_width / 2.0, bottom)),
+                    );
+                }
+            }
+        }
+        stencil
+    }
+
+    /// Whether the note is marked with a circle above it.
+    fn is_open(&self) -> bool {
+        match self.pitch {
+            PitchKind::Percussion(instrument) => instrument.is_open(),
+            PitchKind::Rest | PitchKind::Unpitched | PitchKind::Pitch(_) => false,
+        }
+    }
+
+    /// Articulations for a note whose notehead is at `pitch_y`, given the note without them.
+    ///
+    /// Staccatos and tenutos are written in the nearest space, and may be inside the staff. Other
+    /// marks are written outside the staff, and marks above a stem-up note clear the stem. The
+    /// circle of an open hi-hat is written above the note, before any other marks.
+    fn print_articulations(
+        &self,
+        note: &Stencil,
+        pitch_y: f64,
+        head_right: f64,
+        has_stem: bool,
+        stem_direction: StemDirection,
+    ) -> Stencil {
+        const PADDING: f64 = 62.5;
+        let rect = note.rect();
+        let is_note = self.is_note();
+
+        // The nearest free y above and below the note.
+        let (mut above, mut below) = match (is_note, has_stem, stem_direction) {
+            (false, _, _) => (rect.y0, rect.y1),
+            (true, false, _) => (pitch_y - 125.0, pitch_y + 125.0),
+            (true, true, StemDirection::Up) => {
+                (rect.y0.min((pitch_y - 875.0).min(0.0)), pitch_y + 125.0)
+            }
+            (true, true, StemDirection::Down) => {
+                (pitch_y - 125.0, rect.y1.max((pitch_y + 875.0).max(0.0)))
+            }
+        };
+
+        let open = if self.is_open() {
+            Some(Stencil::ring(OPEN_RADIUS, OPEN_THICKNESS, Point::ZERO))
+        } else {
+            None
+        };
+        let marks =
+            open.into_iter()
+                .map(|mark| (mark, true, false))
+                .chain(self.articulations.iter().map(|&articulation| {
+                    let is_above = !is_note
+                        || articulation.always_above()
+                        || stem_direction == StemDirection::Down;
+                    (
+                        articulation.stencil(is_above),
+                        is_above,
+                        articulation.fits_in_space(),
+                    )
+                }));
+
+        let mut stencil = Stencil::default();
+        for (mark, is_above, fits_in_space) in marks {
+            let edge = if is_above { &mut above } else { &mut below };
+            let sign = if is_above { -1.0 } else { 1.0 };
+            let mark_rect = mark.rect();
+            let x = head_right / 2.0 - (mark_rect.x0 + mark_rect.x1) / 2.0;
+
+            let y = if fits_in_space {
+                let mut center = *edge + sign * 125.0;
+                if center.abs() <= 500.0 && center % 250.0 == 0.0 {
+                    center += sign * 125.0;
+                }
+                center - (mark_rect.y0 + mark_rect.y1) / 2.0
+            } else if is_above {
+                edge.min(-500.0 - PADDING * 2.0) - mark_rect.y1
+            } else {
+                edge.max(500.0 + PADDING * 2.0) - mark_rect.y0
+            };
+
+            *edge = if is_above {
+                y + mark_rect.y0 - PADDING
+            } else {
+                y + mark_rect.y1 + PADDING
+            };
+            stencil = stencil.and(mark.with_translation(Vec2::new(x, y)));
+        }
+
+        stencil
+    }
+
+    /// The augmentation dots of the note or rest, starting at x = 0.
+    fn print_dots(&self) -> Option<Stencil> {
+        let dots = self.duration.display_dots()?;
+        let mut dot_stencil = Stencil::default();
+        for i in 0..dots {
+            if i == 0 {
+                dot_stencil = dot_stencil.and_right(Stencil::padding(112.5));
+            } else {
+                dot_stencil = dot_stencil.and_right(Stencil::padding(12.5));
+            }
+            dot_stencil = dot_stencil.and_right(Stencil::augmentation_dot());
+        }
+
+        Some(dot_stencil)
+    }
+
+    /// Prints the fret number of a note on a tab staff, on the line at `string_y`, with its stem
+    /// and flag below the staff.
+    ///
+    /// As with `print`, if the note is beamed, the stem attachment is returned instead.
+    pub fn print_tab(&self, string_y: f64, fret: u8, has_beam: bool) -> (Stencil, Option<Point>) {
+        let mut number = Stencil::fret_number(fret);
+        if self.grace.is_some() {
+            number = number.with_scale(GRACE_SCALE);
+        }
+        let rect = number.rect();
+        let center = (rect.x0 + rect.x1) / 2.0;
+        let mut stencil = number.with_translation(Vec2::new(0.0, string_y));
+
+        let attachment = match self.duration.duration_display_base() {
+            Some(NoteValue::Maxima)
+            | Some(NoteValue::Longa)
+            | Some(NoteValue::DoubleWhole)
+            | Some(NoteValue::Whole) => None,
+            _ => Some(Point::new(center, TAB_STEM_START)),
+        };
+        if let (Some(attachment), false) = (attachment, has_beam) {
+            stencil = stencil.and(self.print_stem(attachment, 0.0, StemDirection::Down));
+        }
+
+        // Dots are beside the stem, or beside the number if there is no stem.
+        if let Some(dots) = self.print_dots() {
+            let offset = match attachment {
+                Some(attachment) => Vec2::new(attachment.x, attachment.y + 250.0),
+                None => Vec2::new(rect.x1, string_y - 125.0),
+            };
+            stencil = stencil.and(dots.with_translation(offset));
+        }
+
+        (stencil, attachment)
+    }
+
+    /// Prints the notehead, stem and flag, or the rest.
+    ///
+    /// Accidentals and leger lines are printed by `PrintAccidentals` and `PrintLegerLines`.
+    ///
+    /// If the note is beamed, the stem is drawn by the beam, and the stem attachment is returned
+    /// instead.
+    pub fn print(
+        &self,
+        context: &Context,
+        stem_direction: StemDirection,
+        has_beam: bool,
+    ) -> (Stencil, Option<Point>) {
         let mut stencil = Stencil::default();
         let head_right;
         let mut attachment_for_beam = None;
         let pitch_y;
 
         match self.pitch {
-            PitchKind::Pitch(pitch) => {
-                pitch_y = pitch.y(context.clef);
-                let is_up = pitch_y > 0.0 || has_beam;
-                let (head, mut attachment) = match (self.duration.duration_display_base(), is_up) {
-                    (Some(NoteValue::Maxima), _)
-                    | (Some(NoteValue::Longa), _)
-                    | (Some(NoteValue::DoubleWhole), _) => Stencil::notehead_double_whole(),
-                    (Some(NoteValue::Whole), _) => Stencil::notehead_whole(),
-                    (Some(NoteValue::Half), true) => Stencil::notehead_half_up(),
-                    (Some(NoteValue::Quarter), true)
-                    | (Some(NoteValue::Eighth), true)
-                    | (Some(NoteValue::Sixteenth), true)
-                    | (Some(NoteValue::ThirtySecond), true)
-                    | (Some(NoteValue::SixtyFourth), true)
-                    | (Some(NoteValue::HundredTwentyEighth), true)
-                    | (Some(NoteValue::TwoHundredFiftySixth), true)
-                    | (None, true) => Stencil::notehead_black_up(),
-                    (Some(NoteValue::Half), false) => Stencil::notehead_half_down(),
-                    (Some(NoteValue::Quarter), false)
-                    | (Some(NoteValue::Eighth), false)
-                    | (Some(NoteValue::Sixteenth), false)
-                    | (Some(NoteValue::ThirtySecond), false)
-                    | (Some(NoteValue::SixtyFourth), false)
-                    | (Some(NoteValue::HundredTwentyEighth), false)
-                    | (Some(NoteValue::TwoHundredFiftySixth), false)
-                    | (None, false) => Stencil::notehead_black_down(),
-                };
+            PitchKind::Pitch(_) | PitchKind::Percussion(_) => {
+                pitch_y = self.y(context.clef).unwrap_or(0.0);
+                let (head, attachment) = self.notehead(stem_direction);
 
                 attachment_for_beam = attachment.map(|a| a + Vec2::new(0.0, pitch_y));
-                if has_beam {
-                    attachment = None;
-                }
-
-                // TODO(joshuan): accidentals should be their own entity.
-                if context
-                    .accidentals
-                    .get(&(pitch.name(), pitch.octave()))
-                    .cloned()
-                    != pitch.modifier()
-                {
-                    let accidentals = match pitch.modifier() {
-                        None => Stencil::natural(),
-                        Some(NoteModifier::SemiUp) => Stencil::sharp(),
-                        Some(NoteModifier::SemiDown) => Stencil::flat(),
-                    };
-                    let x1 = accidentals.rect().x1;
-                    stencil =
-                        stencil.and(accidentals.with_translation(Vec2::new(-x1 - 20.0, pitch_y)));
-                }
 
                 head_right = head.rect().x1;
+                stencil = stencil.and(head.with_translation(Vec2::new(0.0, pitch_y)));
 
-                // TODO(joshuan): Determine direction elsewhere. Be clever with middle stems.
-                if let Some(attachment) = attachment {
-                    if is_up {
-                        stencil = stencil.and(head.with_translation(Vec2::new(0.0, pitch_y)));
-                        let flag = match self.duration.duration_display_base() {
-                            Some(NoteValue::Eighth) => Some(Stencil::flag_up_8()),
-                            Some(NoteValue::Sixteenth) => Some(Stencil::flag_up_16()),
-                            Some(NoteValue::ThirtySecond) => Some(Stencil::flag_up_32()),
-                            Some(NoteValue::SixtyFourth) => Some(Stencil::flag_up_64()),
-                            Some(NoteValue::HundredTwentyEighth) => Some(Stencil::flag_up_128()),
-                            Some(NoteValue::TwoHundredFiftySixth) => Some(Stencil::flag_up_256()),
-                            _ => None,
-                        };
-
-                        let top = (attachment.y + pitch_y - 875.0).min(0.0);
-                        let stem = Stencil::stem_line(
-                            attachment.x,
-                            attachment.y + pitch_y,
-                            top + flag.as_ref().map(|a| a.1.y).unwrap_or(0.0),
-                        );
-                        let stem_width = stem.rect().width();
-                        stencil = stencil.and(stem);
-
-                        if let Some((flag, _)) = flag {
-                            stencil =
-                                stencil.and(flag.with_translation(Vec2::new(
-                                    attachment.x - stem_width / 2.0,
-                                    top,
-                                )));
-                        }
-                    } else {
-                        let flag = match self.duration.duration_display_base() {
-                            Some(NoteValue::Eighth) => Some(Stencil::flag_down_8()),
-                            Some(NoteValue::Sixteenth) => Some(Stencil::flag_down_16()),
-                            Some(NoteValue::ThirtySecond) => Some(Stencil::flag_down_32()),
-                            Some(NoteValue::SixtyFourth) => Some(Stencil::flag_down_64()),
-                            Some(NoteValue::HundredTwentyEighth) => Some(Stencil::flag_down_128()),
-                            Some(NoteValue::TwoHundredFiftySixth) => Some(Stencil::flag_down_256()),
-                            _ => None,
-                        };
-
-                        stencil = stencil.and(head.with_translation(Vec2::new(0.0, pitch_y)));
-
-                        let bottom = (attachment.y + pitch_y + 875.0).max(0.0);
-                        let stem = Stencil::stem_line(
-                            0.0,
-                            attachment.y + pitch_y,
-                            bottom + flag.as_ref().map(|a| a.1.y).unwrap_or(0.0),
-                        );
-                        let stem_width = stem.rect().width();
-                        stencil = stencil.and(stem);
-
-                        if let Some((flag, _)) = flag {
-                            stencil = stencil.and(flag.with_translation(Vec2::new(
-                                attachment.x - stem_width / 2.0,
-                                bottom,
-                            )));
-                        }
-                    }
-                } else {
-                    stencil = stencil.and(head.with_translation(Vec2::new(0.0, pitch_y)));
-                }
-
-                // TODO(joshuan): Leger lines should be their own entities.
-                if pitch_y >= 750.0 {
-                    let bottom_legers = (pitch_y / 250.0).floor() as usize - 2;
-                    for i in 0..bottom_legers {
-                        stencil = stencil.and(Stencil::leger_line(
-                            0.0,
-                            head_right,
-                            ((i + 3) as f64) * 250.0,
-                        ));
-                    }
-                }
-                if pitch_y <= -750.0 {
-                    let top_legers = (pitch_y / -250.0).floor() as usize - 2;
-                    for i in 0..top_legers {
-                        stencil = stencil.and(Stencil::leger_line(
-                            0.0,
-                            head_right,
-                            ((i + 3) as f64) * -250.0,
-                        ));
-                    }
+                if let (Some(attachment), false) = (attachment, has_beam) {
+                    stencil = stencil.and(self.print_stem(attachment, pitch_y, stem_direction));
                 }
             }
             PitchKind::Unpitched => {
                 pitch_y = 0.0;
-                let (head, attachment) = match self.duration.duration_display_base() {
-                    Some(NoteValue::Maxima)
-                    | Some(NoteValue::Longa)
-                    | Some(NoteValue::DoubleWhole) => Stencil::notehead_x_double_whole(),
-                    Some(NoteValue::Whole) => Stencil::notehead_x_whole(),
-                    Some(NoteValue::Half) => Stencil::notehead_x_half_up(),
-                    Some(NoteValue::Quarter)
-                    | Some(NoteValue::Eighth)
-                    | Some(NoteValue::Sixteenth)
-                    | Some(NoteValue::ThirtySecond)
-                    | Some(NoteValue::SixtyFourth)
-                    | Some(NoteValue::HundredTwentyEighth)
-                    | Some(NoteValue::TwoHundredFiftySixth)
-                    | None => Stencil::notehead_x_black_up(),
-                };
+                let (head, attachment) = self.notehead(stem_direction);
 
                 attachment_for_beam = attachment;
                 head_right = head.rect().x1;
                 stencil = head;
 
-                if let Some(attachment) = attachment {
-                    let flag = match self.duration.duration_display_base() {
-                        Some(NoteValue::Eighth) => Some(Stencil::flag_up_8()),
-                        Some(NoteValue::Sixteenth) => Some(Stencil::flag_up_16()),
-                        Some(NoteValue::ThirtySecond) => Some(Stencil::flag_up_32()),
-                        Some(NoteValue::SixtyFourth) => Some(Stencil::flag_up_64()),
-                        Some(NoteValue::HundredTwentyEighth) => Some(Stencil::flag_up_128()),
-                        Some(NoteValue::TwoHundredFiftySixth) => Some(Stencil::flag_up_256()),
-                        _ => None,
-                    };
-
-                    let top = attachment.y - 875.0;
-                    let stem = Stencil::stem_line(
-                        attachment.x,
-                        attachment.y,
-                        top + flag.as_ref().map(|a| a.1.y).unwrap_or(0.0),
-                    );
-                    let stem_width = stem.rect().width();
-                    stencil = stencil.and(stem);
-
-                    if let Some((flag, _)) = flag {
-                        stencil = stencil.and(
-                            flag.with_translation(Vec2::new(attachment.x - stem_width / 2.0, top)),
-                        );
-                    }
+                if let (Some(attachment), false) = (attachment, has_beam) {
+                    stencil = stencil.and(self.print_stem(attachment, pitch_y, stem_direction));
                 }
             }
+            PitchKind::Rest if self.slash => {
+                pitch_y = 0.0;
+                stencil = Stencil::notehead_slash_black_up().0;
+                head_right = stencil.rect().x1;
+            }
             PitchKind::Rest => {
                 pitch_y = 0.0;
                 stencil = match self.duration.duration_display_base() {
@@ -256,16 +516,7 @@ impl Chord {
             }
         };
 
-        if let Some(dots) = self.duration.display_dots() {
-            let mut dot_stencil = Stencil::default();
-            for i in 0..dots {
-                if i == 0 {
-                    dot_stencil = dot_stencil.and_right(Stencil::padding(112.5));
-                } else {
-                    dot_stencil = dot_stencil.and_right(Stencil::padding(12.5));
-                }
-                dot_stencil = dot_stencil.and_right(Stencil::augmentation_dot());
-            }
+        if let Some(dot_stencil) = self.print_dots() {
             stencil = stencil.and(dot_stencil.with_translation(Vec2::new(
                 head_right,
                 if (pitch_y as i64) % 250 == 0 {
@@ -276,6 +527,37 @@ impl Chord {
             )));
         }
 
+        if !self.articulations.is_empty() || self.is_open() {
+            let articulations = self.print_articulations(
+                &stencil,
+                pitch_y,
+                head_right,
+                attachment_for_beam.is_some(),
+                stem_direction,
+            );
+            stencil = stencil.and(articulations);
+        }
+
+        if let Some(grace) = self.grace {
+            if let (Grace::Acciaccatura, Some(attachment), false) =
+                (grace, attachment_for_beam, has_beam)
+            {
+                stencil = stencil.and(Stencil::grace_note_slash(Point::new(
+                    attachment.x,
+                    attachment.y - stem_direction.sign() * 500.0,
+                )));
+            }
+
+            // Grace notes are shrunk around their notehead.
+            let origin = Vec2::new(0.0, pitch_y);
+            stencil = stencil
+                .with_translation(-origin)
+                .with_scale(GRACE_SCALE)
+                .with_translation(origin);
+            attachment_for_beam = attachment_for_beam
+                .map(|a| Point::new(a.x * GRACE_SCALE, pitch_y + (a.y - pitch_y) * GRACE_SCALE));
+        }
+
         (stencil, attachment_for_beam)
     }
 }
@@ -286,6 +568,13 @@ impl Default for Chord {
             duration: Duration::new(NoteValue::Quarter, 0, None),
             natural_duration: Duration::new(NoteValue::Quarter, 0, None),
             pitch: PitchKind::Rest,
+            beam_override: None,
+            articulations: BTreeSet::new(),
+            grace: None,
+            slash: false,
+            string: None,
+            transposition: Interval::unison(),
+            out_of_range: false,
         }
     }
 }
@@ -306,7 +595,7 @@ mod tests {
                     Duration::new(NoteValue::DoubleWhole, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -315,7 +604,7 @@ mod tests {
                     Duration::new(NoteValue::Whole, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -324,7 +613,7 @@ mod tests {
                     Duration::new(NoteValue::Half, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -333,7 +622,7 @@ mod tests {
                     Duration::new(NoteValue::Quarter, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -342,7 +631,7 @@ mod tests {
                     Duration::new(NoteValue::Eighth, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -351,7 +640,7 @@ mod tests {
                     Duration::new(NoteValue::Sixteenth, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -360,7 +649,7 @@ mod tests {
                     Duration::new(NoteValue::ThirtySecond, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -369,7 +658,7 @@ mod tests {
                     Duration::new(NoteValue::SixtyFourth, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -378,7 +667,7 @@ mod tests {
                     Duration::new(NoteValue::HundredTwentyEighth, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -387,7 +676,7 @@ mod tests {
                     Duration::new(NoteValue::TwoHundredFiftySixth, 0, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -396,7 +685,7 @@ mod tests {
                     Duration::new(NoteValue::DoubleWhole, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -405,7 +694,7 @@ mod tests {
                     Duration::new(NoteValue::Whole, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -414,7 +703,7 @@ mod tests {
                     Duration::new(NoteValue::Half, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -423,7 +712,7 @@ mod tests {
                     Duration::new(NoteValue::Quarter, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -432,7 +721,7 @@ mod tests {
                     Duration::new(NoteValue::Eighth, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -441,7 +730,7 @@ mod tests {
                     Duration::new(NoteValue::Sixteenth, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -450,7 +739,7 @@ mod tests {
                     Duration::new(NoteValue::ThirtySecond, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -459,7 +748,7 @@ mod tests {
                     Duration::new(NoteValue::SixtyFourth, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -468,7 +757,7 @@ mod tests {
                     Duration::new(NoteValue::HundredTwentyEighth, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -477,7 +766,7 @@ mod tests {
                     Duration::new(NoteValue::TwoHundredFiftySixth, 1, None),
                     PitchKind::Unpitched,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -486,31 +775,31 @@ mod tests {
                     Duration::new(NoteValue::DoubleWhole, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Whole, 0, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Half, 0, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Quarter, 0, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Eighth, 0, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -519,7 +808,7 @@ mod tests {
                     Duration::new(NoteValue::Sixteenth, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -528,7 +817,7 @@ mod tests {
                     Duration::new(NoteValue::ThirtySecond, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -537,7 +826,7 @@ mod tests {
                     Duration::new(NoteValue::SixtyFourth, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -546,7 +835,7 @@ mod tests {
                     Duration::new(NoteValue::HundredTwentyEighth, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -555,7 +844,7 @@ mod tests {
                     Duration::new(NoteValue::TwoHundredFiftySixth, 0, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -564,31 +853,31 @@ mod tests {
                     Duration::new(NoteValue::DoubleWhole, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Whole, 2, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Half, 2, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Quarter, 2, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
             .and_right(
                 Chord::new(Duration::new(NoteValue::Eighth, 2, None), PitchKind::Rest)
-                    .print(&context, false)
+                    .print(&context, StemDirection::Up, false)
                     .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -597,7 +886,7 @@ mod tests {
                     Duration::new(NoteValue::Sixteenth, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -606,7 +895,7 @@ mod tests {
                     Duration::new(NoteValue::ThirtySecond, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -615,7 +904,7 @@ mod tests {
                     Duration::new(NoteValue::SixtyFourth, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -624,7 +913,7 @@ mod tests {
                     Duration::new(NoteValue::HundredTwentyEighth, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             )
             .and_right(Stencil::padding(200.0))
@@ -633,7 +922,7 @@ mod tests {
                     Duration::new(NoteValue::TwoHundredFiftySixth, 2, None),
                     PitchKind::Rest,
                 )
-                .print(&context, false)
+                .print(&context, StemDirection::Up, false)
                 .0,
             );
 
@@ -1,7 +1,7 @@
 use num_rational::Rational;
-use pitch::{Clef, NoteModifier, NoteName};
+use pitch::{transpose_key, Clef, Interval, NoteModifier, NoteName, Pitch};
 use specs::{Component, VecStorage};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct Context {
@@ -9,15 +9,41 @@ pub struct Context {
     pub beat: Rational,
     pub natural_beat: Rational,
     pub clef: Clef,
+
+    /// The key of the music, at concert pitch.
     pub key: i8,
+
+    /// How far above concert pitch the music on this staff is written.
+    pub transposition: Interval,
+
     pub time: (u8, u8),
+
+    /// The accidentals in effect, as written.
     pub accidentals: HashMap<(NoteName, i8), NoteModifier>,
+
+    /// Notes which show their accidental even if it is already in effect, as a reminder.
+    pub cautionary: HashSet<(NoteName, i8)>,
 }
 
 impl Component for Context {
     type Storage = VecStorage<Self>;
 }
 
+impl Context {
+    /// True if `pitch` is written with an accidental, because it differs from the accidental in
+    /// effect, or as a reminder.
+    pub fn needs_accidental(&self, pitch: Pitch) -> bool {
+        let pitch_base = (pitch.name(), pitch.octave());
+        self.accidentals.get(&pitch_base).cloned() != pitch.modifier()
+            || self.cautionary.contains(&pitch_base)
+    }
+
+    /// The key signature written for `key`, a key at concert pitch.
+    pub fn written_key(&self, key: i8) -> i8 {
+        transpose_key(key, self.transposition).0
+    }
+}
+
 impl Default for Context {
     fn default() -> Context {
         Context {
@@ -26,8 +52,10 @@ impl Default for Context {
             natural_beat: Rational::new(0, 1),
             clef: Clef::G,
             key: 0,
+            transposition: Interval::unison(),
             time: (4, 4),
             accidentals: HashMap::new(),
+            cautionary: HashSet::new(),
         }
     }
 }
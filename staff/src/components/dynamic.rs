@@ -0,0 +1,71 @@
+use num_rational::Rational;
+use specs::{Component, VecStorage};
+use stencil::components::Stencil;
+use wasm_bindgen::prelude::*;
+
+/// How loudly to play, from a dynamic mark.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Dynamic {
+    Pianissimo,
+    Piano,
+    MezzoPiano,
+    MezzoForte,
+    Forte,
+    Fortissimo,
+    /// A sudden accent on one note, after which the previous dynamic resumes.
+    Sforzando,
+    /// Loud, then immediately soft.
+    FortePiano,
+}
+
+impl Dynamic {
+    /// The MIDI velocity of the note the mark is written on.
+    pub fn velocity(self) -> u8 {
+        match self {
+            Dynamic::Pianissimo => 33,
+            Dynamic::Piano => 49,
+            Dynamic::MezzoPiano => 64,
+            Dynamic::MezzoForte => 80,
+            Dynamic::Forte | Dynamic::FortePiano => 96,
+            Dynamic::Fortissimo => 112,
+            Dynamic::Sforzando => 127,
+        }
+    }
+
+    /// The MIDI velocity of the notes after the one the mark is written on, given the velocity
+    /// before the mark.
+    pub fn sustained_velocity(self, prev: u8) -> u8 {
+        match self {
+            Dynamic::Sforzando => prev,
+            Dynamic::FortePiano => Dynamic::Piano.velocity(),
+            _ => self.velocity(),
+        }
+    }
+
+    pub fn print(self) -> Stencil {
+        match self {
+            Dynamic::Pianissimo => Stencil::dynamic_piano().and_right(Stencil::dynamic_piano()),
+            Dynamic::Piano => Stencil::dynamic_piano(),
+            Dynamic::MezzoPiano => Stencil::dynamic_mezzo().and_right(Stencil::dynamic_piano()),
+            Dynamic::MezzoForte => Stencil::dynamic_mezzo().and_right(Stencil::dynamic_forte()),
+            Dynamic::Forte => Stencil::dynamic_forte(),
+            Dynamic::Fortissimo => Stencil::dynamic_forte().and_right(Stencil::dynamic_forte()),
+            Dynamic::Sforzando => Stencil::dynamic_sforzando()
+                .and_right(Stencil::dynamic_forte())
+                .and_right(Stencil::dynamic_z()),
+            Dynamic::FortePiano => Stencil::dynamic_forte().and_right(Stencil::dynamic_piano()),
+        }
+    }
+}
+
+/// A dynamic mark at a time in its parent bar.
+#[derive(Debug, Clone)]
+pub struct DynamicMark {
+    pub dynamic: Dynamic,
+    pub time: Rational,
+}
+
+impl Component for DynamicMark {
+    type Storage = VecStorage<Self>;
+}
@@ -0,0 +1,40 @@
+use num_rational::Rational;
+use specs::{Component, Entity, VecStorage};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HairpinKind {
+    Crescendo,
+    Decrescendo,
+}
+
+/// A crescendo or decrescendo from `start` in its parent bar, to `end` in `end_bar`.
+///
+/// The bars may be on different lines.
+#[derive(Debug, Clone)]
+pub struct Hairpin {
+    pub kind: HairpinKind,
+    pub start: Rational,
+    pub end_bar: Entity,
+    pub end: Rational,
+
+    /// The part of the hairpin on each line it spans, managed by `PrintDynamics`.
+    pub segments: Vec<Entity>,
+}
+
+impl Component for Hairpin {
+    type Storage = VecStorage<Self>;
+}
+
+impl Hairpin {
+    pub fn new(kind: HairpinKind, start: Rational, end_bar: Entity, end: Rational) -> Hairpin {
+        Hairpin {
+            kind,
+            start,
+            end_bar,
+            end,
+            segments: vec![],
+        }
+    }
+}
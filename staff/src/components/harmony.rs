@@ -0,0 +1,22 @@
+use crate::ChordSymbol;
+use num_rational::Rational;
+use specs::{Component, VecStorage};
+
+/// A chord symbol above the staff, at `beat` of the bar that is its parent.
+#[derive(Debug, Clone)]
+pub struct Harmony {
+    pub symbol: ChordSymbol,
+
+    /// When the chord starts, in whole notes from the start of the bar.
+    pub beat: Rational,
+}
+
+impl Component for Harmony {
+    type Storage = VecStorage<Self>;
+}
+
+impl Harmony {
+    pub fn new(symbol: ChordSymbol, beat: Rational) -> Harmony {
+        Harmony { symbol, beat }
+    }
+}
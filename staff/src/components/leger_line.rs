@@ -0,0 +1,34 @@
+use specs::{Component, Entity, VecStorage};
+use stencil::components::Stencil;
+
+/// A leger line above or below the staff, for the chord that is its parent.
+///
+/// Notes in a chord share leger lines, so a leger line spans every notehead that needs it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegerLine {
+    pub y: f64,
+
+    /// The left of the leftmost notehead on or beyond this line.
+    pub x0: f64,
+
+    /// The right of the rightmost notehead on or beyond this line.
+    pub x1: f64,
+}
+
+impl Component for LegerLine {
+    type Storage = VecStorage<Self>;
+}
+
+impl LegerLine {
+    pub fn print(&self) -> Stencil {
+        Stencil::leger_line(self.x0, self.x1, self.y)
+    }
+}
+
+/// The leger lines of a chord, which are managed by `PrintLegerLines`.
+#[derive(Debug, Default)]
+pub struct LegerLinesForChord(pub Vec<Entity>);
+
+impl Component for LegerLinesForChord {
+    type Storage = VecStorage<Self>;
+}
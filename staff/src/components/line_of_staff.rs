@@ -1,9 +1,14 @@
+use crate::Skyline;
 use specs::{Component, Entity, VecStorage};
 
 #[derive(Debug)]
 pub struct LineOfStaff {
     pub width: f64,
     pub staff_lines: Entity,
+
+    /// The outline of everything on this line, relative to the middle staff line. Set by
+    /// `UpdateSkyline`, and extended by systems which place things above or below the staff.
+    pub skyline: Skyline,
 }
 
 impl Component for LineOfStaff {
@@ -15,6 +20,7 @@ impl LineOfStaff {
         LineOfStaff {
             width: 0.0,
             staff_lines,
+            skyline: Skyline::default(),
         }
     }
 }
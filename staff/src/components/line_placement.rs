@@ -0,0 +1,15 @@
+use specs::{Component, Entity, VecStorage};
+
+/// Where `PrintStaff` placed a bar or multi-measure rest.
+#[derive(Debug)]
+pub struct LinePlacement {
+    /// The LineOfStaff the bar or rest is on.
+    pub line: Entity,
+
+    /// The start of the bar or rest, relative to the start of the line.
+    pub x: f64,
+}
+
+impl Component for LinePlacement {
+    type Storage = VecStorage<Self>;
+}
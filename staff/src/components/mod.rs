@@ -1,26 +1,51 @@
+mod accidental;
 mod beam;
 mod beam_for_chord;
 mod children;
 mod chord;
 mod context;
 mod cursor;
+mod dynamic;
 mod flag_attachment;
+mod hairpin;
+mod harmony;
+mod leger_line;
 mod line_of_staff;
+mod line_placement;
+mod multirest;
+mod rehearsal_mark;
 pub(crate) mod signature;
+mod simile;
+mod slur;
 mod song;
 mod space_time_warp;
 mod staff;
+mod stem_direction;
+mod tab_staff;
 
 pub use self::staff::Staff;
 pub use children::Children;
 pub use cursor::Cursor;
 pub use line_of_staff::LineOfStaff;
+pub use line_placement::LinePlacement;
+pub use multirest::Multirest;
+pub use rehearsal_mark::{RehearsalMark, RehearsalMarkStyle};
 pub use signature::Signature;
-pub use song::Song;
+pub use simile::Simile;
+pub use slur::Slur;
+pub use song::{BarNumbering, BeamingStyle, ChordDiagrams, Song};
+pub use tab_staff::{Linked, TabNote, TabStaff};
 
+pub use accidental::{Accidental, AccidentalsForChord};
 pub use beam::Beam;
 pub use beam_for_chord::BeamForChord;
-pub use chord::Chord;
+pub(crate) use chord::GRACE_SCALE;
+pub use chord::{Articulation, BeamOverride, Chord, Grace};
 pub use context::Context;
+pub use dynamic::{Dynamic, DynamicMark};
 pub use flag_attachment::FlagAttachment;
+pub use hairpin::{Hairpin, HairpinKind};
+pub use harmony::Harmony;
+pub use leger_line::{LegerLine, LegerLinesForChord};
 pub use space_time_warp::SpaceTimeWarp;
+pub use stem_direction::StemDirection;
@@ -0,0 +1,14 @@
+use specs::{Component, Entity, VecStorage};
+
+/// Consecutive empty bars, drawn as a single multi-measure rest.
+///
+/// The bars, and the barlines between them, are replaced by this on the line.
+#[derive(Debug)]
+pub struct Multirest {
+    /// The consolidated bars, in order.
+    pub bars: Vec<Entity>,
+}
+
+impl Component for Multirest {
+    type Storage = VecStorage<Self>;
+}
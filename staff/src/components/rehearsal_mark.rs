@@ -0,0 +1,72 @@
+use crate::text::print_text;
+use specs::{Component, VecStorage};
+use stencil::components::Stencil;
+use wasm_bindgen::prelude::*;
+
+/// The height of the text of rehearsal marks and section labels.
+const FONT_SIZE: f64 = 600.0;
+
+/// How automatic rehearsal marks are labelled.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RehearsalMarkStyle {
+    /// A, B, C, ..., skipping I, which looks like 1.
+    Letters,
+
+    /// The number of the bar the mark is on.
+    BarNumbers,
+}
+
+/// A rehearsal mark or section label at the start of the bar that is its parent.
+#[derive(Debug, Clone)]
+pub struct RehearsalMark {
+    /// The text to show, such as "Chorus". If None, the mark is labelled automatically.
+    pub label: Option<String>,
+
+    /// Rehearsal marks are boxed. Section labels are not.
+    pub boxed: bool,
+
+    /// The text that is shown, including automatic labels. Set by `PrintRehearsalMarks`.
+    pub text: String,
+}
+
+impl Component for RehearsalMark {
+    type Storage = VecStorage<Self>;
+}
+
+impl RehearsalMark {
+    pub fn new(label: Option<String>, boxed: bool) -> RehearsalMark {
+        RehearsalMark {
+            label,
+            boxed,
+            text: String::new(),
+        }
+    }
+
+    /// The automatic label of the `n`th (0-indexed) lettered mark: A to Z, then AA, BB, and so on.
+    pub fn letter(n: usize) -> String {
+        const LETTERS: &str = "ABCDEFGHJKLMNOPQRSTUVWXYZ";
+        let letter = LETTERS.chars().nth(n % LETTERS.len()).unwrap();
+        letter.to_string().repeat(n / LETTERS.len() + 1)
+    }
+
+    /// Print the mark with its bottom at y = 0, starting at x = 0.
+    pub fn print(&self) -> Stencil {
+        print_text(&self.text, FONT_SIZE, self.boxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters() {
+        assert_eq!(RehearsalMark::letter(0), "A");
+        assert_eq!(RehearsalMark::letter(7), "H");
+        assert_eq!(RehearsalMark::letter(8), "J");
+        assert_eq!(RehearsalMark::letter(24), "Z");
+        assert_eq!(RehearsalMark::letter(25), "AA");
+        assert_eq!(RehearsalMark::letter(51), "BBB");
+    }
+}
@@ -6,6 +6,9 @@ use std::cmp::Ordering;
 use stencil::components::Stencil;
 use wasm_bindgen::prelude::*;
 
+/// Clef changes in the middle of a line are smaller than clefs at the start of a line.
+const CLEF_CHANGE_SCALE: f64 = 0.75;
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Barline {
@@ -13,7 +16,7 @@ pub enum Barline {
     Final,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Signature {
     pub clef: Option<Clef>,
     pub time: Option<(u8, u8)>,
@@ -47,15 +50,20 @@ impl Signature {
         }
     }
 
-    fn key_signature_pitches(key: i8, clef: Clef) -> Vec<Pitch> {
-        if clef == Clef::Percussion {
-            return vec![];
-        }
+    /// True if this is just a barline, so that the bars around it can be consolidated into a
+    /// multi-measure rest.
+    pub fn is_plain_barline(&self) -> bool {
+        self.clef.is_none()
+            && self.time.is_none()
+            && self.key.is_none()
+            && matches!(self.barline, None | Some(Barline::Normal))
+    }
 
+    fn key_signature_pitches(key: i8, clef: Clef) -> Vec<Pitch> {
         let octave_offset = match clef {
             Clef::G => 0,
             Clef::F => -2,
-            Clef::Percussion => panic!("Unexpected percussion clef"),
+            Clef::Percussion | Clef::Tab => return vec![],
         };
 
         let sharps = [
@@ -85,36 +93,102 @@ impl Signature {
         }
     }
 
+    /// The accidentals of `prev_key` which are not in `key`, and need to be cancelled by naturals.
+    ///
+    /// Accidentals are cancelled when moving to a key with fewer sharps or flats, or between sharps
+    /// and flats.
+    fn cancelled_pitches(prev_key: i8, key: i8, clef: Clef) -> Vec<Pitch> {
+        let prev_pitches = Self::key_signature_pitches(prev_key, clef);
+        if key == 0 || key.signum() != prev_key.signum() {
+            prev_pitches
+        } else {
+            prev_pitches
+                .into_iter()
+                .skip(key.unsigned_abs() as usize)
+                .collect()
+        }
+    }
+
+    /// Add the key signature `key` to the right of `stencil`, after naturals cancelling `prev_key`.
+    fn and_key(mut stencil: Stencil, prev_key: i8, key: i8, clef: Clef) -> Stencil {
+        if matches!(clef, Clef::Percussion | Clef::Tab) {
+            return stencil;
+        }
+
+        let cancelled = Self::cancelled_pitches(prev_key, key, clef);
+        if key == 0 && cancelled.is_empty() {
+            return stencil;
+        }
+
+        stencil = stencil.and_right(Stencil::padding(100.0));
+        for pitch in &cancelled {
+            stencil = stencil
+                .and_right(Stencil::natural().with_translation(Vec2::new(0.0, pitch.y(clef))));
+        }
+        if !cancelled.is_empty() && key != 0 {
+            stencil = stencil.and_right(Stencil::padding(100.0));
+        }
+
+        for pitch in Self::key_signature_pitches(key, clef) {
+            stencil = stencil.and_right(
+                if key < 0 {
+                    Stencil::flat()
+                } else {
+                    Stencil::sharp()
+                }
+                .with_translation(Vec2::new(0.0, pitch.y(clef))),
+            );
+        }
+
+        stencil.and_right(Stencil::padding(100.0))
+    }
+
+    /// Render `clef` on the correct line of the staff, at the smaller size for clef changes if
+    /// `change` is true.
+    fn render_clef(clef: Clef, change: bool) -> Stencil {
+        let glyph = match clef {
+            Clef::G => Stencil::clef_g(),
+            Clef::F => Stencil::clef_f(),
+            Clef::Percussion => Stencil::clef_unpitched(),
+            Clef::Tab => Stencil::clef_tab(),
+        };
+        let glyph = if change {
+            glyph.with_scale(CLEF_CHANGE_SCALE)
+        } else {
+            glyph
+        };
+
+        match clef {
+            Clef::G => glyph.with_translation(Vec2::new(0f64, 250f64)),
+            Clef::F => glyph.with_translation(Vec2::new(0f64, -250f64)),
+            Clef::Percussion | Clef::Tab => glyph,
+        }
+    }
+
+    /// Add a smaller clef to the right of `stencil` if the clef changes here. By convention, it
+    /// goes before the barline.
+    fn and_clef_change(&self, stencil: Stencil, context: &Context) -> Stencil {
+        match self.clef.filter(|&clef| clef != context.clef) {
+            Some(clef) => stencil
+                .and_right(Stencil::padding(100.0))
+                .and_right(Self::render_clef(clef, true))
+                .and_right(Stencil::padding(100.0)),
+            None => stencil,
+        }
+    }
+
     pub fn render_start(&self, context: &Context) -> Stencil {
         let mut stencil = Stencil::default();
 
         let clef = self.clef.unwrap_or(context.clef);
-        let key = self.key.unwrap_or(context.key);
+        let key = context.written_key(self.key.unwrap_or(context.key));
 
         stencil = stencil
             .and_right(Stencil::padding(100.0))
-            .and_right(match clef {
-                Clef::G => Stencil::clef_g().with_translation(Vec2::new(0f64, 250f64)),
-                Clef::F => Stencil::clef_f().with_translation(Vec2::new(0f64, -250f64)),
-                Clef::Percussion => Stencil::clef_unpitched(),
-            })
+            .and_right(Self::render_clef(clef, false))
             .and_right(Stencil::padding(100.0));
 
-        if key != 0 && clef != Clef::Percussion {
-            stencil = stencil.and_right(Stencil::padding(100.0));
-            for pitch in Signature::key_signature_pitches(key, clef) {
-                stencil = stencil.and_right(
-                    if key < 0 {
-                        Stencil::flat()
-                    } else {
-                        Stencil::sharp()
-                    }
-                    .with_translation(Vec2::new(0.0, pitch.y(clef))),
-                );
-            }
-
-            stencil = stencil.and_right(Stencil::padding(100.0));
-        }
+        stencil = Self::and_key(stencil, key, key, clef);
 
         if let Some((num, den)) = self.time {
             stencil = stencil.and_right(Stencil::time_sig_fraction(num, den));
@@ -124,7 +198,7 @@ impl Signature {
     }
 
     pub fn render_mid(&self, context: &Context) -> Stencil {
-        let mut stencil = Stencil::default();
+        let mut stencil = self.and_clef_change(Stencil::default(), context);
 
         match self.barline {
             Some(Barline::Normal) => {
@@ -143,33 +217,10 @@ impl Signature {
             None => {}
         }
 
-        if let Some(clef) = self.clef {
-            stencil = stencil
-                .and_right(Stencil::padding(100.0))
-                .and_right(match clef {
-                    Clef::G => Stencil::clef_g().with_translation(Vec2::new(0f64, 250f64)),
-                    Clef::F => Stencil::clef_f().with_translation(Vec2::new(0f64, -250f64)),
-                    Clef::Percussion => Stencil::clef_unpitched(),
-                })
-                .and_right(Stencil::padding(100.0));
-        }
-
-        if let (Some(key), clef) = (self.key, self.clef.unwrap_or(context.clef)) {
-            if key != 0 && clef != Clef::Percussion {
-                stencil = stencil.and_right(Stencil::padding(100.0));
-                for pitch in Self::key_signature_pitches(key, clef) {
-                    stencil = stencil.and_right(
-                        if key < 0 {
-                            Stencil::flat()
-                        } else {
-                            Stencil::sharp()
-                        }
-                        .with_translation(Vec2::new(0.0, pitch.y(clef))),
-                    );
-                }
-
-                stencil = stencil.and_right(Stencil::padding(100.0));
-            }
+        let clef = self.clef.unwrap_or(context.clef);
+        if let Some(key) = self.key {
+            let (prev_key, key) = (context.written_key(context.key), context.written_key(key));
+            stencil = Self::and_key(stencil, prev_key, key, clef);
         }
 
         if let Some((num, den)) = self.time {
@@ -179,8 +230,14 @@ impl Signature {
         stencil
     }
 
-    pub fn render_end(&self, _context: &Context) -> Stencil {
-        let mut stencil = Stencil::default();
+    /// Render the end of a line, including courtesy clefs, key signatures, and time signatures if
+    /// they change.
+    pub fn render_end(&self, context: &Context) -> Stencil {
+        let mut stencil = if self.barline == Some(Barline::Final) {
+            Stencil::default()
+        } else {
+            self.and_clef_change(Stencil::default(), context)
+        };
 
         match self.barline {
             Some(Barline::Normal) => {
@@ -198,7 +255,22 @@ impl Signature {
             None => {}
         }
 
-        // TODO: warnings!
+        // Nothing follows the end of the song.
+        if self.barline == Some(Barline::Final) {
+            return stencil;
+        }
+
+        let clef = self.clef.unwrap_or(context.clef);
+        if let Some(key) = self.key.filter(|&key| key != context.key) {
+            let (prev_key, key) = (context.written_key(context.key), context.written_key(key));
+            stencil = Self::and_key(stencil, prev_key, key, clef);
+        }
+
+        if let Some((num, den)) = self.time.filter(|&time| time != context.time) {
+            stencil = stencil
+                .and_right(Stencil::padding(100.0))
+                .and_right(Stencil::time_sig_fraction(num, den));
+        }
 
         stencil
     }
@@ -225,8 +297,10 @@ mod tests {
             natural_beat: Rational::new(0, 1),
             clef: Clef::G,
             key: 0,
+            transposition: Default::default(),
             time: (4, 4),
             accidentals: Default::default(),
+            cautionary: Default::default(),
         };
 
         snapshot(
@@ -293,4 +367,55 @@ mod tests {
             .to_svg_doc_for_testing(),
         );
     }
+
+    #[test]
+    fn clef_changes() {
+        use specs::world::EntitiesRes;
+
+        let ents = EntitiesRes::default();
+        let signature = |clef| Signature {
+            clef,
+            time: None,
+            key: None,
+            barline: Some(Barline::Normal),
+            stencil_start: ents.create(),
+            stencil_middle: ents.create(),
+            stencil_end: ents.create(),
+        };
+        let context = Context::default();
+
+        let barline = signature(None).render_mid(&context).advance();
+        // Restating the clef does not draw it.
+        assert_eq!(
+            signature(Some(Clef::G)).render_mid(&context).advance(),
+            barline
+        );
+
+        // A change is drawn smaller than the clef at the start of a line.
+        let change = signature(Some(Clef::F)).render_mid(&context).advance() - barline;
+        assert!(change > 0.0);
+        assert!(change < signature(Some(Clef::F)).render_start(&context).advance());
+
+        // A change at the end of a line is a courtesy clef.
+        assert!(
+            signature(Some(Clef::F)).render_end(&context).advance()
+                > signature(None).render_end(&context).advance()
+        );
+    }
+
+    #[test]
+    fn cancelled() {
+        let names = |prev_key, key| -> Vec<NoteName> {
+            Signature::cancelled_pitches(prev_key, key, Clef::G)
+                .iter()
+                .map(|pitch| pitch.name())
+                .collect()
+        };
+
+        assert_eq!(names(3, 1), vec![NoteName::C, NoteName::G]);
+        assert_eq!(names(-2, 0), vec![NoteName::B, NoteName::E]);
+        assert_eq!(names(2, -1), vec![NoteName::F, NoteName::C]);
+        assert_eq!(names(1, 3), vec![]);
+        assert_eq!(names(0, -2), vec![]);
+    }
 }
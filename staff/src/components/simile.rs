@@ -0,0 +1,56 @@
+use rhythm::components::Bar;
+use specs::{Component, Entity, ReadStorage, VecStorage};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// A repeat sign (simile) which replaces the contents of a bar, or of two bars.
+///
+/// This is attached to a bar. A two-bar repeat is attached to the first of the two bars it
+/// replaces.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Simile {
+    /// Repeat the bar before this one.
+    OneBar,
+
+    /// Repeat the two bars before this one and the next.
+    TwoBars,
+}
+
+impl Component for Simile {
+    type Storage = VecStorage<Self>;
+}
+
+impl Simile {
+    /// How many bars before it a bar replaced by this repeats.
+    pub fn distance(self) -> usize {
+        match self {
+            Simile::OneBar => 1,
+            Simile::TwoBars => 2,
+        }
+    }
+
+    /// The bars in `children` of a staff which are replaced by a repeat sign, and the simile which
+    /// replaces them.
+    ///
+    /// Both bars of a two-bar repeat are included.
+    pub fn replaced_bars(
+        children: &[Entity],
+        bars: &ReadStorage<Bar>,
+        similes: &ReadStorage<Simile>,
+    ) -> HashMap<Entity, Simile> {
+        let mut replaced = HashMap::new();
+        let mut second_bar = false;
+        for &child in children.iter().filter(|&&child| bars.contains(child)) {
+            if let Some(&simile) = similes.get(child) {
+                replaced.insert(child, simile);
+                second_bar = simile == Simile::TwoBars;
+            } else if second_bar {
+                replaced.insert(child, Simile::TwoBars);
+                second_bar = false;
+            }
+        }
+
+        replaced
+    }
+}
@@ -0,0 +1,27 @@
+use specs::{Component, Entity, VecStorage};
+
+/// A slur or phrase mark from one chord to another.
+///
+/// The chords may be in different bars, or on different lines.
+#[derive(Debug, Clone)]
+pub struct Slur {
+    pub start: Entity,
+    pub end: Entity,
+
+    /// The part of the slur on each line it spans, managed by `PrintSlur`.
+    pub segments: Vec<Entity>,
+}
+
+impl Component for Slur {
+    type Storage = VecStorage<Self>;
+}
+
+impl Slur {
+    pub fn new(start: Entity, end: Entity) -> Slur {
+        Slur {
+            start,
+            end,
+            segments: vec![],
+        }
+    }
+}
@@ -1,6 +1,53 @@
+use crate::{
+    components::{RehearsalMarkStyle, TabStaff},
+    DrumKit, Voicing,
+};
 use specs::{Component, Entity, VecStorage};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
 
-#[derive(Debug)]
+/// How notes are grouped into beams when the user has not overridden it.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BeamingStyle {
+    /// Beam by metre division, e.g., eighths in 4/4 are beamed in fours.
+    Division,
+
+    /// Beam by beat, e.g., eighths in 4/4 are beamed in pairs.
+    Beat,
+}
+
+/// Which bars have their number printed above them.
+///
+/// The first bar of the song, and pickup bars, are never numbered.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BarNumbering {
+    Never,
+
+    /// Number the first bar of each line.
+    System,
+
+    /// Number every bar that is a multiple of `Song::bar_number_interval`.
+    Interval,
+
+    EveryBar,
+}
+
+/// Where fretboard diagrams of chord symbols are shown.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ChordDiagrams {
+    Never,
+
+    /// Above the staff, at the first use of each chord.
+    AboveStaff,
+
+    /// In a legend at the top of the page, in the order the chords are first used.
+    Legend,
+}
+
+#[derive(Debug, Clone)]
 pub struct Song {
     pub freeze_spacing: Option<isize>,
     pub prev_freeze_spacing: Option<isize>,
@@ -23,6 +70,47 @@ pub struct Song {
     ///  - 6-7 are used for choral music, cue saves, or ossia.
     ///  - 8 is used for full scores.
     pub rastal_size: u8,
+    pub prev_rastal_size: u8,
+
+    pub beaming_style: BeamingStyle,
+
+    /// If true, consecutive empty bars are drawn as a single multi-measure rest, as in parts.
+    pub multirests: bool,
+
+    /// How rehearsal marks without an explicit label are labelled.
+    pub rehearsal_mark_style: RehearsalMarkStyle,
+
+    pub bar_numbering: BarNumbering,
+
+    /// How many bars apart bar numbers are, for `BarNumbering::Interval`.
+    pub bar_number_interval: usize,
+
+    pub bar_numbers_boxed: bool,
+
+    /// The number of the first full bar. A pickup bar before it is one less.
+    pub first_bar_number: isize,
+
+    /// If true, accidentals are repeated as a reminder in the bar after an altered note, and in
+    /// other octaves of an altered note in the same bar.
+    pub cautionary_accidentals: bool,
+
+    /// If true, notes on staffs for transposing instruments are written at the pitch they sound,
+    /// instead of the pitch the player reads.
+    pub concert_pitch: bool,
+
+    /// Which sounds percussion notes are played with.
+    pub drum_kit: DrumKit,
+
+    pub chord_diagrams: ChordDiagrams,
+
+    /// The tuning of the instrument that chord diagrams are for, like `TabStaff::tuning`.
+    pub chord_diagram_tuning: Vec<u8>,
+
+    /// Voicings to show for chord symbols, by symbol, instead of generated ones.
+    pub chord_voicings: HashMap<String, Voicing>,
+
+    /// The legend of chord diagrams, for `ChordDiagrams::Legend`. Set by `PrintChordSymbols`.
+    pub chord_legend_stencil: Option<Entity>,
 
     pub title: String,
     pub title_width: f64,
@@ -31,6 +119,16 @@ pub struct Song {
     pub author: String,
     pub author_width: f64,
     pub author_stencil: Option<Entity>,
+
+    /// If this song is a part, the score it was extracted from. A score and its parts are kept
+    /// alive together, so any of them can be the root.
+    pub score: Option<Entity>,
+
+    /// The parts extracted from this song.
+    pub parts: Vec<Entity>,
+
+    /// The name of the instrument at the top left of a part. Set by `PrintMeta`.
+    pub part_name_stencil: Option<Entity>,
 }
 
 impl Component for Song {
@@ -46,17 +144,66 @@ impl Default for Song {
             width: 0f64,
             height: 0f64,
             rastal_size: 3,
+            prev_rastal_size: 3,
+            beaming_style: BeamingStyle::Division,
+            multirests: false,
+            rehearsal_mark_style: RehearsalMarkStyle::Letters,
+            bar_numbering: BarNumbering::Never,
+            bar_number_interval: 5,
+            bar_numbers_boxed: false,
+            first_bar_number: 1,
+            cautionary_accidentals: false,
+            concert_pitch: false,
+            drum_kit: DrumKit::Acoustic,
+            chord_diagrams: ChordDiagrams::Never,
+            chord_diagram_tuning: TabStaff::guitar().tuning,
+            chord_voicings: HashMap::new(),
+            chord_legend_stencil: None,
             title: String::default(),
             title_width: 0f64,
             title_stencil: None,
             author: String::default(),
             author_width: 0f64,
             author_stencil: None,
+            score: None,
+            parts: vec![],
+            part_name_stencil: None,
         }
     }
 }
 
 impl Song {
+    /// A part of this song, which is `score`, with the same page and notation settings.
+    ///
+    /// Parts are written at the pitch the player reads, and consolidate empty bars into
+    /// multi-measure rests. These can be changed separately from the score.
+    pub fn part(&self, score: Entity) -> Song {
+        Song {
+            width: self.width,
+            height: self.height,
+            rastal_size: self.rastal_size,
+            prev_rastal_size: self.rastal_size,
+            beaming_style: self.beaming_style,
+            multirests: true,
+            rehearsal_mark_style: self.rehearsal_mark_style,
+            bar_numbering: self.bar_numbering,
+            bar_number_interval: self.bar_number_interval,
+            bar_numbers_boxed: self.bar_numbers_boxed,
+            first_bar_number: self.first_bar_number,
+            cautionary_accidentals: self.cautionary_accidentals,
+            drum_kit: self.drum_kit,
+            chord_diagrams: self.chord_diagrams,
+            chord_diagram_tuning: self.chord_diagram_tuning.clone(),
+            chord_voicings: self.chord_voicings.clone(),
+            title: self.title.clone(),
+            title_width: self.title_width,
+            author: self.author.clone(),
+            author_width: self.author_width,
+            score: Some(score),
+            ..Song::default()
+        }
+    }
+
     pub fn scale(&self) -> f64 {
         match self.rastal_size {
             0 => 9.2 / 1000.0,
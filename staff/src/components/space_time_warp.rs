@@ -27,4 +27,3 @@ impl SpaceTimeWarp {
         0.0
     }
 }
-
@@ -1,9 +1,29 @@
+use crate::{components::StemDirection, Instrument};
 use specs::{Component, Entity, VecStorage};
+use std::collections::HashMap;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Staff {
     /// This is a line of a staff, not the 5 staff lines.
     pub lines: Vec<Entity>,
+
+    /// Multi-measure rests on this staff, if the song consolidates empty bars.
+    pub multirests: Vec<Entity>,
+
+    /// Printed bar numbers on this staff, if the song numbers bars.
+    pub bar_numbers: Vec<Entity>,
+
+    /// If set, all stems on this staff point in this direction, regardless of pitch.
+    pub stem_direction: Option<StemDirection>,
+
+    pub instrument: Instrument,
+
+    /// If set, this staff shows the bars of the given staff instead of its own children, as a tab
+    /// staff or a part. They are copied by `UpdateLinkedStaffs`.
+    pub link: Option<Entity>,
+
+    /// The entity on this staff which shows each entity of the linked staff.
+    pub mirrors: HashMap<Entity, Entity>,
 }
 
 impl Component for Staff {
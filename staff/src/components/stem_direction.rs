@@ -0,0 +1,27 @@
+use specs::{Component, VecStorage};
+use wasm_bindgen::prelude::*;
+
+/// The direction of a note's stem, set by `UpdateStemDirection`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StemDirection {
+    Up,
+    Down,
+}
+
+impl Component for StemDirection {
+    type Storage = VecStorage<Self>;
+}
+
+impl StemDirection {
+    /// 1 if stems point up (towards negative y), -1 otherwise.
+    ///
+    /// Beams stack towards the notehead, so this is the direction in which secondary beams are
+    /// added.
+    pub fn sign(self) -> f64 {
+        match self {
+            StemDirection::Up => 1.0,
+            StemDirection::Down => -1.0,
+        }
+    }
+}
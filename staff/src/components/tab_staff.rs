@@ -0,0 +1,135 @@
+use specs::{Component, Entity, VecStorage};
+
+/// The highest fret that notes are placed on.
+const MAX_FRET: u8 = 24;
+
+/// A staff of tablature, where notes are written as fret numbers on the line of the string they
+/// are played on.
+///
+/// This is attached to a staff, alongside `Staff`.
+#[derive(Debug, Clone)]
+pub struct TabStaff {
+    /// The pitch of each open string, as a MIDI key, from the highest string, which is drawn at
+    /// the top, to the lowest.
+    pub tuning: Vec<u8>,
+}
+
+impl Component for TabStaff {
+    type Storage = VecStorage<Self>;
+}
+
+/// Where a note on a tab staff is played. Set by `UpdateTablature`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabNote {
+    /// Strings are numbered from 1, the highest.
+    pub string: u8,
+    pub fret: u8,
+}
+
+impl Component for TabNote {
+    type Storage = VecStorage<Self>;
+}
+
+/// An entity which shows the entity of a linked staff, and is kept in sync with it.
+#[derive(Debug, Clone, Copy)]
+pub struct Linked(pub Entity);
+
+impl Component for Linked {
+    type Storage = VecStorage<Self>;
+}
+
+impl TabStaff {
+    pub fn new(tuning: Vec<u8>) -> TabStaff {
+        TabStaff { tuning }
+    }
+
+    /// A six-string guitar in standard tuning, E2 A2 D3 G3 B3 E4.
+    pub fn guitar() -> TabStaff {
+        TabStaff::new(vec![64, 59, 55, 50, 45, 40])
+    }
+
+    /// The y of the line of `string`, relative to the middle of the staff.
+    ///
+    /// The outer strings are on the outer lines of a standard staff, so that barlines and spacing
+    /// are the same as on other staffs.
+    pub fn string_y(&self, string: u8) -> f64 {
+        let gaps = self.tuning.len().saturating_sub(1).max(1) as f64;
+        -500.0 + 1000.0 * f64::from(string.saturating_sub(1)) / gaps
+    }
+
+    /// The pitch of `fret` on `string`, as a MIDI key.
+    pub fn key(&self, string: u8, fret: u8) -> Option<u8> {
+        let open = *self.tuning.get(usize::from(string).checked_sub(1)?)?;
+        open.checked_add(fret)
+    }
+
+    /// Where to play `key`, or None if it is not on any string.
+    ///
+    /// If `string` is set and `key` can be played on it, it is played there. Otherwise, open strings
+    /// are preferred, and then the fret closest to `hand`, the fret of the last note. Without a
+    /// previous note, lower frets are preferred.
+    pub fn place(&self, key: u8, string: Option<u8>, hand: Option<u8>) -> Option<TabNote> {
+        let candidates = self.tuning.iter().enumerate().filter_map(|(i, &open)| {
+            let fret = key.checked_sub(open).filter(|&fret| fret <= MAX_FRET)?;
+            Some(TabNote {
+                string: i as u8 + 1,
+                fret,
+            })
+        });
+
+        if let Some(string) = string {
+            if let Some(note) = candidates.clone().find(|note| note.string == string) {
+                return Some(note);
+            }
+        }
+
+        candidates.min_by_key(|note| {
+            let distance = match (note.fret, hand) {
+                (0, _) => 0,
+                (fret, Some(hand)) => (i16::from(fret) - i16::from(hand)).abs(),
+                (fret, None) => i16::from(fret),
+            };
+            (distance, note.fret)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place() {
+        let guitar = TabStaff::guitar();
+        let note = |string, fret| Some(TabNote { string, fret });
+
+        // Open strings, and then low frets.
+        assert_eq!(guitar.place(40, None, None), note(6, 0));
+        assert_eq!(guitar.place(59, None, None), note(2, 0));
+        assert_eq!(guitar.place(60, None, None), note(2, 1));
+
+        // Notes stay near the hand.
+        assert_eq!(guitar.place(60, None, Some(6)), note(3, 5));
+        assert_eq!(guitar.place(60, None, Some(9)), note(4, 10));
+
+        // Strings can be chosen, if the note is on them.
+        assert_eq!(guitar.place(60, Some(5), None), note(5, 15));
+        assert_eq!(guitar.place(60, Some(1), None), note(2, 1));
+
+        // Notes outside the range of the guitar are not placed.
+        assert_eq!(guitar.place(39, None, None), None);
+        assert_eq!(guitar.place(89, None, None), None);
+    }
+
+    #[test]
+    fn strings() {
+        let guitar = TabStaff::guitar();
+        assert_eq!(guitar.string_y(1), -500.0);
+        assert_eq!(guitar.string_y(6), 500.0);
+        assert_eq!(guitar.key(3, 2), Some(57));
+        assert_eq!(guitar.key(7, 0), None);
+
+        let bass = TabStaff::new(vec![43, 38, 33, 28]);
+        assert_eq!(bass.string_y(2), -500.0 + 1000.0 / 3.0);
+    }
+}
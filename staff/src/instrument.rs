@@ -0,0 +1,80 @@
+use pitch::{Clef, Interval};
+
+/// What plays the music on a staff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instrument {
+    pub name: String,
+
+    /// How far above the sounding, concert pitch music for this instrument is written. For
+    /// example, music for a trumpet in Bb is written a major second higher than it sounds.
+    pub transposition: Interval,
+
+    /// The clef used before a signature sets one.
+    pub clef: Clef,
+
+    /// The lowest and highest notes the instrument can play, as concert midi notes.
+    pub range: (u8, u8),
+}
+
+impl Default for Instrument {
+    fn default() -> Instrument {
+        Instrument {
+            name: String::new(),
+            transposition: Interval::unison(),
+            clef: Clef::G,
+            range: (0, 127),
+        }
+    }
+}
+
+impl Instrument {
+    /// Whether `midi`, a concert pitch, can be played.
+    pub fn in_range(&self, midi: u8) -> bool {
+        self.range.0 <= midi && midi <= self.range.1
+    }
+
+    /// A trumpet in Bb, which sounds a major second below written pitch, from E3 to C6.
+    pub fn trumpet_bb() -> Instrument {
+        Instrument {
+            name: "Trumpet in Bb".to_owned(),
+            transposition: Interval::new(1, 2),
+            clef: Clef::G,
+            range: (52, 84),
+        }
+    }
+
+    /// A horn in F, which sounds a perfect fifth below written pitch, from B1 to F5.
+    pub fn horn_f() -> Instrument {
+        Instrument {
+            name: "Horn in F".to_owned(),
+            transposition: Interval::new(4, 7),
+            clef: Clef::G,
+            range: (35, 77),
+        }
+    }
+
+    /// An alto saxophone in Eb, which sounds a major sixth below written pitch, from Db3 to Ab5.
+    pub fn alto_sax() -> Instrument {
+        Instrument {
+            name: "Alto Saxophone".to_owned(),
+            transposition: Interval::new(5, 9),
+            clef: Clef::G,
+            range: (49, 80),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range() {
+        let trumpet = Instrument::trumpet_bb();
+        assert!(!trumpet.in_range(51));
+        assert!(trumpet.in_range(52));
+        assert!(trumpet.in_range(84));
+        assert!(!trumpet.in_range(85));
+        assert!(Instrument::default().in_range(0));
+    }
+}
@@ -1,11 +1,23 @@
-#![allow(clippy::implicit_hasher, clippy::blacklisted_name)]
+#![allow(clippy::implicit_hasher, clippy::disallowed_names)]
 
 mod beam_attachment;
+mod chord_symbol;
 pub mod components;
+mod instrument;
+mod percussion;
 mod pitch_kind;
 pub mod resources;
+mod skyline;
 pub mod systems;
+mod text;
+mod voicing;
 
 pub use beam_attachment::BeamAttachment;
+pub use chord_symbol::{ChordNote, ChordSymbol};
 pub use components::signature::Barline;
+pub use instrument::Instrument;
+pub use percussion::{DrumKit, PercussionInstrument, PercussionNotehead};
 pub use pitch_kind::PitchKind;
+pub use skyline::Skyline;
+pub use text::print_text;
+pub use voicing::Voicing;
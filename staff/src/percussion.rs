@@ -0,0 +1,144 @@
+use wasm_bindgen::prelude::*;
+
+/// A drum or cymbal in a drum kit.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PercussionInstrument {
+    Kick,
+    Snare,
+    HiHatClosed,
+    HiHatOpen,
+    Ride,
+    Crash,
+    HighTom,
+    MidTom,
+    FloorTom,
+}
+
+/// The shape of the notehead of a percussion note.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PercussionNotehead {
+    /// The same notehead as a pitched note, for drums.
+    Normal,
+
+    /// A cross, for cymbals.
+    X,
+}
+
+impl PercussionInstrument {
+    pub const ALL: [PercussionInstrument; 9] = [
+        PercussionInstrument::Kick,
+        PercussionInstrument::Snare,
+        PercussionInstrument::HiHatClosed,
+        PercussionInstrument::HiHatOpen,
+        PercussionInstrument::Ride,
+        PercussionInstrument::Crash,
+        PercussionInstrument::HighTom,
+        PercussionInstrument::MidTom,
+        PercussionInstrument::FloorTom,
+    ];
+
+    /// Where the instrument is written on a five-line percussion staff, relative to the middle
+    /// line.
+    ///
+    /// Drums are in the staff, from the kick in the bottom space to the high tom in the top space,
+    /// and cymbals are above it.
+    pub fn y(self) -> f64 {
+        match self {
+            PercussionInstrument::Kick => 375.0,
+            PercussionInstrument::FloorTom => 125.0,
+            PercussionInstrument::Snare => -125.0,
+            PercussionInstrument::MidTom => -250.0,
+            PercussionInstrument::HighTom => -375.0,
+            PercussionInstrument::Ride => -500.0,
+            PercussionInstrument::HiHatClosed | PercussionInstrument::HiHatOpen => -625.0,
+            PercussionInstrument::Crash => -750.0,
+        }
+    }
+
+    pub fn notehead(self) -> PercussionNotehead {
+        match self {
+            PercussionInstrument::Kick
+            | PercussionInstrument::Snare
+            | PercussionInstrument::HighTom
+            | PercussionInstrument::MidTom
+            | PercussionInstrument::FloorTom => PercussionNotehead::Normal,
+            PercussionInstrument::HiHatClosed
+            | PercussionInstrument::HiHatOpen
+            | PercussionInstrument::Ride
+            | PercussionInstrument::Crash => PercussionNotehead::X,
+        }
+    }
+
+    /// Whether the note is marked with a small circle above it, as for an open hi-hat.
+    pub fn is_open(self) -> bool {
+        self == PercussionInstrument::HiHatOpen
+    }
+}
+
+/// Which General MIDI sounds a drum chart is played with.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DrumKit {
+    Acoustic,
+    Electric,
+}
+
+impl DrumKit {
+    /// The General MIDI percussion key for an instrument in this kit.
+    pub fn midi(self, instrument: PercussionInstrument) -> u8 {
+        match (self, instrument) {
+            (DrumKit::Acoustic, PercussionInstrument::Kick) => 35,
+            (DrumKit::Electric, PercussionInstrument::Kick) => 36,
+            (DrumKit::Acoustic, PercussionInstrument::Snare) => 38,
+            (DrumKit::Electric, PercussionInstrument::Snare) => 40,
+            (_, PercussionInstrument::HiHatClosed) => 42,
+            (_, PercussionInstrument::HiHatOpen) => 46,
+            (_, PercussionInstrument::Ride) => 51,
+            (_, PercussionInstrument::Crash) => 49,
+            (DrumKit::Acoustic, PercussionInstrument::HighTom) => 50,
+            (DrumKit::Electric, PercussionInstrument::HighTom) => 48,
+            (DrumKit::Acoustic, PercussionInstrument::MidTom) => 47,
+            (DrumKit::Electric, PercussionInstrument::MidTom) => 45,
+            (DrumKit::Acoustic, PercussionInstrument::FloorTom) => 41,
+            (DrumKit::Electric, PercussionInstrument::FloorTom) => 43,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kits() {
+        // Both kits use the standard hi-hat and cymbal keys, but different drums.
+        for &instrument in &[
+            PercussionInstrument::HiHatClosed,
+            PercussionInstrument::HiHatOpen,
+            PercussionInstrument::Ride,
+            PercussionInstrument::Crash,
+        ] {
+            assert_eq!(
+                DrumKit::Acoustic.midi(instrument),
+                DrumKit::Electric.midi(instrument)
+            );
+            assert_eq!(instrument.notehead(), PercussionNotehead::X);
+        }
+        assert_eq!(DrumKit::Acoustic.midi(PercussionInstrument::Snare), 38);
+        assert_eq!(DrumKit::Electric.midi(PercussionInstrument::Kick), 36);
+
+        // Every key is in the General MIDI percussion range.
+        for &kit in &[DrumKit::Acoustic, DrumKit::Electric] {
+            for &instrument in &[
+                PercussionInstrument::Kick,
+                PercussionInstrument::Snare,
+                PercussionInstrument::HighTom,
+                PercussionInstrument::MidTom,
+                PercussionInstrument::FloorTom,
+            ] {
+                assert!((35..=81).contains(&kit.midi(instrument)));
+            }
+        }
+    }
+}
@@ -1,15 +1,34 @@
-use pitch::Pitch;
+use crate::PercussionInstrument;
+use pitch::{Clef, Interval, Pitch};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum PitchKind {
     Rest,
     Unpitched,
     Pitch(Pitch),
+    Percussion(PercussionInstrument),
 }
 
 impl PitchKind {
     pub fn is_rest(self) -> bool {
         self == PitchKind::Rest
     }
-}
 
+    /// This pitch moved by `interval`. Only pitched notes are moved.
+    pub fn transpose(self, interval: Interval) -> PitchKind {
+        match self {
+            PitchKind::Pitch(pitch) => PitchKind::Pitch(pitch.transpose(interval)),
+            PitchKind::Rest | PitchKind::Unpitched | PitchKind::Percussion(_) => self,
+        }
+    }
+
+    /// Where the notehead is written, relative to the middle line, or None for rests.
+    pub fn y(self, clef: Clef) -> Option<f64> {
+        match self {
+            PitchKind::Rest => None,
+            PitchKind::Unpitched => Some(0.0),
+            PitchKind::Pitch(pitch) => Some(pitch.y(clef)),
+            PitchKind::Percussion(instrument) => Some(instrument.y()),
+        }
+    }
+}
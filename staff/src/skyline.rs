@@ -0,0 +1,167 @@
+use kurbo::Rect;
+
+/// A part of the outline of one side of a line, where everything between `x0` and `x1` is above
+/// (or below) `y`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Step {
+    x0: f64,
+    x1: f64,
+    y: f64,
+}
+
+/// The outline of everything above and below a line of a staff, relative to its middle staff line.
+///
+/// Things placed above or below the staff ask the skyline where they can go without colliding
+/// with what is already there, and then add themselves to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skyline {
+    /// The highest point at each x, from left to right.
+    above: Vec<Step>,
+
+    /// The lowest point at each x, from left to right.
+    below: Vec<Step>,
+}
+
+impl Default for Skyline {
+    /// The outline of the staff lines, with nothing on them.
+    fn default() -> Skyline {
+        Skyline {
+            above: vec![Step {
+                x0: f64::NEG_INFINITY,
+                x1: f64::INFINITY,
+                y: -500.0,
+            }],
+            below: vec![Step {
+                x0: f64::NEG_INFINITY,
+                x1: f64::INFINITY,
+                y: 500.0,
+            }],
+        }
+    }
+}
+
+/// Set the outline to `extreme(y, step.y)` between `x0` and `x1`, splitting steps where needed.
+fn raise(steps: &mut Vec<Step>, x0: f64, x1: f64, y: f64, extreme: fn(f64, f64) -> f64) {
+    let mut raised: Vec<Step> = Vec::with_capacity(steps.len() + 2);
+    let mut push = |step: Step| {
+        if step.x1 <= step.x0 {
+            return;
+        }
+        match raised.last_mut() {
+            Some(last) if last.y == step.y => last.x1 = step.x1,
+            _ => raised.push(step),
+        }
+    };
+
+    for &step in steps.iter() {
+        if step.x1 <= x0 || step.x0 >= x1 {
+            push(step);
+            continue;
+        }
+        push(Step { x1: x0, ..step });
+        push(Step {
+            x0: step.x0.max(x0),
+            x1: step.x1.min(x1),
+            y: extreme(step.y, y),
+        });
+        push(Step { x0: x1, ..step });
+    }
+
+    *steps = raised;
+}
+
+/// `extreme` of the outline between `x0` and `x1`.
+fn extreme_in(steps: &[Step], x0: f64, x1: f64, extreme: fn(f64, f64) -> f64) -> Option<f64> {
+    steps
+        .iter()
+        .filter(|step| step.x1 > x0 && step.x0 < x1)
+        .map(|step| step.y)
+        .fold(None, |acc, y| Some(acc.map_or(y, |acc| extreme(acc, y))))
+}
+
+impl Skyline {
+    /// Add something with the given bounds to the outline.
+    pub fn insert(&mut self, rect: Rect) {
+        if rect.width() <= 0.0 {
+            return;
+        }
+        raise(&mut self.above, rect.x0, rect.x1, rect.y0, f64::min);
+        raise(&mut self.below, rect.x0, rect.x1, rect.y1, f64::max);
+    }
+
+    /// The highest point of anything between `x0` and `x1`, which is the lowest y free above it.
+    pub fn top(&self, x0: f64, x1: f64) -> f64 {
+        extreme_in(&self.above, x0, x1, f64::min).unwrap_or(-500.0)
+    }
+
+    /// The lowest point of anything between `x0` and `x1`, which is the highest y free below it.
+    pub fn bottom(&self, x0: f64, x1: f64) -> f64 {
+        extreme_in(&self.below, x0, x1, f64::max).unwrap_or(500.0)
+    }
+
+    /// The highest point of anything on the line.
+    pub fn highest(&self) -> f64 {
+        self.top(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// The lowest point of anything on the line.
+    pub fn lowest(&self) -> f64 {
+        self.bottom(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// How far below `self` the line `next` must be so that they just touch.
+    ///
+    /// This is less than `self.lowest() - next.highest()` when the lowest point of this line is
+    /// not above the highest point of the next.
+    pub fn distance_to(&self, next: &Skyline) -> f64 {
+        let mut distance = f64::NEG_INFINITY;
+        for below in &self.below {
+            for above in &next.above {
+                if below.x1 > above.x0 && below.x0 < above.x1 {
+                    distance = distance.max(below.y - above.y);
+                }
+            }
+        }
+        distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outline() {
+        let mut skyline = Skyline::default();
+        assert_eq!(skyline.top(0.0, 1000.0), -500.0);
+        assert_eq!(skyline.bottom(0.0, 1000.0), 500.0);
+
+        skyline.insert(Rect::new(100.0, -1000.0, 200.0, 0.0));
+        skyline.insert(Rect::new(150.0, -750.0, 400.0, 900.0));
+        assert_eq!(skyline.top(0.0, 100.0), -500.0);
+        assert_eq!(skyline.top(0.0, 101.0), -1000.0);
+        assert_eq!(skyline.top(200.0, 1000.0), -750.0);
+        assert_eq!(skyline.bottom(0.0, 150.0), 500.0);
+        assert_eq!(skyline.bottom(300.0, 1000.0), 900.0);
+        assert_eq!(skyline.highest(), -1000.0);
+        assert_eq!(skyline.lowest(), 900.0);
+
+        // Steps at the same height are merged.
+        assert_eq!(skyline.above.len(), 4);
+        skyline.insert(Rect::new(200.0, -1000.0, 400.0, 0.0));
+        assert_eq!(skyline.above.len(), 3);
+    }
+
+    #[test]
+    fn distance() {
+        let mut upper = Skyline::default();
+        let mut lower = Skyline::default();
+        assert_eq!(upper.distance_to(&lower), 1000.0);
+
+        // Things which do not overlap horizontally do not push lines apart.
+        upper.insert(Rect::new(0.0, 0.0, 100.0, 2000.0));
+        lower.insert(Rect::new(500.0, -2000.0, 600.0, 0.0));
+        assert_eq!(upper.distance_to(&lower), 2500.0);
+        assert_eq!(upper.lowest() - lower.highest(), 4000.0);
+    }
+}
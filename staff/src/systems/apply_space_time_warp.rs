@@ -1,6 +1,6 @@
 use crate::{components::SpaceTimeWarp, resources::KeepSpacing};
 use rhythm::{components::Bar, components::Spacing, BarChild};
-use specs::{Join, ReadStorage, System, WriteStorage, Read};
+use specs::{Join, Read, ReadStorage, System, WriteStorage};
 
 #[derive(Debug, Default)]
 pub struct ApplySpaceTimeWarp;
@@ -18,16 +18,26 @@ impl<'a> System<'a> for ApplySpaceTimeWarp {
             return;
         }
         for (bar, warp) in (&bars, &warps).join() {
+            // Grace notes keep their width, and are packed before the next note.
+            let mut next_x = warp.t_to_x(bar.metre().duration());
             for BarChild {
                 duration,
                 start,
                 stencil,
+                grace,
                 ..
-            } in bar.children()
+            } in bar.children().into_iter().rev()
             {
                 if let Some(rel_spacing) = spacings.get_mut(stencil) {
-                    rel_spacing.start_x = warp.t_to_x(start);
-                    rel_spacing.end_x = warp.t_to_x(start + duration.duration());
+                    if grace {
+                        let width = rel_spacing.end_x - rel_spacing.start_x;
+                        rel_spacing.end_x = next_x;
+                        rel_spacing.start_x = next_x - width;
+                    } else {
+                        rel_spacing.start_x = warp.t_to_x(start);
+                        rel_spacing.end_x = warp.t_to_x(start + duration.duration());
+                    }
+                    next_x = rel_spacing.start_x;
                 }
             }
         }
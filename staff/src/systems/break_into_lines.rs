@@ -1,11 +1,17 @@
 #![allow(clippy::type_complexity)]
 
+use std::collections::{HashMap, HashSet};
+
 use num_rational::Rational;
 
 use crate::{
-    components::{Children, LineOfStaff, Signature, Song, Staff},
+    components::{
+        AccidentalsForChord, Children, LegerLinesForChord, LineOfStaff, Multirest, RehearsalMark,
+        Signature, Song, Staff,
+    },
     resources::{KeepSpacing, Root},
 };
+use kurbo::Rect;
 use rhythm::{components::Bar, components::Spacing, BarChild, Duration};
 use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
 use stencil::components::{Parent, Stencil};
@@ -21,6 +27,10 @@ impl<'a> System<'a> for BreakIntoLines {
         ReadStorage<'a, Song>,
         ReadStorage<'a, Bar>,
         ReadStorage<'a, Signature>,
+        ReadStorage<'a, Multirest>,
+        ReadStorage<'a, RehearsalMark>,
+        ReadStorage<'a, AccidentalsForChord>,
+        ReadStorage<'a, LegerLinesForChord>,
         ReadStorage<'a, Stencil>,
         WriteStorage<'a, Spacing>,
         WriteStorage<'a, Staff>,
@@ -38,6 +48,10 @@ impl<'a> System<'a> for BreakIntoLines {
             songs,
             bars,
             signatures,
+            multirests,
+            rehearsal_marks,
+            accidentals_for_chords,
+            leger_lines_for_chords,
             stencils,
             mut spacings,
             mut staffs,
@@ -52,22 +66,82 @@ impl<'a> System<'a> for BreakIntoLines {
 
         let song = root.0.and_then(|root| songs.get(root));
 
-        // TODO(joshuan): scale is fixed as rastal size 3.
-        let width = song.map(|song| song.width / 7.0 * 1000.0).unwrap_or(0.0) - STAFF_MARGIN * 2f64;
+        let width = song.map(|song| song.width / song.scale()).unwrap_or(0.0) - STAFF_MARGIN * 2f64;
+
+        // The extent of each note or rest, including its accidentals and leger lines.
+        let mut note_rects: HashMap<Entity, Rect> = HashMap::new();
+        for bar in (&bars).join() {
+            for BarChild { stencil, .. } in bar.children() {
+                let accidentals = accidentals_for_chords.get(stencil).map(|a| &a.0);
+                let leger_lines = leger_lines_for_chords.get(stencil).map(|l| &l.0);
+                let parts = accidentals.into_iter().chain(leger_lines).flatten();
+                let rect = parts
+                    .filter_map(|&part| stencils.get(part))
+                    .fold(stencils.get(stencil).unwrap().rect(), |rect, part| {
+                        rect.union(part.rect())
+                    });
+                note_rects.insert(stencil, rect);
+            }
+        }
+
+        // Bars with rehearsal marks are good places to start a line.
+        let marked: HashSet<Entity> = (&rehearsal_marks, &parents)
+            .join()
+            .map(|(_, parent)| parent.0)
+            .collect();
 
         let mut to_add = vec![];
         for (id, staff, children) in (&entities, &mut staffs, &mut children).join() {
-            let mut chunks: Vec<Vec<ConditionalChildren>> = Vec::new();
+            let mut chunks: Vec<PartialSolution> = Vec::new();
             let mut current_solution = PartialSolution::default();
             let mut next_solution = PartialSolution::default();
             let mut good_solution = PartialSolution::default();
             let mut recent_signature = None;
 
+            // The first bar of each multi-measure rest, mapped to the rest and its last bar.
+            let multirest_starts: HashMap<Entity, (Entity, Entity)> = staff
+                .multirests
+                .iter()
+                .filter_map(|&multirest| {
+                    let bars = &multirests.get(multirest)?.bars;
+                    Some((*bars.first()?, (multirest, *bars.last()?)))
+                })
+                .collect();
+            let mut multirest_end = None;
+
             // This is greedy.
             for &child in &children.0 {
-                if let Some(bar) = bars.get(child) {
-                    current_solution.add_bar(child, bar, &stencils);
-                    next_solution.add_bar(child, bar, &stencils);
+                // Bars and barlines inside a multi-measure rest are skipped.
+                if let Some(end) = multirest_end {
+                    if child == end {
+                        multirest_end = None;
+                    }
+                    continue;
+                }
+
+                if marked.contains(&child)
+                    && current_solution.is_valid
+                    && current_solution.width < width
+                    && current_solution.width >= width * PREFERRED_BREAK_FILL
+                {
+                    current_solution.apply_spacing(width, &bars, &note_rects, &mut spacings);
+                    chunks.push(current_solution);
+
+                    current_solution = PartialSolution::default();
+                    if let Some(signature) = recent_signature {
+                        current_solution.add_signature(signature, &stencils);
+                    }
+                    next_solution = current_solution.clone();
+                    good_solution = PartialSolution::default();
+                }
+
+                if let Some(&(multirest, end)) = multirest_starts.get(&child) {
+                    current_solution.add_multirest(multirest, &stencils);
+                    next_solution.add_multirest(multirest, &stencils);
+                    multirest_end = Some(end);
+                } else if let Some(bar) = bars.get(child) {
+                    current_solution.add_bar(child, bar, &note_rects);
+                    next_solution.add_bar(child, bar, &note_rects);
                 } else if let Some(signature) = signatures.get(child) {
                     current_solution.add_signature(signature, &stencils);
                     next_solution.add_signature(signature, &stencils);
@@ -84,14 +158,13 @@ impl<'a> System<'a> for BreakIntoLines {
                             next_solution.add_signature(signature, &stencils);
                         }
                     } else {
-                        good_solution.apply_spacing(width, &bars, &mut spacings);
-                        let PartialSolution { entities, .. } = good_solution;
+                        good_solution.apply_spacing(width, &bars, &note_rects, &mut spacings);
                         current_solution = next_solution.clone();
-                        good_solution = PartialSolution::default();
 
-                        if !entities.is_empty() {
-                            chunks.push(entities);
+                        if !good_solution.entities.is_empty() {
+                            chunks.push(good_solution);
                         }
+                        good_solution = PartialSolution::default();
                     }
                 }
             }
@@ -102,9 +175,10 @@ impl<'a> System<'a> for BreakIntoLines {
                 current_solution.apply_spacing(
                     current_solution.width + extra_space,
                     &bars,
+                    &note_rects,
                     &mut spacings,
                 );
-                chunks.push(current_solution.entities);
+                chunks.push(current_solution);
             }
 
             while staff.lines.len() > chunks.len() {
@@ -130,11 +204,12 @@ impl<'a> System<'a> for BreakIntoLines {
                     staff.lines.push(line_of_staff);
                 }
 
-                let line_len = line.len();
+                let line_len = line.entities.len();
                 to_add.push((
                     staff.lines[line_number],
                     Children(
-                        line.into_iter()
+                        line.entities
+                            .into_iter()
                             .enumerate()
                             .map(|(i, cond)| {
                                 if i == 0 {
@@ -159,6 +234,9 @@ impl<'a> System<'a> for BreakIntoLines {
 
 pub(crate) const STAFF_MARGIN: f64 = 2500f64;
 
+/// How full a line must be to end it early, so that the next line starts at a rehearsal mark.
+const PREFERRED_BREAK_FILL: f64 = 0.75;
+
 #[derive(Debug, Clone)]
 struct SignatureMeta {
     /// Stencil and width if at start of line.
@@ -169,31 +247,44 @@ struct SignatureMeta {
     end: (Entity, f64),
 }
 
+/// The space after a grace note, before the next grace note or its main note.
+const GRACE_PADDING: f64 = 100f64;
+
 #[derive(Debug, Clone)]
 /// Line-splitting metadata for notes and signatures.
 enum ItemMeta {
     Note(Duration, Entity, f64),
+    /// Grace notes have a fixed width, since they take no time.
+    Grace(Entity, f64),
+    /// Multi-measure rests have a fixed width, regardless of how many bars they replace.
+    Multirest(Entity, f64),
     Signature(SignatureMeta),
 }
 
 impl ItemMeta {
     fn start_meta(&self) -> (Entity, f64) {
         match self {
-            ItemMeta::Note(_, stencil, width) => (*stencil, *width),
+            ItemMeta::Note(_, stencil, width)
+            | ItemMeta::Grace(stencil, width)
+            | ItemMeta::Multirest(stencil, width) => (*stencil, *width),
             ItemMeta::Signature(bm) => bm.start,
         }
     }
 
     fn mid_meta(&self) -> (Entity, f64) {
         match self {
-            ItemMeta::Note(_, stencil, width) => (*stencil, *width),
+            ItemMeta::Note(_, stencil, width)
+            | ItemMeta::Grace(stencil, width)
+            | ItemMeta::Multirest(stencil, width) => (*stencil, *width),
             ItemMeta::Signature(bm) => bm.mid,
         }
     }
 
     fn end_meta(&self) -> (Entity, f64) {
         match self {
-            ItemMeta::Note(_, stencil, width) => (*stencil, *width),
+            ItemMeta::Note(_, stencil, width)
+            | ItemMeta::Grace(stencil, width)
+            | ItemMeta::Multirest(stencil, width) => (*stencil, *width),
             ItemMeta::Signature(bm) => bm.end,
         }
     }
@@ -201,7 +292,7 @@ impl ItemMeta {
     fn duration(&self) -> Option<Duration> {
         match self {
             ItemMeta::Note(duration, _, _) => Some(*duration),
-            ItemMeta::Signature(_) => None,
+            ItemMeta::Grace(_, _) | ItemMeta::Multirest(_, _) | ItemMeta::Signature(_) => None,
         }
     }
 }
@@ -235,20 +326,28 @@ impl Default for PartialSolution {
 }
 
 impl PartialSolution {
-    fn add_bar(&mut self, entity: Entity, bar: &Bar, stencils: &ReadStorage<Stencil>) {
+    fn add_bar(&mut self, entity: Entity, bar: &Bar, note_rects: &HashMap<Entity, Rect>) {
         self.entities.push(ConditionalChildren {
             start: entity,
             mid: entity,
             end: entity,
         });
         for BarChild {
-            duration, stencil, ..
+            duration,
+            stencil,
+            grace,
+            ..
         } in bar.children()
         {
-            let stencil = &stencils.get(stencil).unwrap();
-            self.shortest = self.shortest.min(duration.duration());
-            self.children
-                .push(ItemMeta::Note(duration, entity, stencil.rect().x1));
+            let rect = note_rects[&stencil];
+            if grace {
+                self.children
+                    .push(ItemMeta::Grace(entity, rect.x1 + GRACE_PADDING));
+            } else {
+                self.shortest = self.shortest.min(duration.duration());
+                self.children
+                    .push(ItemMeta::Note(duration, entity, rect.x1));
+            }
         }
 
         let mut advance_step = 400.0f64;
@@ -277,6 +376,21 @@ impl PartialSolution {
         self.is_valid = false;
     }
 
+    fn add_multirest(&mut self, entity: Entity, stencils: &ReadStorage<Stencil>) {
+        self.entities.push(ConditionalChildren {
+            start: entity,
+            mid: entity,
+            end: entity,
+        });
+
+        let stencil = stencils.get(entity).unwrap();
+        self.children
+            .push(ItemMeta::Multirest(entity, stencil.advance()));
+        self.width += stencil.advance();
+
+        self.is_valid = false;
+    }
+
     fn add_signature(&mut self, signature: &Signature, stencils: &ReadStorage<Stencil>) {
         self.entities.push(ConditionalChildren {
             start: signature.stencil_start,
@@ -298,13 +412,14 @@ impl PartialSolution {
                 stencils.get(signature.stencil_end).unwrap().advance(),
             ),
         }));
-        let w = if self.entities.len() == 1 {
-            stencils.get(signature.stencil_start).unwrap().advance()
+
+        let stencil = if self.entities.len() == 1 {
+            stencils.get(signature.stencil_start).unwrap()
         } else {
             // TODO: should be end, but back to middle when adding another bar.
-            stencils.get(signature.stencil_middle).unwrap().advance()
+            stencils.get(signature.stencil_middle).unwrap()
         };
-        self.width += w;
+        self.width += stencil.advance();
         self.is_valid = true;
     }
 
@@ -314,6 +429,7 @@ impl PartialSolution {
         &self,
         width: f64,
         bars: &ReadStorage<Bar>,
+        note_rects: &HashMap<Entity, Rect>,
         spacing: &mut WriteStorage<Spacing>,
     ) {
         let mut advance_step = 400.0f64;
@@ -334,6 +450,8 @@ impl PartialSolution {
             if let Some(ref duration) = meta.duration() {
                 spring_width += advance_step * Spacing::new(self.shortest, duration).relative;
                 advances += Spacing::new(self.shortest, duration).relative;
+            } else if let ItemMeta::Grace(_, width) | ItemMeta::Multirest(_, width) = meta {
+                strut_width += width;
             } else if i == 0 {
                 strut_width += meta.start_meta().1;
             } else if i + 1 == self.children.len() {
@@ -356,9 +474,21 @@ impl PartialSolution {
                     duration,
                     start,
                     stencil,
+                    grace,
                     ..
                 } in bar.children()
                 {
+                    if grace {
+                        let width = note_rects
+                            .get(&stencil)
+                            .map(|rect| rect.x1 + GRACE_PADDING)
+                            .unwrap_or(GRACE_PADDING);
+                        let my_spacing = Spacing::grace(start, advance, advance + width);
+                        advance = my_spacing.end_x;
+                        spacing.insert(stencil, my_spacing).unwrap();
+                        continue;
+                    }
+
                     let mut my_spacing = Spacing::new(self.shortest, &duration);
                     my_spacing.t = start;
                     my_spacing.start_x = advance;
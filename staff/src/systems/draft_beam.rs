@@ -1,18 +1,59 @@
-use crate::{components::Beam, components::BeamForChord, BeamAttachment};
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    components::{
+        Beam, BeamForChord, BeamOverride, BeamingStyle, Children, Chord, Grace, Simile, Song,
+        Staff, StemDirection,
+    },
+    resources::Root,
+    BeamAttachment,
+};
 use kurbo::Point;
 use num_rational::Rational;
 use rhythm::{components::Bar, BarChild, Duration};
-use specs::{Entities, Entity, Join, ReadStorage, System, WriteStorage};
-use std::collections::BTreeSet;
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
+use std::collections::{BTreeSet, HashSet};
 use stencil::components::Parent;
 
 #[derive(Debug, Default)]
 pub struct DraftBeam;
 
+/// How grace notes with the given number of beams are beamed to each other.
+///
+/// Grace notes before the same note are beamed together, regardless of the metre.
+fn grace_beaming(beam_counts: &[u8]) -> Vec<(u8, u8)> {
+    let last = beam_counts.len().saturating_sub(1);
+    beam_counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            (
+                if i == 0 { 0 } else { count },
+                if i == last { 0 } else { count },
+            )
+        })
+        .collect()
+}
+
+/// A note or rest that may be beamed.
+struct Candidate {
+    duration: Duration,
+    entity: Entity,
+    is_note: bool,
+    /// See `Bar::beaming_with_breaks`.
+    force_break: Option<bool>,
+}
+
 impl<'a> System<'a> for DraftBeam {
     type SystemData = (
         Entities<'a>,
+        Read<'a, Root>,
+        ReadStorage<'a, Song>,
         ReadStorage<'a, Bar>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Simile>,
         WriteStorage<'a, Parent>,
         WriteStorage<'a, BeamForChord>,
         WriteStorage<'a, Beam>,
@@ -20,59 +61,174 @@ impl<'a> System<'a> for DraftBeam {
 
     fn run(
         &mut self,
-        (entities, bars, mut parents, mut beam_for_chords, mut beams): Self::SystemData,
+        (
+            entities,
+            root,
+            songs,
+            bars,
+            chords,
+            staffs,
+            children,
+            similes,
+            mut parents,
+            mut beam_for_chords,
+            mut beams,
+        ): Self::SystemData,
     ) {
         let mut beams_to_delete: BTreeSet<Entity> =
             (&entities, &beams).join().map(|(ent, _)| ent).collect();
 
+        let beaming_style = root
+            .0
+            .and_then(|root| songs.get(root))
+            .map(|song| song.beaming_style)
+            .unwrap_or(BeamingStyle::Division);
+
+        // Bars replaced by a repeat sign have no beams.
+        let replaced: HashSet<Entity> = (&staffs, &children)
+            .join()
+            .flat_map(|(_, children)| Simile::replaced_bars(&children.0, &bars, &similes))
+            .map(|(bar, _)| bar)
+            .collect();
+
         for (bar_id, bar) in (&entities, &bars).join() {
             if bar.whole_rest() {
                 continue;
             }
+            let beats = bar.metre().beats();
             let mut candidates = vec![];
-            let mut current_candidate: Option<(Rational, Vec<(Duration, Entity)>)> = None;
+            let mut current_candidate: Option<(Rational, Vec<Candidate>)> = None;
+            // Rests that will be beamed over if another note follows them.
+            let mut pending_rests = vec![];
+            let mut join_next = false;
+            let mut join_over_rests = false;
+            let mut full_bar = false;
             // We reuse these if we have more beams, and delete them otherwise.
             let mut available_beam_entities = BTreeSet::new();
+            // Grace notes before the same note, with their number of beams.
+            let mut grace_groups: Vec<(Rational, Vec<(Entity, u8, Option<Grace>)>)> = vec![];
             for BarChild {
                 duration,
                 start,
                 lifetime,
                 stencil,
+                grace,
             } in bar.children()
             {
-                if !lifetime.is_temporary()
-                    && !lifetime.is_automatic()
+                if let Some(beam) = beam_for_chords.remove(stencil) {
+                    available_beam_entities.insert(beam.0);
+                }
+                if replaced.contains(&bar_id) {
+                    continue;
+                }
+
+                let chord = chords.get(stencil);
+                if grace {
+                    let beam_count = duration
+                        .duration_display_base()
+                        .map(|b| b.beam_count())
+                        .unwrap_or(0);
+                    let note = (stencil, beam_count, chord.and_then(|chord| chord.grace));
+                    match grace_groups.last_mut() {
+                        Some((t, group)) if *t == start && beam_count > 0 => group.push(note),
+                        _ if beam_count > 0 => grace_groups.push((start, vec![note])),
+                        _ => grace_groups.push((start, vec![])),
+                    }
+                    continue;
+                }
+
+                let beam_override = chord.and_then(|chord| chord.beam_override);
+                let is_note = chord.map(|chord| chord.is_note()).unwrap_or(false);
+                let beamable = !lifetime.is_temporary()
                     && duration
                         .duration_display_base()
                         .map(|b| b.beam_count())
                         .unwrap_or(0)
-                        > 0
-                {
+                        > 0;
+
+                if is_note && beamable {
+                    if beam_override == Some(BeamOverride::BreakBefore) && !full_bar {
+                        if let Some(current_candidate) = current_candidate.take() {
+                            candidates.push(current_candidate);
+                        }
+                        pending_rests.clear();
+                    }
+
+                    let force_break = if join_next || full_bar || !pending_rests.is_empty() {
+                        Some(false)
+                    } else if beaming_style == BeamingStyle::Beat && beats.contains(&start) {
+                        Some(true)
+                    } else {
+                        None
+                    };
+                    let candidate = Candidate {
+                        duration,
+                        entity: stencil,
+                        is_note,
+                        force_break,
+                    };
                     if let Some(current_candidate) = &mut current_candidate {
-                        current_candidate.1.push((duration, stencil));
+                        current_candidate.1.append(&mut pending_rests);
+                        current_candidate.1.push(candidate);
                     } else {
-                        current_candidate = Some((start, vec![(duration, stencil)]));
+                        current_candidate = Some((start, vec![candidate]));
                     }
-                } else if let Some(current_candidate) = current_candidate.take() {
-                    candidates.push(current_candidate);
-                }
-                if let Some(beam) = beam_for_chords.get(stencil) {
-                    available_beam_entities.insert(beam.0);
+
+                    full_bar |= beam_override == Some(BeamOverride::FullBar);
+                    join_next = beam_override == Some(BeamOverride::JoinNext);
+                    join_over_rests =
+                        full_bar || beam_override == Some(BeamOverride::JoinOverRests);
+                } else if !is_note && beamable && join_over_rests && current_candidate.is_some() {
+                    pending_rests.push(Candidate {
+                        duration,
+                        entity: stencil,
+                        is_note,
+                        force_break: Some(false),
+                    });
+                } else {
+                    if let Some(current_candidate) = current_candidate.take() {
+                        candidates.push(current_candidate);
+                    }
+                    pending_rests.clear();
+                    join_next = false;
+                    join_over_rests = false;
                 }
             }
             if let Some(current_candidate) = current_candidate.take() {
                 candidates.push(current_candidate);
             }
-            for (t0, durations) in candidates {
+            for (t0, candidates) in candidates {
                 let mut beam_entity = None;
                 let mut beam_attachments = Vec::new();
 
-                for (beaming, (_duration, chord_entity)) in bar
-                    .beaming(t0, durations.iter().map(|(d, _e)| *d).collect())
+                let breaks: Vec<Option<bool>> = candidates.iter().map(|c| c.force_break).collect();
+                for (beaming, candidate) in bar
+                    .beaming_with_breaks(
+                        t0,
+                        candidates.iter().map(|c| c.duration).collect(),
+                        &breaks,
+                    )
                     .iter()
-                    .zip(durations)
+                    .zip(candidates)
                 {
-                    if let Some(beaming) = beaming {
+                    // Each beamed group gets its own entity, so that it can have its own slope
+                    // and direction.
+                    let starts_group = match beaming {
+                        Some(beaming) => beaming.entering == 0,
+                        None => true,
+                    };
+                    if starts_group {
+                        if let Some(beam_entity) = beam_entity.take() {
+                            parents.entry(beam_entity).unwrap().replace(Parent(bar_id));
+                            beams
+                                .entry(beam_entity)
+                                .unwrap()
+                                .replace(Beam(std::mem::take(&mut beam_attachments)));
+                        }
+                    }
+
+                    // Rests are beamed over, but are not attached to the beam.
+                    if let (Some(beaming), true) = (beaming, candidate.is_note) {
                         let next_available_beam = available_beam_entities.iter().next().copied();
                         let this_beam_entity = beam_entity
                             .or_else(|| {
@@ -80,9 +236,8 @@ impl<'a> System<'a> for DraftBeam {
                             })
                             .unwrap_or_else(|| entities.create());
                         beam_for_chords
-                            .entry(chord_entity)
-                            .unwrap()
-                            .replace(BeamForChord(this_beam_entity));
+                            .insert(candidate.entity, BeamForChord(this_beam_entity))
+                            .unwrap();
                         beams_to_delete.remove(&this_beam_entity);
                         beam_entity = Some(this_beam_entity);
                         beam_attachments.push(BeamAttachment {
@@ -90,16 +245,9 @@ impl<'a> System<'a> for DraftBeam {
                             extreme_y: 0.0,
                             entering: beaming.entering,
                             leaving: beaming.leaving,
+                            stem_direction: StemDirection::Up,
+                            grace: None,
                         });
-                    } else {
-                        if let Some(beam_entity) = beam_entity.take() {
-                            parents.entry(beam_entity).unwrap().replace(Parent(bar_id));
-                            beams
-                                .entry(beam_entity)
-                                .unwrap()
-                                .replace(Beam(std::mem::take(&mut beam_attachments)));
-                        }
-                        beam_for_chords.remove(chord_entity);
                     }
                 }
 
@@ -111,6 +259,40 @@ impl<'a> System<'a> for DraftBeam {
                         .replace(Beam(std::mem::take(&mut beam_attachments)));
                 }
             }
+
+            for (_, group) in grace_groups {
+                if group.len() < 2 {
+                    continue;
+                }
+                let next_available_beam = available_beam_entities.iter().next().copied();
+                let beam_entity = next_available_beam
+                    .and_then(|f| available_beam_entities.take(&f))
+                    .unwrap_or_else(|| entities.create());
+                beams_to_delete.remove(&beam_entity);
+
+                let beam_counts: Vec<u8> = group.iter().map(|&(_, count, _)| count).collect();
+                let mut beam_attachments = vec![];
+                for (&(entity, _, grace), (entering, leaving)) in
+                    group.iter().zip(grace_beaming(&beam_counts))
+                {
+                    beam_for_chords
+                        .insert(entity, BeamForChord(beam_entity))
+                        .unwrap();
+                    beam_attachments.push(BeamAttachment {
+                        stem_start: Point::default(),
+                        extreme_y: 0.0,
+                        entering,
+                        leaving,
+                        stem_direction: StemDirection::Up,
+                        grace,
+                    });
+                }
+                parents.entry(beam_entity).unwrap().replace(Parent(bar_id));
+                beams
+                    .entry(beam_entity)
+                    .unwrap()
+                    .replace(Beam(beam_attachments));
+            }
         }
 
         for beam in beams_to_delete {
@@ -119,3 +301,17 @@ impl<'a> System<'a> for DraftBeam {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grace_beams() {
+        assert_eq!(grace_beaming(&[1, 1]), vec![(0, 1), (1, 0)]);
+        assert_eq!(grace_beaming(&[2, 2, 2]), vec![(0, 2), (2, 2), (2, 0)]);
+
+        // Mixed values get partial beams.
+        assert_eq!(grace_beaming(&[1, 2]), vec![(0, 1), (2, 0)]);
+    }
+}
@@ -1,11 +1,11 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    components::{Children, Chord, Context, FlagAttachment},
+    components::{Children, Chord, Context, FlagAttachment, Linked},
     PitchKind,
 };
 use rhythm::{components::Bar, components::Spacing, BarChild};
-use specs::{world::Builder, Entities, Join, LazyUpdate, Read, System, WriteStorage};
+use specs::{world::Builder, Entities, Join, LazyUpdate, Read, ReadStorage, System, WriteStorage};
 use stencil::components::{Parent, Stencil};
 
 #[derive(Debug, Default)]
@@ -15,13 +15,19 @@ impl<'a> System<'a> for MaintainAutorests {
     type SystemData = (
         Entities<'a>,
         Read<'a, LazyUpdate>,
+        ReadStorage<'a, Linked>,
         WriteStorage<'a, Chord>,
         WriteStorage<'a, Context>,
         WriteStorage<'a, Bar>,
+        WriteStorage<'a, Parent>,
     );
 
-    fn run(&mut self, (entities, lazy, mut chords, mut contexts, mut bars): Self::SystemData) {
-        for (bar_id, bar) in (&entities, &mut bars).join() {
+    fn run(
+        &mut self,
+        (entities, lazy, linked, mut chords, mut contexts, mut bars, mut parents): Self::SystemData,
+    ) {
+        // Bars on linked staffs are copied, with their rests, by `UpdateLinkedStaffs`.
+        for (bar_id, bar, ()) in (&entities, &mut bars, !&linked).join() {
             let bar_context = contexts.get(bar_id).cloned().unwrap_or_default();
             while let Some((duration, start)) = bar.next_missing_child() {
                 bar.push_managed_entity(
@@ -41,8 +47,10 @@ impl<'a> System<'a> for MaintainAutorests {
                 );
             }
 
+            // Unneeded rests are detached rather than deleted, so that undoing an edit can bring
+            // them back. `DeleteOrphans` deletes them otherwise.
             while let Some(entity) = bar.pop_managed_entity() {
-                entities.delete(entity).unwrap();
+                parents.remove(entity);
             }
 
             for BarChild {
@@ -50,12 +58,14 @@ impl<'a> System<'a> for MaintainAutorests {
                 start,
                 stencil,
                 lifetime,
+                ..
             } in bar.children()
             {
                 if let Some(chord) = chords.get_mut(stencil) {
                     chord.duration = duration;
                     if lifetime.is_automatic() {
                         chord.natural_duration = duration;
+                        chord.slash = bar.slash_fill();
                     }
                 }
                 if let Some(context_data) = contexts.get_mut(stencil) {
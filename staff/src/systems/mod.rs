@@ -2,26 +2,54 @@ mod apply_space_time_warp;
 pub(crate) mod break_into_lines;
 mod draft_beam;
 mod maintain_autorests;
+mod print_accidentals;
+mod print_bar_numbers;
 mod print_beam;
 mod print_chord;
+mod print_chord_symbols;
 mod print_cursor;
+mod print_dynamics;
+mod print_leger_lines;
+mod print_rehearsal_marks;
 mod print_signature;
+mod print_simile;
+mod print_slur;
 mod print_staff;
 mod print_staff_lines;
+mod print_tablature;
 mod record_space_time_warp;
 mod space_beam;
 mod update_context;
+mod update_linked_staffs;
+mod update_multirests;
+mod update_skyline;
+mod update_stem_direction;
+mod update_tablature;
 
 pub use apply_space_time_warp::ApplySpaceTimeWarp;
 pub use break_into_lines::BreakIntoLines;
 pub use draft_beam::DraftBeam;
 pub use maintain_autorests::MaintainAutorests;
+pub use print_accidentals::PrintAccidentals;
+pub use print_bar_numbers::PrintBarNumbers;
 pub use print_beam::PrintBeam;
 pub use print_chord::PrintChord;
+pub use print_chord_symbols::PrintChordSymbols;
 pub use print_cursor::PrintCursor;
+pub use print_dynamics::PrintDynamics;
+pub use print_leger_lines::PrintLegerLines;
+pub use print_rehearsal_marks::PrintRehearsalMarks;
 pub use print_signature::PrintSignature;
+pub use print_simile::PrintSimile;
+pub use print_slur::PrintSlur;
 pub use print_staff::PrintStaff;
 pub use print_staff_lines::PrintStaffLines;
+pub use print_tablature::PrintTablature;
 pub use record_space_time_warp::RecordSpaceTimeWarp;
 pub use space_beam::SpaceBeam;
 pub use update_context::UpdateContext;
+pub use update_linked_staffs::UpdateLinkedStaffs;
+pub use update_multirests::UpdateMultirests;
+pub use update_skyline::UpdateSkyline;
+pub use update_stem_direction::UpdateStemDirection;
+pub use update_tablature::UpdateTablature;
@@ -0,0 +1,161 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    components::{Accidental, AccidentalsForChord, Chord, Context, GRACE_SCALE},
+    PitchKind,
+};
+use kurbo::Vec2;
+use specs::{Entities, Join, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil};
+
+/// The space between an accidental and its notehead, or between columns of accidentals.
+const ACCIDENTAL_PADDING: f64 = 20.0;
+
+/// How far apart accidentals in the same column must be, so that they do not overlap.
+const ACCIDENTAL_CLEARANCE: f64 = 750.0;
+
+#[derive(Debug, Default)]
+pub struct PrintAccidentals;
+
+/// The column of each accidental, given the y of each notehead from top to bottom.
+///
+/// Each accidental goes in the column nearest the notes where it clears the accidental above it.
+fn stack_accidentals(ys: &[f64]) -> Vec<usize> {
+    // The y of the lowest accidental in each column.
+    let mut columns: Vec<f64> = vec![];
+    ys.iter()
+        .map(|&y| {
+            let column = columns
+                .iter()
+                .position(|&bottom| y - bottom >= ACCIDENTAL_CLEARANCE)
+                .unwrap_or(columns.len());
+            if column == columns.len() {
+                columns.push(y);
+            } else {
+                columns[column] = y;
+            }
+            column
+        })
+        .collect()
+}
+
+impl<'a> System<'a> for PrintAccidentals {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, Context>,
+        WriteStorage<'a, AccidentalsForChord>,
+        WriteStorage<'a, Accidental>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            chords,
+            contexts,
+            mut accidentals_for_chords,
+            mut accidentals,
+            mut parents,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        for (chord_entity, chord, context) in (&entities, &chords, &contexts).join() {
+            let pitches = match chord.written_pitch() {
+                PitchKind::Pitch(pitch) if !chord.slash => vec![pitch],
+                PitchKind::Pitch(_)
+                | PitchKind::Rest
+                | PitchKind::Unpitched
+                | PitchKind::Percussion(_) => vec![],
+            };
+            let origin_y = pitches
+                .first()
+                .map(|pitch| pitch.y(context.clef))
+                .unwrap_or(0.0);
+
+            let mut shown: Vec<Accidental> = pitches
+                .into_iter()
+                .filter(|&pitch| context.needs_accidental(pitch))
+                .map(|pitch| Accidental {
+                    modifier: pitch.modifier(),
+                    y: pitch.y(context.clef),
+                    column: 0,
+                })
+                .collect();
+            shown.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+            let ys: Vec<f64> = shown.iter().map(|accidental| accidental.y).collect();
+            for (accidental, column) in shown.iter_mut().zip(stack_accidentals(&ys)) {
+                accidental.column = column;
+            }
+
+            // Each column is as wide as its widest accidental.
+            let mut widths: Vec<f64> = vec![];
+            for accidental in &shown {
+                if widths.len() <= accidental.column {
+                    widths.resize(accidental.column + 1, 0.0);
+                }
+                let width = Accidental::glyph(accidental.modifier).rect().width();
+                widths[accidental.column] = widths[accidental.column].max(width);
+            }
+            let mut column_x = vec![];
+            let mut x = -ACCIDENTAL_PADDING;
+            for width in widths {
+                column_x.push(x);
+                x -= width + ACCIDENTAL_PADDING;
+            }
+
+            let for_chord = accidentals_for_chords
+                .entry(chord_entity)
+                .unwrap()
+                .or_insert_with(Default::default);
+            let count = shown.len();
+            for (i, accidental) in shown.into_iter().enumerate() {
+                if for_chord.0.len() == i {
+                    for_chord.0.push(entities.create());
+                }
+                let entity = for_chord.0[i];
+
+                let mut stencil = accidental.print(column_x[accidental.column]);
+                if chord.grace.is_some() {
+                    // Grace notes are shrunk around their notehead.
+                    let origin = Vec2::new(0.0, origin_y);
+                    stencil = stencil
+                        .with_translation(-origin)
+                        .with_scale(GRACE_SCALE)
+                        .with_translation(origin);
+                }
+
+                parents.insert(entity, Parent(chord_entity)).unwrap();
+                stencils.insert(entity, stencil).unwrap();
+                accidentals.insert(entity, accidental).unwrap();
+            }
+
+            // Orphaned accidentals are deleted by DeleteOrphans.
+            for entity in for_chord.0.drain(count..) {
+                parents.remove(entity);
+                stencils.remove(entity);
+                accidentals.remove(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns() {
+        assert_eq!(stack_accidentals(&[]), Vec::<usize>::new());
+        assert_eq!(stack_accidentals(&[0.0]), vec![0]);
+        // A third apart collides, a sixth apart does not.
+        assert_eq!(stack_accidentals(&[0.0, 250.0]), vec![0, 1]);
+        assert_eq!(stack_accidentals(&[0.0, 750.0]), vec![0, 0]);
+        assert_eq!(
+            stack_accidentals(&[0.0, 250.0, 500.0, 750.0]),
+            vec![0, 1, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::HashSet;
+
+use crate::{
+    components::{
+        BarNumbering, Children, Context, LineOfStaff, LinePlacement, Multirest, RehearsalMark,
+        Song, Staff,
+    },
+    resources::Root,
+    systems::break_into_lines::STAFF_MARGIN,
+    text::print_text,
+};
+use kurbo::Vec2;
+use num_rational::Rational;
+use rhythm::components::Bar;
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// The height of bar numbers.
+const FONT_SIZE: f64 = 400.0;
+
+/// The space between a bar number and the highest thing below it.
+const BAR_NUMBER_PADDING: f64 = 250.0;
+
+#[derive(Debug, Default)]
+pub struct PrintBarNumbers;
+
+/// Whether to number a bar, given its index in the staff and its number.
+fn is_numbered(song: &Song, idx: usize, number: isize, first_in_line: bool) -> bool {
+    if idx == 0 {
+        return false;
+    }
+
+    match song.bar_numbering {
+        BarNumbering::Never => false,
+        BarNumbering::System => first_in_line,
+        BarNumbering::Interval => {
+            song.bar_number_interval > 0 && number % song.bar_number_interval as isize == 0
+        }
+        BarNumbering::EveryBar => true,
+    }
+}
+
+impl<'a> System<'a> for PrintBarNumbers {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Root>,
+        ReadStorage<'a, Song>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Context>,
+        ReadStorage<'a, Multirest>,
+        ReadStorage<'a, LinePlacement>,
+        ReadStorage<'a, RehearsalMark>,
+        WriteStorage<'a, Staff>,
+        WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            root,
+            songs,
+            children,
+            bars,
+            contexts,
+            multirests,
+            placements,
+            rehearsal_marks,
+            mut staffs,
+            mut line_of_staffs,
+            mut parents,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        let song = match root.0.and_then(|root| songs.get(root)) {
+            Some(song) => song,
+            None => return,
+        };
+
+        // Rehearsal marks take the place of bar numbers.
+        let marked: HashSet<Entity> = (&rehearsal_marks, &parents)
+            .join()
+            .map(|(_, parent)| parent.0)
+            .collect();
+
+        for (staff_entity, staff, staff_children) in (&entities, &mut staffs, &children).join() {
+            // Pickup bars are bar 0.
+            let pickup = staff_children
+                .0
+                .iter()
+                .find_map(|&child| bars.get(child))
+                .map(|bar| bar.skip() > Rational::new(0, 1))
+                .unwrap_or(false);
+
+            let mut count = 0;
+            for &line in &staff.lines {
+                let line_children = match children.get(line) {
+                    Some(line_children) => &line_children.0,
+                    None => continue,
+                };
+
+                let mut first_in_line = true;
+                for &child in line_children {
+                    // Multi-measure rests are numbered by their first bar.
+                    let bar = if bars.get(child).is_some() {
+                        child
+                    } else if let Some(multirest) = multirests.get(child) {
+                        match multirest.bars.first() {
+                            Some(&bar) => bar,
+                            None => continue,
+                        }
+                    } else {
+                        continue;
+                    };
+                    let is_first_in_line = first_in_line;
+                    first_in_line = false;
+
+                    let idx = match contexts.get(bar) {
+                        Some(context) => context.bar,
+                        None => continue,
+                    };
+                    let number = song.first_bar_number + idx as isize - if pickup { 1 } else { 0 };
+                    if !is_numbered(song, idx, number, is_first_in_line)
+                        || (!is_first_in_line && marked.contains(&bar))
+                    {
+                        continue;
+                    }
+
+                    // At the start of a line, the number goes above the clef.
+                    let x = if is_first_in_line {
+                        STAFF_MARGIN
+                    } else {
+                        match placements.get(child) {
+                            Some(placement) => placement.x,
+                            None => continue,
+                        }
+                    };
+
+                    if staff.bar_numbers.len() == count {
+                        staff.bar_numbers.push(entities.create());
+                    }
+                    let entity = staff.bar_numbers[count];
+                    count += 1;
+
+                    let stencil =
+                        print_text(&number.to_string(), FONT_SIZE, song.bar_numbers_boxed);
+                    let rect = stencil.rect();
+                    let y = match line_of_staffs.get_mut(line) {
+                        Some(line_of_staff) => {
+                            let skyline = &mut line_of_staff.skyline;
+                            let y = skyline.top(x + rect.x0, x + rect.x1) - BAR_NUMBER_PADDING;
+                            skyline.insert(rect + Vec2::new(x, y));
+                            y
+                        }
+                        None => -500.0 - BAR_NUMBER_PADDING,
+                    };
+                    parents.insert(entity, Parent(staff_entity)).unwrap();
+                    stencils.insert(entity, stencil).unwrap();
+                    if let Some(line_map) = stencil_maps.get_mut(line) {
+                        *line_map = std::mem::take(line_map).and(entity, Some(Vec2::new(x, y)));
+                    }
+                }
+            }
+
+            // Orphaned numbers are deleted by DeleteOrphans.
+            for entity in staff.bar_numbers.drain(count..) {
+                parents.remove(entity);
+                stencils.remove(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_bars() {
+        let mut song = Song::default();
+        assert!(!is_numbered(&song, 4, 5, true));
+
+        song.bar_numbering = BarNumbering::System;
+        assert!(is_numbered(&song, 4, 5, true));
+        assert!(!is_numbered(&song, 4, 5, false));
+        // The first bar is never numbered.
+        assert!(!is_numbered(&song, 0, 1, true));
+
+        song.bar_numbering = BarNumbering::Interval;
+        assert!(is_numbered(&song, 4, 5, false));
+        assert!(!is_numbered(&song, 5, 6, true));
+        song.bar_number_interval = 0;
+        assert!(!is_numbered(&song, 4, 5, false));
+
+        song.bar_numbering = BarNumbering::EveryBar;
+        assert!(is_numbered(&song, 5, 6, false));
+    }
+}
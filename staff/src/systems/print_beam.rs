@@ -1,5 +1,9 @@
-use crate::components::Beam;
-use kurbo::{Line, Point};
+use super::space_beam::BEAM_SPACING;
+use crate::{
+    components::{Beam, Grace, StemDirection, GRACE_SCALE},
+    BeamAttachment,
+};
+use kurbo::{Line, Point, Vec2};
 use specs::{Join, ReadStorage, System, WriteStorage};
 use stencil::components::Stencil;
 
@@ -11,25 +15,55 @@ impl<'a> System<'a> for PrintBeam {
 
     fn run(&mut self, (beams, mut stencils): Self::SystemData) {
         for (beam, stencil_entry) in (&beams, stencils.entries()).join() {
+            // Grace note beams are drawn full size, then shrunk around the first stem.
+            let is_grace = beam.0.first().map(|a| a.grace.is_some()).unwrap_or(false);
+            let origin = beam
+                .0
+                .first()
+                .map(|a| Vec2::new(a.stem_start.x, a.extreme_y))
+                .unwrap_or_default();
+            let full_size = |p: Point| {
+                if is_grace {
+                    (origin + (p.to_vec2() - origin) / GRACE_SCALE).to_point()
+                } else {
+                    p
+                }
+            };
+            let attachments: Vec<BeamAttachment> = beam
+                .0
+                .iter()
+                .map(|a| BeamAttachment {
+                    stem_start: full_size(a.stem_start),
+                    extreme_y: full_size(Point::new(a.stem_start.x, a.extreme_y)).y,
+                    ..*a
+                })
+                .collect();
+
             let mut stencil = Stencil::default();
+            // Secondary beams are stacked towards the noteheads.
+            let sign = attachments
+                .first()
+                .map(|a| a.stem_direction)
+                .unwrap_or(StemDirection::Up)
+                .sign();
 
             let mut level = 0;
-            for (i, attachment) in beam.0.iter().enumerate() {
+            for (i, attachment) in attachments.iter().enumerate() {
                 // Backwards fractional.
                 for l in level..attachment.entering {
-                    let next_level = beam.0.get(i + 1).map(|l| l.entering).unwrap_or(0);
+                    let next_level = attachments.get(i + 1).map(|l| l.entering).unwrap_or(0);
                     if next_level <= l {
                         let start_x = attachment.stem_start.x - 295.0;
                         let start_y = attachment.extreme_y;
                         stencil = stencil.and(Stencil::beam(
                             Line::new(
-                                Point::new(start_x, start_y + 187.5 * (l as f64)),
+                                Point::new(start_x, start_y + BEAM_SPACING * sign * (l as f64)),
                                 Point::new(
                                     attachment.stem_start.x,
-                                    attachment.extreme_y + 187.5 * (l as f64),
+                                    attachment.extreme_y + BEAM_SPACING * sign * (l as f64),
                                 ),
                             ),
-                            level as isize,
+                            level as isize * sign as isize,
                         ));
                     }
                 }
@@ -39,7 +73,7 @@ impl<'a> System<'a> for PrintBeam {
                     let mut end_x = attachment.stem_start.x;
                     let mut end_y = attachment.extreme_y;
                     let mut fractional = true;
-                    for maybe_end in beam.0.iter().skip(i + 1) {
+                    for maybe_end in attachments.iter().skip(i + 1) {
                         if maybe_end.entering <= l {
                             if fractional {
                                 end_x += 295.0;
@@ -55,11 +89,11 @@ impl<'a> System<'a> for PrintBeam {
                         Line::new(
                             Point::new(
                                 attachment.stem_start.x,
-                                attachment.extreme_y + 187.5 * (l as f64),
+                                attachment.extreme_y + BEAM_SPACING * sign * (l as f64),
                             ),
-                            Point::new(end_x, end_y + 187.5 * (l as f64)),
+                            Point::new(end_x, end_y + BEAM_SPACING * sign * (l as f64)),
                         ),
-                        level as isize,
+                        level as isize * sign as isize,
                     ));
                 }
                 level = attachment.leaving;
@@ -70,6 +104,23 @@ impl<'a> System<'a> for PrintBeam {
                 ));
             }
 
+            // Beamed acciaccaturas are slashed through the first stem.
+            if let Some(first) = attachments.first() {
+                if first.grace == Some(Grace::Acciaccatura) {
+                    stencil = stencil.and(Stencil::grace_note_slash(Point::new(
+                        first.stem_start.x,
+                        first.stem_start.y - first.stem_direction.sign() * 500.0,
+                    )));
+                }
+            }
+
+            if is_grace {
+                stencil = stencil
+                    .with_translation(-origin)
+                    .with_scale(GRACE_SCALE)
+                    .with_translation(origin);
+            }
+
             stencil_entry.replace(stencil);
         }
     }
@@ -2,7 +2,7 @@
 
 use stencil::components::Stencil;
 
-use crate::components::{BeamForChord, Chord, Context, FlagAttachment};
+use crate::components::{BeamForChord, Chord, Context, FlagAttachment, StemDirection};
 use specs::{Join, ReadStorage, System, WriteStorage};
 
 #[derive(Debug, Default)]
@@ -13,24 +13,30 @@ impl<'a> System<'a> for PrintChord {
         ReadStorage<'a, Chord>,
         ReadStorage<'a, Context>,
         ReadStorage<'a, BeamForChord>,
+        ReadStorage<'a, StemDirection>,
         WriteStorage<'a, FlagAttachment>,
         WriteStorage<'a, Stencil>,
     );
 
     fn run(
         &mut self,
-        (chords, contexts, beam_for_chord, mut attachments, mut stencils): Self::SystemData,
+        (chords, contexts, beam_for_chord, stem_directions, mut attachments, mut stencils): Self::SystemData,
     ) {
-        for (chord, context, beam, attachment, stencil) in (
+        for (chord, context, beam, stem_direction, attachment, stencil) in (
             &chords,
             &contexts,
             beam_for_chord.maybe(),
+            stem_directions.maybe(),
             &mut attachments,
             &mut stencils,
         )
             .join()
         {
-            let result = chord.print(context, beam.is_some());
+            let result = chord.print(
+                context,
+                stem_direction.copied().unwrap_or(StemDirection::Up),
+                beam.is_some(),
+            );
             *stencil = result.0;
             attachment.0 = result.1;
         }
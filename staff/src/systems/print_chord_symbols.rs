@@ -0,0 +1,172 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    components::{Children, ChordDiagrams, Harmony, LineOfStaff, LinePlacement, Song, Staff},
+    resources::Root,
+    systems::break_into_lines::STAFF_MARGIN,
+    Voicing,
+};
+use kurbo::Vec2;
+use rhythm::components::{Bar, Spacing};
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// The space between a chord symbol and the highest thing below it.
+const CHORD_SYMBOL_PADDING: f64 = 250.0;
+
+/// The space between diagrams in the legend, and between rows of them.
+const LEGEND_SPACING: f64 = 500.0;
+
+/// Prints chord symbols above the staff, and their fretboard diagrams where the song shows them.
+#[derive(Debug, Default)]
+pub struct PrintChordSymbols;
+
+impl<'a> System<'a> for PrintChordSymbols {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Root>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Spacing>,
+        ReadStorage<'a, LinePlacement>,
+        ReadStorage<'a, Harmony>,
+        WriteStorage<'a, Song>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            root,
+            staffs,
+            children,
+            bars,
+            spacings,
+            placements,
+            harmonies,
+            mut songs,
+            mut parents,
+            mut line_of_staffs,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        let (root, song) = match root.0.and_then(|root| Some((root, songs.get(root)?))) {
+            Some(song) => song,
+            None => return,
+        };
+
+        let mut harmonies_by_bar: HashMap<Entity, Vec<Entity>> = HashMap::new();
+        for (entity, _, parent) in (&entities, &harmonies, &parents).join() {
+            harmonies_by_bar.entry(parent.0).or_default().push(entity);
+        }
+        for bar_harmonies in harmonies_by_bar.values_mut() {
+            bar_harmonies.sort_by_key(|&entity| harmonies.get(entity).unwrap().beat);
+        }
+
+        let mut used = HashSet::new();
+        let mut legend = vec![];
+        let song_children = children.get(root).map(|c| &c.0[..]).unwrap_or_default();
+        for &staff in song_children {
+            let staff_children = match (staffs.get(staff), children.get(staff)) {
+                (Some(_), Some(staff_children)) => staff_children,
+                _ => continue,
+            };
+            for &bar_entity in &staff_children.0 {
+                let (bar, bar_harmonies) =
+                    match (bars.get(bar_entity), harmonies_by_bar.get(&bar_entity)) {
+                        (Some(bar), Some(bar_harmonies)) => (bar, bar_harmonies),
+                        _ => continue,
+                    };
+
+                for &entity in bar_harmonies {
+                    let harmony = harmonies.get(entity).unwrap();
+                    let mut stencil = harmony.symbol.print();
+
+                    // Diagrams are shown for the first use of each chord.
+                    let name = harmony.symbol.to_string();
+                    if song.chord_diagrams != ChordDiagrams::Never && used.insert(name.clone()) {
+                        let voicing = song.chord_voicings.get(&name).cloned().or_else(|| {
+                            Voicing::generate(&harmony.symbol, &song.chord_diagram_tuning)
+                        });
+                        match (song.chord_diagrams, voicing) {
+                            (ChordDiagrams::AboveStaff, Some(voicing)) => {
+                                let diagram = voicing.print_diagram(stencil);
+                                let rect = diagram.rect();
+                                stencil = diagram.with_translation(Vec2::new(0.0, -rect.y1));
+                            }
+                            (ChordDiagrams::Legend, Some(voicing)) => {
+                                legend.push(voicing.print_diagram(harmony.symbol.print()));
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    let rect = stencil.rect();
+                    stencils.insert(entity, stencil).unwrap();
+
+                    let (placement, line) = match placements.get(bar_entity).and_then(|placement| {
+                        Some((placement, line_of_staffs.get_mut(placement.line)?))
+                    }) {
+                        Some(placement) => placement,
+                        None => continue,
+                    };
+
+                    // Symbols are over the note that is playing when the chord starts.
+                    let start_x = bar
+                        .children()
+                        .iter()
+                        .rev()
+                        .find(|child| !child.grace && child.start <= harmony.beat)
+                        .and_then(|child| spacings.get(child.stencil))
+                        .map(|spacing| spacing.start_x)
+                        .unwrap_or(0.0);
+                    let x = placement.x + start_x;
+                    let y = line.skyline.top(x + rect.x0, x + rect.x1) - CHORD_SYMBOL_PADDING;
+                    line.skyline.insert(rect + Vec2::new(x, y));
+                    if let Some(line_map) = stencil_maps.get_mut(placement.line) {
+                        *line_map = std::mem::take(line_map).and(entity, Some(Vec2::new(x, y)));
+                    }
+                }
+            }
+        }
+
+        let song = songs.get_mut(root).unwrap();
+        if legend.is_empty() {
+            if let Some(legend) = song.chord_legend_stencil.take() {
+                parents.remove(legend);
+            }
+            return;
+        }
+
+        // The legend is laid out in rows across the page, with its top at y = 0. The grids of
+        // each row are aligned, whatever is above them.
+        let width = song.width / song.scale() - STAFF_MARGIN;
+        let above = legend.iter().map(|d| -d.rect().y0).fold(0.0, f64::max);
+        let below = legend.iter().map(|d| d.rect().y1).fold(0.0, f64::max);
+        let mut stencil = Stencil::default();
+        let (mut x, mut y) = (STAFF_MARGIN, above);
+        for diagram in legend {
+            let rect = diagram.rect();
+            if x > STAFF_MARGIN && x + rect.width() > width {
+                x = STAFF_MARGIN;
+                y += above + below + LEGEND_SPACING;
+            }
+            stencil = stencil.and(diagram.with_translation(Vec2::new(x - rect.x0, y)));
+            x += rect.width() + LEGEND_SPACING;
+        }
+
+        let legend = *song
+            .chord_legend_stencil
+            .get_or_insert_with(|| entities.create());
+        parents.insert(legend, Parent(root)).unwrap();
+        stencils.insert(legend, stencil).unwrap();
+    }
+}
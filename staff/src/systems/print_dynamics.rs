@@ -0,0 +1,254 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::HashMap;
+
+use crate::components::{
+    DynamicMark, Hairpin, HairpinKind, LineOfStaff, LinePlacement, SpaceTimeWarp, Staff,
+};
+use kurbo::Vec2;
+use num_rational::Rational;
+use specs::{Entities, Entity, Join, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// The baseline of dynamic marks, 2.5 spaces below the staff, unless something is in the way.
+const DYNAMIC_Y: f64 = 1125.0;
+
+/// The middle of hairpins, level with the middle of dynamic marks, unless something is in the way.
+const HAIRPIN_Y: f64 = 1000.0;
+
+/// The space between dynamics and the lowest thing above them.
+const DYNAMIC_PADDING: f64 = 125.0;
+
+/// The distance between the lines at the open end of a hairpin.
+const HAIRPIN_OPENING: f64 = 250.0;
+
+/// The distance between the lines where a hairpin is broken across lines.
+const HAIRPIN_CONTINUATION_OPENING: f64 = 125.0;
+
+/// The space between a hairpin and a dynamic mark at its start or end.
+const HAIRPIN_PADDING: f64 = 125.0;
+
+/// Dynamic marks are centered under the notehead.
+const NOTEHEAD_CENTER: f64 = 147.5;
+
+#[derive(Debug, Default)]
+pub struct PrintDynamics;
+
+/// The distance between the lines of a hairpin at `progress` (0 to 1) along it.
+///
+/// Where the hairpin is broken across lines, it is partially open, so that the continuation is
+/// clear.
+fn hairpin_opening(kind: HairpinKind, progress: f64, is_break: bool) -> f64 {
+    let progress = match kind {
+        HairpinKind::Crescendo => progress,
+        HairpinKind::Decrescendo => 1.0 - progress,
+    };
+
+    if is_break {
+        HAIRPIN_CONTINUATION_OPENING + (HAIRPIN_OPENING - HAIRPIN_CONTINUATION_OPENING) * progress
+    } else {
+        HAIRPIN_OPENING * progress
+    }
+}
+
+impl<'a> System<'a> for PrintDynamics {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, DynamicMark>,
+        ReadStorage<'a, SpaceTimeWarp>,
+        ReadStorage<'a, LinePlacement>,
+        ReadStorage<'a, Staff>,
+        WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Hairpin>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            marks,
+            warps,
+            placements,
+            staffs,
+            mut line_of_staffs,
+            mut hairpins,
+            mut parents,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        // The width of each mark, so that hairpins can avoid them.
+        let mut mark_widths: HashMap<(Entity, Rational), f64> = HashMap::new();
+
+        for (entity, mark, parent) in (&entities, &marks, &parents).join() {
+            let bar = parent.0;
+            let stencil = mark.dynamic.print();
+            let rect = stencil.rect();
+            mark_widths.insert((bar, mark.time), rect.width());
+
+            if let (Some(warp), Some(bar_map)) = (warps.get(bar), stencil_maps.get_mut(bar)) {
+                let x = warp.t_to_x(mark.time) + NOTEHEAD_CENTER - (rect.x0 + rect.x1) / 2.0;
+                let line = placements.get(bar).and_then(|placement| {
+                    Some((placement.x, line_of_staffs.get_mut(placement.line)?))
+                });
+                let y = match line {
+                    Some((bar_x, line)) => {
+                        let (x0, x1) = (bar_x + x + rect.x0, bar_x + x + rect.x1);
+                        let y =
+                            DYNAMIC_Y.max(line.skyline.bottom(x0, x1) + DYNAMIC_PADDING - rect.y0);
+                        line.skyline.insert(rect + Vec2::new(bar_x + x, y));
+                        y
+                    }
+                    None => DYNAMIC_Y,
+                };
+                *bar_map = std::mem::take(bar_map).and(entity, Some(Vec2::new(x, y)));
+            }
+            stencils.insert(entity, stencil).unwrap();
+        }
+
+        // Where the first bar on each line starts.
+        let mut line_starts: HashMap<Entity, f64> = HashMap::new();
+        for placement in placements.join() {
+            let start = line_starts.entry(placement.line).or_insert(placement.x);
+            *start = start.min(placement.x);
+        }
+
+        let hairpin_entities: Vec<(Entity, Entity)> = (&entities, &hairpins, &parents)
+            .join()
+            .map(|(entity, _, parent)| (entity, parent.0))
+            .collect();
+
+        for (entity, start_bar) in hairpin_entities {
+            let hairpin = hairpins.get_mut(entity).unwrap();
+
+            // The line index and x of each end of the hairpin.
+            let ends = (
+                placements.get(start_bar),
+                warps.get(start_bar),
+                placements.get(hairpin.end_bar),
+                warps.get(hairpin.end_bar),
+            );
+            let (start_placement, start_warp, end_placement, end_warp) = match ends {
+                (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+                _ => continue,
+            };
+            let lines = match parents
+                .get(start_placement.line)
+                .and_then(|parent| staffs.get(parent.0))
+            {
+                Some(staff) => &staff.lines,
+                None => continue,
+            };
+            let (start_line, end_line) = match (
+                lines.iter().position(|&l| l == start_placement.line),
+                lines.iter().position(|&l| l == end_placement.line),
+            ) {
+                (Some(start_line), Some(end_line)) => (start_line, end_line),
+                _ => continue,
+            };
+
+            let mut x0 = start_placement.x + start_warp.t_to_x(hairpin.start);
+            if let Some(width) = mark_widths.get(&(start_bar, hairpin.start)) {
+                x0 += NOTEHEAD_CENTER + width / 2.0 + HAIRPIN_PADDING;
+            }
+            let mut x1 = end_placement.x + end_warp.t_to_x(hairpin.end);
+            if let Some(width) = mark_widths.get(&(hairpin.end_bar, hairpin.end)) {
+                x1 += NOTEHEAD_CENTER - width / 2.0 - HAIRPIN_PADDING;
+            }
+
+            let spans: Vec<(Entity, f64, f64)> = if end_line < start_line {
+                vec![]
+            } else {
+                (start_line..=end_line)
+                    .map(|i| {
+                        let line = lines[i];
+                        let span_x0 = if i == start_line {
+                            x0
+                        } else {
+                            line_starts.get(&line).copied().unwrap_or(0.0)
+                        };
+                        let span_x1 = if i == end_line {
+                            x1
+                        } else {
+                            line_of_staffs.get(line).map(|l| l.width).unwrap_or(0.0)
+                        };
+                        (line, span_x0, span_x1.max(span_x0))
+                    })
+                    .collect()
+            };
+
+            let total: f64 = spans.iter().map(|(_, x0, x1)| x1 - x0).sum();
+            let mut done = 0.0;
+            for (i, &(line, span_x0, span_x1)) in spans.iter().enumerate() {
+                let progress = |x: f64| if total > 0.0 { x / total } else { 0.0 };
+                let opening0 = hairpin_opening(hairpin.kind, progress(done), i != 0);
+                done += span_x1 - span_x0;
+                let opening1 = hairpin_opening(hairpin.kind, progress(done), i + 1 != spans.len());
+
+                if hairpin.segments.len() == i {
+                    hairpin.segments.push(entities.create());
+                }
+                let segment = hairpin.segments[i];
+                let stencil = Stencil::hairpin(span_x0, span_x1, opening0, opening1);
+                let rect = stencil.rect();
+                let y = match line_of_staffs.get_mut(line) {
+                    Some(line_of_staff) => {
+                        let skyline = &mut line_of_staff.skyline;
+                        let y = HAIRPIN_Y
+                            .max(skyline.bottom(rect.x0, rect.x1) + DYNAMIC_PADDING - rect.y0);
+                        skyline.insert(rect + Vec2::new(0.0, y));
+                        y
+                    }
+                    None => HAIRPIN_Y,
+                };
+                parents.insert(segment, Parent(entity)).unwrap();
+                stencils.insert(segment, stencil).unwrap();
+                if let Some(line_map) = stencil_maps.get_mut(line) {
+                    *line_map = std::mem::take(line_map).and(segment, Some(Vec2::new(0.0, y)));
+                }
+            }
+
+            // Orphaned segments are deleted by DeleteOrphans.
+            for segment in hairpin.segments.drain(spans.len()..) {
+                parents.remove(segment);
+                stencils.remove(segment);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openings() {
+        assert_eq!(hairpin_opening(HairpinKind::Crescendo, 0.0, false), 0.0);
+        assert_eq!(
+            hairpin_opening(HairpinKind::Crescendo, 1.0, false),
+            HAIRPIN_OPENING
+        );
+        assert_eq!(
+            hairpin_opening(HairpinKind::Decrescendo, 0.0, false),
+            HAIRPIN_OPENING
+        );
+        assert_eq!(hairpin_opening(HairpinKind::Decrescendo, 1.0, false), 0.0);
+
+        // Breaks are never closed.
+        assert_eq!(
+            hairpin_opening(HairpinKind::Crescendo, 0.0, true),
+            HAIRPIN_CONTINUATION_OPENING
+        );
+        assert_eq!(
+            hairpin_opening(HairpinKind::Decrescendo, 1.0, true),
+            HAIRPIN_CONTINUATION_OPENING
+        );
+        assert!(
+            hairpin_opening(HairpinKind::Crescendo, 0.5, true)
+                > hairpin_opening(HairpinKind::Crescendo, 0.25, true)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+#![allow(clippy::type_complexity)]
+
+use crate::components::{
+    Chord, Context, LegerLine, LegerLinesForChord, StemDirection, GRACE_SCALE,
+};
+use kurbo::Vec2;
+use specs::{Entities, Join, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil};
+
+#[derive(Debug, Default)]
+pub struct PrintLegerLines;
+
+/// The leger lines needed by noteheads, given as the y of each notehead and its left and right.
+///
+/// Each line spans every notehead on or beyond it, so noteheads share lines. Lines above the staff
+/// come first, from the staff outwards, then lines below the staff.
+fn leger_lines(heads: &[(f64, f64, f64)]) -> Vec<LegerLine> {
+    let mut lines = vec![];
+    for &sign in &[-1.0, 1.0] {
+        let mut y = 750.0;
+        loop {
+            let beyond = heads.iter().filter(|(head_y, _, _)| head_y * sign >= y);
+            let span = beyond.fold(None, |span: Option<(f64, f64)>, &(_, x0, x1)| {
+                Some(span.map_or((x0, x1), |(a, b)| (a.min(x0), b.max(x1))))
+            });
+            match span {
+                Some((x0, x1)) => lines.push(LegerLine {
+                    y: y * sign,
+                    x0,
+                    x1,
+                }),
+                None => break,
+            }
+            y += 250.0;
+        }
+    }
+    lines
+}
+
+impl<'a> System<'a> for PrintLegerLines {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, Context>,
+        ReadStorage<'a, StemDirection>,
+        WriteStorage<'a, LegerLinesForChord>,
+        WriteStorage<'a, LegerLine>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            chords,
+            contexts,
+            stem_directions,
+            mut leger_lines_for_chords,
+            mut leger_line_storage,
+            mut parents,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        for (chord_entity, chord, context, stem_direction) in
+            (&entities, &chords, &contexts, stem_directions.maybe()).join()
+        {
+            let ys: Vec<f64> = chord.y(context.clef).into_iter().collect();
+            let origin_y = ys.first().copied().unwrap_or(0.0);
+
+            let head_right = chord
+                .notehead(stem_direction.copied().unwrap_or(StemDirection::Up))
+                .0
+                .rect()
+                .x1;
+            let heads: Vec<(f64, f64, f64)> = ys.iter().map(|&y| (y, 0.0, head_right)).collect();
+            let lines = leger_lines(&heads);
+
+            let for_chord = leger_lines_for_chords
+                .entry(chord_entity)
+                .unwrap()
+                .or_insert_with(Default::default);
+            let count = lines.len();
+            for (i, line) in lines.into_iter().enumerate() {
+                if for_chord.0.len() == i {
+                    for_chord.0.push(entities.create());
+                }
+                let entity = for_chord.0[i];
+
+                let mut stencil = line.print();
+                if chord.grace.is_some() {
+                    // Grace notes are shrunk around their notehead.
+                    let origin = Vec2::new(0.0, origin_y);
+                    stencil = stencil
+                        .with_translation(-origin)
+                        .with_scale(GRACE_SCALE)
+                        .with_translation(origin);
+                }
+
+                parents.insert(entity, Parent(chord_entity)).unwrap();
+                stencils.insert(entity, stencil).unwrap();
+                leger_line_storage.insert(entity, line).unwrap();
+            }
+
+            // Orphaned leger lines are deleted by DeleteOrphans.
+            for entity in for_chord.0.drain(count..) {
+                parents.remove(entity);
+                stencils.remove(entity);
+                leger_line_storage.remove(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_lines() {
+        assert!(leger_lines(&[(500.0, 0.0, 300.0), (-625.0, 0.0, 300.0)]).is_empty());
+
+        let ys = |lines: Vec<LegerLine>| lines.iter().map(|line| line.y).collect::<Vec<_>>();
+        assert_eq!(ys(leger_lines(&[(875.0, 0.0, 300.0)])), vec![750.0]);
+        assert_eq!(
+            ys(leger_lines(&[(-1000.0, 0.0, 300.0), (1250.0, 0.0, 300.0)])),
+            vec![-750.0, -1000.0, 750.0, 1000.0, 1250.0]
+        );
+
+        // The line nearest the staff spans both noteheads.
+        assert_eq!(
+            leger_lines(&[(750.0, 0.0, 300.0), (1000.0, 300.0, 600.0)]),
+            vec![
+                LegerLine {
+                    y: 750.0,
+                    x0: 0.0,
+                    x1: 600.0
+                },
+                LegerLine {
+                    y: 1000.0,
+                    x0: 300.0,
+                    x1: 600.0
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::HashMap;
+
+use crate::{
+    components::{
+        Children, Context, LineOfStaff, LinePlacement, Multirest, RehearsalMark,
+        RehearsalMarkStyle, Song, Staff,
+    },
+    resources::Root,
+};
+use kurbo::Vec2;
+use rhythm::components::Bar;
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// The space between a rehearsal mark and the highest thing below it.
+const REHEARSAL_MARK_PADDING: f64 = 375.0;
+
+/// The space between marks on the same bar.
+const REHEARSAL_MARK_SPACING: f64 = 250.0;
+
+#[derive(Debug, Default)]
+pub struct PrintRehearsalMarks;
+
+impl<'a> System<'a> for PrintRehearsalMarks {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Root>,
+        ReadStorage<'a, Song>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Context>,
+        ReadStorage<'a, Multirest>,
+        ReadStorage<'a, LinePlacement>,
+        ReadStorage<'a, Parent>,
+        WriteStorage<'a, RehearsalMark>,
+        WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            root,
+            songs,
+            staffs,
+            children,
+            bars,
+            contexts,
+            multirests,
+            placements,
+            parents,
+            mut marks,
+            mut line_of_staffs,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        let style = root
+            .0
+            .and_then(|root| songs.get(root))
+            .map(|song| song.rehearsal_mark_style)
+            .unwrap_or(RehearsalMarkStyle::Letters);
+
+        let mut marks_by_bar: HashMap<Entity, Vec<Entity>> = HashMap::new();
+        for (entity, _, parent) in (&entities, &marks, &parents).join() {
+            marks_by_bar.entry(parent.0).or_default().push(entity);
+        }
+
+        // A consolidated bar's marks are drawn on its multi-measure rest.
+        let mut multirest_starts: HashMap<Entity, Entity> = HashMap::new();
+        for (entity, multirest) in (&entities, &multirests).join() {
+            if let Some(&bar) = multirest.bars.first() {
+                multirest_starts.insert(bar, entity);
+            }
+        }
+
+        for (_, staff_children) in (&staffs, &children).join() {
+            let mut letters = 0;
+            for &bar_entity in &staff_children.0 {
+                let bar_marks = match (bars.get(bar_entity), marks_by_bar.get(&bar_entity)) {
+                    (Some(_), Some(bar_marks)) => bar_marks,
+                    _ => continue,
+                };
+
+                // Where the marks go.
+                let placement = placements.get(bar_entity).or_else(|| {
+                    multirest_starts
+                        .get(&bar_entity)
+                        .and_then(|&multirest| placements.get(multirest))
+                });
+
+                let mut x = 0.0;
+                for &entity in bar_marks {
+                    let mark = marks.get_mut(entity).unwrap();
+                    mark.text = match (&mark.label, style) {
+                        (Some(label), _) => label.clone(),
+                        (None, RehearsalMarkStyle::Letters) => {
+                            letters += 1;
+                            RehearsalMark::letter(letters - 1)
+                        }
+                        (None, RehearsalMarkStyle::BarNumbers) => contexts
+                            .get(bar_entity)
+                            .map(|context| (context.bar + 1).to_string())
+                            .unwrap_or_default(),
+                    };
+
+                    let stencil = mark.print();
+                    let rect = stencil.rect();
+                    stencils.insert(entity, stencil).unwrap();
+
+                    let (placement, line) = match placement.and_then(|placement| {
+                        Some((placement, line_of_staffs.get_mut(placement.line)?))
+                    }) {
+                        Some(placement) => placement,
+                        None => continue,
+                    };
+                    let x0 = placement.x + x;
+                    let y = line.skyline.top(x0 + rect.x0, x0 + rect.x1) - REHEARSAL_MARK_PADDING;
+                    line.skyline.insert(rect + Vec2::new(x0, y));
+                    if let Some(line_map) = stencil_maps.get_mut(placement.line) {
+                        *line_map = std::mem::take(line_map).and(entity, Some(Vec2::new(x0, y)));
+                    }
+                    x += rect.width() + REHEARSAL_MARK_SPACING;
+                }
+            }
+        }
+    }
+}
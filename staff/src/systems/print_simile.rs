@@ -0,0 +1,100 @@
+#![allow(clippy::type_complexity)]
+
+use crate::components::{
+    AccidentalsForChord, Children, LegerLinesForChord, Signature, Simile, Staff,
+};
+use kurbo::Vec2;
+use rhythm::{components::Bar, BarChild};
+use specs::{Join, ReadStorage, System, WriteStorage};
+use stencil::components::Stencil;
+
+/// The space before a repeat sign, or half of the space taken by a two-bar repeat sign in each
+/// of its bars.
+const SIMILE_PADDING: f64 = 400.0;
+
+#[derive(Debug, Default)]
+pub struct PrintSimile;
+
+impl<'a> System<'a> for PrintSimile {
+    type SystemData = (
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Simile>,
+        ReadStorage<'a, Signature>,
+        ReadStorage<'a, AccidentalsForChord>,
+        ReadStorage<'a, LegerLinesForChord>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            staffs,
+            children,
+            bars,
+            similes,
+            signatures,
+            accidentals_for_chords,
+            leger_lines_for_chords,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        for (_, staff_children) in (&staffs, &children).join() {
+            let replaced = Simile::replaced_bars(&staff_children.0, &bars, &similes);
+            let mut two_bar_start = false;
+            for &child in &staff_children.0 {
+                let bar = match bars.get(child) {
+                    Some(bar) => bar,
+                    None => {
+                        // A two-bar repeat sign is written over the barline between its bars.
+                        if let (true, Some(signature)) = (two_bar_start, signatures.get(child)) {
+                            if let Some(stencil) = stencils.get_mut(signature.stencil_middle) {
+                                let rect = stencil.rect();
+                                let sign = Stencil::repeat_2_bars();
+                                let sign_rect = sign.rect();
+                                *stencil =
+                                    std::mem::take(stencil).and(sign.with_translation(Vec2::new(
+                                        (rect.x0 + rect.x1 - sign_rect.x0 - sign_rect.x1) / 2.0,
+                                        0.0,
+                                    )));
+                            }
+                            two_bar_start = false;
+                        }
+                        continue;
+                    }
+                };
+                let simile = match replaced.get(&child) {
+                    Some(&simile) => simile,
+                    None => continue,
+                };
+
+                // The notes that are repeated are not drawn.
+                let bar_children = bar.children();
+                for BarChild { stencil, .. } in &bar_children {
+                    let accidentals = accidentals_for_chords.get(*stencil).map(|a| &a.0);
+                    let leger_lines = leger_lines_for_chords.get(*stencil).map(|l| &l.0);
+                    let parts = accidentals.into_iter().chain(leger_lines).flatten();
+                    for &entity in std::iter::once(stencil).chain(parts) {
+                        if let Some(stencil) = stencils.get_mut(entity) {
+                            *stencil = Stencil::default();
+                        }
+                    }
+                }
+
+                let sign = match simile {
+                    Simile::OneBar => Stencil::padding(SIMILE_PADDING)
+                        .and_right(Stencil::repeat_1_bar())
+                        .and_right(Stencil::padding(SIMILE_PADDING)),
+                    Simile::TwoBars => {
+                        two_bar_start = similes.contains(child);
+                        Stencil::padding(SIMILE_PADDING)
+                    }
+                };
+                if let Some(first) = bar_children.iter().find(|child| !child.grace) {
+                    stencils.insert(first.stencil, sign).unwrap();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::HashMap;
+
+use crate::components::{
+    Beam, BeamForChord, Children, Chord, Context, LineOfStaff, LinePlacement, Slur, Staff,
+    StemDirection,
+};
+use kurbo::{CubicBez, Point, Rect};
+use rhythm::{components::Bar, components::Spacing, BarChild};
+use specs::{Entities, Entity, Join, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// The space between a slur and the notes, stems and beams it avoids.
+const CLEARANCE: f64 = 125.0;
+
+/// The shortest and tallest a slur is, without collisions.
+const MIN_HEIGHT: f64 = 125.0;
+const MAX_HEIGHT: f64 = 500.0;
+
+/// Beyond this, slurs are moved away from the notes instead of curving further.
+const MAX_COLLISION_HEIGHT: f64 = 1000.0;
+
+/// Slurs start and end over the middle of the notehead.
+const NOTEHEAD_CENTER: f64 = 147.5;
+
+#[derive(Debug, Default)]
+pub struct PrintSlur;
+
+/// A chord, where it was placed, and how far it extends above and below.
+struct Placed {
+    entity: Entity,
+    line: usize,
+    x: f64,
+    /// The y of the notehead, or None for rests.
+    y: Option<f64>,
+    top: f64,
+    bottom: f64,
+    stem_direction: Option<StemDirection>,
+}
+
+impl Placed {
+    /// The outermost y in the given direction.
+    fn extreme(&self, above: bool) -> f64 {
+        if above {
+            self.top
+        } else {
+            self.bottom
+        }
+    }
+
+    /// Where a slur on the given side starts or ends.
+    fn anchor(&self, above: bool) -> Point {
+        let sign = if above { -1.0 } else { 1.0 };
+        let stem_on_side = match self.stem_direction {
+            Some(StemDirection::Up) => above,
+            Some(StemDirection::Down) => !above,
+            None => false,
+        };
+
+        match self.y {
+            Some(y) if !stem_on_side => Point::new(self.x + NOTEHEAD_CENTER, y + sign * 250.0),
+            _ => Point::new(
+                self.x + NOTEHEAD_CENTER,
+                self.extreme(above) + sign * CLEARANCE,
+            ),
+        }
+    }
+}
+
+/// The inner edge of a slur from `p0` to `p3`, clearing `obstacles`.
+///
+/// Slurs curve more when they are longer, and when they need to avoid notes. If curving is not
+/// enough, the slur is moved away from the notes.
+fn slur_curve(mut p0: Point, mut p3: Point, obstacles: &[Point], above: bool) -> CubicBez {
+    let sign = if above { -1.0 } else { 1.0 };
+    let width = p3.x - p0.x;
+    if width <= 0.0 {
+        return CubicBez::new(p0, p0, p3, p3);
+    }
+
+    // How far each obstacle is outside the straight line between the ends, plus clearance, at
+    // `t` along the slur.
+    let needs: Vec<(f64, f64)> = obstacles
+        .iter()
+        .filter(|o| o.x > p0.x && o.x < p3.x)
+        .map(|o| {
+            let t = (o.x - p0.x) / width;
+            let line_y = p0.y + (p3.y - p0.y) * t;
+            (t, sign * (o.y - line_y) + CLEARANCE)
+        })
+        .collect();
+
+    // The control points are 4/3 as far out as the middle of the curve, and the offset at `t` is
+    // 3t(1 - t) of the control offset.
+    let mut control = (width * 0.1).clamp(MIN_HEIGHT, MAX_HEIGHT) * 4.0 / 3.0;
+    for &(t, need) in &needs {
+        control = control.max(need / (3.0 * t * (1.0 - t)));
+    }
+    control = control.min(MAX_COLLISION_HEIGHT * 4.0 / 3.0);
+
+    let lift = needs
+        .iter()
+        .map(|&(t, need)| need - 3.0 * t * (1.0 - t) * control)
+        .fold(0.0, f64::max);
+    p0.y += sign * lift;
+    p3.y += sign * lift;
+
+    let p1 = Point::new(
+        p0.x + width / 3.0,
+        p0.y + (p3.y - p0.y) / 3.0 + sign * control,
+    );
+    let p2 = Point::new(
+        p0.x + width * 2.0 / 3.0,
+        p0.y + (p3.y - p0.y) * 2.0 / 3.0 + sign * control,
+    );
+
+    CubicBez::new(p0, p1, p2, p3)
+}
+
+impl<'a> System<'a> for PrintSlur {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, Context>,
+        ReadStorage<'a, StemDirection>,
+        ReadStorage<'a, Spacing>,
+        ReadStorage<'a, Beam>,
+        ReadStorage<'a, BeamForChord>,
+        ReadStorage<'a, LinePlacement>,
+        WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Slur>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            staffs,
+            children,
+            bars,
+            chords,
+            contexts,
+            stem_directions,
+            spacings,
+            beams,
+            beam_for_chords,
+            placements,
+            mut line_of_staffs,
+            mut slurs,
+            mut parents,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        // Every chord of each staff, in order, and where it is in that list.
+        let mut staff_chords: Vec<(&Staff, Vec<Placed>)> = vec![];
+        let mut chord_index: HashMap<Entity, (usize, usize)> = HashMap::new();
+        // Where the first bar on each line starts.
+        let mut line_starts: HashMap<Entity, f64> = HashMap::new();
+
+        for staff in staffs.join() {
+            let mut placed = vec![];
+            for (line_idx, &line) in staff.lines.iter().enumerate() {
+                let line_children = match children.get(line) {
+                    Some(Children(line_children)) => line_children,
+                    None => continue,
+                };
+                for &bar_entity in line_children {
+                    let (bar, placement) = match (bars.get(bar_entity), placements.get(bar_entity))
+                    {
+                        (Some(bar), Some(placement)) => (bar, placement),
+                        _ => continue,
+                    };
+                    let line_start = line_starts.entry(line).or_insert(placement.x);
+                    *line_start = line_start.min(placement.x);
+
+                    for BarChild { stencil, .. } in bar.children() {
+                        let (chord, spacing) = match (chords.get(stencil), spacings.get(stencil)) {
+                            (Some(chord), Some(spacing)) => (chord, spacing),
+                            _ => continue,
+                        };
+                        let x = placement.x + spacing.start_x;
+                        let rect = stencils.get(stencil).map(|s| s.rect()).unwrap_or_default();
+                        let mut rect = Rect::new(x + rect.x0, rect.y0, x + rect.x1, rect.y1);
+
+                        // Beamed stems are drawn by the beam.
+                        let beam_attachment = beam_for_chords
+                            .get(stencil)
+                            .and_then(|beam| beams.get(beam.0))
+                            .and_then(|beam| {
+                                beam.0.iter().min_by(|a, b| {
+                                    let da = (a.stem_start.x - spacing.start_x).abs();
+                                    let db = (b.stem_start.x - spacing.start_x).abs();
+                                    da.partial_cmp(&db).unwrap()
+                                })
+                            });
+                        if let Some(attachment) = beam_attachment {
+                            rect.y0 = rect.y0.min(attachment.extreme_y);
+                            rect.y1 = rect.y1.max(attachment.extreme_y);
+                        }
+
+                        let y = contexts
+                            .get(stencil)
+                            .and_then(|context| chord.y(context.clef));
+
+                        chord_index.insert(stencil, (staff_chords.len(), placed.len()));
+                        placed.push(Placed {
+                            entity: stencil,
+                            line: line_idx,
+                            x,
+                            y,
+                            top: rect.y0,
+                            bottom: rect.y1,
+                            stem_direction: stem_directions.get(stencil).copied(),
+                        });
+                    }
+                }
+            }
+            staff_chords.push((staff, placed));
+        }
+
+        let slur_entities: Vec<Entity> = (&entities, &slurs).join().map(|(e, _)| e).collect();
+        for entity in slur_entities {
+            let slur = slurs.get_mut(entity).unwrap();
+
+            // The line entity, and each end of the slur on that line.
+            let mut spans: Vec<(Entity, CubicBez, bool)> = vec![];
+            if let (Some(&(staff_idx, start)), Some(&(end_staff_idx, end))) =
+                (chord_index.get(&slur.start), chord_index.get(&slur.end))
+            {
+                let (staff, placed) = &staff_chords[staff_idx];
+                if staff_idx == end_staff_idx && start < end {
+                    let notes = &placed[start..=end];
+
+                    // Slurs go on the notehead side if all stems agree, and above otherwise.
+                    let above = !notes
+                        .iter()
+                        .filter(|note| note.y.is_some())
+                        .all(|note| note.stem_direction == Some(StemDirection::Up));
+
+                    let first = &placed[start];
+                    let last = &placed[end];
+                    for line_idx in first.line..=last.line {
+                        let line = staff.lines[line_idx];
+                        let p0 = if line_idx == first.line {
+                            first.anchor(above)
+                        } else {
+                            let y = last.anchor(above).y;
+                            let x = line_starts.get(&line).copied().unwrap_or(0.0) - CLEARANCE;
+                            Point::new(x, y)
+                        };
+                        let p3 = if line_idx == last.line {
+                            last.anchor(above)
+                        } else {
+                            let y = first.anchor(above).y;
+                            let x = line_of_staffs.get(line).map(|l| l.width).unwrap_or(0.0);
+                            Point::new(x, y)
+                        };
+
+                        let obstacles: Vec<Point> = notes
+                            .iter()
+                            .filter(|note| {
+                                note.line == line_idx
+                                    && note.entity != first.entity
+                                    && note.entity != last.entity
+                            })
+                            .map(|note| Point::new(note.x + NOTEHEAD_CENTER, note.extreme(above)))
+                            .collect();
+
+                        spans.push((line, slur_curve(p0, p3, &obstacles, above), above));
+                    }
+                }
+            }
+
+            for (i, &(line, curve, above)) in spans.iter().enumerate() {
+                if slur.segments.len() == i {
+                    slur.segments.push(entities.create());
+                }
+                let segment = slur.segments[i];
+                parents.insert(segment, Parent(entity)).unwrap();
+                let stencil = Stencil::slur(curve, above);
+                if let Some(line_of_staff) = line_of_staffs.get_mut(line) {
+                    line_of_staff.skyline.insert(stencil.rect());
+                }
+                stencils.insert(segment, stencil).unwrap();
+                if let Some(line_map) = stencil_maps.get_mut(line) {
+                    *line_map = std::mem::take(line_map).and(segment, None);
+                }
+            }
+
+            // Orphaned segments are deleted by DeleteOrphans.
+            for segment in slur.segments.drain(spans.len()..) {
+                parents.remove(segment);
+                stencils.remove(segment);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::ParamCurve;
+
+    #[test]
+    fn unobstructed() {
+        let curve = slur_curve(
+            Point::new(0.0, -500.0),
+            Point::new(2000.0, -500.0),
+            &[],
+            true,
+        );
+        assert_eq!(curve.eval(0.0), Point::new(0.0, -500.0));
+        assert_eq!(curve.eval(1.0), Point::new(2000.0, -500.0));
+        assert!((curve.eval(0.5).y - (-700.0)).abs() < 1e-9);
+
+        // Short slurs still curve, and long slurs do not curve too much.
+        let short = slur_curve(Point::new(0.0, 0.0), Point::new(100.0, 0.0), &[], false);
+        assert!((short.eval(0.5).y - MIN_HEIGHT).abs() < 1e-9);
+        let long = slur_curve(Point::new(0.0, 0.0), Point::new(1e5, 0.0), &[], false);
+        assert!((long.eval(0.5).y - MAX_HEIGHT).abs() < 1e-9);
+    }
+
+    #[test]
+    fn avoids_obstacles() {
+        // A tall note in the middle.
+        let obstacle = Point::new(1000.0, -1200.0);
+        let curve = slur_curve(
+            Point::new(0.0, -500.0),
+            Point::new(2000.0, -500.0),
+            &[obstacle],
+            true,
+        );
+        assert!(curve.eval(0.5).y <= obstacle.y - CLEARANCE + 1e-9);
+        assert_eq!(curve.eval(0.0), Point::new(0.0, -500.0));
+
+        // A stem right next to the end of the slur moves the whole slur.
+        let obstacle = Point::new(50.0, 1500.0);
+        let curve = slur_curve(
+            Point::new(0.0, 500.0),
+            Point::new(2000.0, 500.0),
+            &[obstacle],
+            false,
+        );
+        assert!(curve.eval(0.0).y > 500.0);
+        assert!(curve.eval(0.025).y >= obstacle.y + CLEARANCE - 1e-9);
+    }
+}
@@ -2,7 +2,10 @@
 
 use std::collections::BTreeSet;
 
-use crate::components::{BeamForChord, Children, LineOfStaff};
+use crate::components::{
+    AccidentalsForChord, BeamForChord, Children, LegerLinesForChord, LineOfStaff, LinePlacement,
+    Multirest,
+};
 use crate::systems::break_into_lines::STAFF_MARGIN;
 use kurbo::{Rect, Vec2};
 use rhythm::{components::Bar, components::Spacing, BarChild};
@@ -17,10 +20,14 @@ impl<'a> System<'a> for PrintStaff {
         Entities<'a>,
         ReadStorage<'a, Bar>,
         ReadStorage<'a, BeamForChord>,
+        ReadStorage<'a, AccidentalsForChord>,
+        ReadStorage<'a, LegerLinesForChord>,
         ReadStorage<'a, Spacing>,
         ReadStorage<'a, Stencil>,
         ReadStorage<'a, Children>,
+        ReadStorage<'a, Multirest>,
         WriteStorage<'a, LineOfStaff>,
+        WriteStorage<'a, LinePlacement>,
         WriteStorage<'a, StencilMap>,
     );
 
@@ -30,10 +37,14 @@ impl<'a> System<'a> for PrintStaff {
             entities,
             bars,
             beam_for_chords,
+            accidentals_for_chords,
+            leger_lines_for_chords,
             spacings,
             stencils,
             children,
+            multirests,
             mut line_of_staffs,
+            mut line_placements,
             mut stencil_maps,
         ): Self::SystemData,
     ) {
@@ -62,6 +73,14 @@ impl<'a> System<'a> for PrintStaff {
                             beams.insert(beam.0);
                         }
 
+                        let accidentals = accidentals_for_chords.get(stencil).map(|a| &a.0);
+                        let leger_lines = leger_lines_for_chords.get(stencil).map(|l| &l.0);
+                        let parts = accidentals.into_iter().chain(leger_lines).flatten();
+                        for &part in parts {
+                            bar_stencil = bar_stencil
+                                .and(part, Some(Vec2::new(relative_spacing.start_x, 0.0)));
+                        }
+
                         if let Some(Children(children)) = children.get(stencil) {
                             for &child in children {
                                 bar_stencil = bar_stencil
@@ -76,10 +95,28 @@ impl<'a> System<'a> for PrintStaff {
                     bar_stencil.set_explicit_rect(Rect::new(start, -1500f64, advance, 1500f64));
 
                     stencil_maps.entry(child).unwrap().replace(bar_stencil);
+                    line_placements
+                        .entry(child)
+                        .unwrap()
+                        .replace(LinePlacement {
+                            line: entity,
+                            x: staff_advance,
+                        });
 
                     staff_stencil = staff_stencil.and(child, Some(Vec2::new(staff_advance, 0.0)));
                     staff_advance += advance;
                 } else if let Some(stencil) = stencils.get(child) {
+                    if multirests.contains(child) {
+                        line_placements
+                            .insert(
+                                child,
+                                LinePlacement {
+                                    line: entity,
+                                    x: staff_advance,
+                                },
+                            )
+                            .unwrap();
+                    }
                     staff_stencil = staff_stencil.and(child, Some(Vec2::new(staff_advance, 0.0)));
                     if let Some(Children(children)) = children.get(child) {
                         for &child in children {
@@ -1,29 +1,147 @@
+#![allow(clippy::type_complexity)]
+
 use kurbo::Vec2;
 
-use crate::components::LineOfStaff;
+use crate::components::{
+    Children, Chord, LineOfStaff, LinePlacement, Simile, Staff, TabNote, TabStaff, GRACE_SCALE,
+};
 use crate::systems::break_into_lines::STAFF_MARGIN;
-use specs::{Join, ReadStorage, System, WriteStorage};
+use rhythm::{
+    components::{Bar, Spacing},
+    BarChild,
+};
+use specs::{Entities, Entity, Join, ReadStorage, System, WriteStorage};
+use std::collections::HashMap;
 use stencil::components::Stencil;
 
+/// The space between a line of tablature and the fret numbers on it.
+const FRET_NUMBER_PADDING: f64 = 40.0;
+
 #[derive(Debug, Default)]
 pub struct PrintStaffLines;
 
+/// The parts of each string of a line of tablature which are covered by fret numbers.
+type Gaps = HashMap<u8, Vec<(f64, f64)>>;
+
+/// Prints a line from `start` to `end` with breaks at `gaps`, which may overlap.
+fn print_broken_line(start: f64, end: f64, y: f64, gaps: &[(f64, f64)]) -> Stencil {
+    let mut gaps = gaps.to_vec();
+    gaps.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut stencil = Stencil::default();
+    let mut x = start;
+    for (gap_start, gap_end) in gaps.into_iter().chain(std::iter::once((end, end))) {
+        let gap_start = gap_start.min(end);
+        if gap_start > x {
+            stencil =
+                stencil.and(Stencil::staff_line(gap_start - x).with_translation(Vec2::new(x, y)));
+        }
+        x = x.max(gap_end);
+    }
+
+    stencil
+}
+
 impl<'a> System<'a> for PrintStaffLines {
-    type SystemData = (ReadStorage<'a, LineOfStaff>, WriteStorage<'a, Stencil>);
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, TabStaff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Simile>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, TabNote>,
+        ReadStorage<'a, Spacing>,
+        ReadStorage<'a, LinePlacement>,
+        ReadStorage<'a, LineOfStaff>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            staffs,
+            tab_staffs,
+            children,
+            bars,
+            similes,
+            chords,
+            tab_notes,
+            spacings,
+            line_placements,
+            lines,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        // Lines of tablature are broken around fret numbers.
+        let mut tab_lines: HashMap<Entity, (&TabStaff, Gaps)> = HashMap::new();
+        for (staff, tab_staff, staff_children) in (&staffs, &tab_staffs, &children).join() {
+            for &line in &staff.lines {
+                tab_lines.insert(line, (tab_staff, Gaps::new()));
+            }
+
+            let replaced = Simile::replaced_bars(&staff_children.0, &bars, &similes);
+            for &child in &staff_children.0 {
+                let (bar, placement) = match (bars.get(child), line_placements.get(child)) {
+                    (Some(bar), Some(placement)) if !replaced.contains_key(&child) => {
+                        (bar, placement)
+                    }
+                    _ => continue,
+                };
+                let gaps = match tab_lines.get_mut(&placement.line) {
+                    Some((_, gaps)) => gaps,
+                    None => continue,
+                };
+                for BarChild { stencil, .. } in bar.children() {
+                    let (chord, note, spacing) = match (
+                        chords.get(stencil),
+                        tab_notes.get(stencil),
+                        spacings.get(stencil),
+                    ) {
+                        (Some(chord), Some(note), Some(spacing)) => (chord, note, spacing),
+                        _ => continue,
+                    };
+                    let mut number = Stencil::fret_number(note.fret);
+                    if chord.grace.is_some() {
+                        number = number.with_scale(GRACE_SCALE);
+                    }
+                    let rect = number.rect();
+                    let x = placement.x + spacing.start_x;
+                    gaps.entry(note.string).or_default().push((
+                        x + rect.x0 - FRET_NUMBER_PADDING,
+                        x + rect.x1 + FRET_NUMBER_PADDING,
+                    ));
+                }
+            }
+        }
 
-    fn run(&mut self, (staffs, mut stencils): Self::SystemData) {
-        for staff in staffs.join() {
-            // TODO: coordinate advance with sys_print_staff.
+        for (entity, line) in (&entities, &lines).join() {
+            let start = STAFF_MARGIN;
+            let end = line.width;
             let mut stencil = Stencil::default();
 
-            for i in -2..=2 {
-                stencil = stencil.and(
-                    Stencil::staff_line(staff.width - STAFF_MARGIN)
-                        .with_translation(Vec2::new(STAFF_MARGIN, (i * 250) as f64)),
-                );
+            match tab_lines.get(&entity) {
+                Some((tab_staff, gaps)) => {
+                    for string in 1..=tab_staff.tuning.len() as u8 {
+                        let y = tab_staff.string_y(string);
+                        let gaps = gaps.get(&string).map(|g| &g[..]).unwrap_or_default();
+                        stencil = stencil.and(print_broken_line(start, end, y, gaps));
+                    }
+                }
+                None => {
+                    // TODO: coordinate advance with sys_print_staff.
+                    for i in -2..=2 {
+                        stencil = stencil.and(
+                            Stencil::staff_line(end - start)
+                                .with_translation(Vec2::new(start, (i * 250) as f64)),
+                        );
+                    }
+                }
             }
 
-            stencils.entry(staff.staff_lines).unwrap().replace(stencil);
+            stencils.entry(line.staff_lines).unwrap().replace(stencil);
         }
     }
 }
@@ -0,0 +1,75 @@
+#![allow(clippy::type_complexity)]
+
+use crate::components::{
+    AccidentalsForChord, BeamForChord, Children, Chord, FlagAttachment, LegerLinesForChord,
+    TabNote, TabStaff,
+};
+use rhythm::{components::Bar, BarChild};
+use specs::{Join, ReadStorage, System, WriteStorage};
+use stencil::components::Stencil;
+
+/// Prints notes on tab staffs as fret numbers, replacing their notehead, accidentals and leger
+/// lines.
+#[derive(Debug, Default)]
+pub struct PrintTablature;
+
+impl<'a> System<'a> for PrintTablature {
+    type SystemData = (
+        ReadStorage<'a, TabStaff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, TabNote>,
+        ReadStorage<'a, BeamForChord>,
+        ReadStorage<'a, AccidentalsForChord>,
+        ReadStorage<'a, LegerLinesForChord>,
+        WriteStorage<'a, FlagAttachment>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            tab_staffs,
+            children,
+            bars,
+            chords,
+            tab_notes,
+            beam_for_chords,
+            accidentals_for_chords,
+            leger_lines_for_chords,
+            mut attachments,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        for (tab_staff, staff_children) in (&tab_staffs, &children).join() {
+            for bar in staff_children.0.iter().filter_map(|&child| bars.get(child)) {
+                for BarChild { stencil, .. } in bar.children() {
+                    let (chord, note) = match (chords.get(stencil), tab_notes.get(stencil)) {
+                        (Some(chord), Some(note)) => (chord, note),
+                        _ => continue,
+                    };
+
+                    let (printed, attachment) = chord.print_tab(
+                        tab_staff.string_y(note.string),
+                        note.fret,
+                        beam_for_chords.contains(stencil),
+                    );
+                    stencils.insert(stencil, printed).unwrap();
+                    attachments
+                        .insert(stencil, FlagAttachment(attachment))
+                        .unwrap();
+
+                    let accidentals = accidentals_for_chords.get(stencil).map(|a| &a.0);
+                    let leger_lines = leger_lines_for_chords.get(stencil).map(|l| &l.0);
+                    let parts = accidentals.into_iter().chain(leger_lines).flatten();
+                    for &part in parts {
+                        if let Some(stencil) = stencils.get_mut(part) {
+                            *stencil = Stencil::default();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
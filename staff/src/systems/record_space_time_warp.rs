@@ -1,6 +1,6 @@
 use crate::{components::SpaceTimeWarp, resources::KeepSpacing};
 use rhythm::{components::Bar, components::Spacing, BarChild};
-use specs::{Join, ReadStorage, System, WriteStorage, Read};
+use specs::{Join, Read, ReadStorage, System, WriteStorage};
 
 #[derive(Debug, Default)]
 pub struct RecordSpaceTimeWarp;
@@ -21,7 +21,17 @@ impl<'a> System<'a> for RecordSpaceTimeWarp {
         for (bar, warp_entry) in (&bars, warps.entries()).join() {
             let mut warp = Vec::new();
             let mut max_x = 0.0;
-            for BarChild { start, stencil, .. } in bar.children() {
+            for BarChild {
+                start,
+                stencil,
+                grace,
+                ..
+            } in bar.children()
+            {
+                // Grace notes are before their time.
+                if grace {
+                    continue;
+                }
                 if let Some(spacing) = rel_spacings.get(stencil) {
                     warp.push((start, spacing.start_x));
                     max_x = spacing.end_x.max(max_x);
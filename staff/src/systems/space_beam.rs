@@ -1,26 +1,120 @@
 #![allow(clippy::type_complexity)]
 
-use crate::components::{Beam, BeamForChord, FlagAttachment};
+use crate::{
+    components::{Beam, BeamForChord, FlagAttachment, StemDirection, GRACE_SCALE},
+    BeamAttachment,
+};
 use kurbo::Point;
 use rhythm::{components::Bar, components::Spacing, BarChild};
-use specs::{Join, ReadStorage, System, WriteStorage};
+use specs::{Entity, Join, ReadStorage, System, WriteStorage};
+use std::collections::HashMap;
+
+/// The distance between the centres of two stacked beams.
+pub(crate) const BEAM_SPACING: f64 = 187.5;
+
+/// The usual length of a stem, 3.5 spaces.
+const STEM_LENGTH: f64 = 875.0;
+
+/// Beams never rise or fall more than a space.
+const MAX_BEAM_RISE: f64 = 250.0;
 
 #[derive(Debug, Default)]
 pub struct SpaceBeam;
 
+/// How far the beam should rise (negative) or fall (positive) from the first to the last stem,
+/// given the y of each stem's notehead.
+///
+/// The beam follows the contour of the first and last notes, but is flat if an inner note is closer
+/// to the beam than both outer notes, and slopes less than the interval it spans.
+fn beam_rise(ys: &[f64], stem_direction: StemDirection) -> f64 {
+    let (first, last) = match (ys.first(), ys.last()) {
+        (Some(&first), Some(&last)) if ys.len() > 1 => (first, last),
+        _ => return 0.0,
+    };
+
+    let inner = &ys[1..ys.len() - 1];
+    let concave = match stem_direction {
+        StemDirection::Up => inner.iter().any(|&y| y < first.min(last)),
+        StemDirection::Down => inner.iter().any(|&y| y > first.max(last)),
+    };
+    if concave {
+        return 0.0;
+    }
+
+    // Steps between the first and last note. A step is half a space.
+    let steps = ((last - first).abs() / 125.0).round() as usize;
+    let rise = match steps {
+        0 => 0.0,
+        1 => 62.5,
+        2 => 125.0,
+        3 => 187.5,
+        _ => MAX_BEAM_RISE,
+    };
+
+    rise * (last - first).signum()
+}
+
+/// Sets the `extreme_y` of every stem in a beamed group.
+///
+/// Every stem is at least `STEM_LENGTH`, plus room for any beams past the second, and reaches
+/// the middle line. Grace notes have short stems, and do not need to reach the middle line.
+fn place_beam(attachments: &mut [BeamAttachment]) {
+    let (first, last) = match (attachments.first(), attachments.last()) {
+        (Some(first), Some(last)) => (first.stem_start, last.stem_start),
+        _ => return,
+    };
+    let stem_direction = attachments[0].stem_direction;
+    let is_grace = attachments[0].grace.is_some();
+    let scale = if is_grace { GRACE_SCALE } else { 1.0 };
+    let ys: Vec<f64> = attachments.iter().map(|a| a.stem_start.y).collect();
+    let rise = beam_rise(&ys, stem_direction) * scale;
+    let offset = |x: f64| {
+        if last.x > first.x {
+            rise * (x - first.x) / (last.x - first.x)
+        } else {
+            0.0
+        }
+    };
+
+    let constraints = attachments.iter().flat_map(|attachment| {
+        let beams = attachment.entering.max(attachment.leaving);
+        let length = (STEM_LENGTH + BEAM_SPACING * (beams.saturating_sub(2) as f64)) * scale;
+        let offset = offset(attachment.stem_start.x);
+        let mut constraints =
+            vec![attachment.stem_start.y - stem_direction.sign() * length - offset];
+        if !is_grace {
+            constraints.push(-offset);
+        }
+        constraints
+    });
+
+    let start = match stem_direction {
+        StemDirection::Up => constraints.fold(f64::INFINITY, f64::min),
+        StemDirection::Down => constraints.fold(f64::NEG_INFINITY, f64::max),
+    };
+
+    for attachment in attachments {
+        attachment.extreme_y = start + offset(attachment.stem_start.x);
+    }
+}
+
 impl<'a> System<'a> for SpaceBeam {
     type SystemData = (
         ReadStorage<'a, Bar>,
         ReadStorage<'a, Spacing>,
         ReadStorage<'a, BeamForChord>,
         ReadStorage<'a, FlagAttachment>,
+        ReadStorage<'a, StemDirection>,
         WriteStorage<'a, Beam>,
     );
 
-    fn run(&mut self, (bars, spacings, beam_for_chords, attachments, mut beams): Self::SystemData) {
+    fn run(
+        &mut self,
+        (bars, spacings, beam_for_chords, attachments, stem_directions, mut beams): Self::SystemData,
+    ) {
         for bar in bars.join() {
-            let mut prev_beam = None;
-            let mut idx_in_beam = 0;
+            // Grace notes may be beamed within a beamed group, so beams can be interleaved.
+            let mut idx_in_beam: HashMap<Entity, usize> = HashMap::new();
 
             for BarChild { stencil, .. } in bar.children() {
                 if let (
@@ -36,18 +130,88 @@ impl<'a> System<'a> for SpaceBeam {
                     spacings.get(stencil),
                     attachments.get(stencil),
                 ) {
-                    if Some(beam_id) != prev_beam {
-                        idx_in_beam = 0;
-                    }
-                    if let Some(beam_attachment) = beam.0.get_mut(idx_in_beam) {
+                    let idx_in_beam = idx_in_beam.entry(*beam_id).or_insert(0);
+                    if let Some(beam_attachment) = beam.0.get_mut(*idx_in_beam) {
                         beam_attachment.stem_start =
                             Point::new(spacing.start_x + attachment.x, attachment.y);
-                        beam_attachment.extreme_y = -1000.0;
+                        beam_attachment.stem_direction = stem_directions
+                            .get(stencil)
+                            .copied()
+                            .unwrap_or(StemDirection::Up);
                     }
-                    idx_in_beam += 1;
-                    prev_beam = Some(beam_id);
+                    *idx_in_beam += 1;
                 }
             }
         }
+
+        for beam in (&mut beams).join() {
+            place_beam(&mut beam.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(x: f64, y: f64, stem_direction: StemDirection) -> BeamAttachment {
+        BeamAttachment {
+            stem_start: Point::new(x, y),
+            extreme_y: 0.0,
+            entering: 1,
+            leaving: 1,
+            stem_direction,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn rise() {
+        // Follows the contour.
+        assert_eq!(beam_rise(&[250.0, 125.0], StemDirection::Up), -62.5);
+        assert_eq!(beam_rise(&[0.0, 250.0, 375.0], StemDirection::Up), 187.5);
+        assert_eq!(beam_rise(&[-250.0, -500.0], StemDirection::Down), -125.0);
+
+        // Clamped.
+        assert_eq!(
+            beam_rise(&[-1000.0, 1000.0], StemDirection::Up),
+            MAX_BEAM_RISE
+        );
+
+        // Flat when concave, or repeated.
+        assert_eq!(beam_rise(&[250.0, -250.0, 0.0], StemDirection::Up), 0.0);
+        assert_eq!(beam_rise(&[-250.0, 250.0, 0.0], StemDirection::Down), 0.0);
+        assert_eq!(beam_rise(&[250.0, 0.0, 250.0], StemDirection::Down), 0.0);
+        assert_eq!(beam_rise(&[250.0], StemDirection::Up), 0.0);
+    }
+
+    #[test]
+    fn stem_length() {
+        // Flat, with the shortest stem at the usual length.
+        let mut attachments = [
+            attachment(0.0, 250.0, StemDirection::Up),
+            attachment(500.0, -250.0, StemDirection::Up),
+            attachment(1000.0, 250.0, StemDirection::Up),
+        ];
+        place_beam(&mut attachments);
+        assert_eq!(attachments[0].extreme_y, -250.0 - STEM_LENGTH);
+        assert_eq!(attachments[1].extreme_y, -250.0 - STEM_LENGTH);
+
+        // Notes far below the staff reach the middle line.
+        let mut attachments = [
+            attachment(0.0, 1500.0, StemDirection::Up),
+            attachment(500.0, 1500.0, StemDirection::Up),
+        ];
+        place_beam(&mut attachments);
+        assert_eq!(attachments[0].extreme_y, 0.0);
+
+        // Sloped, stems down.
+        let mut attachments = [
+            attachment(0.0, -500.0, StemDirection::Down),
+            attachment(1000.0, 0.0, StemDirection::Down),
+        ];
+        place_beam(&mut attachments);
+        assert_eq!(attachments[0].extreme_y, STEM_LENGTH - MAX_BEAM_RISE);
+        assert_eq!(attachments[1].extreme_y, STEM_LENGTH);
     }
 }
@@ -1,40 +1,56 @@
 #![allow(clippy::type_complexity)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
-    components::{Children, Chord, Context, Signature, Staff},
+    components::{Children, Chord, Context, Signature, Song, Staff},
+    resources::Root,
     PitchKind,
 };
 use num_rational::Rational;
-use pitch::{key_signature_note_names, Clef, NoteModifier, NoteName};
+use pitch::{key_signature_note_names, transpose_key, Interval, NoteModifier, NoteName};
 use rhythm::{components::Bar, BarChild};
-use specs::{Join, ReadStorage, System, WriteStorage};
+use specs::{Join, Read, ReadStorage, System, WriteStorage};
 
 #[derive(Debug, Default)]
 pub struct UpdateContext;
 
 impl<'a> System<'a> for UpdateContext {
     type SystemData = (
+        Read<'a, Root>,
+        ReadStorage<'a, Song>,
         ReadStorage<'a, Staff>,
         ReadStorage<'a, Children>,
         ReadStorage<'a, Bar>,
         ReadStorage<'a, Signature>,
-        ReadStorage<'a, Chord>,
+        WriteStorage<'a, Chord>,
         WriteStorage<'a, Context>,
     );
 
     fn run(
         &mut self,
-        (staffs, ordered_children, bars, signatures, chords, mut contexts): Self::SystemData,
+        (root, songs, staffs, ordered_children, bars, signatures, mut chords, mut contexts): Self::SystemData,
     ) {
-        for (_staff, Children(children)) in (&staffs, &ordered_children).join() {
+        let song = root.0.and_then(|root| songs.get(root));
+        let cautionary_accidentals = song.is_some_and(|song| song.cautionary_accidentals);
+        let concert_pitch = song.is_some_and(|song| song.concert_pitch);
+
+        for (staff, Children(children)) in (&staffs, &ordered_children).join() {
+            let instrument = &staff.instrument;
+            let transposition = if concert_pitch {
+                Interval::unison()
+            } else {
+                instrument.transposition
+            };
+
             let mut idx = 0;
-            let mut clef = Clef::G;
+            let mut clef = instrument.clef;
             let mut key = 0;
             let mut time = (4, 4);
             let mut def_accidentals: HashMap<(NoteName, i8), NoteModifier> = HashMap::new();
             let mut start_beat = Rational::new(0, 1);
+            // Notes altered in the previous bar.
+            let mut prev_altered: HashSet<(NoteName, i8)> = HashSet::new();
 
             for (i, &child) in children.iter().enumerate() {
                 // HACK: get correct correct pickup start
@@ -46,24 +62,60 @@ impl<'a> System<'a> for UpdateContext {
                     context.bar = idx;
                     context.clef = clef;
                     context.key = key;
+                    context.transposition = transposition;
                     context.time = time;
                     context.beat = start_beat;
                     context.accidentals = def_accidentals.clone();
+                    context.cautionary = HashSet::new();
                 }
                 if let Some(bar) = bars.get(child) {
                     let mut accidentals = def_accidentals.clone();
+                    let mut altered = HashSet::new();
+                    let mut cautionary = if cautionary_accidentals {
+                        std::mem::take(&mut prev_altered)
+                    } else {
+                        HashSet::new()
+                    };
                     for BarChild { stencil, .. } in bar.children() {
                         if let (Some(context), Some(chord)) =
-                            (contexts.get_mut(stencil), chords.get(stencil))
+                            (contexts.get_mut(stencil), chords.get_mut(stencil))
                         {
                             context.bar = idx;
                             context.clef = clef;
                             context.key = key;
+                            context.transposition = transposition;
                             context.time = time;
                             context.accidentals = accidentals.clone();
+                            context.cautionary = cautionary.clone();
+
+                            chord.transposition = transpose_key(key, transposition).1;
+                            chord.out_of_range = match chord.pitch {
+                                PitchKind::Pitch(pitch) => {
+                                    !chord.slash && !instrument.in_range(pitch.midi())
+                                }
+                                PitchKind::Rest
+                                | PitchKind::Unpitched
+                                | PitchKind::Percussion(_) => false,
+                            };
 
-                            if let PitchKind::Pitch(pitch) = chord.pitch {
+                            // Accidentals are tracked as written.
+                            if let (PitchKind::Pitch(pitch), false) =
+                                (chord.written_pitch(), chord.slash)
+                            {
                                 let pitch_base = (pitch.name(), pitch.octave());
+                                cautionary.remove(&pitch_base);
+
+                                if def_accidentals.get(&pitch_base).cloned() != pitch.modifier() {
+                                    altered.insert(pitch_base);
+                                    if cautionary_accidentals {
+                                        for octave in -2..=8 {
+                                            if octave != pitch.octave() {
+                                                cautionary.insert((pitch.name(), octave));
+                                            }
+                                        }
+                                    }
+                                }
+
                                 if accidentals.get(&pitch_base).cloned() != pitch.modifier() {
                                     if let Some(modifier) = pitch.modifier() {
                                         accidentals.insert(pitch_base, modifier);
@@ -74,6 +126,7 @@ impl<'a> System<'a> for UpdateContext {
                             }
                         }
                     }
+                    prev_altered = altered;
                     idx += 1;
                 }
                 if let Some(signature) = signatures.get(child) {
@@ -83,7 +136,8 @@ impl<'a> System<'a> for UpdateContext {
                     if let Some(new_key) = signature.key {
                         def_accidentals = HashMap::new();
                         key = new_key;
-                        for (note_name, note_modifier) in key_signature_note_names(key) {
+                        let written_key = transpose_key(key, transposition).0;
+                        for (note_name, note_modifier) in key_signature_note_names(written_key) {
                             for octave in -2..=8 {
                                 def_accidentals.insert((note_name, octave), note_modifier);
                             }
@@ -0,0 +1,288 @@
+#![allow(clippy::type_complexity)]
+
+use crate::components::{
+    Children, Chord, Context, DynamicMark, FlagAttachment, Hairpin, Harmony, Linked, RehearsalMark,
+    Signature, Simile, Slur, Staff, TabStaff,
+};
+use pitch::Clef;
+use rhythm::{components::Bar, components::Spacing, BarChild};
+use specs::{Entities, Entity, Join, ReadStorage, System, WriteStorage};
+use std::collections::HashMap;
+use stencil::components::{Parent, Stencil, StencilMap};
+
+/// Copies the bars and signatures of each linked staff to the staffs linked to it, replacing their
+/// children.
+///
+/// Parts also copy the instrument, and the dynamics, slurs, chord symbols and rehearsal marks of
+/// each bar. Tab staffs only show the notes, and have their own clef.
+///
+/// This runs after automatic rests are created, so that they are copied too.
+#[derive(Debug, Default)]
+pub struct UpdateLinkedStaffs;
+
+/// The entity which shows `source` on a linked staff, reusing the one from the last update if
+/// there is one.
+fn mirror_of(
+    source: Entity,
+    entities: &Entities,
+    old_mirrors: &HashMap<Entity, Entity>,
+    mirrors: &mut HashMap<Entity, Entity>,
+    linked: &mut WriteStorage<Linked>,
+) -> (Entity, bool) {
+    let (mirror, is_new) = match old_mirrors.get(&source) {
+        Some(&mirror) if entities.is_alive(mirror) => (mirror, false),
+        _ => (entities.create(), true),
+    };
+    linked.insert(mirror, Linked(source)).unwrap();
+    mirrors.insert(source, mirror);
+
+    (mirror, is_new)
+}
+
+impl<'a> System<'a> for UpdateLinkedStaffs {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, Staff>,
+        ReadStorage<'a, TabStaff>,
+        WriteStorage<'a, Linked>,
+        WriteStorage<'a, Children>,
+        WriteStorage<'a, Bar>,
+        WriteStorage<'a, Chord>,
+        WriteStorage<'a, Signature>,
+        WriteStorage<'a, Simile>,
+        WriteStorage<'a, DynamicMark>,
+        WriteStorage<'a, Hairpin>,
+        WriteStorage<'a, Harmony>,
+        WriteStorage<'a, RehearsalMark>,
+        WriteStorage<'a, Slur>,
+        WriteStorage<'a, Context>,
+        WriteStorage<'a, Spacing>,
+        WriteStorage<'a, FlagAttachment>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+        WriteStorage<'a, StencilMap>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut staffs,
+            tab_staffs,
+            mut linked,
+            mut children,
+            mut bars,
+            mut chords,
+            mut signatures,
+            mut similes,
+            mut dynamic_marks,
+            mut hairpins,
+            mut harmonies,
+            mut rehearsal_marks,
+            mut slurs,
+            mut contexts,
+            mut spacings,
+            mut flag_attachments,
+            mut parents,
+            mut stencils,
+            mut stencil_maps,
+        ): Self::SystemData,
+    ) {
+        let links: Vec<(Entity, Entity)> = (&entities, &staffs)
+            .join()
+            .filter_map(|(entity, staff)| Some((entity, staff.link?)))
+            .collect();
+
+        for (staff_entity, link) in links {
+            let source_children = match children.get(link) {
+                Some(source_children) => source_children.0.clone(),
+                None => continue,
+            };
+            let is_tab = tab_staffs.contains(staff_entity);
+            if !is_tab {
+                if let Some(instrument) = staffs.get(link).map(|staff| staff.instrument.clone()) {
+                    staffs.get_mut(staff_entity).unwrap().instrument = instrument;
+                }
+            }
+
+            let old_mirrors = std::mem::take(&mut staffs.get_mut(staff_entity).unwrap().mirrors);
+            let mut mirrors = HashMap::new();
+            let mut mirror_children = vec![];
+            for source in source_children {
+                if let Some(bar) = bars.get(source).cloned() {
+                    let (mirror_bar, is_new) =
+                        mirror_of(source, &entities, &old_mirrors, &mut mirrors, &mut linked);
+                    if is_new {
+                        stencil_maps
+                            .insert(mirror_bar, StencilMap::default())
+                            .unwrap();
+                        contexts.insert(mirror_bar, Context::default()).unwrap();
+                    }
+
+                    let mut bar_mirrors = HashMap::new();
+                    for BarChild { stencil, .. } in bar.children() {
+                        let (mirror, is_new) =
+                            mirror_of(stencil, &entities, &old_mirrors, &mut mirrors, &mut linked);
+                        if is_new {
+                            spacings.insert(mirror, Spacing::default()).unwrap();
+                            flag_attachments
+                                .insert(mirror, FlagAttachment::default())
+                                .unwrap();
+                            stencils.insert(mirror, Stencil::default()).unwrap();
+                            children.insert(mirror, Children::default()).unwrap();
+                        }
+                        if let Some(context) = contexts.get(stencil).cloned() {
+                            contexts.insert(mirror, context).unwrap();
+                        }
+                        if let Some(chord) = chords.get(stencil).cloned() {
+                            chords.insert(mirror, chord).unwrap();
+                        }
+                        parents.insert(mirror, Parent(mirror_bar)).unwrap();
+                        bar_mirrors.insert(stencil, mirror);
+                    }
+
+                    let mirrored = bar.map_entities(|entity| bar_mirrors[&entity]);
+                    bars.insert(mirror_bar, mirrored).unwrap();
+                    match similes.get(source).copied() {
+                        Some(simile) => {
+                            similes.insert(mirror_bar, simile).unwrap();
+                        }
+                        None => {
+                            similes.remove(mirror_bar);
+                        }
+                    }
+                    mirror_children.push(mirror_bar);
+                } else if let Some(signature) = signatures.get(source) {
+                    // Tab staffs have their own clef, and otherwise show the same signatures.
+                    let clef = if is_tab {
+                        signature.clef.map(|_| Clef::Tab)
+                    } else {
+                        signature.clef
+                    };
+                    let (time, key, barline) = (signature.time, signature.key, signature.barline);
+
+                    let (mirror, is_new) =
+                        mirror_of(source, &entities, &old_mirrors, &mut mirrors, &mut linked);
+                    if is_new {
+                        let stencil_start = entities.create();
+                        let stencil_middle = entities.create();
+                        let stencil_end = entities.create();
+                        for &stencil in &[stencil_start, stencil_middle, stencil_end] {
+                            stencils.insert(stencil, Stencil::default()).unwrap();
+                            children.insert(stencil, Children::default()).unwrap();
+                            parents.insert(stencil, Parent(mirror)).unwrap();
+                        }
+                        signatures
+                            .insert(
+                                mirror,
+                                Signature {
+                                    clef,
+                                    time,
+                                    key,
+                                    barline,
+                                    stencil_start,
+                                    stencil_middle,
+                                    stencil_end,
+                                },
+                            )
+                            .unwrap();
+                        contexts.insert(mirror, Context::default()).unwrap();
+                        children.insert(mirror, Children::default()).unwrap();
+                    } else if let Some(mirror_signature) = signatures.get_mut(mirror) {
+                        mirror_signature.clef = clef;
+                        mirror_signature.time = time;
+                        mirror_signature.key = key;
+                        mirror_signature.barline = barline;
+                    }
+                    mirror_children.push(mirror);
+                }
+            }
+
+            if !is_tab {
+                // Marks belong to a bar or, for slurs, to the chord they start on.
+                let marks: Vec<(Entity, Entity)> = (&entities, &parents)
+                    .join()
+                    .filter(|&(_, parent)| mirrors.contains_key(&parent.0))
+                    .filter(|&(mark, _)| !mirrors.contains_key(&mark))
+                    .map(|(mark, parent)| (mark, mirrors[&parent.0]))
+                    .collect();
+                for (source, mirror_parent) in marks {
+                    let (mirror, is_new) = if dynamic_marks.contains(source)
+                        || harmonies.contains(source)
+                        || rehearsal_marks.contains(source)
+                        || hairpins.contains(source)
+                        || slurs.contains(source)
+                    {
+                        mirror_of(source, &entities, &old_mirrors, &mut mirrors, &mut linked)
+                    } else {
+                        continue;
+                    };
+                    if is_new && !hairpins.contains(source) && !slurs.contains(source) {
+                        stencils.insert(mirror, Stencil::default()).unwrap();
+                    }
+                    parents.insert(mirror, Parent(mirror_parent)).unwrap();
+
+                    if let Some(mark) = dynamic_marks.get(source).cloned() {
+                        dynamic_marks.insert(mirror, mark).unwrap();
+                    }
+                    if let Some(harmony) = harmonies.get(source).cloned() {
+                        harmonies.insert(mirror, harmony).unwrap();
+                    }
+                    if let Some(mark) = rehearsal_marks.get(source) {
+                        let mark = RehearsalMark::new(mark.label.clone(), mark.boxed);
+                        rehearsal_marks.insert(mirror, mark).unwrap();
+                    }
+                    // Segments are kept, since they are managed by the print systems.
+                    if let Some(hairpin) = hairpins.get(source) {
+                        let (kind, start, end) = (hairpin.kind, hairpin.start, hairpin.end);
+                        let end_bar = mirrors.get(&hairpin.end_bar).copied();
+                        match (hairpins.get_mut(mirror), end_bar) {
+                            (Some(mirror_hairpin), Some(end_bar)) => {
+                                mirror_hairpin.kind = kind;
+                                mirror_hairpin.start = start;
+                                mirror_hairpin.end_bar = end_bar;
+                                mirror_hairpin.end = end;
+                            }
+                            (None, Some(end_bar)) => {
+                                hairpins
+                                    .insert(mirror, Hairpin::new(kind, start, end_bar, end))
+                                    .unwrap();
+                            }
+                            (_, None) => {}
+                        }
+                    }
+                    if let Some(slur) = slurs.get(source) {
+                        let ends = (mirrors.get(&slur.start), mirrors.get(&slur.end));
+                        if let (Some(&start), Some(&end)) = ends {
+                            match slurs.get_mut(mirror) {
+                                Some(mirror_slur) => {
+                                    mirror_slur.start = start;
+                                    mirror_slur.end = end;
+                                }
+                                None => {
+                                    slurs.insert(mirror, Slur::new(start, end)).unwrap();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            for &child in &mirror_children {
+                parents.insert(child, Parent(staff_entity)).unwrap();
+            }
+            children
+                .insert(staff_entity, Children(mirror_children))
+                .unwrap();
+
+            // Mirrors of entities which are gone are deleted by DeleteOrphans.
+            for (source, mirror) in old_mirrors {
+                if !mirrors.contains_key(&source) {
+                    parents.remove(mirror);
+                    stencils.remove(mirror);
+                }
+            }
+            staffs.get_mut(staff_entity).unwrap().mirrors = mirrors;
+        }
+    }
+}
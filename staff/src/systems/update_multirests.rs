@@ -0,0 +1,170 @@
+#![allow(clippy::type_complexity)]
+
+use std::collections::HashSet;
+
+use crate::{
+    components::{
+        Children, LinePlacement, Multirest, RehearsalMark, Signature, Simile, Song, Staff,
+    },
+    resources::{KeepSpacing, Root},
+};
+use num_rational::Rational;
+use rhythm::components::Bar;
+use specs::{Entities, Entity, Join, Read, ReadStorage, System, WriteStorage};
+use stencil::components::{Parent, Stencil};
+
+/// The width of a multi-measure rest, regardless of how many bars it replaces.
+const MULTIREST_WIDTH: f64 = 3000.0;
+
+#[derive(Debug, Default)]
+pub struct UpdateMultirests;
+
+/// True if `bar` can be part of a multi-measure rest.
+///
+/// Bars filled with beat slashes are not empty.
+fn is_empty(bar: &Bar) -> bool {
+    bar.whole_rest()
+        && !bar.slash_fill()
+        && bar.skip() == Rational::new(0, 1)
+        && bar.children().iter().all(|c| !c.grace)
+}
+
+/// Split `children` of a staff into runs of at least two empty bars, which are separated only by
+/// plain barlines.
+///
+/// Bars in `marked` have a rehearsal mark, so they can only start a run. Bars replaced by a repeat
+/// sign are never part of one.
+fn consolidate(
+    children: &[Entity],
+    bars: &ReadStorage<Bar>,
+    signatures: &ReadStorage<Signature>,
+    similes: &ReadStorage<Simile>,
+    marked: &HashSet<Entity>,
+) -> Vec<Vec<Entity>> {
+    let replaced = Simile::replaced_bars(children, bars, similes);
+    let mut groups = vec![];
+    let mut group = vec![];
+
+    let mut flush = |group: &mut Vec<Entity>| {
+        if group.len() > 1 {
+            groups.push(std::mem::take(group));
+        } else {
+            group.clear();
+        }
+    };
+
+    for &child in children {
+        if let Some(bar) = bars.get(child) {
+            if !is_empty(bar) || replaced.contains_key(&child) {
+                flush(&mut group);
+                continue;
+            }
+
+            // The count is drawn with time signature digits.
+            if group.len() == u8::MAX as usize || marked.contains(&child) {
+                flush(&mut group);
+            }
+            group.push(child);
+        } else if let Some(signature) = signatures.get(child) {
+            if !signature.is_plain_barline() {
+                flush(&mut group);
+            }
+        }
+    }
+    flush(&mut group);
+
+    groups
+}
+
+impl<'a> System<'a> for UpdateMultirests {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, Root>,
+        Read<'a, KeepSpacing>,
+        ReadStorage<'a, Song>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Signature>,
+        ReadStorage<'a, Simile>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, RehearsalMark>,
+        WriteStorage<'a, Staff>,
+        WriteStorage<'a, Multirest>,
+        WriteStorage<'a, LinePlacement>,
+        WriteStorage<'a, Parent>,
+        WriteStorage<'a, Stencil>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            root,
+            keep_spacing,
+            songs,
+            bars,
+            signatures,
+            similes,
+            children,
+            rehearsal_marks,
+            mut staffs,
+            mut multirests,
+            mut line_placements,
+            mut parents,
+            mut stencils,
+        ): Self::SystemData,
+    ) {
+        if keep_spacing.0 {
+            return;
+        }
+
+        let enabled = root
+            .0
+            .and_then(|root| songs.get(root))
+            .map(|song| song.multirests)
+            .unwrap_or(false);
+
+        let marked: HashSet<Entity> = (&rehearsal_marks, &parents)
+            .join()
+            .map(|(_, parent)| parent.0)
+            .collect();
+
+        for (staff_entity, staff, staff_children) in (&entities, &mut staffs, &children).join() {
+            let groups = if enabled {
+                consolidate(&staff_children.0, &bars, &signatures, &similes, &marked)
+            } else {
+                vec![]
+            };
+
+            let count = groups.len();
+            for (i, group) in groups.into_iter().enumerate() {
+                if staff.multirests.len() == i {
+                    staff.multirests.push(entities.create());
+                }
+                let multirest = staff.multirests[i];
+
+                // Consolidated bars are not on any line.
+                for bar in &group {
+                    line_placements.remove(*bar);
+                }
+
+                parents.insert(multirest, Parent(staff_entity)).unwrap();
+                stencils
+                    .insert(
+                        multirest,
+                        Stencil::multirest(MULTIREST_WIDTH, group.len() as u8),
+                    )
+                    .unwrap();
+                multirests
+                    .insert(multirest, Multirest { bars: group })
+                    .unwrap();
+            }
+
+            // Orphaned multirests are deleted by DeleteOrphans.
+            for multirest in staff.multirests.drain(count..) {
+                parents.remove(multirest);
+                stencils.remove(multirest);
+                multirests.remove(multirest);
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    components::{Cursor, LineOfStaff},
+    Skyline,
+};
+use kurbo::TranslateScale;
+use specs::{Entity, Join, ReadStorage, System, WriteStorage};
+use stencil::components::{Stencil, StencilMap};
+
+#[derive(Debug, Default)]
+pub struct UpdateSkyline;
+
+/// Add everything drawn by `entity` to `skyline`.
+fn add_to_skyline(
+    entity: Entity,
+    transform: TranslateScale,
+    cursors: &ReadStorage<Cursor>,
+    stencils: &ReadStorage<Stencil>,
+    stencil_maps: &ReadStorage<StencilMap>,
+    skyline: &mut Skyline,
+) {
+    // The cursor moves as the song is edited, and should not move anything else.
+    if cursors.contains(entity) {
+        return;
+    }
+
+    if let Some(stencil) = stencils.get(entity) {
+        skyline.insert(transform * stencil.rect());
+    } else if let Some(stencil_map) = stencil_maps.get(entity) {
+        let transform =
+            transform * TranslateScale::translate(stencil_map.translate().unwrap_or_default());
+        for (child, offset) in stencil_map.get_sorted_children() {
+            add_to_skyline(
+                child,
+                transform * TranslateScale::translate(offset.unwrap_or_default()),
+                cursors,
+                stencils,
+                stencil_maps,
+                skyline,
+            );
+        }
+    }
+}
+
+impl<'a> System<'a> for UpdateSkyline {
+    type SystemData = (
+        ReadStorage<'a, Cursor>,
+        ReadStorage<'a, Stencil>,
+        ReadStorage<'a, StencilMap>,
+        WriteStorage<'a, LineOfStaff>,
+    );
+
+    fn run(&mut self, (cursors, stencils, stencil_maps, mut line_of_staffs): Self::SystemData) {
+        for line_map in (&stencil_maps, &mut line_of_staffs).join() {
+            let (stencil_map, line) = line_map;
+            let mut skyline = Skyline::default();
+            for (child, offset) in stencil_map.get_sorted_children() {
+                // The staff lines are already part of every skyline.
+                if child == line.staff_lines {
+                    continue;
+                }
+                add_to_skyline(
+                    child,
+                    TranslateScale::translate(offset.unwrap_or_default()),
+                    &cursors,
+                    &stencils,
+                    &stencil_maps,
+                    &mut skyline,
+                );
+            }
+            line.skyline = skyline;
+        }
+    }
+}
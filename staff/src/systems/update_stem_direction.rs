@@ -0,0 +1,231 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    components::{BeamForChord, Children, Chord, Context, Staff, StemDirection},
+    PitchKind,
+};
+use rhythm::{components::Bar, BarChild};
+use specs::{Entity, Join, ReadStorage, System, WriteStorage};
+
+/// Decides which way each note's stem points.
+#[derive(Debug, Default)]
+pub struct UpdateStemDirection;
+
+/// A note in a bar, before its direction is known.
+struct Note {
+    entity: Entity,
+    /// None for unpitched and percussion notes.
+    y: Option<f64>,
+    beam: Option<Entity>,
+    direction: Option<StemDirection>,
+}
+
+/// The direction of a note that is not beamed.
+///
+/// Notes below the middle line have stems up, and notes above have stems down. `None` means the
+/// note is on the middle line, and should follow its neighbours.
+fn single_direction(y: f64) -> Option<StemDirection> {
+    if y > 0.0 {
+        Some(StemDirection::Up)
+    } else if y < 0.0 {
+        Some(StemDirection::Down)
+    } else {
+        None
+    }
+}
+
+/// The direction of a note on the middle line, given the direction of the nearest notes not on
+/// the middle line before and after it in the same bar.
+///
+/// These notes are stem down, unless all their neighbours are stem up.
+fn middle_line_direction(
+    prev: Option<StemDirection>,
+    next: Option<StemDirection>,
+) -> StemDirection {
+    match (prev, next) {
+        (Some(StemDirection::Up), Some(StemDirection::Up))
+        | (Some(StemDirection::Up), None)
+        | (None, Some(StemDirection::Up)) => StemDirection::Up,
+        _ => StemDirection::Down,
+    }
+}
+
+/// The direction of a beamed group, given the y of each notehead.
+///
+/// The note furthest from the middle line decides. If the furthest notes above and below are
+/// equally far, the majority decides, and if that is also a tie, stems go down.
+fn group_direction(ys: &[f64]) -> StemDirection {
+    let highest = ys.iter().cloned().fold(0.0, f64::min);
+    let lowest = ys.iter().cloned().fold(0.0, f64::max);
+
+    if lowest + highest > 0.0 {
+        return StemDirection::Up;
+    }
+    if lowest + highest < 0.0 {
+        return StemDirection::Down;
+    }
+
+    let below = ys.iter().filter(|&&y| y > 0.0).count();
+    let above = ys.iter().filter(|&&y| y < 0.0).count();
+    if below > above {
+        StemDirection::Up
+    } else {
+        StemDirection::Down
+    }
+}
+
+impl<'a> System<'a> for UpdateStemDirection {
+    type SystemData = (
+        ReadStorage<'a, Staff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Chord>,
+        ReadStorage<'a, Context>,
+        ReadStorage<'a, BeamForChord>,
+        WriteStorage<'a, StemDirection>,
+    );
+
+    fn run(
+        &mut self,
+        (staffs, children, bars, chords, contexts, beam_for_chords, mut stem_directions): Self::SystemData,
+    ) {
+        for (staff, Children(children)) in (&staffs, &children).join() {
+            for bar in children.iter().filter_map(|&child| bars.get(child)) {
+                let mut notes = Vec::new();
+                for BarChild { stencil, grace, .. } in bar.children() {
+                    let (chord, context) = match (chords.get(stencil), contexts.get(stencil)) {
+                        (Some(chord), Some(context)) => (chord, context),
+                        _ => continue,
+                    };
+                    // Grace notes are always stems-up, and do not affect other notes.
+                    if grace {
+                        stem_directions
+                            .entry(stencil)
+                            .unwrap()
+                            .replace(StemDirection::Up);
+                        continue;
+                    }
+                    let y = match chord.written_pitch() {
+                        PitchKind::Rest => {
+                            stem_directions.remove(stencil);
+                            continue;
+                        }
+                        PitchKind::Unpitched | PitchKind::Percussion(_) => None,
+                        PitchKind::Pitch(_) if chord.slash => None,
+                        PitchKind::Pitch(pitch) => Some(pitch.y(context.clef)),
+                    };
+                    notes.push(Note {
+                        entity: stencil,
+                        y,
+                        beam: beam_for_chords.get(stencil).map(|beam| beam.0),
+                        direction: staff.stem_direction,
+                    });
+                }
+
+                // Beamed groups.
+                let mut i = 0;
+                while i < notes.len() {
+                    let beam = match notes[i].beam {
+                        Some(beam) => beam,
+                        None => {
+                            i += 1;
+                            continue;
+                        }
+                    };
+                    let end = notes[i..]
+                        .iter()
+                        .position(|note| note.beam != Some(beam))
+                        .map(|len| i + len)
+                        .unwrap_or_else(|| notes.len());
+                    let group = &mut notes[i..end];
+                    if group.iter().all(|note| note.direction.is_none()) {
+                        // Percussion is written stems-up.
+                        let ys: Option<Vec<f64>> = group.iter().map(|note| note.y).collect();
+                        let direction = ys
+                            .map(|ys| group_direction(&ys))
+                            .unwrap_or(StemDirection::Up);
+                        for note in group {
+                            note.direction = Some(direction);
+                        }
+                    }
+                    i = end;
+                }
+
+                // Single notes off the middle line.
+                for note in &mut notes {
+                    if note.direction.is_none() {
+                        note.direction = match note.y {
+                            Some(y) => single_direction(y),
+                            None => Some(StemDirection::Up),
+                        };
+                    }
+                }
+
+                // Single notes on the middle line.
+                let resolved: Vec<Option<StemDirection>> =
+                    notes.iter().map(|note| note.direction).collect();
+                for (i, note) in notes.iter_mut().enumerate() {
+                    if note.direction.is_none() {
+                        let prev = resolved[..i].iter().rev().find_map(|d| *d);
+                        let next = resolved[i + 1..].iter().find_map(|d| *d);
+                        note.direction = Some(middle_line_direction(prev, next));
+                    }
+                }
+
+                for note in notes {
+                    if let Some(direction) = note.direction {
+                        stem_directions
+                            .entry(note.entity)
+                            .unwrap()
+                            .replace(direction);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_notes() {
+        assert_eq!(single_direction(250.0), Some(StemDirection::Up));
+        assert_eq!(single_direction(-125.0), Some(StemDirection::Down));
+        assert_eq!(single_direction(0.0), None);
+
+        assert_eq!(
+            middle_line_direction(Some(StemDirection::Up), Some(StemDirection::Up)),
+            StemDirection::Up
+        );
+        assert_eq!(
+            middle_line_direction(None, Some(StemDirection::Up)),
+            StemDirection::Up
+        );
+        assert_eq!(
+            middle_line_direction(Some(StemDirection::Up), Some(StemDirection::Down)),
+            StemDirection::Down
+        );
+        assert_eq!(middle_line_direction(None, None), StemDirection::Down);
+    }
+
+    #[test]
+    fn beamed_groups() {
+        // The furthest note decides.
+        assert_eq!(group_direction(&[-250.0, 750.0]), StemDirection::Up);
+        assert_eq!(
+            group_direction(&[-750.0, 250.0, 500.0]),
+            StemDirection::Down
+        );
+
+        // Otherwise, the majority decides.
+        assert_eq!(group_direction(&[-500.0, 500.0, 250.0]), StemDirection::Up);
+        assert_eq!(
+            group_direction(&[-500.0, 500.0, -250.0]),
+            StemDirection::Down
+        );
+        assert_eq!(group_direction(&[-500.0, 500.0]), StemDirection::Down);
+        assert_eq!(group_direction(&[0.0, 0.0]), StemDirection::Down);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    components::{Children, Chord, TabNote, TabStaff},
+    PitchKind,
+};
+use rhythm::{components::Bar, BarChild};
+use specs::{Join, ReadStorage, System, WriteStorage};
+
+/// Chooses the string and fret of each note on a tab staff.
+///
+/// Each note is played near the one before it, unless its string is chosen.
+#[derive(Debug, Default)]
+pub struct UpdateTablature;
+
+impl<'a> System<'a> for UpdateTablature {
+    type SystemData = (
+        ReadStorage<'a, TabStaff>,
+        ReadStorage<'a, Children>,
+        ReadStorage<'a, Bar>,
+        ReadStorage<'a, Chord>,
+        WriteStorage<'a, TabNote>,
+    );
+
+    fn run(&mut self, (tab_staffs, children, bars, chords, mut tab_notes): Self::SystemData) {
+        for (tab_staff, staff_children) in (&tab_staffs, &children).join() {
+            // The fret of the last fretted note.
+            let mut hand = None;
+            for bar in staff_children.0.iter().filter_map(|&child| bars.get(child)) {
+                for BarChild { stencil, .. } in bar.children() {
+                    let note = chords.get(stencil).and_then(|chord| match chord.pitch {
+                        PitchKind::Pitch(pitch) if !chord.slash => {
+                            tab_staff.place(pitch.midi(), chord.string, hand)
+                        }
+                        _ => None,
+                    });
+                    match note {
+                        Some(note) => {
+                            if note.fret > 0 {
+                                hand = Some(note.fret);
+                            }
+                            tab_notes.insert(stencil, note).unwrap();
+                        }
+                        None => {
+                            tab_notes.remove(stencil);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
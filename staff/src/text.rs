@@ -0,0 +1,31 @@
+use kurbo::{Rect, Vec2};
+use stencil::components::Stencil;
+
+/// The width of a character, relative to the font size.
+///
+/// Titles are measured by the browser, but generated text like rehearsal marks and bar numbers is
+/// only known here, so its width is estimated from capitals and digits in a serif font.
+const CHARACTER_WIDTH: f64 = 0.7;
+
+/// The height of capitals and digits, relative to the font size.
+pub(crate) const CAP_HEIGHT: f64 = 0.66;
+
+/// The space between text and a box around it.
+const ENCLOSURE_PADDING: f64 = 125.0;
+
+/// Print generated text with its bottom at y = 0, starting at x = 0, optionally boxed.
+pub fn print_text(text: &str, font_size: f64, boxed: bool) -> Stencil {
+    let width = text.chars().count() as f64 * font_size * CHARACTER_WIDTH;
+    let text = Stencil::text(text, font_size, width);
+    if !boxed {
+        return text;
+    }
+
+    Stencil::text_enclosure(Rect::new(
+        0.0,
+        -font_size * CAP_HEIGHT - ENCLOSURE_PADDING * 2.0,
+        width + ENCLOSURE_PADDING * 2.0,
+        0.0,
+    ))
+    .and(text.with_translation(Vec2::new(ENCLOSURE_PADDING, -ENCLOSURE_PADDING)))
+}
@@ -0,0 +1,396 @@
+use crate::{text::print_text, ChordSymbol};
+use kurbo::{Line, Point, Rect, Vec2};
+use std::fmt;
+use stencil::components::Stencil;
+
+/// The number of frets in a diagram, which is also how far a hand can stretch.
+const SPAN: u8 = 4;
+
+/// The highest fret that voicings start on.
+const MAX_POSITION: u8 = 12;
+
+const MAX_FINGERS: usize = 4;
+
+const STRING_SPACING: f64 = 150.0;
+const FRET_SPACING: f64 = 180.0;
+const LINE_THICKNESS: f64 = 16.0;
+const NUT_THICKNESS: f64 = 60.0;
+const DOT_RADIUS: f64 = 55.0;
+
+/// The radius of the marks above open and unplayed strings.
+const MARK_RADIUS: f64 = 40.0;
+
+/// The space between the top of the diagram and the center of the marks above it.
+const MARK_SPACING: f64 = 100.0;
+
+/// The space between the diagram and the name of the chord above it.
+const NAME_SPACING: f64 = 150.0;
+
+const FRET_NUMBER_SIZE: f64 = 200.0;
+
+/// How a chord is played on a fretted instrument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Voicing {
+    /// The fret of each string, or None if it is not played, from the highest string to the
+    /// lowest, like `TabStaff::tuning`.
+    pub frets: Vec<Option<u8>>,
+}
+
+impl Voicing {
+    /// Parses a voicing as it is written in chord books, from the lowest string to the highest,
+    /// with "x" for strings that are not played, such as "x32010". Voicings with frets above 9
+    /// are written with spaces, such as "x 10 12 12 12 10".
+    pub fn parse(text: &str) -> Option<Voicing> {
+        let text = text.trim();
+        let tokens: Vec<String> = if text.contains(char::is_whitespace) {
+            text.split_whitespace().map(str::to_owned).collect()
+        } else {
+            text.chars().map(String::from).collect()
+        };
+
+        let mut frets = tokens
+            .iter()
+            .map(|token| match token.as_str() {
+                "x" | "X" => Some(None),
+                fret => fret.parse().ok().map(Some),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        frets.reverse();
+
+        Some(Voicing { frets }).filter(|voicing| !voicing.frets.is_empty())
+    }
+
+    /// The easiest way to play `symbol` on an instrument with the given tuning, or None if there
+    /// is no way.
+    ///
+    /// Every note of the chord is played, except that the fifth can be left out of chords with five
+    /// or more notes, and the lowest note played is the bass. Strings which are not played must be
+    /// the lowest strings. Voicings with fewer unplayed strings, lower on the neck, with fewer
+    /// fingers, and with less of a stretch are preferred.
+    pub fn generate(symbol: &ChordSymbol, tuning: &[u8]) -> Option<Voicing> {
+        let notes = symbol.notes();
+        let fifth = (symbol.root_class() + 7) % 12;
+        let required: Vec<u8> = notes
+            .iter()
+            .copied()
+            .filter(|&note| notes.len() < 5 || note != fifth)
+            .collect();
+
+        let mut best: Option<((usize, usize, usize), Voicing)> = None;
+        for position in 1..=MAX_POSITION {
+            // The frets on each string which play a note of the chord.
+            let options: Vec<Vec<u8>> = tuning
+                .iter()
+                .map(|&open| {
+                    std::iter::once(0)
+                        .chain(position..position + SPAN)
+                        .filter(|&fret| notes.contains(&((open + fret) % 12)))
+                        .collect()
+                })
+                .collect();
+
+            let mut frets = Vec::with_capacity(tuning.len());
+            Self::search(&options, &mut frets, &mut |frets| {
+                let voicing = Voicing {
+                    frets: frets.to_vec(),
+                };
+                let played: Vec<u8> = voicing
+                    .frets
+                    .iter()
+                    .zip(tuning)
+                    .filter_map(|(fret, open)| fret.map(|fret| (open + fret) % 12))
+                    .collect();
+                if played.len() < required.len().min(3)
+                    || played.last() != Some(&symbol.bass_class())
+                    || required.iter().any(|note| !played.contains(note))
+                {
+                    return;
+                }
+                let fingers = match voicing.fingers() {
+                    Some(fingers) => fingers,
+                    None => return,
+                };
+
+                let unplayed = tuning.len() - played.len();
+                let lowest = voicing.lowest_fret().unwrap_or(0);
+                let highest = voicing.frets.iter().flatten().copied().max().unwrap_or(0);
+                let stretch = usize::from(highest.saturating_sub(lowest));
+                let score = (
+                    unplayed + usize::from(lowest) + fingers + stretch,
+                    fingers,
+                    voicing.muffled(),
+                );
+                if best.as_ref().is_none_or(|(best, _)| score < *best) {
+                    best = Some((score, voicing));
+                }
+            });
+        }
+
+        best.map(|(_, voicing)| voicing)
+    }
+
+    /// Calls `f` with each voicing that uses `options`, where strings which are not played are the
+    /// lowest strings.
+    fn search(options: &[Vec<u8>], frets: &mut Vec<Option<u8>>, f: &mut impl FnMut(&[Option<u8>])) {
+        let string = frets.len();
+        if string == options.len() {
+            f(frets);
+            return;
+        }
+
+        for &fret in &options[string] {
+            frets.push(Some(fret));
+            Self::search(options, frets, f);
+            frets.pop();
+        }
+
+        // The rest of the strings are not played.
+        if string > 0 {
+            let before = frets.len();
+            frets.resize(options.len(), None);
+            f(frets);
+            frets.truncate(before);
+        }
+    }
+
+    /// The lowest fret that is held down, if any is.
+    fn lowest_fret(&self) -> Option<u8> {
+        self.frets
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&fret| fret > 0)
+            .min()
+    }
+
+    fn fretted(&self) -> usize {
+        self.frets
+            .iter()
+            .flatten()
+            .filter(|&&fret| fret > 0)
+            .count()
+    }
+
+    /// The number of open strings between strings that are held down, which are easy to muffle by
+    /// accident.
+    fn muffled(&self) -> usize {
+        let first = self.frets.iter().position(|&f| f.is_some_and(|f| f > 0));
+        let last = self.frets.iter().rposition(|&f| f.is_some_and(|f| f > 0));
+        match (first, last) {
+            (Some(first), Some(last)) => self.frets[first..=last]
+                .iter()
+                .filter(|&&f| f == Some(0))
+                .count(),
+            _ => 0,
+        }
+    }
+
+    /// The fret, and first and last strings (as indices of `frets`) of a barre, if one finger
+    /// must hold down several strings at the lowest fret because there are more notes than
+    /// fingers.
+    pub fn barre(&self) -> Option<(u8, usize, usize)> {
+        if self.fretted() <= MAX_FINGERS {
+            return None;
+        }
+
+        let fret = self.lowest_fret()?;
+        let first = self.frets.iter().position(|&f| f == Some(fret))?;
+        let last = self.frets.iter().rposition(|&f| f == Some(fret))?;
+
+        // Open strings under the barre would be held down by it.
+        if self.frets[first..=last].contains(&Some(0)) {
+            None
+        } else {
+            Some((fret, first, last))
+        }
+    }
+
+    /// How many fingers hold down strings, or None if there are too many notes to play.
+    fn fingers(&self) -> Option<usize> {
+        let fretted = self.fretted();
+        if fretted <= MAX_FINGERS {
+            return Some(fretted);
+        }
+
+        let (fret, ..) = self.barre()?;
+        let fingers = 1 + self
+            .frets
+            .iter()
+            .flatten()
+            .filter(|&&other| other > fret)
+            .count();
+
+        Some(fingers).filter(|&fingers| fingers <= MAX_FINGERS)
+    }
+
+    /// Print a fretboard diagram of the voicing, with `name` centered above it.
+    ///
+    /// The top of the grid is at y = 0 and the lowest string is at x = 0.
+    pub fn print_diagram(&self, name: Stencil) -> Stencil {
+        let strings = self.frets.len();
+        let width = (strings.saturating_sub(1)) as f64 * STRING_SPACING;
+        let height = f64::from(SPAN) * FRET_SPACING;
+        let string_x = |string: usize| (strings - 1 - string) as f64 * STRING_SPACING;
+
+        // Diagrams which fit below the 4th fret start at the nut. Others start at the lowest
+        // fret, which is numbered.
+        let highest = self.frets.iter().flatten().copied().max().unwrap_or(0);
+        let first_fret = match self.lowest_fret() {
+            Some(lowest) if highest > SPAN => lowest,
+            _ => 1,
+        };
+        let fret_y = |fret: u8| (f64::from(fret - first_fret) + 0.5) * FRET_SPACING;
+
+        let mut stencil = Stencil::default();
+        for i in 0..strings {
+            let x = string_x(i);
+            stencil = stencil.and(Stencil::line(
+                Line::new(Point::new(x, 0.0), Point::new(x, height)),
+                LINE_THICKNESS,
+            ));
+        }
+        for fret in 0..=SPAN {
+            let y = f64::from(fret) * FRET_SPACING;
+            let thickness = if fret == 0 && first_fret == 1 {
+                NUT_THICKNESS
+            } else {
+                LINE_THICKNESS
+            };
+            stencil = stencil.and(Stencil::line(
+                Line::new(Point::new(0.0, y), Point::new(width, y)),
+                thickness,
+            ));
+        }
+        if first_fret > 1 {
+            stencil = stencil.and(
+                print_text(&format!("{}fr", first_fret), FRET_NUMBER_SIZE, false).with_translation(
+                    Vec2::new(
+                        width + DOT_RADIUS * 2.0,
+                        fret_y(first_fret) + FRET_NUMBER_SIZE / 3.0,
+                    ),
+                ),
+            );
+        }
+
+        let barre = self.barre();
+        if let Some((fret, first, last)) = barre {
+            let y = fret_y(fret);
+            stencil = stencil.and(Stencil::round_filled_box(
+                Rect::new(
+                    string_x(last) - DOT_RADIUS,
+                    y - DOT_RADIUS,
+                    string_x(first) + DOT_RADIUS,
+                    y + DOT_RADIUS,
+                ),
+                DOT_RADIUS * 2.0,
+            ));
+        }
+
+        for (string, &fret) in self.frets.iter().enumerate() {
+            let x = string_x(string);
+            match fret {
+                None => {
+                    let d = MARK_RADIUS * std::f64::consts::FRAC_1_SQRT_2;
+                    let center = Point::new(x, -MARK_SPACING);
+                    for &(dx, dy) in &[(d, d), (d, -d)] {
+                        stencil = stencil.and(Stencil::line(
+                            Line::new(center - Vec2::new(dx, dy), center + Vec2::new(dx, dy)),
+                            LINE_THICKNESS,
+                        ));
+                    }
+                }
+                Some(0) => {
+                    stencil = stencil.and(Stencil::ring(
+                        MARK_RADIUS,
+                        LINE_THICKNESS,
+                        Point::new(x, -MARK_SPACING),
+                    ));
+                }
+                Some(fret) if barre.is_some_and(|(barre, ..)| barre == fret) => {}
+                Some(fret) => {
+                    stencil = stencil.and(Stencil::circle(DOT_RADIUS, Point::new(x, fret_y(fret))));
+                }
+            }
+        }
+
+        let name_rect = name.rect();
+        let name_x = (width - name_rect.width()) / 2.0 - name_rect.x0;
+        let name_y = -MARK_SPACING - MARK_RADIUS - NAME_SPACING;
+
+        stencil.and(name.with_translation(Vec2::new(name_x, name_y)))
+    }
+}
+
+/// Writes a voicing as `parse` reads it.
+impl fmt::Display for Voicing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let spaced = self
+            .frets
+            .iter()
+            .any(|&fret| fret.is_some_and(|fret| fret > 9));
+        for (i, fret) in self.frets.iter().rev().enumerate() {
+            if spaced && i > 0 {
+                write!(f, " ")?;
+            }
+            match fret {
+                Some(fret) => write!(f, "{}", fret)?,
+                None => write!(f, "x")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::TabStaff;
+
+    fn generate(symbol: &str) -> Voicing {
+        let guitar = TabStaff::guitar();
+        Voicing::generate(&ChordSymbol::parse(symbol).unwrap(), &guitar.tuning).unwrap()
+    }
+
+    #[test]
+    fn open_chords() {
+        assert_eq!(generate("C"), Voicing::parse("x32010").unwrap());
+        assert_eq!(generate("G"), Voicing::parse("320003").unwrap());
+        assert_eq!(generate("D"), Voicing::parse("xx0232").unwrap());
+        assert_eq!(generate("Am"), Voicing::parse("x02210").unwrap());
+        assert_eq!(generate("E"), Voicing::parse("022100").unwrap());
+        assert_eq!(generate("E7"), Voicing::parse("020100").unwrap());
+        assert_eq!(generate("G/B"), Voicing::parse("x20003").unwrap());
+    }
+
+    #[test]
+    fn barres() {
+        let f = generate("F");
+        assert_eq!(f, Voicing::parse("133211").unwrap());
+        assert_eq!(f.barre(), Some((1, 0, 5)));
+
+        let fm = generate("Fm");
+        assert_eq!(fm, Voicing::parse("133111").unwrap());
+        assert_eq!(fm.barre(), Some((1, 0, 5)));
+
+        let bb = generate("Bb");
+        assert_eq!(bb, Voicing::parse("x13331").unwrap());
+        assert_eq!(bb.barre(), Some((1, 0, 4)));
+
+        assert_eq!(generate("C").barre(), None);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Voicing::parse("x 10 12 12 12 10").unwrap().frets,
+            vec![Some(10), Some(12), Some(12), Some(12), Some(10), None]
+        );
+        assert_eq!(Voicing::parse("x3201?"), None);
+        assert_eq!(Voicing::parse(""), None);
+
+        for text in &["x32010", "x 10 12 12 12 10"] {
+            assert_eq!(Voicing::parse(text).unwrap().to_string(), *text);
+        }
+    }
+}
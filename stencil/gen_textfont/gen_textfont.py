@@ -0,0 +1,222 @@
+#!/usr/bin/env python3
+"""Write the outlines of printable ASCII and Latin-1 characters in a TrueType font as Rust.
+
+Usage: gen_textfont.py FONT.ttf > ../src/textfont.rs
+"""
+
+import struct
+import sys
+
+# Outlines are scaled to this many units per em, and flipped so that y points down.
+UNITS_PER_EM = 1000
+
+CHARS = [chr(c) for c in list(range(0x20, 0x7F)) + list(range(0xA0, 0x100))]
+
+
+def tables(font):
+    num_tables = struct.unpack(">H", font[4:6])[0]
+    result = {}
+    for i in range(num_tables):
+        tag, _, offset, length = struct.unpack(">4sIII", font[12 + 16 * i : 28 + 16 * i])
+        result[tag.decode("latin-1")] = font[offset : offset + length]
+    return result
+
+
+def cmap(table):
+    """Map code points to glyph ids, using the Unicode BMP (format 4) subtable."""
+    num_subtables = struct.unpack(">H", table[2:4])[0]
+    for i in range(num_subtables):
+        platform, encoding, offset = struct.unpack(">HHI", table[4 + 8 * i : 12 + 8 * i])
+        if (platform, encoding) not in ((0, 3), (3, 1)):
+            continue
+        sub = table[offset:]
+        if struct.unpack(">H", sub[0:2])[0] != 4:
+            continue
+        seg_count = struct.unpack(">H", sub[6:8])[0] // 2
+        ends = struct.unpack(">%dH" % seg_count, sub[14 : 14 + 2 * seg_count])
+        base = 16 + 2 * seg_count
+        starts = struct.unpack(">%dH" % seg_count, sub[base : base + 2 * seg_count])
+        deltas = struct.unpack(
+            ">%dh" % seg_count, sub[base + 2 * seg_count : base + 4 * seg_count]
+        )
+        range_base = base + 4 * seg_count
+        range_offsets = struct.unpack(
+            ">%dH" % seg_count, sub[range_base : range_base + 2 * seg_count]
+        )
+        result = {}
+        for seg in range(seg_count):
+            for c in range(starts[seg], ends[seg] + 1):
+                if c == 0xFFFF:
+                    continue
+                if range_offsets[seg] == 0:
+                    glyph = (c + deltas[seg]) & 0xFFFF
+                else:
+                    at = range_base + 2 * seg + range_offsets[seg] + 2 * (c - starts[seg])
+                    glyph = struct.unpack(">H", sub[at : at + 2])[0]
+                    if glyph != 0:
+                        glyph = (glyph + deltas[seg]) & 0xFFFF
+                result[c] = glyph
+        return result
+    raise Exception("No Unicode BMP cmap")
+
+
+class Font:
+    def __init__(self, data):
+        t = tables(data)
+        self.units_per_em = struct.unpack(">H", t["head"][18:20])[0]
+        long_loca = struct.unpack(">h", t["head"][50:52])[0] == 1
+        num_glyphs = struct.unpack(">H", t["maxp"][4:6])[0]
+        if long_loca:
+            self.loca = struct.unpack(">%dI" % (num_glyphs + 1), t["loca"][: 4 * (num_glyphs + 1)])
+        else:
+            self.loca = [
+                2 * x
+                for x in struct.unpack(
+                    ">%dH" % (num_glyphs + 1), t["loca"][: 2 * (num_glyphs + 1)]
+                )
+            ]
+        num_h_metrics = struct.unpack(">H", t["hhea"][34:36])[0]
+        self.advances = [
+            struct.unpack(">H", t["hmtx"][4 * i : 4 * i + 2])[0] for i in range(num_h_metrics)
+        ]
+        self.glyf = t["glyf"]
+        self.cmap = cmap(t["cmap"])
+
+    def advance(self, glyph):
+        return self.advances[min(glyph, len(self.advances) - 1)]
+
+    def contours(self, glyph, dx=0, dy=0):
+        """Contours as lists of (x, y, on_curve) points."""
+        data = self.glyf[self.loca[glyph] : self.loca[glyph + 1]]
+        if not data:
+            return []
+        num_contours = struct.unpack(">h", data[0:2])[0]
+        if num_contours < 0:
+            return self.composite_contours(data, dx, dy)
+
+        ends = struct.unpack(">%dH" % num_contours, data[10 : 10 + 2 * num_contours])
+        num_points = ends[-1] + 1 if ends else 0
+        at = 10 + 2 * num_contours
+        instruction_length = struct.unpack(">H", data[at : at + 2])[0]
+        at += 2 + instruction_length
+
+        flags = []
+        while len(flags) < num_points:
+            flag = data[at]
+            at += 1
+            flags.append(flag)
+            if flag & 8:
+                repeat = data[at]
+                at += 1
+                flags.extend([flag] * repeat)
+
+        def coordinates(short_bit, same_bit):
+            nonlocal at
+            values = []
+            value = 0
+            for flag in flags:
+                if flag & short_bit:
+                    delta = data[at]
+                    at += 1
+                    value += delta if flag & same_bit else -delta
+                elif not flag & same_bit:
+                    value += struct.unpack(">h", data[at : at + 2])[0]
+                    at += 2
+                values.append(value)
+            return values
+
+        xs = coordinates(2, 16)
+        ys = coordinates(4, 32)
+        contours = []
+        start = 0
+        for end in ends:
+            contours.append(
+                [(xs[i] + dx, ys[i] + dy, bool(flags[i] & 1)) for i in range(start, end + 1)]
+            )
+            start = end + 1
+        return contours
+
+    def composite_contours(self, data, dx, dy):
+        contours = []
+        at = 10
+        while True:
+            flags, glyph = struct.unpack(">HH", data[at : at + 4])
+            at += 4
+            if flags & 1:
+                arg1, arg2 = struct.unpack(">hh", data[at : at + 4])
+                at += 4
+            else:
+                arg1, arg2 = struct.unpack(">bb", data[at : at + 2])
+                at += 2
+            if not flags & 2:
+                raise Exception("Point-matched components are not supported")
+            if flags & (8 | 64 | 128):
+                raise Exception("Scaled components are not supported")
+            contours.extend(self.contours(glyph, dx + arg1, dy + arg2))
+            if not flags & 32:
+                return contours
+
+
+def number(value):
+    value = round(value, 1)
+    if value == int(value):
+        return str(int(value))
+    return str(value)
+
+
+def svg_path(contours, scale):
+    """Quadratic TrueType contours as an SVG path, with y pointing down."""
+    parts = []
+
+    def point(p):
+        return number(p[0] * scale) + " " + number(-p[1] * scale)
+
+    for contour in contours:
+        if not contour:
+            continue
+        # Start on an on-curve point, adding one between two off-curve points if needed.
+        start = next((i for i, p in enumerate(contour) if p[2]), None)
+        if start is None:
+            a, b = contour[0], contour[1]
+            contour = [((a[0] + b[0]) / 2, (a[1] + b[1]) / 2, True)] + contour[1:] + [a]
+            start = 0
+        contour = contour[start:] + contour[:start]
+        parts.append("M" + point(contour[0]))
+        control = None
+        for p in contour[1:] + [contour[0]]:
+            if p[2]:
+                if control is None:
+                    parts.append("L" + point(p))
+                else:
+                    parts.append("Q" + point(control) + " " + point(p))
+                    control = None
+            else:
+                if control is not None:
+                    mid = ((control[0] + p[0]) / 2, (control[1] + p[1]) / 2)
+                    parts.append("Q" + point(control) + " " + point(mid))
+                control = p
+        parts.append("Z")
+    return "".join(parts)
+
+
+def main():
+    font = Font(open(sys.argv[1], "rb").read())
+    scale = UNITS_PER_EM / font.units_per_em
+
+    print("// Generated by gen_textfont/Makefile. Do not edit.")
+    print("pub(crate) static UNITS_PER_EM: usize = %d;" % UNITS_PER_EM)
+    print("pub(crate) static FIRST_CHAR: char = %r;" % CHARS[0])
+    print()
+    print("/// The advance and outline of each character, from FIRST_CHAR to '~', then from")
+    print("/// '\\u{a0}' to '\\u{ff}'.")
+    print("pub(crate) static GLYPHS: [(f64, &str); %d] = [" % len(CHARS))
+    for c in CHARS:
+        glyph = font.cmap.get(ord(c), 0)
+        print(
+            '    (%s_f64, "%s"),'
+            % (number(font.advance(glyph) * scale), svg_path(font.contours(glyph), scale))
+        )
+    print("];")
+
+
+main()
@@ -1,6 +1,7 @@
 use crate::corefont;
+use crate::textfont;
 use crate::util::{escape, normal, tangent, BEZIER_CIRCLE_FACTOR};
-use kurbo::{BezPath, Line, Point, Rect, TranslateScale, Vec2};
+use kurbo::{Affine, BezPath, CubicBez, Line, Point, Rect, Shape, TranslateScale, Vec2};
 use specs::{Component, VecStorage};
 
 #[derive(Debug, Clone)]
@@ -37,6 +38,19 @@ pub enum Stencil {
     Translate(Vec2, Box<Stencil>),
 }
 
+/// The thickness of the horizontal bar of a multi-measure rest.
+const MULTIREST_THICKNESS: f64 = 250.0;
+
+/// The space between the ends of a multi-measure rest and the barlines around it.
+const MULTIREST_PADDING: f64 = 300.0;
+
+/// The size of fret numbers, relative to time signature digits, so that they fit between the
+/// lines of tablature.
+const FRET_NUMBER_SCALE: f64 = 0.36;
+
+/// The size of the letters of a tablature clef.
+const TAB_CLEF_FONT_SIZE: f64 = 400.0;
+
 impl Component for Stencil {
     type Storage = VecStorage<Self>;
 }
@@ -86,6 +100,37 @@ impl Stencil {
         })
     }
 
+    /// Draw the outline of a circle, with the given line thickness centered on `radius`.
+    pub fn ring(radius: f64, thickness: f64, center: Point) -> Stencil {
+        let outer = radius + thickness / 2.0;
+        let inner = radius - thickness / 2.0;
+
+        // The inner circle is drawn in the opposite direction, so that it is a hole.
+        let mut path = BezPath::new();
+        for &(rx, ry) in &[(outer, outer), (-inner, inner)] {
+            let rx = Vec2::new(rx, 0.0);
+            let ry = Vec2::new(0.0, ry);
+            let cx = rx * BEZIER_CIRCLE_FACTOR;
+            let cy = ry * BEZIER_CIRCLE_FACTOR;
+            path.move_to(center + rx);
+            path.curve_to(center + rx + cy, center + ry + cx, center + ry);
+            path.curve_to(center + ry - cx, center - rx + cy, center - rx);
+            path.curve_to(center - rx - cy, center - ry - cx, center - ry);
+            path.curve_to(center - ry + cx, center + rx - cy, center + rx);
+            path.close_path();
+        }
+        Stencil::Path(Path {
+            bounds: Rect::new(
+                center.x - outer,
+                center.y - outer,
+                center.x + outer,
+                center.y + outer,
+            ),
+            outline: path,
+            advance: center.x + outer,
+        })
+    }
+
     /// Draw a line with rounded edges.
     ///
     /// This stencil has a blot diameter equal to the thickness, which is included in the
@@ -126,12 +171,7 @@ impl Stencil {
         // Done!
         path.close_path();
         Stencil::Path(Path {
-            bounds: Rect::new(
-                line.p0.x - thickness / 2.0,
-                line.p0.y - thickness / 2.0,
-                line.p1.x + thickness / 2.0,
-                line.p1.y + thickness / 2.0,
-            ),
+            bounds: Rect::from_points(line.p0, line.p1).inflate(thickness / 2.0, thickness / 2.0),
             outline: path,
             advance: line.p1.x,
         })
@@ -153,6 +193,64 @@ impl Stencil {
         )
     }
 
+    /// Draw a crescendo or decrescendo hairpin from `x0` to `x1`, centered on y = 0.
+    ///
+    /// `opening0` and `opening1` are the distances between the two lines at each end.
+    pub fn hairpin(x0: f64, x1: f64, opening0: f64, opening1: f64) -> Stencil {
+        Self::line(
+            Line::new(
+                Point::new(x0, -opening0 / 2.0),
+                Point::new(x1, -opening1 / 2.0),
+            ),
+            corefont::HAIRPIN_THICKNESS,
+        )
+        .and(Self::line(
+            Line::new(
+                Point::new(x0, opening0 / 2.0),
+                Point::new(x1, opening1 / 2.0),
+            ),
+            corefont::HAIRPIN_THICKNESS,
+        ))
+    }
+
+    /// Draw a slur whose inner edge is `curve`, thickened towards -y if `above`, or +y otherwise.
+    ///
+    /// Slurs are thinnest at their ends, and thickest in the middle.
+    pub fn slur(curve: CubicBez, above: bool) -> Stencil {
+        let sign = if above { -1.0 } else { 1.0 };
+        let end = Vec2::new(0.0, sign * corefont::SLUR_ENDPOINT_THICKNESS);
+        // The midpoint of a cubic Bézier moves by 3/4 of its control points, and 1/4 of its ends.
+        let control = Vec2::new(
+            0.0,
+            sign * (4.0 * corefont::SLUR_MIDPOINT_THICKNESS - corefont::SLUR_ENDPOINT_THICKNESS)
+                / 3.0,
+        );
+
+        let mut path = BezPath::new();
+        path.move_to(curve.p0);
+        path.curve_to(curve.p1, curve.p2, curve.p3);
+        path.line_to(curve.p3 + end);
+        path.curve_to(curve.p2 + control, curve.p1 + control, curve.p0 + end);
+        path.close_path();
+
+        Stencil::Path(Path {
+            bounds: path.bounding_box(),
+            outline: path,
+            advance: curve.p3.x,
+        })
+    }
+
+    /// The slash through the stem of an acciaccatura, centered on `center`.
+    pub fn grace_note_slash(center: Point) -> Stencil {
+        Self::line(
+            Line::new(
+                center + Vec2::new(-200.0, 150.0),
+                center + Vec2::new(200.0, -150.0),
+            ),
+            corefont::STEM_THICKNESS,
+        )
+    }
+
     pub fn leger_line(notehead_x0: f64, notehead_x1: f64, y: f64) -> Stencil {
         Self::line(
             Line::new(
@@ -294,12 +392,64 @@ impl Stencil {
         )
     }
 
+    /// Draw a box around `rect`, such as around a rehearsal mark.
+    ///
+    /// The box is centered on the edges of `rect`.
+    pub fn text_enclosure(rect: Rect) -> Stencil {
+        let thickness = corefont::TEXT_ENCLOSURE_THICKNESS;
+        let horizontal = |y| {
+            Self::line(
+                Line::new(
+                    Point::new(rect.x0 - thickness / 2.0, y),
+                    Point::new(rect.x1 + thickness / 2.0, y),
+                ),
+                thickness,
+            )
+        };
+        let vertical = |x| {
+            Self::line(
+                Line::new(Point::new(x, rect.y0), Point::new(x, rect.y1)),
+                thickness,
+            )
+        };
+
+        horizontal(rect.y0)
+            .and(horizontal(rect.y1))
+            .and(vertical(rect.x0))
+            .and(vertical(rect.x1))
+    }
+
+    /// Draw a multi-measure rest of `count` bars, `width` wide.
+    ///
+    /// This is a thick horizontal bar on the middle line, closed by short vertical lines, with
+    /// the number of bars centered above the staff.
+    pub fn multirest(width: f64, count: u8) -> Stencil {
+        let x0 = MULTIREST_PADDING;
+        let x1 = width - MULTIREST_PADDING;
+        let number = Self::time_sig_number(count);
+        let number_x = (width - number.advance()) / 2.0;
+
+        Self::round_filled_box(
+            Rect::new(
+                x0,
+                -MULTIREST_THICKNESS / 2.0,
+                x1,
+                MULTIREST_THICKNESS / 2.0,
+            ),
+            corefont::THIN_BARLINE_THICKNESS,
+        )
+        .and(Self::barline_thin(x0, -250.0, 250.0))
+        .and(Self::barline_thin(x1, -250.0, 250.0))
+        .and(number.with_translation(Vec2::new(number_x, -1000.0)))
+        .and(Self::padding(width))
+    }
+
     /// Initialize a stencil, in staff cordinates.
     fn from_corefont(corefont: &(f64, [f64; 4], &str)) -> Stencil {
         assert_eq!(corefont::UNITS_PER_EM, 1000);
         Stencil::RawSvg(RawSvg {
             svg: corefont.2.to_owned(),
-            bounds: Rect::new(corefont.1[0], -corefont.1[1], corefont.1[2], -corefont.1[3]),
+            bounds: Rect::new(corefont.1[0], -corefont.1[3], corefont.1[2], -corefont.1[1]),
             advance: corefont.0,
         })
     }
@@ -398,6 +548,30 @@ impl Stencil {
         Self::from_corefont(&corefont::UNPITCHED_PERCUSSION_CLEF1)
     }
 
+    /// "TAB", written down the staff.
+    pub fn clef_tab() -> Stencil {
+        let width = TAB_CLEF_FONT_SIZE * 0.7;
+        let mut stencil = Stencil::padding(width);
+        for (i, letter) in ["T", "A", "B"].iter().enumerate() {
+            let baseline = (i as f64 - 1.0) * 333.0 + TAB_CLEF_FONT_SIZE / 3.0;
+            stencil = stencil.and(
+                Stencil::text(letter, TAB_CLEF_FONT_SIZE, width)
+                    .with_translation(Vec2::new(0.0, baseline)),
+            );
+        }
+
+        stencil
+    }
+
+    /// A fret number on a line of tablature, starting at x = 0 and centered on y = 0.
+    pub fn fret_number(fret: u8) -> Stencil {
+        let number = match fret {
+            0 => Self::from_corefont(&corefont::TIME_SIG0),
+            fret => Self::time_sig_number(fret),
+        };
+        number.with_scale(FRET_NUMBER_SCALE)
+    }
+
     pub fn rest_maxima() -> Stencil {
         Self::from_corefont(&corefont::REST_MAXIMA)
     }
@@ -485,6 +659,49 @@ impl Stencil {
         )
     }
 
+    pub fn notehead_slash_whole() -> (Stencil, Option<Point>) {
+        (
+            Self::from_corefont(&corefont::NOTEHEAD_SLASH_WHITE_WHOLE),
+            None,
+        )
+    }
+
+    pub fn notehead_slash_half_up() -> (Stencil, Option<Point>) {
+        (
+            Self::from_corefont(&corefont::NOTEHEAD_SLASH_WHITE_HALF),
+            Some(Self::attachment(
+                corefont::NOTEHEAD_SLASH_WHITE_HALF_STEM_UP,
+            )),
+        )
+    }
+
+    pub fn notehead_slash_half_down() -> (Stencil, Option<Point>) {
+        (
+            Self::from_corefont(&corefont::NOTEHEAD_SLASH_WHITE_HALF),
+            Some(Self::attachment(
+                corefont::NOTEHEAD_SLASH_WHITE_HALF_STEM_DOWN,
+            )),
+        )
+    }
+
+    pub fn notehead_slash_black_up() -> (Stencil, Option<Point>) {
+        (
+            Self::from_corefont(&corefont::NOTEHEAD_SLASH_HORIZONTAL_ENDS),
+            Some(Self::attachment(
+                corefont::NOTEHEAD_SLASH_HORIZONTAL_ENDS_STEM_UP,
+            )),
+        )
+    }
+
+    pub fn notehead_slash_black_down() -> (Stencil, Option<Point>) {
+        (
+            Self::from_corefont(&corefont::NOTEHEAD_SLASH_HORIZONTAL_ENDS),
+            Some(Self::attachment(
+                corefont::NOTEHEAD_SLASH_HORIZONTAL_ENDS_STEM_DOWN,
+            )),
+        )
+    }
+
     pub fn notehead_double_whole() -> (Stencil, Option<Point>) {
         (Self::from_corefont(&corefont::NOTEHEAD_DOUBLE_WHOLE), None)
     }
@@ -609,6 +826,82 @@ impl Stencil {
         Self::from_corefont(&corefont::AUGMENTATION_DOT)
     }
 
+    pub fn accent_above() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_ACCENT_ABOVE)
+    }
+
+    pub fn accent_below() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_ACCENT_BELOW)
+    }
+
+    pub fn staccato_above() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_STACCATO_ABOVE)
+    }
+
+    pub fn staccato_below() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_STACCATO_BELOW)
+    }
+
+    pub fn tenuto_above() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_TENUTO_ABOVE)
+    }
+
+    pub fn tenuto_below() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_TENUTO_BELOW)
+    }
+
+    pub fn marcato_above() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_MARCATO_ABOVE)
+    }
+
+    pub fn marcato_below() -> Stencil {
+        Self::from_corefont(&corefont::ARTIC_MARCATO_BELOW)
+    }
+
+    pub fn fermata_above() -> Stencil {
+        Self::from_corefont(&corefont::FERMATA_ABOVE)
+    }
+
+    pub fn fermata_below() -> Stencil {
+        Self::from_corefont(&corefont::FERMATA_BELOW)
+    }
+
+    pub fn trill() -> Stencil {
+        Self::from_corefont(&corefont::ORNAMENT_TRILL)
+    }
+
+    pub fn turn() -> Stencil {
+        Self::from_corefont(&corefont::ORNAMENT_TURN)
+    }
+
+    pub fn dynamic_piano() -> Stencil {
+        Self::from_corefont(&corefont::DYNAMIC_PIANO)
+    }
+
+    pub fn dynamic_mezzo() -> Stencil {
+        Self::from_corefont(&corefont::DYNAMIC_MEZZO)
+    }
+
+    pub fn dynamic_forte() -> Stencil {
+        Self::from_corefont(&corefont::DYNAMIC_FORTE)
+    }
+
+    pub fn dynamic_sforzando() -> Stencil {
+        Self::from_corefont(&corefont::DYNAMIC_SFORZANDO)
+    }
+
+    pub fn dynamic_z() -> Stencil {
+        Self::from_corefont(&corefont::DYNAMIC_Z)
+    }
+
+    pub fn repeat_1_bar() -> Stencil {
+        Self::from_corefont(&corefont::REPEAT1_BAR)
+    }
+
+    pub fn repeat_2_bars() -> Stencil {
+        Self::from_corefont(&corefont::REPEAT2_BARS)
+    }
+
     pub fn combine(stencils: Vec<Stencil>) -> Stencil {
         Stencil::Combine(CombineStencil(stencils))
     }
@@ -617,6 +910,59 @@ impl Stencil {
         Stencil::Translate(offset, Box::new(self))
     }
 
+    /// Scale this stencil about its origin.
+    ///
+    /// Used for small notes, such as grace notes.
+    pub fn with_scale(self, scale: f64) -> Stencil {
+        let scale_path = |mut outline: BezPath, bounds: Rect, advance: f64| {
+            outline.apply_affine(Affine::scale(scale));
+            Stencil::Path(Path {
+                outline,
+                bounds: TranslateScale::scale(scale) * bounds,
+                advance: advance * scale,
+            })
+        };
+
+        match self {
+            Stencil::RawSvg(svg) => scale_path(
+                BezPath::from_svg(&svg.svg).unwrap(),
+                svg.bounds,
+                svg.advance,
+            ),
+            Stencil::Path(path) => scale_path(path.outline, path.bounds, path.advance),
+            Stencil::Text(text) => Stencil::Text(Text {
+                font_size: text.font_size * scale,
+                width: text.width * scale,
+                ..text
+            }),
+            Stencil::Combine(CombineStencil(stencils)) => Stencil::Combine(CombineStencil(
+                stencils.into_iter().map(|s| s.with_scale(scale)).collect(),
+            )),
+            Stencil::Translate(offset, child) => {
+                Stencil::Translate(offset * scale, Box::new(child.with_scale(scale)))
+            }
+        }
+    }
+
+    /// Replace text with the outlines of its glyphs, so that it looks the same without fonts.
+    ///
+    /// Glyphs are stretched to fill the width the text was laid out with.
+    pub fn with_text_outlines(self) -> Stencil {
+        match self {
+            Stencil::Text(text) => Stencil::Path(text_outline(&text)),
+            Stencil::Combine(CombineStencil(stencils)) => Stencil::Combine(CombineStencil(
+                stencils
+                    .into_iter()
+                    .map(|s| s.with_text_outlines())
+                    .collect(),
+            )),
+            Stencil::Translate(offset, child) => {
+                Stencil::Translate(offset, Box::new(child.with_text_outlines()))
+            }
+            stencil => stencil,
+        }
+    }
+
     pub fn and(self, other: Stencil) -> Stencil {
         match (self, other) {
             (
@@ -744,6 +1090,49 @@ impl Stencil {
     }
 }
 
+/// The advance and outline of a character in the text font, or of '?' if it has none.
+fn text_glyph(c: char) -> &'static (f64, &'static str) {
+    let first = textfont::FIRST_CHAR as usize;
+    let index = match c {
+        ' '..='~' => c as usize - first,
+        '\u{a0}'..='\u{ff}' => c as usize - 0xa0 + ('~' as usize - first + 1),
+        _ => '?' as usize - first,
+    };
+
+    &textfont::GLYPHS[index]
+}
+
+fn text_outline(text: &Text) -> Path {
+    let mut outline = BezPath::new();
+    let mut x = 0.0;
+    for c in text.text.chars() {
+        let (advance, d) = text_glyph(c);
+        let glyph = BezPath::from_svg(d).unwrap();
+        for el in (Affine::translate(Vec2::new(x, 0.0)) * glyph).elements() {
+            outline.push(*el);
+        }
+        x += advance;
+    }
+
+    let scale = text.font_size / (textfont::UNITS_PER_EM as f64);
+    let x_scale = if text.width > 0.0 && x > 0.0 {
+        text.width / (x * scale)
+    } else {
+        1.0
+    };
+    outline.apply_affine(Affine::new([scale * x_scale, 0.0, 0.0, scale, 0.0, 0.0]));
+
+    Path {
+        bounds: if outline.elements().is_empty() {
+            Rect::ZERO
+        } else {
+            outline.bounding_box()
+        },
+        outline,
+        advance: x * scale * x_scale,
+    }
+}
+
 impl Default for Stencil {
     fn default() -> Stencil {
         Stencil::Combine(CombineStencil(vec![]))
@@ -855,4 +1244,29 @@ mod tests {
                 .to_svg_doc_for_testing(),
         );
     }
+
+    #[test]
+    fn text_outlines() {
+        let title = Stencil::text("Allegro à la «Six»", 400.0, 3600.0).with_text_outlines();
+        assert!((title.advance() - 3600.0).abs() < 0.001);
+        assert!(title.rect().y0 < -250.0 && title.rect().y1 > 0.0);
+
+        let unknown = Stencil::text("♩", 400.0, 0.0).with_text_outlines();
+        let question = Stencil::text("?", 400.0, 0.0).with_text_outlines();
+        assert_eq!(unknown.advance(), question.advance());
+
+        snapshot(
+            "./snapshots/text_outline_stencils.svg",
+            &Stencil::padding(200.0)
+                .and_right(title)
+                .and_right(Stencil::padding(200.0))
+                .and_right(
+                    Stencil::text("(Mvt. 1)", 250.0, 0.0)
+                        .with_translation(Vec2::new(0.0, 400.0))
+                        .with_text_outlines(),
+                )
+                .with_translation(Vec2::new(0.0, 1000.0))
+                .to_svg_doc_for_testing(),
+        );
+    }
 }
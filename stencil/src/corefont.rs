@@ -7,7 +7,7 @@ pub(crate) static BEAM_THICKNESS: f64 = 125_f64;
 // pub(crate) static DASHED_BARLINE_DASH_LENGTH: f64 = 125_f64;
 // pub(crate) static DASHED_BARLINE_GAP_LENGTH: f64 = 62.5_f64;
 // pub(crate) static DASHED_BARLINE_THICKNESS: f64 = 40_f64;
-// pub(crate) static HAIRPIN_THICKNESS: f64 = 40_f64;
+pub(crate) static HAIRPIN_THICKNESS: f64 = 40_f64;
 pub(crate) static LEGER_LINE_EXTENSION: f64 = 100_f64;
 pub(crate) static LEGER_LINE_THICKNESS: f64 = 40_f64;
 // pub(crate) static LYRIC_LINE_THICKNESS: f64 = 40_f64;
@@ -15,12 +15,12 @@ pub(crate) static LEGER_LINE_THICKNESS: f64 = 40_f64;
 // pub(crate) static PEDAL_LINE_THICKNESS: f64 = 40_f64;
 // pub(crate) static REPEAT_BARLINE_DOT_SEPARATION: f64 = 40_f64;
 // pub(crate) static REPEAT_ENDING_LINE_THICKNESS: f64 = 40_f64;
-// pub(crate) static SLUR_ENDPOINT_THICKNESS: f64 = 25_f64;
-// pub(crate) static SLUR_MIDPOINT_THICKNESS: f64 = 55_f64;
+pub(crate) static SLUR_ENDPOINT_THICKNESS: f64 = 25_f64;
+pub(crate) static SLUR_MIDPOINT_THICKNESS: f64 = 55_f64;
 pub(crate) static STAFF_LINE_THICKNESS: f64 = 32.5_f64;
 pub(crate) static STEM_THICKNESS: f64 = 30_f64;
 // pub(crate) static SUB_BRACKET_THICKNESS: f64 = 40_f64;
-// pub(crate) static TEXT_ENCLOSURE_THICKNESS: f64 = 40_f64;
+pub(crate) static TEXT_ENCLOSURE_THICKNESS: f64 = 40_f64;
 pub(crate) static THICK_BARLINE_THICKNESS: f64 = 125_f64;
 pub(crate) static THIN_BARLINE_THICKNESS: f64 = 40_f64;
 // pub(crate) static TIE_ENDPOINT_THICKNESS: f64 = 25_f64;
@@ -61,6 +61,21 @@ pub(crate) static NOTEHEAD_BLACK: (f64, [f64; 4], &str) = (
     [0_f64, -125_f64, 295_f64, 125_f64],
     "M97 125c89 0 198 -82 198 -167c0 -51 -40 -83 -97 -83c-110 0 -198 81 -198 167c0 52 43 83 97 83z",
 );
+pub(crate) static NOTEHEAD_SLASH_WHITE_WHOLE: (f64, [f64; 4], &str) = (
+    980_f64,
+    [0_f64, -250_f64, 980_f64, 250_f64],
+    "M845 -220l-370 440h-350l370 -440h350zM430 -250l-430 500h550l430 -500h-550z",
+);
+pub(crate) static NOTEHEAD_SLASH_WHITE_HALF: (f64, [f64; 4], &str) = (
+    780_f64,
+    [0_f64, -250_f64, 780_f64, 250_f64],
+    "M645 -220l-370 440h-150l370 -440h150zM430 -250l-430 500h350l430 -500h-350z",
+);
+pub(crate) static NOTEHEAD_SLASH_HORIZONTAL_ENDS: (f64, [f64; 4], &str) = (
+    531_f64,
+    [0_f64, -250_f64, 530_f64, 250_f64],
+    "M430 -250l-430 500h100l430 -500h-100z",
+);
 pub(crate) static FLAG8TH_UP: (f64, [f64; 4], &str) = (264_f64, [0_f64,-810_f64,264_f64,9_f64], "M238 790c0 0 26 -95 26 -173c0 -125 -52 -243 -115 -343c-51 -79 -93 -165 -109 -261c-3 -16 -11 -22 -30 -22c-6 0 -10 3 -10 15v239c66 12 161 148 197 233c15 34 24 91 24 150c0 45 -7 92 -24 137c-2 6 -3 11 -3 15c0 16 10 25 16 29c1 1 3 1 5 1c7 0 19 -6 23 -20z ");
 pub(crate) static FLAG16TH_UP: (f64, [f64; 4], &str) = (279_f64, [0_f64,-813_f64,279_f64,2_f64], "M272 796c4 -5 7 -62 7 -110v-22c0 -42 -11 -83 -29 -120c0 -3 -1 -5 -1 -9c0 -2 0 -4 1 -7c3 -6 25 -66 25 -127c0 -13 -1 -24 -3 -36c-10 -68 -36 -96 -108 -174c-54 -58 -110 -74 -127 -180c-2 -11 -14 -13 -20 -13s-17 1 -17 1v397h5c62 2 133 4 202 144 c23 48 32 97 32 149c0 29 -3 59 -8 89c-1 4 -1 6 -1 9c0 13 8 26 22 26c7 0 14 -4 20 -17zM209 459c-16 -25 -33 -45 -54 -69c-47 -54 -93 -78 -114 -160c-1 -1 -1 -2 -1 -3c0 -4 6 -10 14 -10h8c61 0 115 56 148 105c18 26 27 57 27 89c0 7 0 13 -1 20c-2 8 -2 18 -7 26 c-1 3 -8 6 -13 6c-3 0 -5 -1 -7 -4z");
 pub(crate) static FLAG32ND_UP: (f64, [f64; 4], &str) = (262_f64, [0_f64,-812_f64,261_f64,149_f64], "M260 673c-2 -39 -11 -77 -26 -113c-1 -2 -1 -4 -1 -7c0 -2 0 -4 1 -6c2 -7 23 -62 23 -119c0 -32 -7 -64 -21 -93c9 -26 20 -67 20 -106c0 -10 0 -19 -2 -28c-11 -61 -34 -88 -101 -161c-50 -54 -104 -70 -121 -170c-1 -9 -14 -19 -20 -19s-12 12 -12 12v560h5 c58 2 124 4 188 134c22 44 31 89 31 137c0 28 -3 56 -8 85c0 3 -1 5 -1 7c0 12 6 21 13 24c2 1 4 2 6 2c6 0 13 -5 20 -16c3 -6 7 -52 7 -95c0 -10 -1 -19 -1 -28zM208 181c11 20 15 35 15 48c0 8 -2 14 -3 21c-2 10 -7 27 -12 44c-14 -18 -32 -37 -55 -62 c-49 -54 -98 -71 -114 -168c55 0 114 24 169 117zM219 456c-2 8 -2 16 -5 23c-1 3 -6 6 -11 6c-3 0 -6 -1 -7 -4c-15 -22 -29 -39 -46 -59l-5 -5c-43 -51 -87 -72 -107 -149c0 -1 7 -12 13 -13c80 0 170 96 170 177c0 8 -1 16 -2 24z");
@@ -88,10 +103,47 @@ pub(crate) static REST256TH: (f64, [f64; 4], &str) = (541_f64, [0_f64,-1000_f64,
 pub(crate) static ACCIDENTAL_FLAT: (f64, [f64; 4], &str) = (226_f64, [0_f64,-175_f64,226_f64,439_f64], "M47 81c0 0 -3 -60 -3 -100c0 -16 1 -28 2 -32c7 -20 47 -49 70 -49c29 0 41 33 41 58c0 54 -46 108 -89 135c-4 2 -7 3 -10 3c-9 0 -11 -10 -11 -15zM12 170c3 4 6 5 9 5s6 -2 6 -2c30 -17 54 -44 79 -61c89 -62 120 -123 120 -169c0 -57 -44 -93 -90 -96 c-17 0 -41 8 -55 17c-6 5 -17 14 -22 14c-2 0 -3 0 -5 -1c-7 -3 -11 -10 -11 -17c1 -22 7 -262 7 -282c0 -11 -9 -17 -19 -17c-14 0 -30 10 -31 28c0 0 4 571 12 581z");
 pub(crate) static ACCIDENTAL_NATURAL: (f64, [f64; 4], &str) = (168_f64, [0_f64,-335_f64,168_f64,341_f64], "M8 195c1 0 6 -1 7 -2c14 -6 70 -30 99 -30c10 0 17 3 17 11v149c0 7 5 12 12 12h13c6 0 12 -5 12 -12v-502c0 -5 -4 -8 -8 -8c-1 0 -3 0 -4 1l-15 5c-2 0 -3 1 -4 1c0 0 -64 23 -90 23c-6 0 -10 -1 -10 -5v-167c0 -7 -6 -12 -12 -12h-13c-7 0 -12 5 -12 12v515 c0 6 3 9 8 9zM37 -39c0 -14 61 -40 85 -40c6 0 9 1 9 5v103c0 18 -57 41 -82 41c-7 0 -12 -2 -12 -6v-103z");
 pub(crate) static ACCIDENTAL_SHARP: (f64, [f64; 4], &str) = (249_f64, [0_f64,-348_f64,249_f64,350_f64], "M8 209c1 0 3 -1 4 -1c0 0 14 -6 23 -9c1 -1 2 -1 3 -1c7 0 12 11 12 16v123c0 6 6 11 13 11c10 0 17 -5 17 -11v-139c0 -13 5 -20 10 -22l61 -25c1 0 3 -1 4 -1c8 0 13 12 13 18v125c0 6 6 11 13 11c11 0 17 -5 17 -11v-142c0 -8 4 -20 11 -23s28 -11 28 -11 c7 -3 12 -11 12 -17v-71c0 -5 -3 -8 -7 -8c-2 0 -3 0 -5 1l-26 10c-6 0 -13 -6 -13 -18v-93c0 -7 5 -26 13 -29l26 -10c7 -3 12 -11 12 -17v-71c0 -5 -3 -8 -7 -8c-2 0 -3 0 -5 1c0 0 -20 8 -25 9c-7 0 -14 -5 -14 -13v-122c0 -6 -6 -11 -14 -11c-10 0 -16 5 -16 11v130 c-1 10 -4 23 -13 29c-12 7 -46 21 -63 25c-9 0 -12 -12 -12 -20v-120c0 -6 -7 -11 -14 -11c-10 0 -16 5 -16 11v135c0 14 -6 24 -12 27s-26 11 -26 11c-7 2 -12 10 -12 16v71c0 6 3 9 8 9c4 0 23 -9 26 -11c10 0 16 5 16 17v99c0 11 -5 20 -11 23c-6 2 -27 11 -27 11 c-7 2 -12 10 -12 16v71c0 6 3 9 8 9zM80 -44c2 -17 48 -38 73 -38c7 0 13 2 15 6c2 5 4 30 4 57s-2 55 -4 64c-6 20 -53 40 -76 40c-6 0 -11 -2 -12 -5c-2 -4 -3 -26 -3 -50c0 -31 1 -66 3 -74z");
+pub(crate) static ARTIC_ACCENT_ABOVE: (f64, [f64; 4], &str) = (339_f64, [0_f64,1_f64,339_f64,245_f64], "M326 -105c13 -3 13 -10 13 -18s0 -14 -13 -18l-300 -102c-4 -1 -8 -2 -9 -2c-9 0 -12 6 -15 14c-1 4 -2 7 -2 10c0 5 3 10 14 14c0 0 216 73 226 77c5 1 7 4 7 7s-2 5 -8 7c-11 3 -225 76 -225 76c-11 5 -14 10 -14 15c0 3 1 6 2 9c3 7 7 15 14 15c1 0 3 0 4 -1z");
+pub(crate) static ARTIC_ACCENT_BELOW: (f64, [f64; 4], &str) = (339_f64, [0_f64,-244_f64,339_f64,0_f64], "M326 140c13 -3 13 -10 13 -18s0 -14 -13 -18l-300 -102c-4 -1 -8 -2 -9 -2c-9 0 -12 6 -15 14c-1 4 -2 7 -2 10c0 5 3 10 14 14c0 0 216 73 226 77c5 1 7 4 7 7s-2 5 -8 7c-11 3 -225 76 -225 76c-11 5 -14 10 -14 15c0 3 1 6 2 9c3 7 7 15 14 15c1 0 3 0 4 -1z");
+pub(crate) static ARTIC_STACCATO_ABOVE: (f64, [f64; 4], &str) = (
+    84_f64,
+    [0_f64, 0_f64, 84_f64, 84_f64],
+    "M84 -42c0 -23 -19 -42 -42 -42s-42 19 -42 42s19 42 42 42s42 -19 42 -42z",
+);
+pub(crate) static ARTIC_STACCATO_BELOW: (f64, [f64; 4], &str) = (
+    84_f64,
+    [0_f64, -84_f64, 84_f64, 0_f64],
+    "M84 42c0 -23 -19 -42 -42 -42s-42 19 -42 42s19 42 42 42s42 -19 42 -42z",
+);
+pub(crate) static ARTIC_TENUTO_ABOVE: (f64, [f64; 4], &str) = (
+    338_f64,
+    [-1_f64, 0_f64, 338_f64, 48_f64],
+    "M314 0c23 0 24 -11 24 -24s0 -24 -24 -24h-291c-24 0 -24 11 -24 24s0 24 24 24h291z",
+);
+pub(crate) static ARTIC_TENUTO_BELOW: (f64, [f64; 4], &str) = (
+    338_f64,
+    [-1_f64, -48_f64, 338_f64, 0_f64],
+    "M314 48c23 0 24 -11 24 -24s0 -24 -24 -24h-291c-24 0 -24 11 -24 24s0 24 24 24h291z",
+);
+pub(crate) static ARTIC_MARCATO_ABOVE: (f64, [f64; 4], &str) = (236_f64, [-1_f64,-1_f64,235_f64,253_f64], "M126 -247c-3 -4 -6 -6 -10 -6c-7 0 -10 6 -10 6l-106 231c-1 2 -1 3 -1 5c0 5 2 10 7 11c1 1 4 1 5 1c4 0 9 -2 11 -7c0 0 54 -119 56 -123c1 -2 2 -3 4 -3s4 1 6 3c1 4 57 123 57 123c3 4 7 6 11 6h68c7 -1 11 -5 11 -11c0 -2 0 -4 -1 -5z");
+pub(crate) static ARTIC_MARCATO_BELOW: (f64, [f64; 4], &str) = (236_f64, [-1_f64,-254_f64,235_f64,0_f64], "M108 248c3 4 6 6 10 6c7 0 10 -6 10 -6l106 -231c1 -2 1 -3 1 -5c0 -5 -2 -10 -7 -11c-1 -1 -4 -1 -5 -1c-4 0 -9 2 -11 7c0 0 -54 119 -56 123c-1 2 -2 3 -4 3s-4 -1 -6 -3c-1 -4 -57 -123 -57 -123c-3 -4 -7 -6 -11 -6h-68c-7 1 -11 5 -11 11c0 2 0 4 1 5z");
+pub(crate) static FERMATA_ABOVE: (f64, [f64; 4], &str) = (605_f64, [3_f64,-3_f64,605_f64,329_f64], "M302 -221c213 0 256 158 266 196c1 3 1 6 2 7c7 14 11 21 21 21c9 0 14 -4 14 -14c0 -3 0 -6 -1 -10c-62 -306 -271 -308 -300 -308c-32 0 -239 2 -300 308c-1 4 -1 8 -1 11c0 10 5 13 13 13c9 0 14 -7 20 -21c1 -1 2 -5 3 -9c12 -41 57 -194 263 -194zM358 -52 c0 -29 -25 -54 -55 -54c-29 0 -54 25 -54 54c0 30 25 55 54 55c30 0 55 -25 55 -55z");
+pub(crate) static FERMATA_BELOW: (f64, [f64; 4], &str) = (605_f64, [3_f64,-332_f64,605_f64,0_f64], "M302 224c-206 0 -251 -153 -263 -194c-1 -4 -2 -8 -3 -9c-6 -14 -11 -21 -20 -21c-8 0 -13 3 -13 13c0 3 0 7 1 11c61 306 268 308 300 308c29 0 238 -2 300 -308c1 -4 1 -7 1 -10c0 -10 -5 -14 -14 -14c-10 0 -14 7 -21 21c-1 1 -1 4 -2 7c-10 38 -53 196 -266 196z M358 55c0 -30 -25 -55 -55 -55c-29 0 -54 25 -54 55c0 29 25 54 54 54c30 0 55 -25 55 -54z");
+pub(crate) static ORNAMENT_TRILL: (f64, [f64; 4], &str) = (521_f64, [0_f64,-10_f64,521_f64,390_f64], "M266 -390c-4 0 -10 2 -13 3l-49 16c-9 3 -13 6 -17 16l-36 88c-4 10 -5 12 -8 12c-4 0 -10 -5 -19 -9c-14 -6 -27 -9 -42 -9c-47 0 -82 28 -82 70c0 31 21 57 66 57c12 0 25 -2 31 -2c2 0 3 1 3 3s-1 5 -3 10l-11 27c-11 27 -19 47 -19 68c0 28 19 50 57 50 c52 0 102 -42 102 -116c0 -24 -4 -46 -13 -68c-2 -5 -3 -7 -3 -10c0 -6 6 -11 22 -20l6 -3c35 -21 59 -35 78 -35c9 0 13 3 13 15c0 10 -3 22 -6 29l-73 181c-2 5 -3 8 -3 11c0 4 3 6 11 6h49c9 0 12 -2 16 -12l65 -162c13 -33 45 -70 67 -70c6 0 10 2 10 6 c0 9 -22 12 -22 40c0 20 15 32 36 32c24 0 42 -20 42 -55c0 -29 -15 -53 -52 -53c-26 0 -47 14 -62 31c-9 10 -10 14 -14 14c-5 0 -1 -7 -9 -22c-7 -13 -22 -22 -44 -22c-40 0 -78 20 -99 31c-13 7 -19 11 -24 11c-2 0 -3 -1 -3 -3c0 -4 3 -10 5 -15l50 -124 c2 -5 3 -8 3 -11c0 -4 -2 -6 -6 -6zM82 -250c11 0 51 9 51 24c0 3 -2 8 -4 13l-4 10c-8 22 -36 24 -57 24c-33 0 -41 -16 -41 -30c0 -18 15 -41 55 -41zM189 -167c3 0 3 3 5 8c0 17 9 36 9 54c0 44 -27 84 -56 84c-8 0 -11 -5 -11 -12c0 -8 4 -21 8 -31c0 -1 1 -1 39 -96 c2 -5 3 -7 6 -7z");
+pub(crate) static ORNAMENT_TURN: (f64, [f64; 4], &str) = (460_f64, [0_f64,0_f64,460_f64,218_f64], "M371 -218c-31 0 -56 14 -56 44c0 22 15 36 36 36c29 0 30 -23 51 -23c17 0 33 21 33 47c0 43 -30 64 -65 64c-32 0 -65 -27 -108 -75c-49 -54 -93 -92 -158 -92c-66 0 -104 50 -104 114c0 72 46 103 89 103c31 0 56 -14 56 -44c0 -22 -15 -36 -36 -36 c-29 0 -30 23 -51 23c-17 0 -33 -21 -33 -47c0 -43 30 -64 65 -64c32 0 65 27 108 75c49 54 93 92 158 92c66 0 104 -50 104 -114c0 -72 -46 -103 -89 -103z");
+pub(crate) static DYNAMIC_PIANO: (f64, [f64; 4], &str) = (365_f64, [-89_f64,-142_f64,366_f64,274_f64], "M-89 130c0 8 4 12 12 12h193c9 0 13 -4 13 -13c0 -8 -4 -12 -12 -12h-40c-6 0 -9 0 -9 -3c0 -1 1 -4 2 -7l45 -112c2 -5 4 -12 9 -12s8 10 24 18c14 7 27 9 44 9c96 0 174 -100 174 -195c0 -58 -36 -89 -92 -89c-31 0 -53 10 -71 26c-14 12 -17 20 -21 20 c-5 0 -2 -7 -11 -24c-7 -12 -22 -21 -48 -21c-59 0 -91 42 -122 99c-5 9 -7 14 -7 19c0 7 5 11 11 11c7 0 11 -6 16 -15c29 -50 49 -76 67 -76c8 0 11 5 11 12c0 8 -3 18 -6 25l-123 305c-3 8 -5 10 -15 10h-31c-9 0 -13 4 -13 13zM163 -125l20 -49c14 -34 40 -63 64 -63 c17 0 23 15 23 37c0 49 -53 176 -101 176c-17 0 -25 -11 -25 -32s8 -41 19 -69z");
+pub(crate) static DYNAMIC_MEZZO: (f64, [f64; 4], &str) = (437_f64, [-20_f64,-10_f64,446_f64,274_f64], "M-9 -144c7 0 10 -5 15 -13c30 -51 50 -78 68 -78c8 0 11 5 11 12c0 8 -3 18 -6 25l-73 181c-2 5 -3 8 -3 11c0 4 3 6 11 6h49c9 0 12 -2 16 -12l65 -162c12 -29 33 -50 55 -50c12 0 15 6 15 14c0 12 -4 23 -10 37c0 1 -1 1 -63 156c-2 5 -3 8 -3 11c0 4 3 6 11 6h49 c9 0 12 -2 16 -12l65 -162c12 -29 33 -50 55 -50c12 0 15 6 15 14c0 35 -62 138 -62 180c0 26 16 40 45 40c38 0 73 -28 105 -81c6 -10 9 -16 9 -21c0 -6 -4 -9 -9 -9c-7 0 -10 5 -15 13c-19 30 -38 54 -51 54c-6 0 -8 -3 -8 -9c0 -35 58 -122 58 -177 c0 -25 -13 -54 -54 -54c-29 0 -52 14 -68 31c-10 10 -11 14 -15 14c-5 0 -2 -8 -8 -21s-19 -24 -44 -24c-29 0 -52 14 -68 31c-10 10 -11 14 -15 14c-5 0 -2 -7 -8 -21c-6 -13 -20 -23 -39 -23c-53 0 -84 43 -115 99c-5 9 -7 14 -7 19c0 7 5 11 11 11z");
+pub(crate) static DYNAMIC_FORTE: (f64, [f64; 4], &str) = (364_f64, [-141_f64,-152_f64,364_f64,444_f64], "M-141 85c0 44 33 67 75 67c76 0 123 -51 178 -164c29 -61 51 -123 75 -207c0 -5 10 -14 14 -14h65c11 0 16 -5 16 -16c0 -10 -5 -15 -15 -15h-60c-7 0 -10 0 -10 -5c0 -3 1 -6 2 -12c20 -87 42 -140 92 -140c8 0 16 2 16 8s-5 5 -16 10c-13 6 -21 20 -21 38 c0 28 20 42 45 42c26 0 49 -17 49 -54s-23 -67 -86 -67c-97 0 -151 69 -184 165c-5 15 -6 15 -20 15h-58c-11 0 -16 5 -16 16c0 10 5 15 15 15h58c6 0 8 0 8 4c0 2 -1 5 -2 10l-63 219c-28 98 -46 130 -86 130c-13 0 -18 -4 -18 -9c0 -8 9 -3 24 -13c12 -8 20 -21 20 -37 c0 -26 -18 -41 -45 -41c-30 0 -52 24 -52 55z");
+pub(crate) static DYNAMIC_SFORZANDO: (f64, [f64; 4], &str) = (229_f64, [0_f64,-10_f64,229_f64,273_f64], "M147 -273c-59 0 -100 30 -100 83c0 35 19 54 54 78c29 20 38 33 38 53c0 22 -19 45 -51 45c-25 0 -42 -8 -42 -15c0 -5 6 -3 17 -10c9 -6 14 -16 14 -29c0 -20 -17 -33 -36 -33c-24 0 -41 20 -41 47c0 33 34 64 87 64c65 0 115 -35 115 -93c0 -28 -10 -49 -49 -78 c-36 -27 -48 -38 -48 -57c0 -16 12 -33 43 -33c17 0 31 4 31 11c0 4 -5 4 -12 9c-6 5 -10 13 -10 24c0 20 15 32 35 32c24 0 37 -19 37 -38c0 -37 -31 -60 -82 -60z");
+pub(crate) static DYNAMIC_Z: (f64, [f64; 4], &str) = (244_f64, [-30_f64,-10_f64,244_f64,268_f64], "M231 -268c-8 0 -13 3 -24 6s-27 5 -41 5c-60 0 -89 -9 -101 -9c-10 0 -15 4 -18 13l-24 70c-2 6 -3 11 -3 15c0 7 5 10 11 10c7 0 11 -6 15 -15l10 -21c3 -6 5 -12 11 -12c4 0 21 3 50 3c18 0 32 -7 38 -7c4 0 -18 22 -19 23l-154 156c-8 8 -12 12 -12 20c0 7 5 12 13 12 c9 0 15 -8 20 -12c8 -6 16 -10 24 -10c28 0 48 31 97 31c56 0 82 -40 82 -81c0 -31 -18 -44 -36 -44s-33 14 -33 35c0 19 13 30 27 30c10 0 13 -5 16 -5c2 0 3 1 3 3s-2 6 -4 10c-4 7 -11 11 -20 11c-30 0 -39 -35 -78 -35c-13 0 -19 6 -22 6c0 0 7 -12 12 -17l160 -158 c8 -8 13 -14 13 -22c0 -7 -6 -11 -13 -11z");
+pub(crate) static REPEAT1_BAR: (f64, [f64; 4], &str) = (533_f64, [0_f64,-250_f64,532_f64,279_f64], "M469 49c-34 0 -62 28 -62 63c0 34 28 62 62 62c35 0 63 -28 63 -62c0 -35 -28 -63 -63 -63zM62 -200c-34 0 -62 28 -62 63c0 34 28 62 62 62c35 0 63 -28 63 -62c0 -35 -28 -63 -63 -63zM527 -264c3 -4 5 -7 5 -10s-2 -5 -6 -5h-106c-6 0 -11 6 -18 15l-399 500 c-2 2 -3 5 -3 7c0 4 3 7 8 7h99c9 0 16 -8 21 -14z");
+pub(crate) static REPEAT2_BARS: (f64, [f64; 4], &str) = (761_f64, [0_f64,-250_f64,762_f64,279_f64], "M0 243c0 4 3 7 8 7h99c9 0 16 -8 21 -14l399 -500c3 -4 5 -7 5 -10s-2 -5 -6 -5h-106c-6 0 -11 6 -18 15l-399 500c-2 2 -3 5 -3 7zM230 243c0 4 3 7 8 7h99c9 0 16 -8 21 -14l399 -500c3 -4 5 -7 5 -10s-2 -5 -6 -5h-106c-6 0 -11 6 -18 15l-399 500c-2 2 -3 5 -3 7z M637 112c0 34 28 62 62 62c35 0 63 -28 63 -62c0 -35 -28 -63 -63 -63c-34 0 -62 28 -62 63zM0 -137c0 34 28 62 62 62c35 0 63 -28 63 -62c0 -35 -28 -63 -63 -63c-34 0 -62 28 -62 63z");
 pub(crate) static NOTEHEAD_X_HALF_STEM_DOWN: [f64; 2] = [0_f64, -103_f64];
 pub(crate) static NOTEHEAD_X_BLACK_STEM_DOWN: [f64; 2] = [0_f64, -110_f64];
 pub(crate) static NOTEHEAD_HALF_STEM_DOWN: [f64; 2] = [0_f64, -42_f64];
 pub(crate) static NOTEHEAD_BLACK_STEM_DOWN: [f64; 2] = [0_f64, -42_f64];
+pub(crate) static NOTEHEAD_SLASH_WHITE_HALF_STEM_DOWN: [f64; 2] = [0_f64, -250_f64];
+pub(crate) static NOTEHEAD_SLASH_HORIZONTAL_ENDS_STEM_DOWN: [f64; 2] = [0_f64, -250_f64];
 pub(crate) static FLAG8TH_DOWN_STEM_DOWN: [f64; 2] = [0_f64, 33_f64];
 pub(crate) static FLAG16TH_DOWN_STEM_DOWN: [f64; 2] = [0_f64, 32_f64];
 pub(crate) static FLAG32ND_DOWN_STEM_DOWN: [f64; 2] = [0_f64, -112_f64];
@@ -102,6 +154,8 @@ pub(crate) static NOTEHEAD_X_HALF_STEM_UP: [f64; 2] = [334_f64, 103_f64];
 pub(crate) static NOTEHEAD_X_BLACK_STEM_UP: [f64; 2] = [290_f64, 111_f64];
 pub(crate) static NOTEHEAD_HALF_STEM_UP: [f64; 2] = [295_f64, 42_f64];
 pub(crate) static NOTEHEAD_BLACK_STEM_UP: [f64; 2] = [295_f64, 42_f64];
+pub(crate) static NOTEHEAD_SLASH_WHITE_HALF_STEM_UP: [f64; 2] = [780_f64, 250_f64];
+pub(crate) static NOTEHEAD_SLASH_HORIZONTAL_ENDS_STEM_UP: [f64; 2] = [530_f64, 250_f64];
 pub(crate) static FLAG8TH_UP_STEM_UP: [f64; 2] = [0_f64, -10_f64];
 pub(crate) static FLAG16TH_UP_STEM_UP: [f64; 2] = [0_f64, -22_f64];
 pub(crate) static FLAG32ND_UP_STEM_UP: [f64; 2] = [0_f64, 94_f64];
@@ -3,8 +3,12 @@
 pub mod components;
 mod corefont;
 mod pdf;
+mod raster;
 mod snapshot;
+mod textfont;
 mod util;
 
-pub use pdf::Pdf;
-pub use snapshot::snapshot;
+pub use pdf::{embedded_files, Pdf};
+pub use raster::Raster;
+pub use snapshot::{snapshot, snapshot_bytes};
+pub use util::escape;
@@ -7,6 +7,10 @@ use kurbo::{Affine, BezPath, PathEl, Point, Size};
 use specs::{shred::Fetch, storage::MaskedStorage, Storage};
 use std::io::{self, Write};
 
+mod read;
+
+pub use read::embedded_files;
+
 struct Counter<T> {
     inner: T,
     count: u64,
@@ -55,7 +59,8 @@ pub struct Pdf {
     page_size: Option<Size>,
     compression: Option<Compression>,
     fill_color: Vec<(f32, f32, f32)>,
-    file: Option<usize>,
+    /// The embedded file object, and its name.
+    file: Option<(usize, String)>,
 }
 
 impl Default for Pdf {
@@ -257,11 +262,15 @@ impl Pdf {
         self.add_object(page_object, true, false);
     }
 
-    pub fn add_file(&mut self, file_str: &str) {
+    /// Embed a file, such as the song the PDF was made from. `mime_type` is, e.g.,
+    /// "application/json".
+    pub fn add_file(&mut self, name: &str, mime_type: &str, file_str: &str) {
+        let subtype = mime_type.replace('/', "#2F");
         let file_stream = if let Some(level) = self.compression {
             let compressed = deflate::deflate_bytes_zlib_conf(file_str.as_bytes(), level);
             let mut file = format!(
-                "<< /Type /EmbeddedFile /Subtype /application#2Fjson /Length {} /Filter [/FlateDecode] >>\nstream\n",
+                "<< /Type /EmbeddedFile /Subtype /{} /Length {} /Filter [/FlateDecode] >>\nstream\n",
+                subtype,
                 compressed.len()
             )
             .into_bytes();
@@ -273,7 +282,8 @@ impl Pdf {
             let file_bytes = file_str.as_bytes();
             file.extend(
                 format!(
-                    "<< /Type /EmbeddedFile /Subtype /application#2Fjson /Length {} >>\nstream\n",
+                    "<< /Type /EmbeddedFile /Subtype /{} /Length {} >>\nstream\n",
+                    subtype,
                     file_bytes.len()
                 )
                 .bytes(),
@@ -284,7 +294,7 @@ impl Pdf {
         };
 
         let file_obj = self.add_object(file_stream, false, false);
-        self.file = Some(file_obj);
+        self.file = Some((file_obj, name.to_owned()));
     }
 
     fn add_object(&mut self, data: Vec<u8>, is_page: bool, is_xobject: bool) -> usize {
@@ -348,10 +358,10 @@ impl Pdf {
 
         // Write out the catalog dictionary object
         self.objects[0].offset = Some(out.pos());
-        let files = if let Some(file) = self.file {
+        let files = if let Some((file, name)) = &self.file {
             format!(
-                "\n/Names << /EmbeddedFiles << /Names [ (sixeight.json) << /EF << /F {} 0 R >> /F (sixeight.json) /Type /F >> ] >> >>",
-                file
+                "\n/Names << /EmbeddedFiles << /Names [ ({}) << /EF << /F {} 0 R >> /F ({}) /Type /F >> ] >> >>",
+                name, file, name
             )
         } else {
             String::default()
@@ -420,13 +430,7 @@ impl Pdf {
         self.fill();
     }
 
-    pub fn write_stencil(
-        &mut self,
-        stencil: &Stencil,
-        transform: Affine,
-        stencils: &Storage<Stencil, Fetch<MaskedStorage<Stencil>>>,
-        stencil_maps: &Storage<StencilMap, Fetch<MaskedStorage<StencilMap>>>,
-    ) {
+    pub fn write_stencil(&mut self, stencil: &Stencil, transform: Affine) {
         match stencil {
             Stencil::RawSvg(svg) => {
                 self.write_path(&BezPath::from_svg(&svg.svg).unwrap(), transform);
@@ -439,16 +443,11 @@ impl Pdf {
             }
             Stencil::Combine(CombineStencil(combine)) => {
                 for stencil in combine {
-                    self.write_stencil(stencil, transform, stencils, stencil_maps);
+                    self.write_stencil(stencil, transform);
                 }
             }
             Stencil::Translate(offset, child) => {
-                self.write_stencil(
-                    child,
-                    transform * Affine::translate(*offset),
-                    stencils,
-                    stencil_maps,
-                );
+                self.write_stencil(child, transform * Affine::translate(*offset));
             }
         }
     }
@@ -473,7 +472,7 @@ impl Pdf {
                 transform
             };
             if let Some(child) = stencils.get(child) {
-                self.write_stencil(child, child_transform, stencils, stencil_maps);
+                self.write_stencil(child, child_transform);
             }
             if let Some(child) = stencil_maps.get(child) {
                 self.write_stencil_map(child, child_transform, stencils, stencil_maps);
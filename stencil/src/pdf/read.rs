@@ -0,0 +1,550 @@
+//! Reading the files embedded in a PDF, such as the song a Six Eight PDF was made from.
+//!
+//! Objects are found by scanning for `obj` keywords instead of reading the cross-reference table,
+//! skipping over stream data, so PDFs with damaged tables can be read, but objects in compressed
+//! object streams cannot.
+
+use std::collections::HashMap;
+
+/// How deep references, arrays, and name trees can nest, so that cyclic PDFs can be read.
+const MAX_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Object {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Vec<u8>),
+    Name(String),
+    Array(Vec<Object>),
+    Dictionary(HashMap<String, Object>),
+    Reference(u32),
+    Stream(HashMap<String, Object>, Vec<u8>),
+}
+
+impl Object {
+    fn dictionary(&self) -> Option<&HashMap<String, Object>> {
+        match self {
+            Object::Dictionary(dict) | Object::Stream(dict, _) => Some(dict),
+            _ => None,
+        }
+    }
+}
+
+fn is_whitespace(c: u8) -> bool {
+    b"\0\t\n\x0c\r ".contains(&c)
+}
+
+fn is_delimiter(c: u8) -> bool {
+    b"()<>[]{}/%".contains(&c)
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Parser<'a> {
+        Parser { data, pos }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, token: &[u8]) -> bool {
+        self.data[self.pos.min(self.data.len())..].starts_with(token)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == b'%' {
+                while self.peek().is_some_and(|c| c != b'\n' && c != b'\r') {
+                    self.pos += 1;
+                }
+            } else if is_whitespace(c) {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// A run of characters that are not whitespace or delimiters.
+    fn token(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !is_whitespace(c) && !is_delimiter(c))
+        {
+            self.pos += 1;
+        }
+        &self.data[start..self.pos]
+    }
+
+    fn object(&mut self, depth: usize) -> Option<Object> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        self.skip_whitespace();
+        match self.peek()? {
+            b'/' => {
+                self.pos += 1;
+                Some(Object::Name(self.name()?))
+            }
+            b'(' => {
+                self.pos += 1;
+                Some(Object::String(self.literal_string()?))
+            }
+            b'<' if self.starts_with(b"<<") => {
+                self.pos += 2;
+                let dict = self.dictionary(depth)?;
+                self.skip_whitespace();
+                if self.starts_with(b"stream") {
+                    self.pos += b"stream".len();
+                    let data = self.stream(&dict)?;
+                    Some(Object::Stream(dict, data))
+                } else {
+                    Some(Object::Dictionary(dict))
+                }
+            }
+            b'<' => {
+                self.pos += 1;
+                Some(Object::String(self.hex_string()?))
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut array = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? == b']' {
+                        self.pos += 1;
+                        return Some(Object::Array(array));
+                    }
+                    array.push(self.object(depth + 1)?);
+                }
+            }
+            _ => {
+                let start = self.pos;
+                let token = self.token();
+                match token {
+                    b"true" => return Some(Object::Bool(true)),
+                    b"false" => return Some(Object::Bool(false)),
+                    b"null" => return Some(Object::Null),
+                    _ => {}
+                }
+                let number: f64 = std::str::from_utf8(token).ok()?.parse().ok()?;
+
+                // "12 0 R" is a reference to object 12.
+                let after_number = self.pos;
+                self.skip_whitespace();
+                let generation = self.token();
+                self.skip_whitespace();
+                if !generation.is_empty()
+                    && generation.iter().all(u8::is_ascii_digit)
+                    && self.token() == b"R"
+                {
+                    let id = std::str::from_utf8(&self.data[start..after_number]).ok()?;
+                    return Some(Object::Reference(id.parse().ok()?));
+                }
+                self.pos = after_number;
+
+                Some(Object::Number(number))
+            }
+        }
+    }
+
+    fn name(&mut self) -> Option<String> {
+        let token = self.token();
+        let mut name = Vec::new();
+        let mut i = 0;
+        while i < token.len() {
+            if token[i] == b'#' {
+                let hex = std::str::from_utf8(token.get(i + 1..i + 3)?).ok()?;
+                name.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 3;
+            } else {
+                name.push(token[i]);
+                i += 1;
+            }
+        }
+
+        String::from_utf8(name).ok()
+    }
+
+    fn literal_string(&mut self) -> Option<Vec<u8>> {
+        let mut string = Vec::new();
+        let mut depth = 0;
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            match c {
+                b'(' => {
+                    depth += 1;
+                    string.push(c);
+                }
+                b')' if depth == 0 => return Some(string),
+                b')' => {
+                    depth -= 1;
+                    string.push(c);
+                }
+                b'\\' => {
+                    let escaped = self.peek()?;
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => string.push(b'\n'),
+                        b'r' => string.push(b'\r'),
+                        b't' => string.push(b'\t'),
+                        b'b' => string.push(8),
+                        b'f' => string.push(12),
+                        b'0'..=b'7' => {
+                            let mut value = u32::from(escaped - b'0');
+                            for _ in 0..2 {
+                                match self.peek() {
+                                    Some(digit @ b'0'..=b'7') => {
+                                        value = value * 8 + u32::from(digit - b'0');
+                                        self.pos += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            string.push(value as u8);
+                        }
+                        // A backslash at the end of a line continues the string.
+                        b'\r' => {
+                            if self.peek() == Some(b'\n') {
+                                self.pos += 1;
+                            }
+                        }
+                        b'\n' => {}
+                        c => string.push(c),
+                    }
+                }
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn hex_string(&mut self) -> Option<Vec<u8>> {
+        let mut digits = Vec::new();
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            match c {
+                b'>' => break,
+                c if is_whitespace(c) => {}
+                c => digits.push((c as char).to_digit(16)? as u8),
+            }
+        }
+        if digits.len() % 2 == 1 {
+            digits.push(0);
+        }
+
+        Some(
+            digits
+                .chunks(2)
+                .map(|pair| pair[0] * 16 + pair[1])
+                .collect(),
+        )
+    }
+
+    fn dictionary(&mut self, depth: usize) -> Option<HashMap<String, Object>> {
+        let mut dict = HashMap::new();
+        loop {
+            self.skip_whitespace();
+            if self.starts_with(b">>") {
+                self.pos += 2;
+                return Some(dict);
+            }
+            if self.peek()? != b'/' {
+                return None;
+            }
+            self.pos += 1;
+            let key = self.name()?;
+            let value = self.object(depth + 1)?;
+            dict.insert(key, value);
+        }
+    }
+
+    /// The data of a stream, after the `stream` keyword.
+    fn stream(&mut self, dict: &HashMap<String, Object>) -> Option<Vec<u8>> {
+        if self.starts_with(b"\r\n") {
+            self.pos += 2;
+        } else if self.starts_with(b"\n") {
+            self.pos += 1;
+        }
+        let start = self.pos;
+
+        // The length may be a reference, which is not resolved here, so the end of the stream is
+        // found from the `endstream` keyword instead.
+        let end = match dict.get("Length") {
+            Some(Object::Number(length)) if self.data[start..].len() >= *length as usize => {
+                start + *length as usize
+            }
+            _ => {
+                let offset = self.data[start..]
+                    .windows(b"endstream".len())
+                    .position(|window| window == b"endstream")?;
+                let mut end = start + offset;
+                if self.data[start..end].ends_with(b"\n") {
+                    end -= 1;
+                }
+                if self.data[start..end].ends_with(b"\r") {
+                    end -= 1;
+                }
+                end
+            }
+        };
+        self.pos = end;
+
+        Some(self.data[start..end].to_vec())
+    }
+}
+
+/// The objects in a PDF, and its trailer.
+struct Document {
+    objects: HashMap<u32, Object>,
+    trailer: Option<HashMap<String, Object>>,
+}
+
+impl Document {
+    fn parse(data: &[u8]) -> Document {
+        let mut objects = HashMap::new();
+        let keyword = b" obj";
+        let mut next = 0;
+        while let Some(offset) = data[next..]
+            .windows(keyword.len())
+            .position(|window| window == keyword)
+        {
+            let pos = next + offset;
+            next = pos + keyword.len();
+
+            // Find "<id> <generation>" before the keyword.
+            let before = &data[..pos];
+            let generation_start = before
+                .iter()
+                .rposition(|c| !c.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            if generation_start == pos || generation_start == 0 {
+                continue;
+            }
+            let id_end = generation_start - 1;
+            if data[id_end] != b' ' {
+                continue;
+            }
+            let id_start = data[..id_end]
+                .iter()
+                .rposition(|c| !c.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            let id = std::str::from_utf8(&data[id_start..id_end])
+                .ok()
+                .and_then(|id| id.parse().ok());
+
+            // Later objects replace earlier ones, as in incremental updates. Scanning continues
+            // after the object, so that the data of a stream is never read as objects.
+            if let Some(id) = id {
+                let mut parser = Parser::new(data, next);
+                if let Some(object) = parser.object(0) {
+                    objects.insert(id, object);
+                    next = parser.pos;
+                }
+            }
+        }
+
+        let trailer = data
+            .windows(b"trailer".len())
+            .rposition(|window| window == b"trailer")
+            .and_then(|pos| Parser::new(data, pos + b"trailer".len()).object(0))
+            .and_then(|trailer| trailer.dictionary().cloned());
+
+        Document { objects, trailer }
+    }
+
+    /// Follow references until an object that is not a reference.
+    fn resolve<'b>(&'b self, mut object: &'b Object) -> Option<&'b Object> {
+        for _ in 0..MAX_DEPTH {
+            match object {
+                Object::Reference(id) => object = self.objects.get(id)?,
+                object => return Some(object),
+            }
+        }
+
+        None
+    }
+
+    fn get<'b>(&'b self, dict: &'b HashMap<String, Object>, key: &str) -> Option<&'b Object> {
+        self.resolve(dict.get(key)?)
+    }
+
+    fn catalog(&self) -> Option<&HashMap<String, Object>> {
+        let root = self
+            .trailer
+            .as_ref()
+            .and_then(|trailer| self.get(trailer, "Root"));
+        match root {
+            Some(root) => root.dictionary(),
+            // Files with cross-reference streams have no trailer, so look for the catalog.
+            None => self.objects.values().find_map(|object| {
+                object
+                    .dictionary()
+                    .filter(|dict| dict.get("Type") == Some(&Object::Name("Catalog".to_owned())))
+            }),
+        }
+    }
+
+    /// Add the name and file specification of each file in a name tree.
+    fn name_tree<'b>(
+        &'b self,
+        node: &'b HashMap<String, Object>,
+        depth: usize,
+        files: &mut Vec<(String, &'b HashMap<String, Object>)>,
+    ) {
+        if depth > MAX_DEPTH {
+            return;
+        }
+        if let Some(Object::Array(names)) = self.get(node, "Names") {
+            for pair in names.chunks(2) {
+                if let (Some(Object::String(name)), Some(spec)) = (
+                    pair.first().and_then(|name| self.resolve(name)),
+                    pair.get(1).and_then(|spec| self.resolve(spec)),
+                ) {
+                    if let Some(spec) = spec.dictionary() {
+                        files.push((String::from_utf8_lossy(name).into_owned(), spec));
+                    }
+                }
+            }
+        }
+        if let Some(Object::Array(kids)) = self.get(node, "Kids") {
+            for kid in kids {
+                if let Some(kid) = self.resolve(kid).and_then(Object::dictionary) {
+                    self.name_tree(kid, depth + 1, files);
+                }
+            }
+        }
+    }
+
+    /// The decoded contents of the file in a file specification.
+    fn file_contents(&self, spec: &HashMap<String, Object>) -> Option<Vec<u8>> {
+        let ef = self.get(spec, "EF")?.dictionary()?;
+        let stream = self.get(ef, "F").or_else(|| self.get(ef, "UF"))?;
+        let (dict, data) = match stream {
+            Object::Stream(dict, data) => (dict, data),
+            _ => return None,
+        };
+
+        let filters = match self.get(dict, "Filter") {
+            None => vec![],
+            Some(Object::Name(filter)) => vec![filter.as_str()],
+            Some(Object::Array(filters)) => filters
+                .iter()
+                .map(|filter| match self.resolve(filter) {
+                    Some(Object::Name(filter)) => Some(filter.as_str()),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?,
+            Some(_) => return None,
+        };
+
+        let mut data = data.clone();
+        for filter in filters {
+            data = match filter {
+                "FlateDecode" => inflate::inflate_bytes_zlib(&data).ok()?,
+                _ => return None,
+            };
+        }
+
+        Some(data)
+    }
+}
+
+/// The name and contents of each file embedded in a PDF, in the order they are listed.
+///
+/// Files that cannot be decoded are skipped. Returns None if `data` is not a PDF.
+pub fn embedded_files(data: &[u8]) -> Option<Vec<(String, Vec<u8>)>> {
+    if !data.starts_with(b"%PDF-") {
+        return None;
+    }
+
+    let document = Document::parse(data);
+    let catalog = document.catalog()?;
+    let mut specs = Vec::new();
+    if let Some(tree) = document
+        .get(catalog, "Names")
+        .and_then(Object::dictionary)
+        .and_then(|names| document.get(names, "EmbeddedFiles"))
+        .and_then(Object::dictionary)
+    {
+        document.name_tree(tree, 0, &mut specs);
+    }
+
+    Some(
+        specs
+            .into_iter()
+            .filter_map(|(name, spec)| Some((name, document.file_contents(spec)?)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf::Pdf;
+    use kurbo::Size;
+
+    #[test]
+    fn round_trip() {
+        let mut pdf = Pdf::new();
+        pdf.add_page(Size::new(215.9, 279.4));
+        pdf.add_file("sixeight.json", "application/json", "{\"v\": 1}");
+        let files = embedded_files(&pdf.into_binary()).unwrap();
+        assert_eq!(
+            files,
+            vec![("sixeight.json".to_owned(), b"{\"v\": 1}".to_vec())]
+        );
+
+        let mut pdf = Pdf::new();
+        pdf.add_page(Size::new(215.9, 279.4));
+        assert_eq!(embedded_files(&pdf.into_binary()), Some(vec![]));
+
+        assert_eq!(embedded_files(b"{\"v\": 1}"), None);
+    }
+
+    #[test]
+    fn name_tree() {
+        // Names in a child of the tree, an uncompressed stream with an indirect length, and
+        // escapes in strings.
+        let pdf = b"%PDF-1.4\n\
+            1 0 obj << /Type /Catalog /Names << /EmbeddedFiles 2 0 R >> >> endobj\n\
+            2 0 obj << /Kids [3 0 R] >> endobj\n\
+            3 0 obj << /Limits [(a) (b)] /Names [(a\\(1\\).txt) 4 0 R <62> 6 0 R] >> endobj\n\
+            4 0 obj << /Type /Filespec /EF << /F 5 0 R >> >> endobj\n\
+            5 0 obj << /Length 7 0 R >>\nstream\nhello\nendstream\nendobj\n\
+            6 0 obj << /EF << /F 8 0 R >> >> endobj\n\
+            7 0 obj 5 endobj\n\
+            8 0 obj << /Filter /LZWDecode >>\nstream\n??\nendstream\nendobj\n\
+            trailer << /Root 1 0 R >>\n%%EOF\n";
+
+        assert_eq!(
+            embedded_files(pdf).unwrap(),
+            vec![("a(1).txt".to_owned(), b"hello".to_vec())]
+        );
+    }
+
+    #[test]
+    fn object_in_stream() {
+        // The data of a stream looks like a later definition of the catalog.
+        let pdf = b"%PDF-1.4\n\
+            1 0 obj << /Type /Catalog /Names << /EmbeddedFiles 2 0 R >> >> endobj\n\
+            2 0 obj << /Names [(a.txt) 3 0 R] >> endobj\n\
+            3 0 obj << /EF << /F 4 0 R >> >> endobj\n\
+            4 0 obj << /Length 22 >>\nstream\n1 0 obj << >> endobj\n\nendstream\nendobj\n\
+            trailer << /Root 1 0 R >>\n%%EOF\n";
+
+        assert_eq!(
+            embedded_files(pdf).unwrap(),
+            vec![("a.txt".to_owned(), b"1 0 obj << >> endobj\n\n".to_vec())]
+        );
+    }
+}
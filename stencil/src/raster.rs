@@ -0,0 +1,397 @@
+//! Draws stencils into an RGBA image, without a GPU, and encodes it as a PNG.
+//!
+//! Paths are filled with the signed-area accumulation approach of font-rs by Raph Levien
+//! (https://github.com/raphlinus/font-rs), which gives exact anti-aliased coverage for each pixel.
+
+use crate::components::{CombineStencil, Stencil, StencilMap};
+use kurbo::{Affine, BezPath, CubicBez, ParamCurve, PathEl, Point, QuadBez, Size};
+use specs::{shred::Fetch, storage::MaskedStorage, Storage};
+
+/// How far, in pixels, a flattened curve may be from the real one.
+const TOLERANCE: f64 = 0.1;
+
+/// An RGBA image, one byte per channel, that stencils are drawn into in black.
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+
+    /// For the PNG pHYs chunk.
+    dpi: f64,
+}
+
+impl Raster {
+    /// Create a white image of a page `size` mm, with `dpi` pixels per inch.
+    pub fn new(size: Size, dpi: f64) -> Raster {
+        let width = (size.width / 25.4 * dpi).round().max(1.0) as usize;
+        let height = (size.height / 25.4 * dpi).round().max(1.0) as usize;
+
+        Raster {
+            width,
+            height,
+            pixels: vec![255; width * height * 4],
+            dpi,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The image, row by row from the top, as RGBA.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The transform from mm to pixels.
+    pub fn mm(&self) -> Affine {
+        Affine::scale(self.dpi / 25.4)
+    }
+
+    /// Fill `path` in black, using the nonzero rule. `transform` maps the path to pixels.
+    pub fn fill_path(&mut self, path: &BezPath, transform: Affine) {
+        let lines = flatten(&(transform * path));
+        if lines.is_empty() {
+            return;
+        }
+
+        // Coverage is accumulated over the bounding box of the path, within the rows of the image.
+        let mut x0 = f64::INFINITY;
+        let mut x1 = f64::NEG_INFINITY;
+        let mut y0 = f64::INFINITY;
+        let mut y1 = f64::NEG_INFINITY;
+        for (p0, p1) in &lines {
+            x0 = x0.min(p0.x).min(p1.x);
+            x1 = x1.max(p0.x).max(p1.x);
+            y0 = y0.min(p0.y).min(p1.y);
+            y1 = y1.max(p0.y).max(p1.y);
+        }
+        if x1 <= 0.0 || x0 >= self.width as f64 || y1 <= 0.0 || y0 >= self.height as f64 {
+            return;
+        }
+        let left = x0.floor();
+        let top = y0.max(0.0).floor();
+        let mut coverage = Coverage::new(
+            (x1.ceil() - left) as usize + 2,
+            (y1.min(self.height as f64).ceil() - top) as usize,
+        );
+        for (p0, p1) in lines {
+            coverage.line(
+                Point::new(p0.x - left, p0.y - top),
+                Point::new(p1.x - left, p1.y - top),
+            );
+        }
+
+        let top = top as usize;
+        let left = left as isize;
+        for y in 0..coverage.height {
+            let mut acc = 0.0;
+            for x in 0..coverage.width {
+                acc += coverage.area[y * coverage.width + x];
+                let image_x = left + x as isize;
+                if image_x < 0 || image_x >= self.width as isize {
+                    continue;
+                }
+                let alpha = acc.abs().min(1.0);
+                if alpha > 0.0 {
+                    let i = ((top + y) * self.width + image_x as usize) * 4;
+                    for channel in &mut self.pixels[i..i + 3] {
+                        *channel = (f32::from(*channel) * (1.0 - alpha)).round() as u8;
+                    }
+                    self.pixels[i + 3] = 255;
+                }
+            }
+        }
+    }
+
+    pub fn write_stencil(&mut self, stencil: &Stencil, transform: Affine) {
+        match stencil {
+            Stencil::RawSvg(svg) => {
+                self.fill_path(&BezPath::from_svg(&svg.svg).unwrap(), transform);
+            }
+            Stencil::Path(path) => {
+                self.fill_path(&path.outline, transform);
+            }
+            Stencil::Text(_) => {
+                self.write_stencil(&stencil.clone().with_text_outlines(), transform);
+            }
+            Stencil::Combine(CombineStencil(combine)) => {
+                for stencil in combine {
+                    self.write_stencil(stencil, transform);
+                }
+            }
+            Stencil::Translate(offset, child) => {
+                self.write_stencil(child, transform * Affine::translate(*offset));
+            }
+        }
+    }
+
+    pub fn write_stencil_map(
+        &mut self,
+        stencil_map: &StencilMap,
+        transform: Affine,
+        stencils: &Storage<Stencil, Fetch<MaskedStorage<Stencil>>>,
+        stencil_maps: &Storage<StencilMap, Fetch<MaskedStorage<StencilMap>>>,
+    ) {
+        let transform = if let Some(translate) = stencil_map.translate {
+            transform * Affine::translate(translate)
+        } else {
+            transform
+        };
+
+        for (child, translation) in stencil_map.get_sorted_children() {
+            let child_transform = if let Some(translation) = translation {
+                transform * Affine::translate(translation)
+            } else {
+                transform
+            };
+            if let Some(child) = stencils.get(child) {
+                self.write_stencil(child, child_transform);
+            }
+            if let Some(child) = stencil_maps.get(child) {
+                self.write_stencil_map(child, child_transform, stencils, stencil_maps);
+            }
+        }
+    }
+
+    /// Encode the image as a PNG file.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGBA, default compression, filtering and no interlacing.
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+        write_chunk(&mut png, b"IHDR", &header);
+
+        let pixels_per_metre = (self.dpi / 0.0254).round() as u32;
+        let mut physical = Vec::with_capacity(9);
+        physical.extend_from_slice(&pixels_per_metre.to_be_bytes());
+        physical.extend_from_slice(&pixels_per_metre.to_be_bytes());
+        physical.push(1);
+        write_chunk(&mut png, b"pHYs", &physical);
+
+        // Each row starts with its filter type, which is always "None".
+        let mut rows = Vec::with_capacity((self.width * 4 + 1) * self.height);
+        for row in self.pixels.chunks(self.width * 4) {
+            rows.push(0);
+            rows.extend_from_slice(row);
+        }
+        write_chunk(&mut png, b"IDAT", &deflate::deflate_bytes_zlib(&rows));
+        write_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+}
+
+/// How much the signed coverage of a shape changes from each pixel to the next, so that the
+/// running sum along a row is the coverage of each pixel.
+struct Coverage {
+    width: usize,
+    height: usize,
+    area: Vec<f32>,
+}
+
+impl Coverage {
+    fn new(width: usize, height: usize) -> Coverage {
+        Coverage {
+            width,
+            height,
+            area: vec![0.0; width * height],
+        }
+    }
+
+    /// Add an edge. Points must be right of x = 0, and left of the last column.
+    fn line(&mut self, p0: Point, p1: Point) {
+        if (p0.y - p1.y).abs() < f64::EPSILON {
+            return;
+        }
+        let (dir, p0, p1) = if p0.y < p1.y {
+            (1.0, p0, p1)
+        } else {
+            (-1.0, p1, p0)
+        };
+        let (p0x, p0y, p1x, p1y) = (p0.x as f32, p0.y as f32, p1.x as f32, p1.y as f32);
+        let dxdy = (p1x - p0x) / (p1y - p0y);
+
+        let mut x = p0x;
+        if p0y < 0.0 {
+            x -= p0y * dxdy;
+        }
+        let first_row = p0y.max(0.0) as usize;
+        let last_row = (p1y.ceil().max(0.0) as usize).min(self.height);
+        for y in first_row..last_row {
+            let row = y * self.width;
+            let dy = ((y + 1) as f32).min(p1y) - (y as f32).max(p0y);
+            let x_next = x + dxdy * dy;
+            let d = dy * dir;
+            let (x0, x1) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let x0_floor = x0.floor();
+            let x0i = x0_floor as usize;
+            let x1_ceil = x1.ceil();
+            let x1i = x1_ceil as usize;
+
+            if x1i <= x0i + 1 {
+                // The edge is within one pixel of this row.
+                let xmf = 0.5 * (x + x_next) - x0_floor;
+                self.area[row + x0i] += d - d * xmf;
+                self.area[row + x0i + 1] += d * xmf;
+            } else {
+                let s = (x1 - x0).recip();
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
+                let x1f = x1 - x1_ceil + 1.0;
+                let am = 0.5 * s * x1f * x1f;
+                self.area[row + x0i] += d * a0;
+                if x1i == x0i + 2 {
+                    self.area[row + x0i + 1] += d * (1.0 - a0 - am);
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    self.area[row + x0i + 1] += d * (a1 - a0);
+                    for xi in x0i + 2..x1i - 1 {
+                        self.area[row + xi] += d * s;
+                    }
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    self.area[row + x1i - 1] += d * (1.0 - a2 - am);
+                }
+                self.area[row + x1i] += d * am;
+            }
+            x = x_next;
+        }
+    }
+}
+
+/// The edges of a path, with curves split into lines, and every subpath closed.
+fn flatten(path: &BezPath) -> Vec<(Point, Point)> {
+    let mut lines = Vec::new();
+    let mut start = Point::ZERO;
+    let mut from = Point::ZERO;
+    for el in path.elements() {
+        match *el {
+            PathEl::MoveTo(to) => {
+                lines.push((from, start));
+                start = to;
+                from = to;
+            }
+            PathEl::LineTo(to) => {
+                lines.push((from, to));
+                from = to;
+            }
+            PathEl::QuadTo(ctrl, to) => {
+                let quad = QuadBez::new(from, ctrl, to);
+                let dd = (from.to_vec2() - ctrl.to_vec2() * 2.0 + to.to_vec2()).hypot();
+                let n = (dd / (4.0 * TOLERANCE)).sqrt().ceil().max(1.0) as usize;
+                for i in 1..=n {
+                    let p = quad.eval(i as f64 / n as f64);
+                    lines.push((from, p));
+                    from = p;
+                }
+            }
+            PathEl::CurveTo(ctrl1, ctrl2, to) => {
+                let cubic = CubicBez::new(from, ctrl1, ctrl2, to);
+                let dd = (from.to_vec2() - ctrl1.to_vec2() * 2.0 + ctrl2.to_vec2())
+                    .hypot()
+                    .max((ctrl1.to_vec2() - ctrl2.to_vec2() * 2.0 + to.to_vec2()).hypot());
+                let n = (0.75 * dd / TOLERANCE).sqrt().ceil().max(1.0) as usize;
+                for i in 1..=n {
+                    let p = cubic.eval(i as f64 / n as f64);
+                    lines.push((from, p));
+                    from = p;
+                }
+            }
+            PathEl::ClosePath => {
+                lines.push((from, start));
+                from = start;
+            }
+        }
+    }
+    lines.push((from, start));
+    lines.retain(|(p0, p1)| p0 != p1);
+
+    lines
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::{Rect, Shape};
+
+    fn alpha(raster: &Raster, x: usize, y: usize) -> u8 {
+        255 - raster.pixels()[(y * raster.width() + x) * 4]
+    }
+
+    #[test]
+    fn coverage() {
+        // 10 x 10 pixels.
+        let mut raster = Raster::new(Size::new(10.0, 10.0), 25.4);
+        assert_eq!((raster.width(), raster.height()), (10, 10));
+
+        raster.fill_path(
+            &Rect::new(1.0, 1.0, 3.5, 3.0).into_bez_path(0.1),
+            Affine::default(),
+        );
+        assert_eq!(alpha(&raster, 0, 1), 0);
+        assert_eq!(alpha(&raster, 1, 1), 255);
+        assert_eq!(alpha(&raster, 2, 2), 255);
+        assert_eq!(alpha(&raster, 3, 2), 127);
+        assert_eq!(alpha(&raster, 4, 2), 0);
+        assert_eq!(alpha(&raster, 1, 3), 0);
+
+        // Holes are not filled, and shapes past the edges are clipped.
+        let mut ring = Rect::new(-5.0, 5.0, 9.0, 15.0).into_bez_path(0.1);
+        ring.move_to((6.0, 7.0));
+        ring.line_to((6.0, 9.0));
+        ring.line_to((8.0, 9.0));
+        ring.line_to((8.0, 7.0));
+        ring.close_path();
+        let mut raster = Raster::new(Size::new(10.0, 10.0), 25.4);
+        raster.fill_path(&ring, Affine::default());
+        assert_eq!(alpha(&raster, 0, 9), 255);
+        assert_eq!(alpha(&raster, 5, 7), 255);
+        assert_eq!(alpha(&raster, 6, 7), 0);
+        assert_eq!(alpha(&raster, 9, 9), 0);
+        assert_eq!(alpha(&raster, 0, 4), 0);
+    }
+
+    #[test]
+    fn png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+
+        let raster = Raster::new(Size::new(25.4, 12.7), 4.0);
+        let png = raster.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 4, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+}
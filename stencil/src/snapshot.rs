@@ -5,3 +5,11 @@ pub fn snapshot(path: &str, contents: &str) {
         assert_eq!(std::fs::read_to_string(path).unwrap(), contents);
     }
 }
+
+pub fn snapshot_bytes(path: &str, contents: &[u8]) {
+    if std::env::vars().any(|(key, _val)| key == "SIX_SNAPSHOT") {
+        std::fs::write(path, contents).unwrap();
+    } else {
+        assert!(std::fs::read(path).unwrap() == contents);
+    }
+}
@@ -0,0 +1,199 @@
+// Generated by gen_textfont/Makefile. Do not edit.
+pub(crate) static UNITS_PER_EM: usize = 1000;
+pub(crate) static FIRST_CHAR: char = ' ';
+
+/// The advance and outline of each character, from FIRST_CHAR to '~', then from
+/// '\u{a0}' to '\u{ff}'.
+pub(crate) static GLYPHS: [(f64, &str); 191] = [
+    (317.9_f64, ""),
+    (401.9_f64, "M136.2 -50.8Q136.2 -78.1 154.8 -97.2Q173.3 -116.2 201.2 -116.2Q228 -116.2 247.1 -97.2Q266.1 -78.1 266.1 -50.8Q266.1 -23.9 247.1 -4.9Q228 14.2 201.2 14.2Q173.3 14.2 154.8 -4.6Q136.2 -23.4 136.2 -50.8ZM138.2 -729L264.2 -729L231 -328.1L231 -205.1L170.9 -205.1L170.9 -328.1L138.2 -729Z"),
+    (460_f64, "M175.8 -729L175.8 -458L98.1 -458L98.1 -729L175.8 -729ZM361.8 -729L361.8 -458L284.2 -458L284.2 -729L361.8 -729Z"),
+    (837.9_f64, "M508.8 -439.9L371.1 -439.9L330.1 -276.9L469.2 -276.9L508.8 -439.9ZM439.9 -717.8L390.1 -515.1L527.8 -515.1L578.1 -717.8L662.1 -717.8L610.8 -515.1L761.2 -515.1L761.2 -439.9L591.8 -439.9L551.8 -276.9L705.1 -276.9L705.1 -202.1L533.2 -202.1L482.9 0L398.9 0L450.2 -202.1L312 -202.1L261.2 0L178.2 0L228 -202.1L77.1 -202.1L77.1 -276.9L247.1 -276.9L287.1 -439.9L132.8 -439.9L132.8 -515.1L306.2 -515.1L356.9 -717.8L439.9 -717.8Z"),
+    (636.2_f64, "M336.9 -33.2Q392.1 -38.1 421.1 -64.9Q450.2 -91.8 450.2 -138.2Q450.2 -180.2 421.4 -209.5Q392.6 -238.8 336.9 -253.9L336.9 -33.2ZM288.1 -579.6Q237.8 -576.2 210 -550.5Q182.1 -524.9 182.1 -481.9Q182.1 -442.9 207.8 -417Q233.4 -391.1 288.1 -375L288.1 -579.6ZM85.9 -35.2L85.9 -169.9L138.7 -169.9Q140.6 -103 177.7 -68.8Q214.8 -34.7 288.1 -33.2L288.1 -270Q175.8 -301.3 130.4 -342Q85 -382.8 85 -450.2Q85 -528.3 138.7 -575.7Q192.4 -623 288.1 -628.9L288.1 -759.8L336.9 -759.8L336.9 -628.9Q386.2 -625 431.9 -613Q477.5 -601.1 521 -581.1L521 -453.1L467.8 -453.1Q461.9 -509.8 428.2 -542.5Q394.5 -575.2 336.9 -579.6L336.9 -358.9Q457 -326.2 505.1 -283.9Q553.2 -241.7 553.2 -170.9Q553.2 -89.8 496.6 -41Q439.9 7.8 336.9 15.1L336.9 147L288.1 147L288.1 15.1Q241.2 14.2 190.9 1.7Q140.6 -10.7 85.9 -35.2Z"),
+    (950.2_f64, "M224.1 -696.8Q181.2 -696.8 157 -656.2Q132.8 -615.7 132.8 -543Q132.8 -471.2 157.5 -430.2Q182.1 -389.2 224.1 -389.2Q265.6 -389.2 289.8 -429.9Q314 -470.7 314 -543Q314 -615.2 289.8 -656Q265.6 -696.8 224.1 -696.8ZM727.1 -338.9Q684.6 -338.9 660.4 -297.9Q636.2 -256.8 636.2 -184.1Q636.2 -112.3 660.6 -71.5Q685.1 -30.8 727.1 -30.8Q769 -30.8 793 -71.5Q816.9 -112.3 816.9 -184.1Q816.9 -256.3 792.7 -297.6Q768.6 -338.9 727.1 -338.9ZM727.1 -382.8Q804.2 -382.8 849.6 -329.1Q895 -275.4 895 -184.1Q895 -92.8 849.4 -39.3Q803.7 14.2 727.1 14.2Q648.9 14.2 603.5 -39.3Q558.1 -92.8 558.1 -184.1Q558.1 -275.9 603.8 -329.3Q649.4 -382.8 727.1 -382.8ZM666 -742.2L740.2 -742.2L284.2 14.2L210 14.2L666 -742.2ZM223.1 -742.2Q300.3 -742.2 346.2 -688.7Q392.1 -635.3 392.1 -543.9Q392.1 -451.7 346.4 -398.4Q300.8 -345.2 223.1 -345.2Q145.5 -345.2 100.3 -398.7Q55.2 -452.1 55.2 -543.9Q55.2 -634.8 100.6 -688.5Q146 -742.2 223.1 -742.2Z"),
+    (890.1_f64, "M540 -112.8L255.9 -412.1Q213.4 -374 192.1 -329.8Q170.9 -285.6 170.9 -235.8Q170.9 -154.8 227.1 -99.9Q283.2 -44.9 367.2 -44.9Q417 -44.9 461.2 -62.3Q505.4 -79.6 540 -112.8ZM842.8 0L647 0L580.1 -70.8Q528.8 -27.8 470.7 -6.8Q412.6 14.2 345.2 14.2Q219.2 14.2 145.5 -50.3Q71.8 -114.7 71.8 -225.1Q71.8 -290 109.1 -346.4Q146.5 -402.8 222.2 -452.1Q197.3 -481.9 185.5 -511.5Q173.8 -541 173.8 -573.2Q173.8 -650.9 229.7 -696.5Q285.6 -742.2 381.8 -742.2Q414.6 -742.2 458.3 -733.9Q502 -725.6 558.1 -709L558.1 -583L502.9 -583Q496.1 -635.3 464.8 -662.6Q433.6 -689.9 379.9 -689.9Q329.6 -689.9 298.3 -662.4Q267.1 -634.8 267.1 -590.8Q267.1 -561 283.7 -531.5Q300.3 -502 353 -446.8L605 -181.2Q638.7 -220.2 658 -265.4Q677.2 -310.5 682.1 -361.8L594.2 -361.8L594.2 -414.1L825.2 -414.1L825.2 -361.8L741.2 -361.8Q732.9 -298.3 708.7 -243.2Q684.6 -188 644 -141.1L728 -51.8L842.8 -51.8L842.8 0Z"),
+    (274.9_f64, "M175.8 -729L175.8 -458L98.1 -458L98.1 -729L175.8 -729Z"),
+    (390.1_f64, "M318.8 155.8Q200.2 102.5 139.6 -12.9Q79.1 -128.4 79.1 -301.8Q79.1 -475.6 139.6 -591.1Q200.2 -706.5 318.8 -759.8L318.8 -711.9Q243.7 -660.2 210.9 -566.2Q178.2 -472.2 178.2 -301.8Q178.2 -131.8 210.9 -37.8Q243.7 56.2 318.8 107.9L318.8 155.8Z"),
+    (390.1_f64, "M70.8 155.8L70.8 107.9Q146 56.2 179 -37.8Q211.9 -131.8 211.9 -301.8Q211.9 -472.2 179 -566.2Q146 -660.2 70.8 -711.9L70.8 -759.8Q189.9 -706.5 250.5 -591.1Q311 -475.6 311 -301.8Q311 -128.4 250.5 -12.9Q189.9 102.5 70.8 155.8Z"),
+    (500_f64, "M483.9 -604L293 -515.1L483.9 -424.8L446.8 -367.2L276.9 -473.1L283.2 -287.1L216.8 -287.1L223.1 -473.1L53.2 -367.2L16.1 -424.8L207 -514.2L16.1 -604L53.2 -662.1L223.1 -556.2L216.8 -742.2L283.2 -742.2L276.9 -556.2L446.8 -662.1L483.9 -604Z"),
+    (837.9_f64, "M458 -627L458 -353L731.9 -353L731.9 -273.9L458 -273.9L458 0L379.9 0L379.9 -273.9L106 -273.9L106 -353L379.9 -353L379.9 -627L458 -627Z"),
+    (317.9_f64, "M36.1 97.2Q82 63.5 103.5 17.8Q125 -27.8 125 -92.8L125 -110.8L221.2 -110.8Q217.3 -27.3 181.9 32.5Q146.5 92.3 75.2 136.2L36.1 97.2Z"),
+    (337.9_f64, "M43.9 -306.2L293.9 -306.2L293.9 -230L43.9 -230L43.9 -306.2Z"),
+    (317.9_f64, "M94.2 -50.8Q94.2 -78.1 112.8 -97.2Q131.3 -116.2 159.2 -116.2Q186 -116.2 205.1 -97.2Q224.1 -78.1 224.1 -50.8Q224.1 -23.9 205.1 -4.9Q186 14.2 159.2 14.2Q131.3 14.2 112.8 -4.6Q94.2 -23.4 94.2 -50.8Z"),
+    (336.9_f64, "M258.8 -729L336.9 -729L78.1 92.8L0 92.8L258.8 -729Z"),
+    (636.2_f64, "M317.9 -34.2Q392.6 -34.2 429.7 -116.2Q466.8 -198.2 466.8 -363.8Q466.8 -529.8 429.7 -611.8Q392.6 -693.8 317.9 -693.8Q243.2 -693.8 206.1 -611.8Q168.9 -529.8 168.9 -363.8Q168.9 -198.2 206.1 -116.2Q243.2 -34.2 317.9 -34.2ZM317.9 14.2Q199.2 14.2 132.6 -85.4Q65.9 -185.1 65.9 -363.8Q65.9 -543 132.6 -642.6Q199.2 -742.2 317.9 -742.2Q437 -742.2 503.4 -642.6Q569.8 -543 569.8 -363.8Q569.8 -185.1 503.4 -85.4Q437 14.2 317.9 14.2Z"),
+    (636.2_f64, "M142.1 0L142.1 -51.8L269 -51.8L269 -658.2L122.1 -563L122.1 -627L299.8 -742.2L367.2 -742.2L367.2 -51.8L494.1 -51.8L494.1 0L142.1 0Z"),
+    (636.2_f64, "M127.9 -555.2L73.2 -555.2L73.2 -685.1Q125.5 -712.9 178.5 -727.5Q231.4 -742.2 282.2 -742.2Q396 -742.2 461.9 -687Q527.8 -631.8 527.8 -537.1Q527.8 -430.2 378.4 -281.2Q366.7 -270 360.8 -264.2L176.8 -80.1L481 -80.1L481 -169.9L538.1 -169.9L538.1 0L67.9 0L67.9 -53.2L289.1 -273.9Q362.3 -347.2 393.6 -408.4Q424.8 -469.7 424.8 -537.1Q424.8 -610.8 386.5 -652.3Q348.1 -693.8 280.8 -693.8Q210.9 -693.8 172.9 -659.2Q134.8 -624.5 127.9 -555.2Z"),
+    (636.2_f64, "M97.2 -698.2Q154.3 -719.7 206.8 -731Q259.3 -742.2 305.2 -742.2Q412.1 -742.2 472.2 -696Q532.2 -649.9 532.2 -567.9Q532.2 -502 490.7 -457.8Q449.2 -413.6 373 -397.9Q462.9 -385.3 512.5 -332.8Q562 -280.3 562 -196.8Q562 -94.7 493.4 -40.3Q424.8 14.2 295.9 14.2Q238.8 14.2 184.3 2Q129.9 -10.3 76.2 -35.2L76.2 -176.8L130.9 -176.8Q135.7 -106.4 178.2 -70.3Q220.7 -34.2 297.9 -34.2Q372.6 -34.2 415.8 -77.4Q459 -120.6 459 -195.8Q459 -281.7 414.6 -325.9Q370.1 -370.1 284.2 -370.1L237.8 -370.1L237.8 -419.9L262.2 -419.9Q347.7 -419.9 390.4 -455.3Q433.1 -490.7 433.1 -562Q433.1 -626 397.9 -659.9Q362.8 -693.8 296.9 -693.8Q231 -693.8 194.6 -662.6Q158.2 -631.3 151.9 -569.8L97.2 -569.8L97.2 -698.2Z"),
+    (636.2_f64, "M349.1 -247.1L349.1 -634.8L100.1 -247.1L349.1 -247.1ZM564 0L231.9 0L231.9 -51.8L349.1 -51.8L349.1 -194.8L30.8 -194.8L30.8 -248L350.1 -742.2L446.8 -742.2L446.8 -247.1L585.9 -247.1L585.9 -194.8L446.8 -194.8L446.8 -51.8L564 -51.8L564 0Z"),
+    (636.2_f64, "M502.9 -729L502.9 -648.9L168.9 -648.9L168.9 -439.9Q194.3 -457.5 228.3 -466.3Q262.2 -475.1 304.2 -475.1Q422.4 -475.1 490.7 -409.7Q559.1 -344.2 559.1 -231Q559.1 -115.2 490 -50.5Q420.9 14.2 295.9 14.2Q245.6 14.2 192.9 2Q140.1 -10.3 85 -35.2L85 -176.8L140.1 -176.8Q144.5 -107.4 184.3 -70.8Q224.1 -34.2 295.9 -34.2Q373 -34.2 414.6 -85Q456.1 -135.7 456.1 -231Q456.1 -325.7 414.8 -376.2Q373.5 -426.8 295.9 -426.8Q252 -426.8 218.5 -411.1Q185.1 -395.5 159.2 -362.8L117.2 -362.8L117.2 -729L502.9 -729Z"),
+    (636.2_f64, "M327.1 -34.2Q396 -34.2 433.1 -84.7Q470.2 -135.3 470.2 -230Q470.2 -324.7 433.1 -375.2Q396 -425.8 327.1 -425.8Q257.3 -425.8 220.7 -377Q184.1 -328.1 184.1 -235.8Q184.1 -138.7 221.2 -86.4Q258.3 -34.2 327.1 -34.2ZM168 -401.4Q201.2 -438 243.2 -456.1Q285.2 -474.1 337.9 -474.1Q446.8 -474.1 510 -408.7Q573.2 -343.3 573.2 -230Q573.2 -119.1 505.1 -52.5Q437 14.2 323.2 14.2Q199.7 14.2 133.3 -77.9Q66.9 -169.9 66.9 -340.8Q66.9 -532.2 145.5 -637.2Q224.1 -742.2 367.2 -742.2Q405.8 -742.2 448.2 -734.9Q490.7 -727.5 535.2 -712.9L535.2 -592.8L480 -592.8Q474.1 -642.1 442.4 -668Q410.6 -693.8 356.9 -693.8Q262.2 -693.8 215.8 -622.1Q169.4 -550.3 168 -401.4Z"),
+    (636.2_f64, "M564 -679.2L278.8 0L206.1 0L478 -648.9L141.1 -648.9L141.1 -559.1L84 -559.1L84 -729L564 -729L564 -679.2Z"),
+    (636.2_f64, "M465.8 -199.2Q465.8 -277.3 427 -320.6Q388.2 -363.8 317.9 -363.8Q247.6 -363.8 208.7 -320.6Q169.9 -277.3 169.9 -199.2Q169.9 -120.6 208.7 -77.4Q247.6 -34.2 317.9 -34.2Q388.2 -34.2 427 -77.4Q465.8 -120.6 465.8 -199.2ZM445.8 -553.2Q445.8 -619.6 412.1 -656.7Q378.4 -693.8 317.9 -693.8Q257.8 -693.8 223.9 -656.7Q189.9 -619.6 189.9 -553.2Q189.9 -486.3 223.9 -449.2Q257.8 -412.1 317.9 -412.1Q378.4 -412.1 412.1 -449.2Q445.8 -486.3 445.8 -553.2ZM393.1 -388.2Q476.1 -377 522.5 -326.9Q568.8 -276.9 568.8 -199.2Q568.8 -96.7 503.9 -41.3Q439 14.2 317.9 14.2Q197.3 14.2 132.1 -41.3Q66.9 -96.7 66.9 -199.2Q66.9 -276.9 113.3 -326.9Q159.7 -377 243.2 -388.2Q169.4 -401.4 130.1 -444.1Q90.8 -486.8 90.8 -553.2Q90.8 -641.1 151.4 -691.7Q211.9 -742.2 317.9 -742.2Q423.8 -742.2 484.4 -691.7Q544.9 -641.1 544.9 -553.2Q544.9 -486.8 505.6 -444.1Q466.3 -401.4 393.1 -388.2Z"),
+    (636.2_f64, "M467.8 -326.7Q435.1 -290 392.6 -272Q350.1 -253.9 296.9 -253.9Q188.5 -253.9 125.7 -319.3Q63 -384.8 63 -498Q63 -608.9 131.1 -675.5Q199.2 -742.2 313 -742.2Q436.5 -742.2 502.7 -650.1Q568.8 -558.1 568.8 -387.2Q568.8 -195.8 490.2 -90.8Q411.6 14.2 269 14.2Q230.5 14.2 188 6.8Q145.5 -0.5 101.1 -15.1L101.1 -136.2L155.8 -136.2Q162.1 -86.9 193.8 -60.5Q225.6 -34.2 278.8 -34.2Q373.5 -34.2 419.9 -105.7Q466.3 -177.2 467.8 -326.7ZM309.1 -693.8Q239.7 -693.8 202.9 -643.3Q166 -592.8 166 -498Q166 -403.3 202.9 -352.5Q239.7 -301.8 309.1 -301.8Q378.4 -301.8 415.3 -350.8Q452.1 -399.9 452.1 -492.2Q452.1 -589.4 415 -641.6Q377.9 -693.8 309.1 -693.8Z"),
+    (336.9_f64, "M104 -50.8Q104 -78.1 122.8 -97.2Q141.6 -116.2 168.9 -116.2Q196.3 -116.2 215.1 -97.2Q233.9 -78.1 233.9 -50.8Q233.9 -23.4 215.3 -4.6Q196.8 14.2 168.9 14.2Q141.1 14.2 122.6 -4.6Q104 -23.4 104 -50.8ZM104 -369.1Q104 -396.5 122.8 -415.3Q141.6 -434.1 168.9 -434.1Q196.8 -434.1 215.3 -415.5Q233.9 -397 233.9 -369.1Q233.9 -341.3 215.3 -322.8Q196.8 -304.2 168.9 -304.2Q141.6 -304.2 122.8 -323Q104 -341.8 104 -369.1Z"),
+    (336.9_f64, "M36.1 97.2Q82 63.5 103.5 17.8Q125 -27.8 125 -92.8L125 -110.8L221.2 -110.8Q217.3 -27.3 181.9 32.5Q146.5 92.3 75.2 136.2L36.1 97.2ZM106 -369.1Q106 -396.5 124.8 -415.3Q143.6 -434.1 170.9 -434.1Q198.2 -434.1 217 -415Q235.8 -396 235.8 -369.1Q235.8 -341.8 217 -323Q198.2 -304.2 170.9 -304.2Q143.6 -304.2 124.8 -323Q106 -341.8 106 -369.1Z"),
+    (837.9_f64, "M731.9 -498L225.1 -313L731.9 -128.9L731.9 -45.9L106 -272.9L106 -354L731.9 -581.1L731.9 -498Z"),
+    (837.9_f64, "M106 -452.1L731.9 -452.1L731.9 -374L106 -374L106 -452.1ZM106 -252.9L731.9 -252.9L731.9 -174.8L106 -174.8L106 -252.9Z"),
+    (837.9_f64, "M106 -498L106 -581.1L731.9 -354L731.9 -272.9L106 -45.9L106 -128.9L612.8 -313L106 -498Z"),
+    (536.1_f64, "M175.8 -50.8Q175.8 -78.1 194.6 -97.2Q213.4 -116.2 241.2 -116.2Q268.1 -116.2 287.1 -97.2Q306.2 -78.1 306.2 -50.8Q306.2 -23.9 287.1 -4.9Q268.1 14.2 241.2 14.2Q213.4 14.2 194.6 -4.6Q175.8 -23.4 175.8 -50.8ZM67.9 -696.8Q119.6 -719.7 166.3 -731Q212.9 -742.2 253.9 -742.2Q362.3 -742.2 425 -689.9Q487.8 -637.7 487.8 -548.8Q487.8 -458 433.8 -400.4Q379.9 -342.8 271 -317.9L271 -193.8L210.9 -193.8L210.9 -354Q297.4 -375 341.1 -424.3Q384.8 -473.6 384.8 -549.8Q384.8 -616.7 349.1 -655.3Q313.5 -693.8 252 -693.8Q196.3 -693.8 160.9 -661.9Q125.5 -629.9 115.2 -569.8L67.9 -569.8L67.9 -696.8Z"),
+    (1000_f64, "M631.8 -121.1Q603 -81.1 567.1 -61.5Q531.2 -42 485.8 -42Q400.4 -42 347.2 -103Q293.9 -164.1 293.9 -262.2Q293.9 -360.4 347.2 -421.1Q400.4 -481.9 485.8 -481.9Q531.2 -481.9 567.1 -462.4Q603 -442.9 631.8 -402.8L631.8 -472.2L708 -472.2L708 -95.2Q791 -113.3 836.9 -174.3Q882.8 -235.4 882.8 -327.1Q882.8 -381.3 866.2 -429.7Q849.6 -478 816.9 -519Q765.1 -584 690.7 -618.4Q616.2 -652.8 528.8 -652.8Q484.9 -652.8 441.9 -643.6Q398.9 -634.3 356.9 -615.2Q258.3 -569.8 203.6 -477.8Q148.9 -385.7 148.9 -265.1Q148.9 -183.6 173.3 -115Q197.8 -46.4 244.1 3.9Q297.4 62 370.4 92.5Q443.4 123 527.8 123Q590.8 123 645.5 104.5Q700.2 85.9 747.1 48.8L772.9 83Q720.2 128.4 653.3 151.1Q586.4 173.8 506.8 173.8Q417.5 173.8 336.2 141.4Q254.9 108.9 193.8 48.8Q130.9 -13.2 98.4 -93Q65.9 -172.9 65.9 -265.1Q65.9 -355 98.6 -434.6Q131.3 -514.2 193.8 -576.2Q255.9 -637.7 340.1 -670.9Q424.3 -704.1 519 -704.1Q623.5 -704.1 709.7 -663.6Q795.9 -623 854 -546.9Q891.1 -499 910.2 -442.9Q929.2 -386.7 929.2 -326.2Q929.2 -195.3 849.6 -119.4Q770 -43.5 632.3 -42L631.8 -121.1ZM631.8 -236.8L631.8 -288.1Q631.8 -349.1 596.9 -387Q562 -424.8 505.9 -424.8Q446.8 -424.8 413.8 -382.1Q380.9 -339.4 380.9 -262.2Q380.9 -184.6 414.1 -141.8Q447.3 -99.1 506.8 -99.1Q562.5 -99.1 597.2 -137.5Q631.8 -175.8 631.8 -236.8Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0Z"),
+    (734.9_f64, "M247.1 -51.8L393.1 -51.8Q481 -51.8 521.5 -89.8Q562 -127.9 562 -210.9Q562 -293.5 521.7 -331.3Q481.4 -369.1 393.1 -369.1L247.1 -369.1L247.1 -51.8ZM247.1 -420.9L371.1 -420.9Q451.2 -420.9 488 -451.7Q524.9 -482.4 524.9 -548.8Q524.9 -615.7 488 -646.2Q451.2 -676.8 371.1 -676.8L247.1 -676.8L247.1 -420.9ZM55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L415 -729Q525.4 -729 581.3 -683.8Q637.2 -638.7 637.2 -548.8Q637.2 -483.9 598.4 -445.3Q559.6 -406.7 484.9 -397.9Q577.6 -386.2 625.7 -338.6Q673.8 -291 673.8 -210.9Q673.8 -102.5 605.5 -51.3Q537.1 0 392.1 0L55.2 0Z"),
+    (765.1_f64, "M705.1 -192.9Q672.9 -90.8 596.9 -38.3Q521 14.2 404.8 14.2Q333.5 14.2 272.5 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.3Q56.2 -275.9 56.2 -363.8Q56.2 -533.7 154.3 -637.9Q252.4 -742.2 413.1 -742.2Q472.7 -742.2 540 -726.6Q607.4 -710.9 685.1 -679.2L685.1 -511.2L629.9 -511.2Q611.8 -603 557.4 -646.5Q502.9 -689.9 404.8 -689.9Q288.1 -689.9 228 -607.2Q168 -524.4 168 -363.8Q168 -203.6 228 -120.8Q288.1 -38.1 404.8 -38.1Q486.3 -38.1 539.1 -76.9Q591.8 -115.7 615.2 -192.9L705.1 -192.9Z"),
+    (801.8_f64, "M247.1 -51.8L337.9 -51.8Q480 -51.8 555.9 -132.8Q631.8 -213.9 631.8 -365.2Q631.8 -516.6 556.2 -596.7Q480.5 -676.8 337.9 -676.8L247.1 -676.8L247.1 -51.8ZM55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L345.2 -729Q533.7 -729 638.9 -632.8Q744.1 -536.6 744.1 -365.2Q744.1 -193.4 638.7 -96.7Q533.2 0 345.2 0L55.2 0Z"),
+    (730_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L642.1 -729L642.1 -566.9L582 -566.9L582 -668.9L247.1 -668.9L247.1 -424.8L485.8 -424.8L485.8 -516.1L545.9 -516.1L545.9 -273.9L485.8 -273.9L485.8 -365.2L247.1 -365.2L247.1 -60.1L589.8 -60.1L589.8 -162.1L649.9 -162.1L649.9 0L55.2 0Z"),
+    (693.8_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L651.9 -729L651.9 -566.9L591.8 -566.9L591.8 -668.9L247.1 -668.9L247.1 -424.8L496.1 -424.8L496.1 -516.1L556.2 -516.1L556.2 -273.9L496.1 -273.9L496.1 -365.2L247.1 -365.2L247.1 -51.8L363.8 -51.8L363.8 0L55.2 0Z"),
+    (798.8_f64, "M640.1 -511.2Q623 -602.5 568.8 -646.2Q514.6 -689.9 418 -689.9Q292 -689.9 230 -608.9Q168 -527.8 168 -363.8Q168 -203.1 231.9 -120.6Q295.9 -38.1 419.9 -38.1Q475.1 -38.1 525.4 -51.8Q575.7 -65.4 621.1 -92.8L621.1 -280.8L483.9 -280.8L483.9 -333L720.2 -333L720.2 -61Q655.3 -23.4 580.3 -4.6Q505.4 14.2 419.9 14.2Q254.9 14.2 155.5 -89.1Q56.2 -192.4 56.2 -363.8Q56.2 -536.6 155.8 -639.4Q255.4 -742.2 423.8 -742.2Q486.3 -742.2 553 -727.8Q619.6 -713.4 694.8 -684.1L694.8 -511.2L640.1 -511.2Z"),
+    (872.1_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -424.8L625 -424.8L625 -676.8L532.2 -676.8L532.2 -729L816.9 -729L816.9 -676.8L724.1 -676.8L724.1 -51.8L816.9 -51.8L816.9 0L532.2 0L532.2 -51.8L625 -51.8L625 -365.2L247.1 -365.2L247.1 -51.8L339.8 -51.8L339.8 0L55.2 0Z"),
+    (395_f64, "M247.1 -51.8L339.8 -51.8L339.8 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -51.8Z"),
+    (400.9_f64, "M-84 174.8L-84 60.1L-28.3 60.1Q-26.9 108.4 -5.4 132.1Q16.1 155.8 59.1 155.8Q117.2 155.8 140.1 119.1Q163.1 82.5 163.1 -23.9L163.1 -676.8L49.8 -676.8L49.8 -729L355 -729L355 -676.8L262.2 -676.8L262.2 -20Q262.2 100.6 213.9 154.3Q165.5 208 58.1 208Q23.4 208 -12.5 199.7Q-48.3 191.4 -84 174.8Z"),
+    (747.1_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -400.9L561 -676.8L481.9 -676.8L481.9 -729L724.1 -729L724.1 -676.8L642.1 -676.8L329.1 -401.9L679.2 -51.8L762.2 -51.8L762.2 0L592.8 0L247.1 -346.2L247.1 -51.8L339.8 -51.8L339.8 0L55.2 0Z"),
+    (664.1_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -60.1L580.1 -60.1L580.1 -182.1L640.1 -182.1L640.1 0L55.2 0Z"),
+    (1023.9_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L49.8 -676.8L49.8 -729L262.2 -729L518.1 -210L773.9 -729L973.1 -729L973.1 -676.8L876 -676.8L876 -51.8L969.2 -51.8L969.2 0L684.1 0L684.1 -51.8L776.9 -51.8L776.9 -615.2L526.9 -106.9L458 -106.9L208 -615.2L208 -51.8L300.8 -51.8L300.8 0L55.2 0Z"),
+    (875_f64, "M48.8 0L48.8 -51.8L147 -51.8L147 -676.8L48.8 -676.8L48.8 -729L235.8 -729L672.9 -153.8L672.9 -676.8L575.2 -676.8L575.2 -729L831.1 -729L831.1 -676.8L732.9 -676.8L732.9 14.2L673.8 14.2L207 -600.1L207 -51.8L305.2 -51.8L305.2 0L48.8 0Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2Z"),
+    (672.9_f64, "M247.1 -371.1L376 -371.1Q448.7 -371.1 486.8 -410.4Q524.9 -449.7 524.9 -523.9Q524.9 -598.6 486.8 -637.7Q448.7 -676.8 376 -676.8L247.1 -676.8L247.1 -371.1ZM55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L399.9 -729Q509.3 -729 573.2 -673.6Q637.2 -618.2 637.2 -523.9Q637.2 -430.2 573.2 -374.5Q509.3 -318.8 399.9 -318.8L247.1 -318.8L247.1 -51.8L359.9 -51.8L359.9 0L55.2 0Z"),
+    (819.8_f64, "M421.9 14.2Q254.4 14.2 155.3 -88.6Q56.2 -191.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -219.2 690.9 -120.8Q617.7 -22.5 488.8 5.9Q515.1 38.6 553 54.2Q590.8 69.8 644 69.8L659.2 69.8L659.2 160.2Q576.7 156.2 517.8 119.9Q459 83.5 421.9 14.2ZM410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1Z"),
+    (752.9_f64, "M479 -361.8Q513.2 -352.5 537.8 -330.3Q562.5 -308.1 582 -268.1L688 -51.8L776.9 -51.8L776.9 0L605 0L491.2 -231.9Q458.5 -299.3 431.2 -319.1Q403.8 -338.9 356 -338.9L247.1 -338.9L247.1 -51.8L350.1 -51.8L350.1 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L424.8 -729Q531.2 -729 589.1 -678.2Q647 -627.4 647 -534.2Q647 -459 604.7 -415.8Q562.5 -372.6 479 -361.8ZM247.1 -391.1L391.1 -391.1Q464.8 -391.1 500 -426Q535.2 -460.9 535.2 -534.2Q535.2 -607.4 500 -642.1Q464.8 -676.8 391.1 -676.8L247.1 -676.8L247.1 -391.1Z"),
+    (685.1_f64, "M92.8 -35.2L92.8 -201.2L148.9 -200.7Q151.4 -117.7 197 -77.9Q242.7 -38.1 335.9 -38.1Q422.9 -38.1 468.5 -72.5Q514.2 -106.9 514.2 -172.9Q514.2 -225.6 486.6 -253.9Q459 -282.2 370.1 -309.1L273.9 -337.9Q169.4 -369.6 126.7 -417Q84 -464.4 84 -546.9Q84 -639.6 149.9 -690.9Q215.8 -742.2 335 -742.2Q385.7 -742.2 446.3 -731.2Q506.8 -720.2 575.2 -699.2L575.2 -543.9L520 -543.9Q511.7 -621.1 468.5 -655.5Q425.3 -689.9 336.9 -689.9Q259.8 -689.9 219.5 -658.4Q179.2 -627 179.2 -566.9Q179.2 -514.6 209.5 -484.9Q239.7 -455.1 337.9 -425.8L428.2 -398.9Q527.3 -369.1 569.6 -323Q611.8 -276.9 611.8 -199.2Q611.8 -93.3 543.9 -39.6Q476.1 14.2 341.8 14.2Q281.7 14.2 219.5 2Q157.2 -10.3 92.8 -35.2Z"),
+    (667_f64, "M190.9 0L190.9 -51.8L284.2 -51.8L284.2 -670.9L69.8 -670.9L69.8 -557.1L9.8 -557.1L9.8 -729L657.2 -729L657.2 -557.1L597.2 -557.1L597.2 -670.9L382.8 -670.9L382.8 -51.8L476.1 -51.8L476.1 0L190.9 0Z"),
+    (842.8_f64, "M140.1 -676.8L46.9 -676.8L46.9 -729L332 -729L332 -676.8L238.8 -676.8L238.8 -299.8Q238.8 -160.2 284.4 -105Q330.1 -49.8 441.9 -49.8Q553.7 -49.8 599.4 -105Q645 -160.2 645 -299.8L645 -676.8L551.8 -676.8L551.8 -729L797.9 -729L797.9 -676.8L705.1 -676.8L705.1 -290Q705.1 -123.5 641.1 -54.7Q577.1 14.2 423.8 14.2Q270.5 14.2 205.3 -55.2Q140.1 -124.5 140.1 -290L140.1 -676.8Z"),
+    (722.2_f64, "M174.8 -676.8L390.1 -117.2L605 -676.8L522.9 -676.8L522.9 -729L736.8 -729L736.8 -676.8L666 -676.8L405.8 0L321.8 0L63 -676.8L-9.8 -676.8L-9.8 -729L255.9 -729L255.9 -676.8L174.8 -676.8Z"),
+    (1027.8_f64, "M762.2 0L682.1 0L515.1 -592.8L348.1 0L268.1 0L77.1 -676.8L4.9 -676.8L4.9 -729L271 -729L271 -676.8L180.2 -676.8L332 -138.2L498 -729L577.1 -729L746.1 -131.8L898.9 -676.8L814.9 -676.8L814.9 -729L1024.9 -729L1024.9 -676.8L953.1 -676.8L762.2 0Z"),
+    (711.9_f64, "M331.1 -312L152.8 -51.8L247.1 -51.8L247.1 0L5.9 0L5.9 -51.8L89.8 -51.8L299.8 -357.9L86.9 -676.8L8.8 -676.8L8.8 -729L297.9 -729L297.9 -676.8L211.9 -676.8L368.2 -441.9L528.8 -676.8L435.1 -676.8L435.1 -729L673.8 -729L673.8 -676.8L591.8 -676.8L398.9 -396L628.9 -51.8L707 -51.8L707 0L418 0L418 -51.8L504.9 -51.8L331.1 -312Z"),
+    (660.2_f64, "M189 0L189 -51.8L282.2 -51.8L282.2 -314L57.1 -676.8L-11.2 -676.8L-11.2 -729L259.8 -729L259.8 -676.8L174.8 -676.8L356.9 -381.8L539.1 -676.8L457 -676.8L457 -729L668.9 -729L668.9 -676.8L600.1 -676.8L380.9 -323.2L380.9 -51.8L474.1 -51.8L474.1 0L189 0Z"),
+    (694.8_f64, "M44.9 0L44.9 -35.2L499 -668.9L122.1 -668.9L122.1 -560.1L62 -560.1L62 -729L638.2 -729L638.2 -693.8L184.1 -60.1L596.2 -60.1L596.2 -162.1L655.8 -162.1L655.8 0L44.9 0Z"),
+    (390.1_f64, "M85.9 -759.8L313 -759.8L313 -708L179.2 -708L179.2 80.1L313 80.1L313 131.8L85.9 131.8L85.9 -759.8Z"),
+    (336.9_f64, "M78.1 -729L336.9 92.8L258.8 92.8L0 -729L78.1 -729Z"),
+    (390.1_f64, "M304.2 -759.8L304.2 131.8L77.1 131.8L77.1 80.1L210.9 80.1L210.9 -708L77.1 -708L77.1 -759.8L304.2 -759.8Z"),
+    (837.9_f64, "M466.8 -729L731.9 -457L657.2 -457L418.9 -643.1L181.2 -457L106 -457L371.1 -729L466.8 -729Z"),
+    (500_f64, "M500 196.8L500 235.8L0 235.8L0 196.8L500 196.8Z"),
+    (500_f64, "M179.2 -798.8L306.2 -615.2L249 -615.2L83 -798.8L179.2 -798.8Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2Z"),
+    (640.1_f64, "M115.2 -51.8L115.2 -708L28.8 -708L28.8 -759.8L205.1 -759.8L205.1 -438Q231.4 -486.8 272.2 -510Q313 -533.2 373 -533.2Q468.8 -533.2 529.3 -457.5Q589.8 -381.8 589.8 -259.8Q589.8 -137.7 529.3 -61.8Q468.8 14.2 373 14.2Q313 14.2 272.2 -9Q231.4 -32.2 205.1 -81.1L205.1 0L28.8 0L28.8 -51.8L115.2 -51.8ZM205.1 -233.9Q205.1 -140.1 241 -91.1Q276.9 -42 345.2 -42Q414.1 -42 449.5 -97.2Q484.9 -152.3 484.9 -259.8Q484.9 -367.7 449.5 -422.4Q414.1 -477.1 345.2 -477.1Q276.9 -477.1 241 -427.7Q205.1 -378.4 205.1 -285.2L205.1 -233.9Z"),
+    (560.1_f64, "M514.2 -155.8Q495.1 -72.8 440.9 -29.3Q386.7 14.2 300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -384.3 118.7 -458.7Q187.5 -533.2 300.8 -533.2Q350.1 -533.2 398.9 -521.7Q447.8 -510.3 497.1 -486.8L497.1 -354L444.8 -354Q434.6 -422.4 400.1 -453.6Q365.7 -484.9 301.8 -484.9Q229 -484.9 191.9 -428.5Q154.8 -372.1 154.8 -259.8Q154.8 -147.5 191.7 -90.8Q228.5 -34.2 301.8 -34.2Q359.9 -34.2 394.5 -64.5Q429.2 -94.7 441.9 -155.8L514.2 -155.8Z"),
+    (640.1_f64, "M524.9 -51.8L610.8 -51.8L610.8 0L435.1 0L435.1 -81.1Q408.7 -32.2 367.9 -9Q327.1 14.2 267.1 14.2Q171.4 14.2 110.6 -61.8Q49.8 -137.7 49.8 -259.8Q49.8 -381.8 110.4 -457.5Q170.9 -533.2 267.1 -533.2Q327.1 -533.2 367.9 -510Q408.7 -486.8 435.1 -438L435.1 -708L350.1 -708L350.1 -759.8L524.9 -759.8L524.9 -51.8ZM435.1 -233.9L435.1 -285.2Q435.1 -378.4 399.2 -427.7Q363.3 -477.1 294.9 -477.1Q225.6 -477.1 190.2 -422.4Q154.8 -367.7 154.8 -259.8Q154.8 -152.3 190.2 -97.2Q225.6 -42 294.9 -42Q363.3 -42 399.2 -91.1Q435.1 -140.1 435.1 -233.9Z"),
+    (591.8_f64, "M542 -250L154.8 -250L154.8 -246.1Q154.8 -141.1 194.3 -87.6Q233.9 -34.2 311 -34.2Q370.1 -34.2 408 -65.2Q445.8 -96.2 460.9 -157.2L533.2 -157.2Q511.7 -71.8 453.9 -28.8Q396 14.2 301.8 14.2Q188 14.2 118.9 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -382.8 117.7 -458Q185.5 -533.2 295.9 -533.2Q413.6 -533.2 476.6 -460.7Q539.6 -388.2 542 -250ZM436 -301.8Q433.1 -392.6 397.7 -438.7Q362.3 -484.9 295.9 -484.9Q233.9 -484.9 198.2 -438.5Q162.6 -392.1 154.8 -301.8L436 -301.8Z"),
+    (370.1_f64, "M430.2 -637.2L382.8 -637.2Q382.3 -673.8 362.1 -692.9Q341.8 -711.9 303.2 -711.9Q252.9 -711.9 232.4 -684.3Q211.9 -656.7 211.9 -585.9L211.9 -519L356.9 -519L356.9 -466.8L211.9 -466.8L211.9 -51.8L327.1 -51.8L327.1 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L122.1 -519L122.1 -584Q122.1 -670.9 167.2 -715.3Q212.4 -759.8 299.8 -759.8Q332.5 -759.8 365.2 -753.9Q397.9 -748 430.2 -735.8L430.2 -637.2Z"),
+    (640.1_f64, "M524.9 -466.8L524.9 -11.2Q524.9 100.6 463.4 161.4Q401.9 222.2 288.1 222.2Q236.8 222.2 189.9 212.9Q143.1 203.6 100.1 185.1L100.1 76.2L147 76.2Q155.8 127 188.5 150.4Q221.2 173.8 282.2 173.8Q361.3 173.8 398.2 129.2Q435.1 84.5 435.1 -11.2L435.1 -81.1Q408.7 -32.2 367.9 -9Q327.1 14.2 267.1 14.2Q171.4 14.2 110.6 -61.8Q49.8 -137.7 49.8 -259.8Q49.8 -381.8 110.4 -457.5Q170.9 -533.2 267.1 -533.2Q327.1 -533.2 367.9 -510Q408.7 -486.8 435.1 -438L435.1 -519L610.8 -519L610.8 -466.8L524.9 -466.8ZM435.1 -285.2Q435.1 -378.4 399.2 -427.7Q363.3 -477.1 294.9 -477.1Q225.6 -477.1 190.2 -422.4Q154.8 -367.7 154.8 -259.8Q154.8 -152.3 190.2 -97.2Q225.6 -42 294.9 -42Q363.3 -42 399.2 -91.1Q435.1 -140.1 435.1 -233.9L435.1 -285.2Z"),
+    (644_f64, "M41 0L41 -51.8L122.1 -51.8L122.1 -708L36.1 -708L36.1 -759.8L211.9 -759.8L211.9 -426.8Q236.8 -479.5 276.6 -506.3Q316.4 -533.2 369.1 -533.2Q455.1 -533.2 495.6 -483.9Q536.1 -434.6 536.1 -330.1L536.1 -51.8L616.2 -51.8L616.2 0L368.2 0L368.2 -51.8L445.8 -51.8L445.8 -301.8Q445.8 -397 422.6 -431.9Q399.4 -466.8 339.8 -466.8Q277.3 -466.8 244.6 -421.4Q211.9 -376 211.9 -289.1L211.9 -51.8L290 -51.8L290 0L41 0Z"),
+    (319.8_f64, "M97.2 -680.2Q97.2 -702.6 113.5 -719.2Q129.9 -735.8 152.8 -735.8Q175.3 -735.8 191.7 -719.2Q208 -702.6 208 -680.2Q208 -657.2 191.9 -641.1Q175.8 -625 152.8 -625Q129.9 -625 113.5 -641.1Q97.2 -657.2 97.2 -680.2ZM211.9 -51.8L296.9 -51.8L296.9 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -51.8Z"),
+    (310.1_f64, "M100.1 -680.2Q100.1 -702.6 116.5 -719.2Q132.8 -735.8 155.8 -735.8Q178.2 -735.8 194.6 -719.2Q210.9 -702.6 210.9 -680.2Q210.9 -657.2 195.1 -641.1Q179.2 -625 155.8 -625Q132.8 -625 116.5 -641.1Q100.1 -657.2 100.1 -680.2ZM122.1 -466.8L37.1 -466.8L37.1 -519L211.9 -519L211.9 50.8Q211.9 130.9 164.3 176.5Q116.7 222.2 33.2 222.2Q-2 222.2 -33.9 214.1Q-65.9 206.1 -96.2 189.9L-96.2 83L-49.8 83Q-46.4 129.9 -25.6 151.9Q-4.9 173.8 35.2 173.8Q79.6 173.8 100.8 143.8Q122.1 113.8 122.1 50.8L122.1 -466.8Z"),
+    (606_f64, "M286.1 0L34.2 0L34.2 -51.8L115.2 -51.8L115.2 -708L28.8 -708L28.8 -759.8L205.1 -759.8L205.1 -265.1L423.8 -466.8L349.1 -466.8L349.1 -519L584 -519L584 -466.8L495.1 -466.8L340.8 -324.2L538.1 -51.8L612.8 -51.8L612.8 0L356.9 0L356.9 -51.8L431.2 -51.8L275.9 -265.1L205.1 -199.2L205.1 -51.8L286.1 -51.8L286.1 0Z"),
+    (319.8_f64, "M205.1 -51.8L290 -51.8L290 0L28.8 0L28.8 -51.8L115.2 -51.8L115.2 -708L28.8 -708L28.8 -759.8L205.1 -759.8L205.1 -51.8Z"),
+    (948.2_f64, "M518.1 -418Q543.9 -475.1 584.2 -504.2Q624.5 -533.2 678.2 -533.2Q759.8 -533.2 799.8 -482.7Q839.8 -432.1 839.8 -330.1L839.8 -51.8L920.9 -51.8L920.9 0L671.9 0L671.9 -51.8L750 -51.8L750 -319.8Q750 -399.4 726.6 -433.1Q703.1 -466.8 648.9 -466.8Q588.9 -466.8 557.4 -421.4Q525.9 -376 525.9 -289.1L525.9 -51.8L604 -51.8L604 0L357.9 0L357.9 -51.8L436 -51.8L436 -323.2Q436 -400.9 412.6 -433.8Q389.2 -466.8 335 -466.8Q274.9 -466.8 243.4 -421.4Q211.9 -376 211.9 -289.1L211.9 -51.8L290 -51.8L290 0L41 0L41 -51.8L122.1 -51.8L122.1 -467.8L36.1 -467.8L36.1 -519L211.9 -519L211.9 -426.8Q236.8 -478.5 275.4 -505.9Q314 -533.2 362.8 -533.2Q423.3 -533.2 463.9 -503.2Q504.4 -473.1 518.1 -418Z"),
+    (644_f64, "M41 0L41 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -426.8Q236.8 -479.5 276.6 -506.3Q316.4 -533.2 369.1 -533.2Q455.1 -533.2 495.6 -483.9Q536.1 -434.6 536.1 -330.1L536.1 -51.8L616.2 -51.8L616.2 0L368.2 0L368.2 -51.8L445.8 -51.8L445.8 -301.8Q445.8 -397 422.4 -432.4Q398.9 -467.8 339.8 -467.8Q277.3 -467.8 244.6 -422.1Q211.9 -376.5 211.9 -289.1L211.9 -51.8L290 -51.8L290 0L41 0Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2Z"),
+    (640.1_f64, "M205.1 -285.2L205.1 -233.9Q205.1 -140.1 241 -91.1Q276.9 -42 345.2 -42Q414.1 -42 449.5 -97.2Q484.9 -152.3 484.9 -259.8Q484.9 -367.7 449.5 -422.4Q414.1 -477.1 345.2 -477.1Q276.9 -477.1 241 -427.7Q205.1 -378.4 205.1 -285.2ZM115.2 -466.8L28.8 -466.8L28.8 -519L205.1 -519L205.1 -438Q231.4 -486.8 272.2 -510Q313 -533.2 373 -533.2Q468.8 -533.2 529.3 -457.5Q589.8 -381.8 589.8 -259.8Q589.8 -137.7 529.3 -61.8Q468.8 14.2 373 14.2Q313 14.2 272.2 -9Q231.4 -32.2 205.1 -81.1L205.1 155.8L290 155.8L290 208L28.8 208L28.8 155.8L115.2 155.8L115.2 -466.8Z"),
+    (640.1_f64, "M524.9 -466.8L524.9 155.8L610.8 155.8L610.8 208L350.1 208L350.1 155.8L435.1 155.8L435.1 -81.1Q408.7 -32.2 367.9 -9Q327.1 14.2 267.1 14.2Q171.4 14.2 110.6 -61.8Q49.8 -137.7 49.8 -259.8Q49.8 -381.8 110.4 -457.5Q170.9 -533.2 267.1 -533.2Q327.1 -533.2 367.9 -510Q408.7 -486.8 435.1 -438L435.1 -519L610.8 -519L610.8 -466.8L524.9 -466.8ZM435.1 -285.2Q435.1 -378.4 399.2 -427.7Q363.3 -477.1 294.9 -477.1Q225.6 -477.1 190.2 -422.4Q154.8 -367.7 154.8 -259.8Q154.8 -152.3 190.2 -97.2Q225.6 -42 294.9 -42Q363.3 -42 399.2 -91.1Q435.1 -140.1 435.1 -233.9L435.1 -285.2Z"),
+    (478_f64, "M478 -520L478 -390.1L426.3 -390.1Q423.8 -428.7 404.8 -447.8Q385.7 -466.8 349.1 -466.8Q282.7 -466.8 247.3 -420.9Q211.9 -375 211.9 -289.1L211.9 -51.8L315.9 -51.8L315.9 0L41 0L41 -51.8L122.1 -51.8L122.1 -467.8L36.1 -467.8L36.1 -519L211.9 -519L211.9 -426.8Q238.3 -481 279.8 -507.1Q321.3 -533.2 380.9 -533.2Q402.8 -533.2 427 -529.8Q451.2 -526.4 478 -520Z"),
+    (513.2_f64, "M56.2 -28.8L56.2 -149.9L107.9 -149.9Q109.9 -91.8 144.3 -63Q178.7 -34.2 246.1 -34.2Q306.6 -34.2 338.4 -56.9Q370.1 -79.6 370.1 -123Q370.1 -157.2 346.9 -178.2Q323.7 -199.2 249 -223.1L184.1 -245.1Q117.2 -266.6 87.2 -298.8Q57.1 -331.1 57.1 -380.9Q57.1 -452.1 109.4 -492.7Q161.6 -533.2 253.9 -533.2Q294.9 -533.2 340.3 -522.5Q385.7 -511.7 434.1 -491.2L434.1 -377.9L382.3 -377.9Q380.4 -428.2 347.2 -456.5Q314 -484.9 256.8 -484.9Q200.2 -484.9 171.1 -464.8Q142.1 -444.8 142.1 -404.8Q142.1 -372.1 164.1 -352.3Q186 -332.5 252 -312L323.2 -290Q397 -267.1 429.4 -232.7Q461.9 -198.2 461.9 -144Q461.9 -70.3 405.5 -28.1Q349.1 14.2 250 14.2Q199.7 14.2 151.9 3.4Q104 -7.3 56.2 -28.8Z"),
+    (401.9_f64, "M107.9 -466.8L28.8 -466.8L28.8 -519L107.9 -519L107.9 -680.2L198.2 -680.2L198.2 -519L367.2 -519L367.2 -466.8L198.2 -466.8L198.2 -137.2Q198.2 -71.3 210.9 -52.7Q223.6 -34.2 257.8 -34.2Q293 -34.2 309.1 -54.9Q325.2 -75.7 326.2 -122.1L394 -122.1Q390.1 -51.3 355.5 -18.6Q320.8 14.2 250 14.2Q172.4 14.2 140.1 -20.3Q107.9 -54.7 107.9 -137.2L107.9 -466.8Z"),
+    (644_f64, "M354 -519L522 -519L522 -51.8L606.9 -51.8L606.9 0L432.1 0L432.1 -91.8Q407.2 -40 367.7 -12.9Q328.1 14.2 275.9 14.2Q189.5 14.2 148.7 -34.9Q107.9 -84 107.9 -189L107.9 -466.8L26.9 -466.8L26.9 -519L198.2 -519L198.2 -216.8Q198.2 -122.1 221.4 -86.9Q244.6 -51.8 304.2 -51.8Q366.7 -51.8 399.4 -97.7Q432.1 -143.6 432.1 -231L432.1 -466.8L354 -466.8L354 -519Z"),
+    (564.9_f64, "M247.1 0L56.2 -466.8L-2.9 -466.8L-2.9 -519L235.8 -519L235.8 -466.8L152.8 -466.8L298.8 -109.9L444.8 -466.8L367.2 -466.8L367.2 -519L562 -519L562 -466.8L503.9 -466.8L313 0L247.1 0Z"),
+    (856_f64, "M480 -519L612.8 -113.8L730 -466.8L654.8 -466.8L654.8 -519L842.8 -519L842.8 -466.8L785.2 -466.8L630.9 0L556.2 0L428.2 -388.2L299.8 0L228 0L74.2 -466.8L16.1 -466.8L16.1 -519L251 -519L251 -466.8L167 -466.8L283.2 -113.8L417 -519L480 -519Z"),
+    (564_f64, "M291 -316.9L399.9 -466.8L330.1 -466.8L330.1 -519L529.8 -519L529.8 -466.8L460.9 -466.8L321.8 -274.9L483.9 -51.8L551.8 -51.8L551.8 0L312 0L312 -51.8L377.9 -51.8L265.1 -207L151.9 -51.8L219.2 -51.8L219.2 0L22 0L22 -51.8L90.8 -51.8L233.9 -249L76.2 -466.8L12.2 -466.8L12.2 -519L244.1 -519L244.1 -466.8L182.1 -466.8L291 -316.9Z"),
+    (564.9_f64, "M215.8 95.2L250 8.8L56.2 -466.8L-2.9 -466.8L-2.9 -519L235.8 -519L235.8 -466.8L152.8 -466.8L298.8 -109.9L444.8 -466.8L367.2 -466.8L367.2 -519L562 -519L562 -466.8L503.9 -466.8L266.1 117.2Q241.7 177.7 211.9 200Q182.1 222.2 127.9 222.2Q105 222.2 80.8 218.3Q56.6 214.4 32.2 207L32.2 107.9L78.1 107.9Q81.1 141.1 95 155.5Q108.9 169.9 138.2 169.9Q165 169.9 181.4 155Q197.8 140.1 215.8 95.2Z"),
+    (526.9_f64, "M40 0L40 -42L365.2 -466.8L107.9 -466.8L107.9 -377L56.2 -377L56.2 -519L481 -519L481 -477.1L155.8 -51.8L438 -51.8L438 -146L490.2 -146L490.2 0L40 0Z"),
+    (636.2_f64, "M511.2 111.8L511.2 163.1L481 163.1Q359.4 163.1 318.1 127Q276.9 90.8 276.9 -17.1L276.9 -143.1Q276.9 -216.3 250.5 -244.6Q224.1 -272.9 154.8 -272.9L125 -272.9L125 -324.2L154.8 -324.2Q224.1 -324.2 250.5 -352.5Q276.9 -380.9 276.9 -454.1L276.9 -580.1Q276.9 -688 318.1 -723.9Q359.4 -759.8 481 -759.8L511.2 -759.8L511.2 -709L478 -709Q409.2 -709 388.2 -687.5Q367.2 -666 367.2 -597.2L367.2 -457Q367.2 -379.9 342.8 -346.7Q318.4 -313.5 254.9 -298.8Q319.3 -282.2 343.3 -249.5Q367.2 -216.8 367.2 -140.1L367.2 0Q367.2 68.8 388.2 90.3Q409.2 111.8 478 111.8L511.2 111.8Z"),
+    (336.9_f64, "M208 -764.2L208 235.8L128.9 235.8L128.9 -764.2L208 -764.2Z"),
+    (636.2_f64, "M125 111.8L159.2 111.8Q227.5 111.8 248.3 90.6Q269 69.3 269 0L269 -140.1Q269 -216.8 293 -249.5Q316.9 -282.2 380.9 -298.8Q317.4 -313.5 293.2 -346.7Q269 -379.9 269 -457L269 -597.2Q269 -666.5 248.3 -687.7Q227.5 -709 159.2 -709L125 -709L125 -759.8L155.8 -759.8Q277.3 -759.8 318.1 -723.9Q358.9 -688 358.9 -580.1L358.9 -454.1Q358.9 -380.9 385.3 -352.5Q411.6 -324.2 481 -324.2L511.2 -324.2L511.2 -272.9L481 -272.9Q411.6 -272.9 385.3 -244.6Q358.9 -216.3 358.9 -143.1L358.9 -17.1Q358.9 90.8 318.1 127Q277.3 163.1 155.8 163.1L125 163.1L125 111.8Z"),
+    (837.9_f64, "M731.9 -394L731.9 -321.8Q683.1 -278.8 639.2 -258.3Q595.2 -237.8 549.8 -237.8Q502.9 -237.8 418 -270L413.1 -272Q407.7 -273.9 397 -278.3Q325.2 -306.2 278.8 -306.2Q233.9 -306.2 191.7 -288.3Q149.4 -270.5 106 -232.9L106 -307.1Q154.8 -349.6 198.5 -369.4Q242.2 -389.2 288.1 -389.2Q335 -389.2 419.9 -356.9L424.8 -355Q430.2 -353 439.9 -349.1Q510.7 -320.8 558.1 -320.8Q603 -320.8 646 -338.9Q689 -356.9 731.9 -394Z"),
+    (317.9_f64, ""),
+    (401.9_f64, "M136.2 -496.1Q136.2 -523.4 154.8 -542.2Q173.3 -561 201.2 -561Q228 -561 247.1 -542Q266.1 -522.9 266.1 -496.1Q266.1 -468.8 247.1 -449.7Q228 -430.7 201.2 -430.7Q173.3 -430.7 154.8 -449.7Q136.2 -468.8 136.2 -496.1ZM138.2 182.1L170.9 -218.8L170.9 -341.8L231 -341.8L231 -218.8L264.2 182.1L138.2 182.1Z"),
+    (636.2_f64, "M307.1 -483.9Q244.1 -476.6 212.6 -420.4Q181.2 -364.3 181.2 -259.8Q181.2 -154.8 212.6 -98.6Q244.1 -42.5 307.1 -35.2L307.1 -483.9ZM539.1 -155.8Q522 -80.6 476.1 -38.3Q430.2 3.9 356 13.2L356 145L307.1 145L307.1 13.2Q199.7 3.9 137.5 -69.3Q75.2 -142.6 75.2 -259.8Q75.2 -377.9 137.5 -450.9Q199.7 -523.9 307.1 -532.2L307.1 -662.1L356 -662.1L356 -532.2Q400.9 -528.3 441.9 -517.1Q482.9 -505.9 522 -486.8L522 -354L470.2 -354Q460.9 -414.1 432.9 -446Q404.8 -478 356 -483.9L356 -35.2Q399.9 -41 428.2 -72Q456.5 -103 466.8 -155.8L539.1 -155.8Z"),
+    (636.2_f64, "M538.1 -598.1L486.8 -598.1Q481.9 -644.5 454.3 -669.2Q426.8 -693.8 379.9 -693.8Q317.9 -693.8 288.3 -656Q258.8 -618.2 258.8 -538.1L258.8 -394L460 -394L460 -341.8L258.8 -341.8L258.8 -59.1L492.2 -59.1L492.2 -169.9L548.8 -169.9L548.8 0L66.9 0L66.9 -51.8L161.1 -51.8L161.1 -341.8L66.9 -341.8L66.9 -394L161.1 -394L161.1 -538.1Q161.1 -638.2 217 -690.2Q272.9 -742.2 380.9 -742.2Q422.4 -742.2 461.9 -736.1Q501.5 -730 538.1 -717.8L538.1 -598.1Z"),
+    (636.2_f64, "M439.9 -481L542 -582L587.9 -536.1L485.8 -434.1Q506.8 -404.8 516.8 -375Q526.9 -345.2 526.9 -313Q526.9 -279.3 516.8 -250.5Q506.8 -221.7 484.9 -193.8L585.9 -92.8L539.1 -46.9L438 -147Q410.6 -125.5 381.6 -115.2Q352.5 -105 318.8 -105Q287.6 -105 257.1 -115Q226.6 -125 196.8 -145L97.2 -44.9L50.8 -90.8L150.9 -191.9Q130.9 -222.2 120.8 -252Q110.8 -281.7 110.8 -313Q110.8 -347.2 121.1 -376.2Q131.3 -405.3 152.8 -433.1L51.8 -534.2L98.1 -580.1L199.2 -479Q226.6 -500.5 255.9 -510.7Q285.2 -521 318.8 -521Q351.1 -521 380.9 -511.2Q410.6 -501.5 439.9 -481ZM450.2 -314Q450.2 -368.7 412.4 -406.2Q374.5 -443.8 318.8 -443.8Q264.2 -443.8 226.1 -406.2Q188 -368.7 188 -314Q188 -258.3 225.8 -220.2Q263.7 -182.1 318.8 -182.1Q374 -182.1 412.1 -220.5Q450.2 -258.8 450.2 -314Z"),
+    (636.2_f64, "M175.8 0L175.8 -51.8L269 -51.8L269 -231L71.8 -231L71.8 -282.2L269 -282.2L269 -314L229 -382.8L71.8 -382.8L71.8 -434.1L200.2 -434.1L60.1 -676.8L-7.8 -676.8L-7.8 -729L262.2 -729L262.2 -676.8L176.8 -676.8L344.2 -381.8L509.8 -676.8L428.2 -676.8L428.2 -729L639.2 -729L639.2 -676.8L569.8 -676.8L431.2 -434.1L564 -434.1L564 -382.8L401.9 -382.8L367.2 -323.2L367.2 -282.2L564 -282.2L564 -231L367.2 -231L367.2 -51.8L460 -51.8L460 0L175.8 0Z"),
+    (336.9_f64, "M208 -199.2L208 170.9L128.9 170.9L128.9 -199.2L208 -199.2ZM208 -699.2L208 -329.1L128.9 -329.1L128.9 -699.2L208 -699.2Z"),
+    (500_f64, "M185.1 -457Q151.9 -434.6 138.4 -414.3Q125 -394 125 -368.2Q125 -303.7 302.2 -204.6L317.9 -195.8Q346.2 -214.4 360.6 -237.8Q375 -261.2 375 -289.1Q375 -325.7 341.1 -358.4Q307.1 -391.1 185.1 -457ZM404.8 -714.8L404.8 -616.2L359.9 -616.2Q358.9 -651.4 333.5 -671.6Q308.1 -691.9 265.1 -691.9Q216.3 -691.9 188.2 -668.2Q160.2 -644.5 160.2 -604Q160.2 -556.6 280.3 -489.7Q293 -482.4 298.8 -479Q394 -425.8 424.6 -389.2Q455.1 -352.5 455.1 -304.2Q455.1 -257.8 429.4 -227.1Q403.8 -196.3 348.1 -174.8Q386.2 -151.9 404.1 -121.1Q421.9 -90.3 421.9 -45.9Q421.9 18.1 372.1 56.6Q322.3 95.2 237.8 95.2Q193.8 95.2 154.8 88.6Q115.7 82 78.1 67.9L78.1 -34.2L123 -33.7Q124 5.4 150.4 25.1Q176.8 44.9 229 44.9Q279.8 44.9 309.3 21Q338.9 -2.9 338.9 -43.9Q338.9 -99.1 227.5 -161.1Q217.3 -167 211.9 -169.9Q106.4 -229.5 75.7 -266.1Q44.9 -302.7 44.9 -351.1Q44.9 -397 73.7 -429.4Q102.5 -461.9 155.8 -477.1Q115.7 -501 96.4 -531.7Q77.1 -562.5 77.1 -601.1Q77.1 -664.6 126 -703.4Q174.8 -742.2 255.9 -742.2Q293.9 -742.2 331.1 -735.4Q368.2 -728.5 404.8 -714.8Z"),
+    (500_f64, "M154.8 -766.1Q178.2 -766.1 195.1 -749.3Q211.9 -732.4 211.9 -709Q211.9 -684.1 195.8 -668Q179.7 -651.9 154.8 -651.9Q130.4 -651.9 114.3 -668Q98.1 -684.1 98.1 -709Q98.1 -732.4 115 -749.3Q131.8 -766.1 154.8 -766.1ZM345.2 -766.1Q368.2 -766.1 385 -749.3Q401.9 -732.4 401.9 -709Q401.9 -684.1 385.7 -668Q369.6 -651.9 345.2 -651.9Q320.3 -651.9 304.2 -668Q288.1 -684.1 288.1 -709Q288.1 -732.4 304.9 -749.3Q321.8 -766.1 345.2 -766.1Z"),
+    (1000_f64, "M671.9 -279.8Q658.7 -215.8 614.5 -181.4Q570.3 -147 501 -147Q409.7 -147 355.2 -205.6Q300.8 -264.2 300.8 -361.8Q300.8 -460 355.7 -519Q410.6 -578.1 501 -578.1Q540 -578.1 577.9 -569.1Q615.7 -560.1 658.2 -541L658.2 -437L617.2 -437Q608.9 -490.2 581.1 -515.1Q553.2 -540 502 -540Q443.8 -540 414.3 -495.4Q384.8 -450.7 384.8 -361.8Q384.8 -273.4 414.1 -229.2Q443.4 -185.1 501 -185.1Q548.3 -185.1 575.9 -208.3Q603.5 -231.4 613.8 -279.8L671.9 -279.8ZM500 -725.1Q574.2 -725.1 638.4 -698.2Q702.6 -671.4 755.9 -618.2Q809.1 -564.9 835.4 -501Q861.8 -437 861.8 -361.8Q861.8 -287.6 835.4 -223.9Q809.1 -160.2 755.9 -106.9Q702.6 -53.7 638.4 -26.9Q574.2 0 500 0Q425.8 0 361.6 -26.9Q297.4 -53.7 244.1 -106.9Q190.9 -160.2 164.6 -223.9Q138.2 -287.6 138.2 -361.8Q138.2 -437 164.6 -501Q190.9 -564.9 244.1 -618.2Q297.4 -671.4 361.6 -698.2Q425.8 -725.1 500 -725.1ZM500 -674.8Q436 -674.8 380.9 -651.9Q325.7 -628.9 279.8 -583Q234.4 -537.6 211.2 -481.7Q188 -425.8 188 -361.8Q188 -298.3 211.2 -243.2Q234.4 -188 279.8 -142.1Q325.7 -96.2 380.9 -73Q436 -49.8 500 -49.8Q564.5 -49.8 619.9 -73Q675.3 -96.2 721.2 -142.1Q766.6 -187.5 789.3 -242.7Q812 -297.9 812 -361.8Q812 -426.8 789.1 -482.2Q766.1 -537.6 721.2 -583Q675.3 -628.9 619.9 -651.9Q564.5 -674.8 500 -674.8Z"),
+    (475.1_f64, "M409.2 -595.2L409.2 -410.2L448.2 -410.2L448.2 -369.1L336.9 -369.1L336.9 -408.2Q313.5 -383.3 283 -371.6Q252.4 -359.9 210 -359.9Q141.1 -359.9 101.1 -391.6Q61 -423.3 61 -478Q61 -533.7 107.4 -564.9Q153.8 -596.2 236.8 -596.2L336.9 -596.2L336.9 -618.2Q336.9 -659.2 308.6 -682.1Q280.3 -705.1 229 -705.1Q186.5 -705.1 161.6 -688Q136.7 -670.9 129.9 -637.2L92.8 -637.2L92.8 -713.9Q130.4 -728 165.5 -735.1Q200.7 -742.2 232.9 -742.2Q319.3 -742.2 364.3 -704.6Q409.2 -667 409.2 -595.2ZM336.9 -486.8L336.9 -559.1L245.1 -559.1Q193.8 -559.1 167.5 -539.6Q141.1 -520 141.1 -481.9Q141.1 -442.4 165.8 -421.6Q190.4 -400.9 237.8 -400.9Q282.7 -400.9 309.8 -424.6Q336.9 -448.2 336.9 -486.8ZM81.1 -312L421.9 -312L421.9 -261.2L81.1 -261.2L81.1 -312Z"),
+    (611.8_f64, "M518.1 -517.1L518.1 -460.9L373 -293L518.1 -125L518.1 -68.8L289.1 -272.9L289.1 -313L518.1 -517.1ZM306.2 -517.1L306.2 -460.9L161.1 -293L306.2 -125L306.2 -68.8L77.1 -272.9L77.1 -313L306.2 -517.1Z"),
+    (837.9_f64, "M106 -420.9L731.9 -420.9L731.9 -140.1L653.8 -140.1L653.8 -341.8L106 -341.8L106 -420.9Z"),
+    (337.9_f64, "M43.9 -306.2L293.9 -306.2L293.9 -230L43.9 -230L43.9 -306.2Z"),
+    (1000_f64, "M437 -386.2L498 -386.2Q538.6 -386.2 558.8 -404.8Q579.1 -423.3 579.1 -460Q579.1 -496.1 558.6 -514.6Q538.1 -533.2 498 -533.2L437 -533.2L437 -386.2ZM557.1 -368.2Q577.6 -361.3 592 -348.9Q606.4 -336.4 616.2 -316.9L672.9 -205.1L710 -205.1L710 -171.9L617.2 -171.9L553.2 -295.9Q536.1 -330.1 521 -341.6Q505.9 -353 481.9 -353L437 -353L437 -205.1L478 -205.1L478 -171.9L323.2 -171.9L323.2 -205.1L363.8 -205.1L363.8 -533.2L323.2 -533.2L323.2 -565.9L525.9 -565.9Q588.4 -565.9 620.1 -538.6Q651.9 -511.2 651.9 -458Q651.9 -417.5 628.4 -395.3Q605 -373 557.1 -368.2ZM500 -674.8Q436 -674.8 380.9 -651.9Q325.7 -628.9 279.8 -583Q234.4 -537.6 211.2 -481.7Q188 -425.8 188 -361.8Q188 -298.3 211.2 -243.2Q234.4 -188 279.8 -142.1Q325.7 -96.2 380.9 -73Q436 -49.8 500 -49.8Q564.5 -49.8 619.9 -73Q675.3 -96.2 721.2 -142.1Q766.6 -187.5 789.3 -242.7Q812 -297.9 812 -361.8Q812 -426.8 789.1 -482.2Q766.1 -537.6 721.2 -583Q675.3 -628.9 619.9 -651.9Q564.5 -674.8 500 -674.8ZM500 -725.1Q574.2 -725.1 638.4 -698.2Q702.6 -671.4 755.9 -618.2Q809.1 -564.9 835.4 -501Q861.8 -437 861.8 -361.8Q861.8 -287.6 835.4 -223.9Q809.1 -160.2 755.9 -106.9Q702.6 -53.7 638.4 -26.9Q574.2 0 500 0Q425.8 0 361.6 -26.9Q297.4 -53.7 244.1 -106.9Q190.9 -160.2 164.6 -223.9Q138.2 -287.6 138.2 -361.8Q138.2 -437 164.6 -501Q190.9 -564.9 244.1 -618.2Q297.4 -671.4 361.6 -698.2Q425.8 -725.1 500 -725.1Z"),
+    (500_f64, "M104 -745.1L396 -745.1L396 -672.9L104 -672.9L104 -745.1Z"),
+    (500_f64, "M250 -674.8Q212.9 -674.8 187.5 -649.2Q162.1 -623.5 162.1 -585.9Q162.1 -548.8 187.3 -523.9Q212.4 -499 250 -499Q287.1 -499 312.5 -524.2Q337.9 -549.3 337.9 -585.9Q337.9 -622.6 312 -648.7Q286.1 -674.8 250 -674.8ZM250 -742.2Q281.2 -742.2 310.1 -730.2Q338.9 -718.3 359.9 -695.8Q382.3 -673.8 393.6 -646Q404.8 -618.2 404.8 -585.9Q404.8 -521.5 359.6 -476.8Q314.5 -432.1 249 -432.1Q183.1 -432.1 139.2 -476.1Q95.2 -520 95.2 -585.9Q95.2 -651.4 140.1 -696.8Q185.1 -742.2 250 -742.2Z"),
+    (837.9_f64, "M458 -627L458 -436L731.9 -436L731.9 -356.9L458 -356.9L458 -166L379.9 -166L379.9 -356.9L106 -356.9L106 -436L379.9 -436L379.9 -627L458 -627ZM106 -78.1L731.9 -78.1L731.9 0L106 0L106 -78.1Z"),
+    (400.9_f64, "M83 -632.8L48.8 -632.8L48.8 -710Q81.1 -725.6 115.2 -733.9Q149.4 -742.2 183.1 -742.2Q250.5 -742.2 292.2 -710Q334 -677.7 334 -627Q334 -567.4 239.3 -482.4L230 -474.1L113.8 -369.1L301.8 -369.1L301.8 -421.9L337.9 -421.9L337.9 -326.2L43.9 -326.2L43.9 -362.8L183.1 -486.8Q225.1 -524.4 243.2 -554.2Q261.2 -584 261.2 -616.2Q261.2 -657.7 237.5 -681.9Q213.9 -706.1 172.9 -706.1Q134.8 -706.1 111.8 -687.5Q88.9 -668.9 83 -632.8Z"),
+    (400.9_f64, "M61 -716.8Q96.7 -729.5 129.4 -735.8Q162.1 -742.2 190.9 -742.2Q258.3 -742.2 294.7 -715.8Q331.1 -689.5 331.1 -641.1Q331.1 -606 305.2 -581.5Q279.3 -557.1 232.9 -548.8Q291 -541 320.6 -512Q350.1 -482.9 350.1 -434.1Q350.1 -378.4 307.4 -348.6Q264.6 -318.8 185.1 -318.8Q149.9 -318.8 116 -325.7Q82 -332.5 48.8 -346.2L48.8 -429.2L83 -428.7Q85.4 -392.6 109.4 -373.8Q133.3 -355 178.2 -355Q224.1 -355 249 -376.5Q273.9 -397.9 273.9 -438Q273.9 -483.4 249 -506.6Q224.1 -529.8 174.8 -529.8L148.9 -529.8L148.9 -565.9L162.1 -565.9Q209.5 -565.9 233.6 -584Q257.8 -602.1 257.8 -638.2Q257.8 -670.9 238 -688.5Q218.3 -706.1 181.2 -706.1Q141.1 -706.1 119.9 -690.4Q98.6 -674.8 95.2 -643.1L61 -643.1L61 -716.8Z"),
+    (500_f64, "M317.9 -799.8L415 -799.8L249 -616.2L191.9 -616.2L317.9 -799.8Z"),
+    (649.9_f64, "M360.8 -519L528.8 -519L528.8 -50.8L613.8 -50.8L613.8 0L439 0L439 -91.8Q413.6 -40 377.7 -12.9Q341.8 14.2 297.9 14.2Q270 14.2 247.1 5.4Q224.1 -3.4 205.1 -21L205.1 155.8L290 155.8L290 208L28.8 208L28.8 155.8L115.2 155.8L115.2 -466.8L34.2 -466.8L34.2 -519L205.1 -519L205.1 -216.8Q205.1 -122.1 228.5 -86.9Q252 -51.8 311 -51.8Q373.5 -51.8 406.2 -97.7Q439 -143.6 439 -231L439 -466.8L360.8 -466.8L360.8 -519Z"),
+    (636.2_f64, "M309.1 -729L571.8 -729L571.8 -676.8L514.2 -676.8L514.2 96.2L461.9 96.2L461.9 -676.8L349.1 -676.8L349.1 96.2L296.9 96.2L296.9 -316.9Q191.9 -325.2 134.5 -379.2Q77.1 -433.1 77.1 -522.9Q77.1 -615.7 140.6 -672.4Q204.1 -729 309.1 -729Z"),
+    (317.9_f64, "M92.8 -347.2Q92.8 -374.5 111.6 -393.3Q130.4 -412.1 158.2 -412.1Q185.1 -412.1 204.1 -393.1Q223.1 -374 223.1 -347.2Q223.1 -319.8 204.1 -301Q185.1 -282.2 158.2 -282.2Q130.4 -282.2 111.6 -300.8Q92.8 -319.3 92.8 -347.2Z"),
+    (500_f64, "M282.2 0Q313.5 31.2 328.9 58.6Q344.2 85.9 344.2 109.9Q344.2 149.4 313.5 171.1Q282.7 192.9 228 192.9Q207.5 192.9 186 189.9Q164.6 187 142.1 181.2L142.1 117.2Q162.1 125.5 181.4 129.6Q200.7 133.8 217.8 133.8Q245.6 133.8 261.7 120.8Q277.8 107.9 277.8 85.9Q277.8 72.3 267.1 50.8Q256.3 29.3 234.9 0L282.2 0Z"),
+    (400.9_f64, "M85.9 -326.2L85.9 -367.2L169.9 -367.2L169.9 -676.8L71.8 -625L71.8 -670.9L189 -733.9L238.8 -733.9L238.8 -367.2L323.2 -367.2L323.2 -326.2L85.9 -326.2Z"),
+    (470.2_f64, "M234.9 -397Q286.6 -397 312.7 -435.8Q338.9 -474.6 338.9 -550.8Q338.9 -627 312.5 -666Q286.1 -705.1 234.9 -705.1Q184.1 -705.1 157.5 -665.5Q130.9 -626 130.9 -550.8Q130.9 -475.1 157.2 -436Q183.6 -397 234.9 -397ZM234.9 -359.9Q149.4 -359.9 97.7 -412.4Q45.9 -464.8 45.9 -550.8Q45.9 -637.2 97.7 -689.7Q149.4 -742.2 234.9 -742.2Q320.3 -742.2 372.1 -689.7Q423.8 -637.2 423.8 -550.8Q423.8 -464.4 372.3 -412.1Q320.8 -359.9 234.9 -359.9ZM66.9 -312L402.8 -312L402.8 -261.2L66.9 -261.2L66.9 -312Z"),
+    (611.8_f64, "M306.2 -517.1L535.2 -313L535.2 -272.9L306.2 -68.8L306.2 -125L451.2 -293L306.2 -460.9L306.2 -517.1ZM94.2 -517.1L323.2 -313L323.2 -272.9L94.2 -68.8L94.2 -125L238.8 -293L94.2 -460.9L94.2 -517.1Z"),
+    (969.2_f64, "M788.1 -147.9L788.1 -346.7L637.7 -147.9L788.1 -147.9ZM928.7 0L716.8 0L716.8 -41L788.1 -41L788.1 -107.9L593.8 -107.9L593.8 -148.9L789.1 -407.7L857.9 -407.7L857.9 -147.9L938 -147.9L938 -107.9L857.9 -107.9L857.9 -41L928.7 -41L928.7 0ZM85.9 -326.2L85.9 -367.2L169.9 -367.2L169.9 -676.8L71.8 -625L71.8 -670.9L189 -733.9L238.8 -733.9L238.8 -367.2L323.2 -367.2L323.2 -326.2L85.9 -326.2ZM677.7 -742.2L751 -742.2L291 14.2L217.8 14.2L677.7 -742.2Z"),
+    (969.2_f64, "M85.9 -326.2L85.9 -367.2L169.9 -367.2L169.9 -676.8L71.8 -625L71.8 -670.9L189 -733.9L238.8 -733.9L238.8 -367.2L323.2 -367.2L323.2 -326.2L85.9 -326.2ZM677.7 -742.2L751 -742.2L291 14.2L217.8 14.2L677.7 -742.2ZM650.9 -306.6L616.7 -306.6L616.7 -383.8Q648.9 -399.4 683.1 -407.7Q717.3 -416 751 -416Q818.4 -416 860.1 -383.8Q901.9 -351.6 901.9 -300.8Q901.9 -241.2 807.1 -156.2L797.9 -147.9L681.6 -43L869.6 -43L869.6 -95.7L905.8 -95.7L905.8 0L611.8 0L611.8 -36.6L751 -160.6Q793 -198.2 811 -228Q829.1 -257.8 829.1 -290Q829.1 -331.5 805.4 -355.7Q781.7 -379.9 740.7 -379.9Q702.6 -379.9 679.7 -361.3Q656.7 -342.8 650.9 -306.6Z"),
+    (969.2_f64, "M788.1 -147.9L788.1 -346.7L637.7 -147.9L788.1 -147.9ZM928.7 0L716.8 0L716.8 -41L788.1 -41L788.1 -107.9L593.8 -107.9L593.8 -148.9L789.1 -407.7L857.9 -407.7L857.9 -147.9L938 -147.9L938 -107.9L857.9 -107.9L857.9 -41L928.7 -41L928.7 0ZM61 -716.8Q96.7 -729.5 129.4 -735.8Q162.1 -742.2 190.9 -742.2Q258.3 -742.2 294.7 -715.8Q331.1 -689.5 331.1 -641.1Q331.1 -606 305.2 -581.5Q279.3 -557.1 232.9 -548.8Q291 -541 320.6 -512Q350.1 -482.9 350.1 -434.1Q350.1 -378.4 307.4 -348.6Q264.6 -318.8 185.1 -318.8Q149.9 -318.8 116 -325.7Q82 -332.5 48.8 -346.2L48.8 -429.2L83 -428.7Q85.4 -392.6 109.4 -373.8Q133.3 -355 178.2 -355Q224.1 -355 249 -376.5Q273.9 -397.9 273.9 -438Q273.9 -483.4 249 -506.6Q224.1 -529.8 174.8 -529.8L148.9 -529.8L148.9 -565.9L162.1 -565.9Q209.5 -565.9 233.6 -584Q257.8 -602.1 257.8 -638.2Q257.8 -670.9 238 -688.5Q218.3 -706.1 181.2 -706.1Q141.1 -706.1 119.9 -690.4Q98.6 -674.8 95.2 -643.1L61 -643.1L61 -716.8ZM677.7 -742.2L751 -742.2L291 14.2L217.8 14.2L677.7 -742.2Z"),
+    (536.1_f64, "M379.9 -496.1Q379.9 -468.8 361.1 -449.7Q342.3 -430.7 314.9 -430.7Q288.1 -430.7 269 -449.7Q250 -468.8 250 -496.1Q250 -522.9 269 -542Q288.1 -561 314.9 -561Q342.8 -561 361.3 -542.2Q379.9 -523.4 379.9 -496.1ZM487.8 149.9Q436 172.9 389.6 184.1Q343.3 195.3 301.8 195.3Q193.8 195.3 130.9 143.1Q67.9 90.8 67.9 2Q67.9 -88.9 122.1 -146.5Q176.3 -204.1 285.2 -229L285.2 -353L345.2 -353L345.2 -192.9Q258.8 -171.9 214.8 -122.6Q170.9 -73.2 170.9 2.9Q170.9 69.8 206.8 108.4Q242.7 147 304.2 147Q359.4 147 395 115Q430.7 83 440.9 22.9L487.8 22.9L487.8 149.9Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0ZM326.7 -927.2L416 -798.3L358.9 -798.3L231 -927.2L326.7 -927.2Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0ZM391.6 -927.2L487.8 -927.2L359.9 -798.3L302.7 -798.3L391.6 -927.2Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0ZM317.9 -927.2L403.8 -927.2L516.6 -798.3L460 -798.3L360.8 -877L261.7 -798.3L205.1 -798.3L317.9 -927.2Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0ZM354.5 -837.4L331.1 -851.1Q321.3 -857.4 311.3 -860.8Q301.3 -864.3 293 -864.3Q273.9 -864.3 261.2 -850.3Q248.5 -836.4 246.6 -814L199.7 -814.5Q200.7 -863.3 225.6 -892.1Q250.5 -920.9 292 -920.9Q309.6 -920.9 324.7 -916.5Q339.8 -912.1 366.7 -897L390.6 -884.3Q401.9 -877.9 412.6 -874.5Q423.3 -871.1 431.6 -871.1Q451.7 -871.1 463.1 -884.5Q474.6 -897.9 474.6 -920.9L522 -920.9Q521 -869.6 495.8 -841.8Q470.7 -814 424.8 -814Q392.1 -814 356.9 -835.9L354.5 -837.4Z"),
+    (722.2_f64, "M200.2 -264.2L467.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L317.9 -729L399.9 -729L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0ZM265.6 -919.9Q289.1 -919.9 305.9 -902.8Q322.8 -885.7 322.8 -863.3Q322.8 -838.9 306.4 -822.5Q290 -806.2 265.6 -806.2Q241.7 -806.2 225.3 -822.5Q209 -838.9 209 -863.3Q209 -885.7 225.8 -902.8Q242.7 -919.9 265.6 -919.9ZM456.1 -919.9Q479 -919.9 495.8 -902.8Q512.7 -885.7 512.7 -863.3Q512.7 -838.9 496.3 -822.5Q480 -806.2 456.1 -806.2Q431.6 -806.2 415.3 -822.5Q398.9 -838.9 398.9 -863.3Q398.9 -885.7 415.8 -902.8Q432.6 -919.9 456.1 -919.9Z"),
+    (722.2_f64, "M433.1 -793.9Q433.1 -824.7 411.4 -846.4Q389.6 -868.2 358.9 -868.2Q327.6 -868.2 306.4 -846.9Q285.2 -825.7 285.2 -793.9Q285.2 -763.2 306.4 -741.7Q327.6 -720.2 358.9 -720.2Q389.6 -720.2 411.4 -741.7Q433.1 -763.2 433.1 -793.9ZM200.2 -264.2L466.8 -264.2L334 -610.8L200.2 -264.2ZM-5.9 0L-5.9 -51.8L58.1 -51.8L296.9 -675.8Q261.7 -693.8 243.4 -723.6Q225.1 -753.4 225.1 -793.9Q225.1 -849.6 264.2 -888.9Q303.2 -928.2 358.9 -928.2Q414.6 -928.2 453.9 -888.9Q493.2 -849.6 493.2 -793.9Q493.2 -754.9 474.1 -723.9Q455.1 -692.9 419.9 -675.8L660.2 -51.8L731.9 -51.8L731.9 0L466.8 0L466.8 -51.8L547.9 -51.8L486.8 -211.9L180.2 -211.9L119.1 -51.8L199.2 -51.8L199.2 0L-5.9 0Z"),
+    (1001_f64, "M208 -264.2L446.8 -264.2L446.8 -676.8L397.9 -676.8L208 -264.2ZM354 0L354 -51.8L446.8 -51.8L446.8 -211.9L184.1 -211.9L109.9 -51.8L189.9 -51.8L189.9 0L-17.1 0L-17.1 -51.8L46.9 -51.8L335 -676.8L230 -676.8L230 -729L940.9 -729L940.9 -566.9L880.9 -566.9L880.9 -668.9L544.9 -668.9L544.9 -424.8L785.2 -424.8L785.2 -516.1L845.2 -516.1L845.2 -273.9L785.2 -273.9L785.2 -365.2L544.9 -365.2L544.9 -60.1L889.2 -60.1L889.2 -162.1L949.2 -162.1L949.2 0L354 0Z"),
+    (765.1_f64, "M705.1 -192.9Q672.9 -90.8 596.9 -38.3Q521 14.2 404.8 14.2Q333.5 14.2 272.5 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.3Q56.2 -275.9 56.2 -363.8Q56.2 -533.7 154.3 -637.9Q252.4 -742.2 413.1 -742.2Q472.7 -742.2 540 -726.6Q607.4 -710.9 685.1 -679.2L685.1 -511.2L629.9 -511.2Q611.8 -603 557.4 -646.5Q502.9 -689.9 404.8 -689.9Q288.1 -689.9 228 -607.2Q168 -524.4 168 -363.8Q168 -203.6 228 -120.8Q288.1 -38.1 404.8 -38.1Q486.3 -38.1 539.1 -76.9Q591.8 -115.7 615.2 -192.9L705.1 -192.9ZM444.3 0Q475.6 31.2 491 58.6Q506.3 85.9 506.3 109.9Q506.3 149.4 475.6 171.1Q444.8 192.9 390.1 192.9Q369.6 192.9 348.1 189.9Q326.7 187 304.2 181.2L304.2 117.2Q324.2 125.5 343.5 129.6Q362.8 133.8 379.9 133.8Q407.7 133.8 423.8 120.8Q439.9 107.9 439.9 85.9Q439.9 72.3 429.2 50.8Q418.5 29.3 397 0L444.3 0Z"),
+    (730_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L642.1 -729L642.1 -566.9L582 -566.9L582 -668.9L247.1 -668.9L247.1 -424.8L485.8 -424.8L485.8 -516.1L545.9 -516.1L545.9 -273.9L485.8 -273.9L485.8 -365.2L247.1 -365.2L247.1 -60.1L589.8 -60.1L589.8 -162.1L649.9 -162.1L649.9 0L55.2 0ZM331.1 -927.2L420.4 -798.3L363.3 -798.3L235.4 -927.2L331.1 -927.2Z"),
+    (730_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L642.1 -729L642.1 -566.9L582 -566.9L582 -668.9L247.1 -668.9L247.1 -424.8L485.8 -424.8L485.8 -516.1L545.9 -516.1L545.9 -273.9L485.8 -273.9L485.8 -365.2L247.1 -365.2L247.1 -60.1L589.8 -60.1L589.8 -162.1L649.9 -162.1L649.9 0L55.2 0ZM396 -927.2L492.2 -927.2L364.3 -798.3L307.1 -798.3L396 -927.2Z"),
+    (730_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L642.1 -729L642.1 -566.9L582 -566.9L582 -668.9L247.1 -668.9L247.1 -424.8L485.8 -424.8L485.8 -516.1L545.9 -516.1L545.9 -273.9L485.8 -273.9L485.8 -365.2L247.1 -365.2L247.1 -60.1L589.8 -60.1L589.8 -162.1L649.9 -162.1L649.9 0L55.2 0ZM322.3 -927.2L408.2 -927.2L521 -798.3L464.4 -798.3L365.2 -877L266.1 -798.3L209.5 -798.3L322.3 -927.2Z"),
+    (730_f64, "M55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L642.1 -729L642.1 -566.9L582 -566.9L582 -668.9L247.1 -668.9L247.1 -424.8L485.8 -424.8L485.8 -516.1L545.9 -516.1L545.9 -273.9L485.8 -273.9L485.8 -365.2L247.1 -365.2L247.1 -60.1L589.8 -60.1L589.8 -162.1L649.9 -162.1L649.9 0L55.2 0ZM270 -919.9Q293.5 -919.9 310.3 -902.8Q327.1 -885.7 327.1 -863.3Q327.1 -838.9 310.8 -822.5Q294.4 -806.2 270 -806.2Q246.1 -806.2 229.7 -822.5Q213.4 -838.9 213.4 -863.3Q213.4 -885.7 230.2 -902.8Q247.1 -919.9 270 -919.9ZM460.4 -919.9Q483.4 -919.9 500.2 -902.8Q517.1 -885.7 517.1 -863.3Q517.1 -838.9 500.7 -822.5Q484.4 -806.2 460.4 -806.2Q436 -806.2 419.7 -822.5Q403.3 -838.9 403.3 -863.3Q403.3 -885.7 420.2 -902.8Q437 -919.9 460.4 -919.9Z"),
+    (395_f64, "M247.1 -51.8L339.8 -51.8L339.8 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -51.8ZM164.1 -927.2L253.4 -798.3L196.3 -798.3L68.4 -927.2L164.1 -927.2Z"),
+    (395_f64, "M247.1 -51.8L339.8 -51.8L339.8 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -51.8ZM229 -927.2L325.2 -927.2L197.3 -798.3L140.1 -798.3L229 -927.2Z"),
+    (395_f64, "M247.1 -51.8L339.8 -51.8L339.8 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -51.8ZM155.3 -927.2L241.2 -927.2L354 -798.3L297.4 -798.3L198.2 -877L99.1 -798.3L42.5 -798.3L155.3 -927.2Z"),
+    (395_f64, "M247.1 -51.8L339.8 -51.8L339.8 0L55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L339.8 -729L339.8 -676.8L247.1 -676.8L247.1 -51.8ZM103 -919.9Q126.5 -919.9 143.3 -902.8Q160.2 -885.7 160.2 -863.3Q160.2 -838.9 143.8 -822.5Q127.4 -806.2 103 -806.2Q79.1 -806.2 62.7 -822.5Q46.4 -838.9 46.4 -863.3Q46.4 -885.7 63.2 -902.8Q80.1 -919.9 103 -919.9ZM293.5 -919.9Q316.4 -919.9 333.3 -902.8Q350.1 -885.7 350.1 -863.3Q350.1 -838.9 333.7 -822.5Q317.4 -806.2 293.5 -806.2Q269 -806.2 252.7 -822.5Q236.3 -838.9 236.3 -863.3Q236.3 -885.7 253.2 -902.8Q270 -919.9 293.5 -919.9Z"),
+    (807.1_f64, "M251 -51.8L342.8 -51.8Q484.9 -51.8 561 -132.8Q637.2 -213.9 637.2 -365.2Q637.2 -516.6 561.3 -596.7Q485.4 -676.8 342.8 -676.8L251 -676.8L251 -412.1L415 -412.1L415 -351.1L251 -351.1L251 -51.8ZM60.1 0L60.1 -51.8L152.8 -51.8L152.8 -351.1L55.2 -351.1L55.2 -412.1L152.8 -412.1L152.8 -676.8L60.1 -676.8L60.1 -729L350.1 -729Q538.6 -729 643.8 -632.8Q749 -536.6 749 -365.2Q749 -193.4 643.6 -96.7Q538.1 0 350.1 0L60.1 0Z"),
+    (875_f64, "M48.8 0L48.8 -51.8L147 -51.8L147 -676.8L48.8 -676.8L48.8 -729L235.8 -729L672.9 -153.8L672.9 -676.8L575.2 -676.8L575.2 -729L831.1 -729L831.1 -676.8L732.9 -676.8L732.9 14.2L673.8 14.2L207 -600.1L207 -51.8L305.2 -51.8L305.2 0L48.8 0ZM431.6 -837.4L408.2 -851.1Q398.4 -857.4 388.4 -860.8Q378.4 -864.3 370.1 -864.3Q351.1 -864.3 338.4 -850.3Q325.7 -836.4 323.7 -814L276.9 -814.5Q277.8 -863.3 302.7 -892.1Q327.6 -920.9 369.1 -920.9Q386.7 -920.9 401.9 -916.5Q417 -912.1 443.8 -897L467.8 -884.3Q479 -877.9 489.7 -874.5Q500.5 -871.1 508.8 -871.1Q528.8 -871.1 540.3 -884.5Q551.8 -897.9 551.8 -920.9L599.1 -920.9Q598.1 -869.6 573 -841.8Q547.9 -814 502 -814Q469.2 -814 434.1 -835.9L431.6 -837.4Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2ZM376 -927.2L465.3 -798.3L408.2 -798.3L280.3 -927.2L376 -927.2Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2ZM440.9 -927.2L537.1 -927.2L409.2 -798.3L352.1 -798.3L440.9 -927.2Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2ZM367.2 -927.2L453.1 -927.2L565.9 -798.3L509.3 -798.3L410.2 -877L311 -798.3L254.4 -798.3L367.2 -927.2Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2ZM403.8 -837.4L380.4 -851.1Q370.6 -857.4 360.6 -860.8Q350.6 -864.3 342.3 -864.3Q323.2 -864.3 310.5 -850.3Q297.9 -836.4 295.9 -814L249 -814.5Q250 -863.3 274.9 -892.1Q299.8 -920.9 341.3 -920.9Q358.9 -920.9 374 -916.5Q389.2 -912.1 416 -897L439.9 -884.3Q451.2 -877.9 461.9 -874.5Q472.7 -871.1 481 -871.1Q501 -871.1 512.5 -884.5Q523.9 -897.9 523.9 -920.9L571.3 -920.9Q570.3 -869.6 545.2 -841.8Q520 -814 474.1 -814Q441.4 -814 406.2 -835.9L403.8 -837.4Z"),
+    (819.8_f64, "M410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -525.4 590.8 -607.7Q529.8 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -202.6 229 -120.4Q290 -38.1 410.2 -38.1ZM410.2 14.2Q335.9 14.2 273.7 -10.3Q211.4 -34.7 164.1 -82Q109.4 -136.7 82.8 -206.1Q56.2 -275.4 56.2 -363.8Q56.2 -452.1 82.8 -521.7Q109.4 -591.3 164.1 -646Q211.9 -693.8 273.4 -718Q335 -742.2 410.2 -742.2Q568.8 -742.2 666.5 -638.2Q764.2 -534.2 764.2 -363.8Q764.2 -276.4 737.3 -206.3Q710.4 -136.2 655.8 -82Q607.9 -34.2 546.4 -10Q484.9 14.2 410.2 14.2ZM314.9 -919.9Q338.4 -919.9 355.2 -902.8Q372.1 -885.7 372.1 -863.3Q372.1 -838.9 355.7 -822.5Q339.4 -806.2 314.9 -806.2Q291 -806.2 274.7 -822.5Q258.3 -838.9 258.3 -863.3Q258.3 -885.7 275.1 -902.8Q292 -919.9 314.9 -919.9ZM505.4 -919.9Q528.3 -919.9 545.2 -902.8Q562 -885.7 562 -863.3Q562 -838.9 545.7 -822.5Q529.3 -806.2 505.4 -806.2Q481 -806.2 464.6 -822.5Q448.2 -838.9 448.2 -863.3Q448.2 -885.7 465.1 -902.8Q481.9 -919.9 505.4 -919.9Z"),
+    (837.9_f64, "M700.2 -539.1L474.1 -313L700.2 -87.9L644 -33.2L418.9 -257.8L193.8 -33.2L138.2 -87.9L362.8 -313L138.2 -539.1L193.8 -594.2L418.9 -369.1L644 -594.2L700.2 -539.1Z"),
+    (819.8_f64, "M592.8 -605Q561 -647.9 515.9 -668.9Q470.7 -689.9 410.2 -689.9Q290 -689.9 229 -607.7Q168 -525.4 168 -363.8Q168 -305.7 175.8 -258.5Q183.6 -211.4 200.2 -171.9L592.8 -605ZM226.1 -125Q257.3 -81.5 303.2 -59.8Q349.1 -38.1 410.2 -38.1Q529.8 -38.1 590.8 -120.4Q651.9 -202.6 651.9 -363.8Q651.9 -420.9 643.8 -469Q635.7 -517.1 619.1 -557.1L226.1 -125ZM679.2 -623Q721.7 -570.3 742.9 -506.1Q764.2 -441.9 764.2 -363.8Q764.2 -195.3 666.3 -90.6Q568.4 14.2 410.2 14.2Q341.3 14.2 283.2 -6.8Q225.1 -27.8 175.8 -69.8L86.9 27.8L48.8 -5.9L140.1 -106.9Q97.7 -158.2 76.9 -221.9Q56.2 -285.6 56.2 -363.8Q56.2 -532.7 154.3 -637.5Q252.4 -742.2 410.2 -742.2Q479 -742.2 537.6 -721.4Q596.2 -700.7 643.1 -660.2L730 -756.8L769 -722.2L679.2 -623Z"),
+    (842.8_f64, "M140.1 -676.8L46.9 -676.8L46.9 -729L332 -729L332 -676.8L238.8 -676.8L238.8 -299.8Q238.8 -160.2 284.4 -105Q330.1 -49.8 441.9 -49.8Q553.7 -49.8 599.4 -105Q645 -160.2 645 -299.8L645 -676.8L551.8 -676.8L551.8 -729L797.9 -729L797.9 -676.8L705.1 -676.8L705.1 -290Q705.1 -123.5 641.1 -54.7Q577.1 14.2 423.8 14.2Q270.5 14.2 205.3 -55.2Q140.1 -124.5 140.1 -290L140.1 -676.8ZM406.7 -927.2L496.1 -798.3L439 -798.3L311 -927.2L406.7 -927.2Z"),
+    (842.8_f64, "M140.1 -676.8L46.9 -676.8L46.9 -729L332 -729L332 -676.8L238.8 -676.8L238.8 -299.8Q238.8 -160.2 284.4 -105Q330.1 -49.8 441.9 -49.8Q553.7 -49.8 599.4 -105Q645 -160.2 645 -299.8L645 -676.8L551.8 -676.8L551.8 -729L797.9 -729L797.9 -676.8L705.1 -676.8L705.1 -290Q705.1 -123.5 641.1 -54.7Q577.1 14.2 423.8 14.2Q270.5 14.2 205.3 -55.2Q140.1 -124.5 140.1 -290L140.1 -676.8ZM471.7 -927.2L567.9 -927.2L439.9 -798.3L382.8 -798.3L471.7 -927.2Z"),
+    (842.8_f64, "M140.1 -676.8L46.9 -676.8L46.9 -729L332 -729L332 -676.8L238.8 -676.8L238.8 -299.8Q238.8 -160.2 284.4 -105Q330.1 -49.8 441.9 -49.8Q553.7 -49.8 599.4 -105Q645 -160.2 645 -299.8L645 -676.8L551.8 -676.8L551.8 -729L797.9 -729L797.9 -676.8L705.1 -676.8L705.1 -290Q705.1 -123.5 641.1 -54.7Q577.1 14.2 423.8 14.2Q270.5 14.2 205.3 -55.2Q140.1 -124.5 140.1 -290L140.1 -676.8ZM397.9 -927.2L483.9 -927.2L596.7 -798.3L540 -798.3L440.9 -877L341.8 -798.3L285.2 -798.3L397.9 -927.2Z"),
+    (842.8_f64, "M140.1 -676.8L46.9 -676.8L46.9 -729L332 -729L332 -676.8L238.8 -676.8L238.8 -299.8Q238.8 -160.2 284.4 -105Q330.1 -49.8 441.9 -49.8Q553.7 -49.8 599.4 -105Q645 -160.2 645 -299.8L645 -676.8L551.8 -676.8L551.8 -729L797.9 -729L797.9 -676.8L705.1 -676.8L705.1 -290Q705.1 -123.5 641.1 -54.7Q577.1 14.2 423.8 14.2Q270.5 14.2 205.3 -55.2Q140.1 -124.5 140.1 -290L140.1 -676.8ZM345.7 -919.9Q369.1 -919.9 386 -902.8Q402.8 -885.7 402.8 -863.3Q402.8 -838.9 386.5 -822.5Q370.1 -806.2 345.7 -806.2Q321.8 -806.2 305.4 -822.5Q289.1 -838.9 289.1 -863.3Q289.1 -885.7 305.9 -902.8Q322.8 -919.9 345.7 -919.9ZM536.1 -919.9Q559.1 -919.9 575.9 -902.8Q592.8 -885.7 592.8 -863.3Q592.8 -838.9 576.4 -822.5Q560.1 -806.2 536.1 -806.2Q511.7 -806.2 495.4 -822.5Q479 -838.9 479 -863.3Q479 -885.7 495.8 -902.8Q512.7 -919.9 536.1 -919.9Z"),
+    (660.2_f64, "M189 0L189 -51.8L282.2 -51.8L282.2 -314L57.1 -676.8L-11.2 -676.8L-11.2 -729L259.8 -729L259.8 -676.8L174.8 -676.8L356.9 -381.8L539.1 -676.8L457 -676.8L457 -729L668.9 -729L668.9 -676.8L600.1 -676.8L380.9 -323.2L380.9 -51.8L474.1 -51.8L474.1 0L189 0ZM360.8 -927.2L457 -927.2L329.1 -798.3L272 -798.3L360.8 -927.2Z"),
+    (675.8_f64, "M247.1 -211.9L376 -211.9Q448.7 -211.9 486.8 -251.2Q524.9 -290.5 524.9 -365.2Q524.9 -439.5 486.8 -478.8Q448.7 -518.1 376 -518.1L247.1 -518.1L247.1 -211.9ZM55.2 0L55.2 -51.8L147.9 -51.8L147.9 -676.8L55.2 -676.8L55.2 -729L359.9 -729L359.9 -676.8L247.1 -676.8L247.1 -569.8L399.9 -569.8Q509.3 -569.8 573.2 -514.4Q637.2 -459 637.2 -365.2Q637.2 -270.5 573 -214.8Q508.8 -159.2 399.9 -159.2L247.1 -159.2L247.1 -51.8L359.9 -51.8L359.9 0L55.2 0Z"),
+    (668_f64, "M459 -590.8Q455.1 -648.9 421.1 -680.4Q387.2 -711.9 329.1 -711.9Q264.6 -711.9 234.9 -680.4Q205.1 -648.9 205.1 -581.1L205.1 0L28.8 0L28.8 -51.8L115.2 -51.8L115.2 -579.1Q115.2 -665 171.1 -712.4Q227.1 -759.8 329.1 -759.8Q433.1 -759.8 487.5 -709Q542 -658.2 547.9 -555.2L537.1 -555.2Q466.3 -555.2 425.5 -528.8Q384.8 -502.4 384.8 -457Q384.8 -431.6 397.9 -412.8Q411.1 -394 446.8 -371.1L490.2 -344.2Q565.9 -295.9 595 -254.4Q624 -212.9 624 -157.2Q624 -81.5 567.6 -33.7Q511.2 14.2 420.9 14.2Q379.4 14.2 338.4 5.1Q297.4 -3.9 258.8 -21L258.8 -134.8L312 -134.8Q314 -85.4 341.1 -59.8Q368.2 -34.2 418 -34.2Q472.2 -34.2 503.2 -63.7Q534.2 -93.3 534.2 -144Q534.2 -185.1 514.6 -213.6Q495.1 -242.2 438 -277.8L396 -304.2Q345.2 -335.9 323.5 -367.4Q301.8 -398.9 301.8 -439Q301.8 -498.5 343 -538.6Q384.3 -578.6 459 -590.8Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM207 -798.8L334 -615.2L276.9 -615.2L110.8 -798.8L207 -798.8Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM345.7 -799.8L442.9 -799.8L276.9 -616.2L219.7 -616.2L345.7 -799.8Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM236.8 -799.8L318.8 -799.8L433.6 -616.2L377 -616.2L277.8 -743.2L178.7 -616.2L122.1 -616.2L236.8 -799.8Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM275.9 -668.9L248 -695.8Q235.4 -707.5 227.3 -711.2Q219.2 -714.8 210 -714.8Q189.5 -714.8 177.7 -695.6Q166 -676.3 163.6 -639.2L116.7 -639.2Q117.7 -705.1 142.6 -741Q167.5 -776.9 211.9 -776.9Q230.5 -776.9 246.1 -770Q261.7 -763.2 279.8 -747.1L307.6 -720.2Q318.8 -709 327.1 -705.1Q335.4 -701.2 345.7 -701.2Q366.7 -701.2 378.4 -720.7Q390.1 -740.2 392.1 -776.9L439 -776.9Q438 -710.9 413.1 -675Q388.2 -639.2 343.8 -639.2Q325.2 -639.2 309.6 -646Q293.9 -652.8 275.9 -668.9Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM182.6 -766.1Q206.1 -766.1 222.9 -749.3Q239.7 -732.4 239.7 -709Q239.7 -684.1 223.6 -668Q207.5 -651.9 182.6 -651.9Q158.2 -651.9 142.1 -668Q126 -684.1 126 -709Q126 -732.4 142.8 -749.3Q159.7 -766.1 182.6 -766.1ZM373 -766.1Q396 -766.1 412.8 -749.3Q429.7 -732.4 429.7 -709Q429.7 -684.1 413.6 -668Q397.5 -651.9 373 -651.9Q348.1 -651.9 332 -668Q315.9 -684.1 315.9 -709Q315.9 -732.4 332.8 -749.3Q349.6 -766.1 373 -766.1Z"),
+    (596.2_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM487.8 -324.2L487.8 -51.8L567.9 -51.8L567.9 0L397.9 0L397.9 -56.2Q368.2 -20 329.1 -2.9Q290 14.2 237.8 14.2Q151.4 14.2 100.6 -31.7Q49.8 -77.6 49.8 -155.8Q49.8 -236.3 107.9 -280.8Q166 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.9 362.1 -452.4Q326.2 -484.9 261.2 -484.9Q207.5 -484.9 175.8 -460.4Q144 -436 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q375 -533.2 431.4 -479.7Q487.8 -426.3 487.8 -324.2ZM411.6 -744.1Q411.6 -688 372.8 -648.9Q334 -609.9 277.8 -609.9Q221.7 -609.9 182.9 -648.9Q144 -688 144 -744.1Q144 -800.3 182.9 -839.1Q221.7 -877.9 277.8 -877.9Q334 -877.9 372.8 -839.1Q411.6 -800.3 411.6 -744.1ZM352.1 -744.1Q352.1 -774.9 330.6 -796.4Q309.1 -817.9 277.8 -817.9Q246.1 -817.9 224.9 -796.6Q203.6 -775.4 203.6 -744.1Q203.6 -712.4 224.9 -691.2Q246.1 -669.9 277.8 -669.9Q309.1 -669.9 330.6 -691.4Q352.1 -712.9 352.1 -744.1Z"),
+    (939.9_f64, "M397.9 -163.1L397.9 -272.9L282.2 -272.9Q215.3 -272.9 182.6 -244.1Q149.9 -215.3 149.9 -155.8Q149.9 -101.6 183.1 -69.8Q216.3 -38.1 272.9 -38.1Q329.1 -38.1 363.5 -72.8Q397.9 -107.4 397.9 -163.1ZM451.2 -73.2Q410.6 -28.8 361.3 -7.3Q312 14.2 251 14.2Q154.8 14.2 102.3 -30.3Q49.8 -74.7 49.8 -155.8Q49.8 -236.3 108.2 -280.8Q166.5 -325.2 272 -325.2L397.9 -325.2L397.9 -360.8Q397.9 -419.4 362.8 -452.1Q327.6 -484.9 265.1 -484.9Q208.5 -484.9 176.3 -460.7Q144 -436.5 136.2 -388.2L89.8 -388.2L89.8 -493.2Q136.7 -513.2 180.9 -523.2Q225.1 -533.2 267.1 -533.2Q335.9 -533.2 383.1 -512Q430.2 -490.7 457 -446.8Q493.2 -489.7 540 -511.5Q586.9 -533.2 644 -533.2Q761.7 -533.2 824.7 -460.4Q887.7 -387.7 889.6 -250L502.9 -250L502.9 -246.1Q502.9 -141.1 542.2 -87.6Q581.5 -34.2 659.2 -34.2Q718.3 -34.2 756.1 -65.2Q793.9 -96.2 809.1 -157.2L880.9 -157.2Q859.9 -71.8 801.8 -28.8Q743.7 14.2 649.9 14.2Q590.3 14.2 539.8 -8.1Q489.3 -30.3 451.2 -73.2ZM784.2 -301.8Q781.2 -392.6 745.8 -438.7Q710.4 -484.9 644 -484.9Q582 -484.9 546.1 -438.5Q510.3 -392.1 502.9 -301.8L784.2 -301.8Z"),
+    (560.1_f64, "M514.2 -155.8Q495.1 -72.8 440.9 -29.3Q386.7 14.2 300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -384.3 118.7 -458.7Q187.5 -533.2 300.8 -533.2Q350.1 -533.2 398.9 -521.7Q447.8 -510.3 497.1 -486.8L497.1 -354L444.8 -354Q434.6 -422.4 400.1 -453.6Q365.7 -484.9 301.8 -484.9Q229 -484.9 191.9 -428.5Q154.8 -372.1 154.8 -259.8Q154.8 -147.5 191.7 -90.8Q228.5 -34.2 301.8 -34.2Q359.9 -34.2 394.5 -64.5Q429.2 -94.7 441.9 -155.8L514.2 -155.8ZM331.1 0Q362.3 31.2 377.7 58.6Q393.1 85.9 393.1 109.9Q393.1 149.4 362.3 171.1Q331.5 192.9 276.9 192.9Q256.3 192.9 234.9 189.9Q213.4 187 190.9 181.2L190.9 117.2Q210.9 125.5 230.2 129.6Q249.5 133.8 266.6 133.8Q294.4 133.8 310.5 120.8Q326.7 107.9 326.7 85.9Q326.7 72.3 315.9 50.8Q305.2 29.3 283.7 0L331.1 0Z"),
+    (591.8_f64, "M542 -250L154.8 -250L154.8 -246.1Q154.8 -141.1 194.3 -87.6Q233.9 -34.2 311 -34.2Q370.1 -34.2 408 -65.2Q445.8 -96.2 460.9 -157.2L533.2 -157.2Q511.7 -71.8 453.9 -28.8Q396 14.2 301.8 14.2Q188 14.2 118.9 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -382.8 117.7 -458Q185.5 -533.2 295.9 -533.2Q413.6 -533.2 476.6 -460.7Q539.6 -388.2 542 -250ZM436 -301.8Q433.1 -392.6 397.7 -438.7Q362.3 -484.9 295.9 -484.9Q233.9 -484.9 198.2 -438.5Q162.6 -392.1 154.8 -301.8L436 -301.8ZM225.1 -798.8L352.1 -615.2L294.9 -615.2L128.9 -798.8L225.1 -798.8Z"),
+    (591.8_f64, "M542 -250L154.8 -250L154.8 -246.1Q154.8 -141.1 194.3 -87.6Q233.9 -34.2 311 -34.2Q370.1 -34.2 408 -65.2Q445.8 -96.2 460.9 -157.2L533.2 -157.2Q511.7 -71.8 453.9 -28.8Q396 14.2 301.8 14.2Q188 14.2 118.9 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -382.8 117.7 -458Q185.5 -533.2 295.9 -533.2Q413.6 -533.2 476.6 -460.7Q539.6 -388.2 542 -250ZM436 -301.8Q433.1 -392.6 397.7 -438.7Q362.3 -484.9 295.9 -484.9Q233.9 -484.9 198.2 -438.5Q162.6 -392.1 154.8 -301.8L436 -301.8ZM363.8 -799.8L460.9 -799.8L294.9 -616.2L237.8 -616.2L363.8 -799.8Z"),
+    (591.8_f64, "M542 -250L154.8 -250L154.8 -246.1Q154.8 -141.1 194.3 -87.6Q233.9 -34.2 311 -34.2Q370.1 -34.2 408 -65.2Q445.8 -96.2 460.9 -157.2L533.2 -157.2Q511.7 -71.8 453.9 -28.8Q396 14.2 301.8 14.2Q188 14.2 118.9 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -382.8 117.7 -458Q185.5 -533.2 295.9 -533.2Q413.6 -533.2 476.6 -460.7Q539.6 -388.2 542 -250ZM436 -301.8Q433.1 -392.6 397.7 -438.7Q362.3 -484.9 295.9 -484.9Q233.9 -484.9 198.2 -438.5Q162.6 -392.1 154.8 -301.8L436 -301.8ZM254.9 -799.8L336.9 -799.8L451.7 -616.2L395 -616.2L295.9 -743.2L196.8 -616.2L140.1 -616.2L254.9 -799.8Z"),
+    (591.8_f64, "M542 -250L154.8 -250L154.8 -246.1Q154.8 -141.1 194.3 -87.6Q233.9 -34.2 311 -34.2Q370.1 -34.2 408 -65.2Q445.8 -96.2 460.9 -157.2L533.2 -157.2Q511.7 -71.8 453.9 -28.8Q396 14.2 301.8 14.2Q188 14.2 118.9 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -382.8 117.7 -458Q185.5 -533.2 295.9 -533.2Q413.6 -533.2 476.6 -460.7Q539.6 -388.2 542 -250ZM436 -301.8Q433.1 -392.6 397.7 -438.7Q362.3 -484.9 295.9 -484.9Q233.9 -484.9 198.2 -438.5Q162.6 -392.1 154.8 -301.8L436 -301.8ZM200.7 -766.1Q224.1 -766.1 241 -749.3Q257.8 -732.4 257.8 -709Q257.8 -684.1 241.7 -668Q225.6 -651.9 200.7 -651.9Q176.3 -651.9 160.2 -668Q144 -684.1 144 -709Q144 -732.4 160.9 -749.3Q177.7 -766.1 200.7 -766.1ZM391.1 -766.1Q414.1 -766.1 430.9 -749.3Q447.8 -732.4 447.8 -709Q447.8 -684.1 431.6 -668Q415.5 -651.9 391.1 -651.9Q366.2 -651.9 350.1 -668Q334 -684.1 334 -709Q334 -732.4 350.8 -749.3Q367.7 -766.1 391.1 -766.1Z"),
+    (319.8_f64, "M211.9 -51.8L296.9 -51.8L296.9 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -51.8ZM89.4 -798.8L216.3 -615.2L159.2 -615.2L-6.8 -798.8L89.4 -798.8Z"),
+    (319.8_f64, "M211.9 -51.8L296.9 -51.8L296.9 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -51.8ZM228 -799.8L325.2 -799.8L159.2 -616.2L102.1 -616.2L228 -799.8Z"),
+    (319.8_f64, "M211.9 -51.8L296.9 -51.8L296.9 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -51.8ZM119.1 -799.8L201.2 -799.8L315.9 -616.2L259.3 -616.2L160.2 -743.2L61 -616.2L4.4 -616.2L119.1 -799.8Z"),
+    (319.8_f64, "M211.9 -51.8L296.9 -51.8L296.9 0L36.1 0L36.1 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -51.8ZM64.9 -766.1Q88.4 -766.1 105.2 -749.3Q122.1 -732.4 122.1 -709Q122.1 -684.1 106 -668Q89.8 -651.9 64.9 -651.9Q40.5 -651.9 24.4 -668Q8.3 -684.1 8.3 -709Q8.3 -732.4 25.1 -749.3Q42 -766.1 64.9 -766.1ZM255.4 -766.1Q278.3 -766.1 295.2 -749.3Q312 -732.4 312 -709Q312 -684.1 295.9 -668Q279.8 -651.9 255.4 -651.9Q230.5 -651.9 214.4 -668Q198.2 -684.1 198.2 -709Q198.2 -732.4 215.1 -749.3Q231.9 -766.1 255.4 -766.1Z"),
+    (602.1_f64, "M400.9 -453.1Q378.9 -457.5 359.1 -459.7Q339.4 -461.9 319.8 -461.9Q235.4 -461.9 195.1 -409.7Q154.8 -357.4 154.8 -248Q154.8 -145.5 193.1 -89.8Q231.4 -34.2 300.8 -34.2Q371.1 -34.2 408.9 -90.1Q446.8 -146 446.8 -250Q446.8 -304.7 435.3 -356.2Q423.8 -407.7 400.9 -453.1ZM371.1 -638.2Q460.4 -556.6 506.1 -461.2Q551.8 -365.7 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2Q189 14.2 119.4 -59.8Q49.8 -133.8 49.8 -252.9Q49.8 -368.2 118.7 -439Q187.5 -509.8 298.8 -509.8Q315.9 -509.8 334.2 -508.3Q352.5 -506.8 377.9 -502.9Q367.2 -528.8 349.4 -554.9Q331.5 -581.1 307.1 -607.9L149.9 -535.2L131.8 -573.2L274.9 -639.2Q245.1 -667.5 206.8 -687.3Q168.5 -707 122.1 -717.8L130.9 -759.8Q189 -747.1 240 -724.1Q291 -701.2 335.9 -668L493.2 -741.2L511.2 -703.1L371.1 -638.2Z"),
+    (644_f64, "M41 0L41 -51.8L122.1 -51.8L122.1 -466.8L36.1 -466.8L36.1 -519L211.9 -519L211.9 -426.8Q236.8 -479.5 276.6 -506.3Q316.4 -533.2 369.1 -533.2Q455.1 -533.2 495.6 -483.9Q536.1 -434.6 536.1 -330.1L536.1 -51.8L616.2 -51.8L616.2 0L368.2 0L368.2 -51.8L445.8 -51.8L445.8 -301.8Q445.8 -397 422.4 -432.4Q398.9 -467.8 339.8 -467.8Q277.3 -467.8 244.6 -422.1Q211.9 -376.5 211.9 -289.1L211.9 -51.8L290 -51.8L290 0L41 0ZM319.8 -668.9L292 -695.8Q279.3 -707.5 271.2 -711.2Q263.2 -714.8 253.9 -714.8Q233.4 -714.8 221.7 -695.6Q210 -676.3 207.5 -639.2L160.6 -639.2Q161.6 -705.1 186.5 -741Q211.4 -776.9 255.9 -776.9Q274.4 -776.9 290 -770Q305.7 -763.2 323.7 -747.1L351.6 -720.2Q362.8 -709 371.1 -705.1Q379.4 -701.2 389.6 -701.2Q410.6 -701.2 422.4 -720.7Q434.1 -740.2 436 -776.9L482.9 -776.9Q481.9 -710.9 457 -675Q432.1 -639.2 387.7 -639.2Q369.1 -639.2 353.5 -646Q337.9 -652.8 319.8 -668.9Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2ZM230 -798.8L356.9 -615.2L299.8 -615.2L133.8 -798.8L230 -798.8Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2ZM368.7 -799.8L465.8 -799.8L299.8 -616.2L242.7 -616.2L368.7 -799.8Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2ZM259.8 -799.8L341.8 -799.8L456.5 -616.2L399.9 -616.2L300.8 -743.2L201.7 -616.2L145 -616.2L259.8 -799.8Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2ZM298.8 -668.9L271 -695.8Q258.3 -707.5 250.2 -711.2Q242.2 -714.8 232.9 -714.8Q212.4 -714.8 200.7 -695.6Q189 -676.3 186.5 -639.2L139.6 -639.2Q140.6 -705.1 165.5 -741Q190.4 -776.9 234.9 -776.9Q253.4 -776.9 269 -770Q284.7 -763.2 302.7 -747.1L330.6 -720.2Q341.8 -709 350.1 -705.1Q358.4 -701.2 368.7 -701.2Q389.6 -701.2 401.4 -720.7Q413.1 -740.2 415 -776.9L461.9 -776.9Q460.9 -710.9 436 -675Q411.1 -639.2 366.7 -639.2Q348.1 -639.2 332.5 -646Q316.9 -652.8 298.8 -668.9Z"),
+    (602.1_f64, "M300.8 -34.2Q373 -34.2 409.9 -91.3Q446.8 -148.4 446.8 -259.8Q446.8 -371.1 409.9 -428Q373 -484.9 300.8 -484.9Q228.5 -484.9 191.7 -428Q154.8 -371.1 154.8 -259.8Q154.8 -148.4 191.9 -91.3Q229 -34.2 300.8 -34.2ZM300.8 14.2Q187.5 14.2 118.7 -60.8Q49.8 -135.7 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q414.6 -533.2 483.2 -458.5Q551.8 -383.8 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2ZM205.6 -766.1Q229 -766.1 245.8 -749.3Q262.7 -732.4 262.7 -709Q262.7 -684.1 246.6 -668Q230.5 -651.9 205.6 -651.9Q181.2 -651.9 165 -668Q148.9 -684.1 148.9 -709Q148.9 -732.4 165.8 -749.3Q182.6 -766.1 205.6 -766.1ZM396 -766.1Q418.9 -766.1 435.8 -749.3Q452.6 -732.4 452.6 -709Q452.6 -684.1 436.5 -668Q420.4 -651.9 396 -651.9Q371.1 -651.9 355 -668Q338.9 -684.1 338.9 -709Q338.9 -732.4 355.7 -749.3Q372.6 -766.1 396 -766.1Z"),
+    (837.9_f64, "M354 -494.1Q354 -521.5 372.8 -540.3Q391.6 -559.1 418.9 -559.1Q445.8 -559.1 464.8 -540Q483.9 -521 483.9 -494.1Q483.9 -466.8 464.8 -448Q445.8 -429.2 418.9 -429.2Q391.1 -429.2 372.6 -447.8Q354 -466.3 354 -494.1ZM354 -132.8Q354 -160.2 372.8 -179.2Q391.6 -198.2 418.9 -198.2Q445.8 -198.2 464.8 -179.2Q483.9 -160.2 483.9 -132.8Q483.9 -106 464.8 -86.9Q445.8 -67.9 418.9 -67.9Q391.1 -67.9 372.6 -86.7Q354 -105.5 354 -132.8ZM106 -353L731.9 -353L731.9 -273.9L106 -273.9L106 -353Z"),
+    (602.1_f64, "M410.2 -429.2Q391.6 -457 364.3 -470.9Q336.9 -484.9 300.8 -484.9Q229 -484.9 191.9 -427.7Q154.8 -370.6 154.8 -259.8Q154.8 -224.6 158.4 -195.8Q162.1 -167 168.9 -143.1L410.2 -429.2ZM190.9 -92.8Q210.4 -63 237.5 -48.6Q264.6 -34.2 300.8 -34.2Q372.6 -34.2 409.7 -91.6Q446.8 -148.9 446.8 -259.8Q446.8 -296.9 443.1 -326.4Q439.5 -356 432.1 -380.9L190.9 -92.8ZM490.2 -450.2Q520.5 -414.1 536.1 -365.7Q551.8 -317.4 551.8 -259.8Q551.8 -135.7 483.2 -60.8Q414.6 14.2 300.8 14.2Q254.9 14.2 215.8 1.2Q176.8 -11.7 144 -38.1L74.2 45.9L37.1 15.1L109.9 -71.8Q80.1 -109.4 64.9 -156.5Q49.8 -203.6 49.8 -259.8Q49.8 -383.8 118.4 -458.5Q187 -533.2 300.8 -533.2Q346.7 -533.2 385.7 -520.8Q424.8 -508.3 456.1 -483.9L523.9 -564.9L561 -534.2L490.2 -450.2Z"),
+    (644_f64, "M354 -519L522 -519L522 -51.8L606.9 -51.8L606.9 0L432.1 0L432.1 -91.8Q407.2 -40 367.7 -12.9Q328.1 14.2 275.9 14.2Q189.5 14.2 148.7 -34.9Q107.9 -84 107.9 -189L107.9 -466.8L26.9 -466.8L26.9 -519L198.2 -519L198.2 -216.8Q198.2 -122.1 221.4 -86.9Q244.6 -51.8 304.2 -51.8Q366.7 -51.8 399.4 -97.7Q432.1 -143.6 432.1 -231L432.1 -466.8L354 -466.8L354 -519ZM214.4 -798.8L341.3 -615.2L284.2 -615.2L118.2 -798.8L214.4 -798.8Z"),
+    (644_f64, "M354 -519L522 -519L522 -51.8L606.9 -51.8L606.9 0L432.1 0L432.1 -91.8Q407.2 -40 367.7 -12.9Q328.1 14.2 275.9 14.2Q189.5 14.2 148.7 -34.9Q107.9 -84 107.9 -189L107.9 -466.8L26.9 -466.8L26.9 -519L198.2 -519L198.2 -216.8Q198.2 -122.1 221.4 -86.9Q244.6 -51.8 304.2 -51.8Q366.7 -51.8 399.4 -97.7Q432.1 -143.6 432.1 -231L432.1 -466.8L354 -466.8L354 -519ZM353 -799.8L450.2 -799.8L284.2 -616.2L227.1 -616.2L353 -799.8Z"),
+    (644_f64, "M354 -519L522 -519L522 -51.8L606.9 -51.8L606.9 0L432.1 0L432.1 -91.8Q407.2 -40 367.7 -12.9Q328.1 14.2 275.9 14.2Q189.5 14.2 148.7 -34.9Q107.9 -84 107.9 -189L107.9 -466.8L26.9 -466.8L26.9 -519L198.2 -519L198.2 -216.8Q198.2 -122.1 221.4 -86.9Q244.6 -51.8 304.2 -51.8Q366.7 -51.8 399.4 -97.7Q432.1 -143.6 432.1 -231L432.1 -466.8L354 -466.8L354 -519ZM244.1 -799.8L326.2 -799.8L440.9 -616.2L384.3 -616.2L285.2 -743.2L186 -616.2L129.4 -616.2L244.1 -799.8Z"),
+    (644_f64, "M354 -519L522 -519L522 -51.8L606.9 -51.8L606.9 0L432.1 0L432.1 -91.8Q407.2 -40 367.7 -12.9Q328.1 14.2 275.9 14.2Q189.5 14.2 148.7 -34.9Q107.9 -84 107.9 -189L107.9 -466.8L26.9 -466.8L26.9 -519L198.2 -519L198.2 -216.8Q198.2 -122.1 221.4 -86.9Q244.6 -51.8 304.2 -51.8Q366.7 -51.8 399.4 -97.7Q432.1 -143.6 432.1 -231L432.1 -466.8L354 -466.8L354 -519ZM189.9 -766.1Q213.4 -766.1 230.2 -749.3Q247.1 -732.4 247.1 -709Q247.1 -684.1 231 -668Q214.8 -651.9 189.9 -651.9Q165.5 -651.9 149.4 -668Q133.3 -684.1 133.3 -709Q133.3 -732.4 150.1 -749.3Q167 -766.1 189.9 -766.1ZM380.4 -766.1Q403.3 -766.1 420.2 -749.3Q437 -732.4 437 -709Q437 -684.1 420.9 -668Q404.8 -651.9 380.4 -651.9Q355.5 -651.9 339.4 -668Q323.2 -684.1 323.2 -709Q323.2 -732.4 340.1 -749.3Q356.9 -766.1 380.4 -766.1Z"),
+    (564.9_f64, "M215.8 95.2L250 8.8L56.2 -466.8L-2.9 -466.8L-2.9 -519L235.8 -519L235.8 -466.8L152.8 -466.8L298.8 -109.9L444.8 -466.8L367.2 -466.8L367.2 -519L562 -519L562 -466.8L503.9 -466.8L266.1 117.2Q241.7 177.7 211.9 200Q182.1 222.2 127.9 222.2Q105 222.2 80.8 218.3Q56.6 214.4 32.2 207L32.2 107.9L78.1 107.9Q81.1 141.1 95 155.5Q108.9 169.9 138.2 169.9Q165 169.9 181.4 155Q197.8 140.1 215.8 95.2ZM351.1 -799.8L448.2 -799.8L282.2 -616.2L225.1 -616.2L351.1 -799.8Z"),
+    (640.1_f64, "M205.1 -285.2L205.1 -233.9Q205.1 -140.1 241 -91.1Q276.9 -42 345.2 -42Q414.1 -42 449.5 -97.2Q484.9 -152.3 484.9 -259.8Q484.9 -367.7 449.5 -422.4Q414.1 -477.1 345.2 -477.1Q276.9 -477.1 241 -427.7Q205.1 -378.4 205.1 -285.2ZM115.2 -708L28.8 -708L28.8 -759.8L205.1 -759.8L205.1 -438Q231.4 -486.8 272.2 -510Q313 -533.2 373 -533.2Q468.8 -533.2 529.3 -457.5Q589.8 -381.8 589.8 -259.8Q589.8 -137.7 529.3 -61.8Q468.8 14.2 373 14.2Q313 14.2 272.2 -9Q231.4 -32.2 205.1 -81.1L205.1 155.8L290 155.8L290 208L28.8 208L28.8 155.8L115.2 155.8L115.2 -708Z"),
+    (564.9_f64, "M215.8 95.2L250 8.8L56.2 -466.8L-2.9 -466.8L-2.9 -519L235.8 -519L235.8 -466.8L152.8 -466.8L298.8 -109.9L444.8 -466.8L367.2 -466.8L367.2 -519L562 -519L562 -466.8L503.9 -466.8L266.1 117.2Q241.7 177.7 211.9 200Q182.1 222.2 127.9 222.2Q105 222.2 80.8 218.3Q56.6 214.4 32.2 207L32.2 107.9L78.1 107.9Q81.1 141.1 95 155.5Q108.9 169.9 138.2 169.9Q165 169.9 181.4 155Q197.8 140.1 215.8 95.2ZM188 -766.1Q211.4 -766.1 228.3 -749.3Q245.1 -732.4 245.1 -709Q245.1 -684.1 229 -668Q212.9 -651.9 188 -651.9Q163.6 -651.9 147.5 -668Q131.3 -684.1 131.3 -709Q131.3 -732.4 148.2 -749.3Q165 -766.1 188 -766.1ZM378.4 -766.1Q401.4 -766.1 418.2 -749.3Q435.1 -732.4 435.1 -709Q435.1 -684.1 418.9 -668Q402.8 -651.9 378.4 -651.9Q353.5 -651.9 337.4 -668Q321.3 -684.1 321.3 -709Q321.3 -732.4 338.1 -749.3Q355 -766.1 378.4 -766.1Z"),
+];
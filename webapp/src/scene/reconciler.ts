@@ -42,6 +42,8 @@ export interface SongProps extends Stylable {
   width: number;
   /** In mm */
   height: number;
+  /** From 0 (large) to 8 (small). Defaults to 3. */
+  rastalSize?: number;
   title: string;
   author: string;
 }
@@ -138,6 +140,7 @@ function createInstance(
         : undefined,
     );
     container.song_set_size(entity, spec.props.width, spec.props.height);
+    container.song_set_rastal_size(entity, spec.props.rastalSize ?? 3);
     container.song_set_title(entity, title, getTextWidth(7, title));
     container.song_set_author(entity, author, getTextWidth(5, author));
   } else if (spec.type === "staff") {
@@ -321,6 +324,13 @@ const Reconciler = ReactReconciler({
         );
       }
 
+      if (oldProps.rastalSize !== newProps.rastalSize) {
+        instance.container.song_set_rastal_size(
+          instance.entity,
+          newProps.rastalSize ?? 3,
+        );
+      }
+
       if (type === "song" && oldProps.title !== newProps.title) {
         const title = newProps.title || "Untitled";
         instance.container.song_set_title(